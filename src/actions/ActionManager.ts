@@ -2,6 +2,9 @@ import { BaseAction } from './BaseAction';
 import { PrettierAction } from './PrettierAction';
 import { EslintAction } from './EslintAction';
 import { HuskyAction } from './HuskyAction';
+import { CargoDenyAction } from './CargoDenyAction';
+import { RustfmtAction } from './RustfmtAction';
+import { ClippyAction } from './ClippyAction';
 import { SetupRecommendation, SetupOptions } from '../types';
 import chalk from 'chalk';
 import {
@@ -18,6 +21,9 @@ export class ActionManager {
     this.registerAction(new PrettierAction());
     this.registerAction(new EslintAction());
     this.registerAction(new HuskyAction());
+    this.registerAction(new CargoDenyAction());
+    this.registerAction(new RustfmtAction());
+    this.registerAction(new ClippyAction());
   }
 
   private registerAction(action: BaseAction): void {
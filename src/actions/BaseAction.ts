@@ -1,6 +1,20 @@
 import { SetupOptions } from '../types';
 import * as path from 'path';
 
+/**
+ * Files touched by an action: `backups` map an edited file to its copy
+ * made by createBackup, `created` lists files that did not exist before
+ */
+export interface ChangeJournal {
+  backups: Array<{ file: string; backup: string }>;
+  created: string[];
+}
+
+/**
+ * Every change an action makes is recorded in
+ * `.woaru/backups/<action>.json`, so that rollback restores exactly the
+ * files this action touched.
+ */
 export abstract class BaseAction {
   abstract name: string;
   abstract description: string;
@@ -210,19 +224,54 @@ export abstract class BaseAction {
     }
   }
 
-  protected async createBackup(filePath: string): Promise<string> {
+  protected createJournal(): ChangeJournal {
+    return { backups: [], created: [] };
+  }
+
+  /**
+   * Back up a file before it is written (or remember that it is new)
+   */
+  protected async trackFile(
+    journal: ChangeJournal,
+    root: string,
+    filePath: string,
+    skipBackup = false
+  ): Promise<void> {
+    if (!(await this.fileExists(filePath))) {
+      journal.created.push(filePath);
+    } else if (!skipBackup) {
+      journal.backups.push({
+        file: filePath,
+        backup: await this.createBackup(filePath, root),
+      });
+    }
+  }
+
+  /**
+   * Copy a file to `.woaru/backups/<action>/<timestamp>/` below `root`,
+   * keeping its path relative to `root`
+   * @returns The backup path, to be recorded in the change journal
+   */
+  protected async createBackup(
+    filePath: string,
+    root: string
+  ): Promise<string> {
     const fs = await import('fs-extra');
 
     try {
       const sanitizedPath = this.sanitizePath(filePath);
+      const relative = path.relative(this.sanitizePath(root), sanitizedPath);
       const timestamp = new Date().toISOString().replace(/[:.]/g, '-');
-      const backupPath = `${sanitizedPath}.woaru-backup-${timestamp}`;
+      const backupPath = path.join(
+        this.getBackupDir(root),
+        timestamp,
+        relative.startsWith('..') || path.isAbsolute(relative)
+          ? path.basename(sanitizedPath)
+          : relative
+      );
 
       if (await fs.pathExists(sanitizedPath)) {
         await fs.copy(sanitizedPath, backupPath);
-
-        // Limit number of backup files (keep last 5)
-        await this.cleanupOldBackups(sanitizedPath);
       }
 
       return backupPath;
@@ -231,26 +280,64 @@ export abstract class BaseAction {
     }
   }
 
-  private async cleanupOldBackups(originalPath: string): Promise<void> {
+  /**
+   * Persist the journal, merging it with entries from earlier runs
+   */
+  protected async saveJournal(
+    root: string,
+    journal: ChangeJournal
+  ): Promise<void> {
+    const journalPath = this.getJournalPath(root);
+    const previous = (await this.fileExists(journalPath))
+      ? await this.readJsonFile<ChangeJournal>(journalPath)
+      : null;
+
+    await this.writeJsonFile(journalPath, {
+      backups: [...(previous?.backups || []), ...journal.backups],
+      created: [...(previous?.created || []), ...journal.created],
+    });
+  }
+
+  /**
+   * Undo every change recorded in the journal (newest first)
+   * @returns Number of restored or removed files, or null without a journal
+   */
+  protected async restoreJournal(root: string): Promise<number | null> {
     const fs = await import('fs-extra');
-    const path = await import('path');
+    const journalPath = this.getJournalPath(root);
+    if (!(await this.fileExists(journalPath))) {
+      return null;
+    }
 
-    try {
-      const dir = path.dirname(originalPath);
-      const basename = path.basename(originalPath);
-      const files = await fs.readdir(dir);
-
-      const backupFiles = files
-        .filter(file => file.startsWith(`${basename}.woaru-backup-`))
-        .sort()
-        .reverse(); // Most recent first
-
-      // Keep only the 5 most recent backups
-      for (let i = 5; i < backupFiles.length; i++) {
-        await fs.remove(path.join(dir, backupFiles[i]));
+    const journal = await this.readJsonFile<ChangeJournal>(journalPath);
+    if (!journal) {
+      return null;
+    }
+
+    let restored = 0;
+    for (const { file, backup } of [...journal.backups].reverse()) {
+      if (await fs.pathExists(backup)) {
+        await fs.move(backup, file, { overwrite: true });
+        restored++;
       }
-    } catch (error) {
-      console.warn('Failed to cleanup old backups:', error);
     }
+    for (const file of journal.created) {
+      if (await fs.pathExists(file)) {
+        await fs.remove(file);
+        restored++;
+      }
+    }
+
+    await fs.remove(journalPath);
+    await fs.remove(this.getBackupDir(root));
+    return restored;
+  }
+
+  private getJournalPath(root: string): string {
+    return path.join(root, '.woaru', 'backups', `${this.name}.json`);
+  }
+
+  private getBackupDir(root: string): string {
+    return path.join(root, '.woaru', 'backups', this.name);
   }
 }
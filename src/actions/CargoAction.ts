@@ -0,0 +1,86 @@
+import { BaseAction, ChangeJournal } from './BaseAction';
+import { CargoWorkspaceResolver } from '../rust/CargoWorkspaceResolver';
+import * as path from 'path';
+
+/**
+ * Base for actions that edit Cargo projects.
+ *
+ * Unlike the npm actions (which only ever create a handful of known files),
+ * Rust actions may edit several manifests and, via `cargo fmt` or
+ * `cargo clippy --fix`, arbitrary source files, which are backed up
+ * before the command runs.
+ */
+export abstract class CargoAction extends BaseAction {
+  constructor(
+    protected resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {
+    super();
+  }
+
+  protected async isCargoProject(projectPath: string): Promise<boolean> {
+    return this.fileExists(path.join(projectPath, 'Cargo.toml'));
+  }
+
+  protected async getWorkspaceRoot(projectPath: string): Promise<string> {
+    const cargoPackage = await this.resolver.resolveForFile(
+      path.join(projectPath, 'Cargo.toml')
+    );
+    return cargoPackage?.workspaceRoot || path.resolve(projectPath);
+  }
+
+  /**
+   * Run a cargo command that may rewrite Rust sources (cargo fmt, clippy
+   * --fix). All sources are backed up first; backups of files the command
+   * left unchanged are discarded again.
+   * @returns The command result plus the number of files it changed
+   */
+  protected async runCargoWithBackups(
+    workspaceRoot: string,
+    args: string[],
+    journal: ChangeJournal,
+    skipBackup = false
+  ): Promise<{ success: boolean; changed: number; error?: string }> {
+    const fs = await import('fs-extra');
+    const sources = await this.findRustSources(workspaceRoot);
+
+    const snapshots = new Map<string, Buffer>();
+    for (const source of sources) {
+      snapshots.set(source, await fs.readFile(source));
+    }
+
+    const result = await this.runCommand('cargo', args, workspaceRoot, {
+      timeout: 300000,
+    });
+
+    let changed = 0;
+    for (const [source, before] of snapshots) {
+      const after = await fs.readFile(source).catch(() => null);
+      if (after && before.equals(after)) continue;
+
+      changed++;
+      if (!skipBackup) {
+        // createBackup copies the current file, so restore the snapshot
+        // into the backup afterwards
+        const backup = await this.createBackup(source, workspaceRoot);
+        await fs.writeFile(backup, before);
+        journal.backups.push({ file: source, backup });
+      }
+    }
+
+    return {
+      success: result.success,
+      changed,
+      error: result.success ? undefined : result.error || result.output,
+    };
+  }
+
+  private async findRustSources(workspaceRoot: string): Promise<string[]> {
+    const glob = await import('glob');
+    return glob.glob('**/*.rs', {
+      cwd: workspaceRoot,
+      absolute: true,
+      nodir: true,
+      ignore: ['**/target/**', '**/node_modules/**', '**/.git/**'],
+    });
+  }
+}
@@ -0,0 +1,103 @@
+import { BaseAction } from './BaseAction';
+import { SetupOptions } from '../types';
+import { t } from '../config/i18n';
+import { CargoWorkspaceResolver } from '../rust/CargoWorkspaceResolver';
+import {
+  DenyConfigGenerator,
+  renderDenyToml,
+} from '../rust/DenyConfigGenerator';
+import * as path from 'path';
+
+export class CargoDenyAction extends BaseAction {
+  name = 'cargo-deny';
+  description = 'Generate a deny.toml from the licenses used in Cargo.lock';
+
+  constructor(
+    private generator: DenyConfigGenerator = new DenyConfigGenerator(),
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {
+    super();
+  }
+
+  async canExecute(projectPath: string): Promise<boolean> {
+    if (!(await this.fileExists(path.join(projectPath, 'Cargo.toml')))) {
+      return false;
+    }
+
+    const workspaceRoot = await this.getWorkspaceRoot(projectPath);
+    return !(await this.fileExists(path.join(workspaceRoot, 'deny.toml')));
+  }
+
+  async execute(projectPath: string, options: SetupOptions): Promise<boolean> {
+    try {
+      if (options.dryRun) {
+        console.log(t('actions.cargo_deny.dry_run_create_config'));
+        return true;
+      }
+
+      // cargo-deny reads deny.toml next to the workspace Cargo.lock
+      const workspaceRoot = await this.getWorkspaceRoot(projectPath);
+      const lockfilePath = path.join(workspaceRoot, 'Cargo.lock');
+      if (!(await this.fileExists(lockfilePath))) {
+        console.log(t('actions.cargo_deny.lockfile_missing'));
+        return false;
+      }
+
+      const denyTomlPath = path.join(workspaceRoot, 'deny.toml');
+      const licenses = await this.generator.collectLicenses(lockfilePath);
+      const journal = this.createJournal();
+      await this.trackFile(
+        journal,
+        workspaceRoot,
+        denyTomlPath,
+        options.skipBackup
+      );
+      const fs = await import('fs-extra');
+      await fs.writeFile(denyTomlPath, renderDenyToml(licenses));
+      await this.saveJournal(workspaceRoot, journal);
+
+      if (licenses.unknown.length > 0) {
+        console.log(
+          t('actions.cargo_deny.unknown_licenses', {
+            count: licenses.unknown.length,
+          })
+        );
+      }
+      console.log(
+        t('actions.cargo_deny.setup_success', {
+          count: licenses.licenses.size,
+        })
+      );
+      return true;
+    } catch (error) {
+      console.error(t('actions.cargo_deny.setup_failed'), error);
+      return false;
+    }
+  }
+
+  async rollback(projectPath: string): Promise<boolean> {
+    try {
+      // Only a deny.toml created (or backed up) by execute is touched
+      const restored = await this.restoreJournal(
+        await this.getWorkspaceRoot(projectPath)
+      );
+      if (restored === null) {
+        console.log(t('actions.cargo_deny.nothing_to_rollback'));
+        return false;
+      }
+
+      console.log(t('actions.cargo_deny.rollback_success'));
+      return true;
+    } catch (error) {
+      console.error(t('actions.cargo_deny.rollback_failed'), error);
+      return false;
+    }
+  }
+
+  private async getWorkspaceRoot(projectPath: string): Promise<string> {
+    const cargoPackage = await this.resolver.resolveForFile(
+      path.join(projectPath, 'Cargo.toml')
+    );
+    return cargoPackage?.workspaceRoot || path.resolve(projectPath);
+  }
+}
@@ -0,0 +1,201 @@
+import { CargoAction } from './CargoAction';
+import { SetupOptions } from '../types';
+import { t } from '../config/i18n';
+import { CargoManifestReader } from '../rust/CargoManifestReader';
+import {
+  appendTomlTables,
+  getRustVersion,
+  hasLintsTable,
+  INHERIT_WORKSPACE_LINTS,
+  renderLintTables,
+  supportsLintsTable,
+} from '../rust/CargoManifestEditor';
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
+import * as path from 'path';
+
+const CLIPPY_CONFIG_FILES = ['clippy.toml', '.clippy.toml'];
+
+const CLIPPY_CONFIG = [
+  '# Generated by WOARU',
+  '# Lint levels live in the [lints] table of Cargo.toml',
+  'allow-dbg-in-tests = true',
+  'allow-unwrap-in-tests = true',
+  'allow-expect-in-tests = true',
+  '',
+].join('\n');
+
+export class ClippyAction extends CargoAction {
+  name = 'clippy';
+  description = 'Configure clippy lints and optionally apply clippy fixes';
+
+  async canExecute(projectPath: string): Promise<boolean> {
+    if (!(await this.isCargoProject(projectPath))) {
+      return false;
+    }
+
+    const workspaceRoot = await this.getWorkspaceRoot(projectPath);
+    if (!(await this.hasClippyConfig(workspaceRoot))) {
+      return true;
+    }
+
+    const manifest = await this.resolver.loadManifest(
+      path.join(workspaceRoot, 'Cargo.toml')
+    );
+    return (
+      manifest !== null &&
+      !hasLintsTable(manifest) &&
+      supportsLintsTable(manifest)
+    );
+  }
+
+  async execute(projectPath: string, options: SetupOptions): Promise<boolean> {
+    try {
+      const workspaceRoot = await this.getWorkspaceRoot(projectPath);
+      const hasConfig = await this.hasClippyConfig(workspaceRoot);
+      const edits = await this.planLintEdits(workspaceRoot);
+
+      if (options.dryRun) {
+        if (!hasConfig) {
+          console.log(t('actions.clippy.dry_run_create_config'));
+        }
+        if (edits.length > 0) {
+          console.log(
+            t('actions.clippy.dry_run_lints', { count: edits.length })
+          );
+        }
+        if (options.fix) {
+          console.log(t('actions.clippy.dry_run_fix'));
+        }
+        return true;
+      }
+
+      const fs = await import('fs-extra');
+      const journal = this.createJournal();
+
+      if (!hasConfig) {
+        const configPath = path.join(workspaceRoot, 'clippy.toml');
+        await this.trackFile(
+          journal,
+          workspaceRoot,
+          configPath,
+          options.skipBackup
+        );
+        await fs.writeFile(configPath, CLIPPY_CONFIG);
+      }
+
+      for (const { manifestPath, tables } of edits) {
+        const content = await fs.readFile(manifestPath, 'utf-8');
+        await this.trackFile(
+          journal,
+          workspaceRoot,
+          manifestPath,
+          options.skipBackup
+        );
+        await fs.writeFile(manifestPath, appendTomlTables(content, tables));
+        console.log(
+          t('actions.clippy.lints_added', {
+            file: path.relative(workspaceRoot, manifestPath),
+          })
+        );
+      }
+      this.resolver.invalidate();
+
+      if (options.fix) {
+        const result = await this.runCargoWithBackups(
+          workspaceRoot,
+          ['clippy', '--fix', '--allow-dirty', '--workspace', '--all-targets'],
+          journal,
+          options.skipBackup
+        );
+        if (result.success) {
+          console.log(
+            t('actions.clippy.fix_applied', { count: result.changed })
+          );
+        } else {
+          console.log(t('actions.clippy.fix_failed', { error: result.error }));
+        }
+      }
+
+      await this.saveJournal(workspaceRoot, journal);
+      console.log(t('actions.clippy.setup_success'));
+      return true;
+    } catch (error) {
+      console.error(t('actions.clippy.setup_failed'), error);
+      return false;
+    }
+  }
+
+  async rollback(projectPath: string): Promise<boolean> {
+    try {
+      const workspaceRoot = await this.getWorkspaceRoot(projectPath);
+      const restored = await this.restoreJournal(workspaceRoot);
+      if (restored === null) {
+        console.log(t('actions.clippy.nothing_to_rollback'));
+        return false;
+      }
+
+      this.resolver.invalidate();
+      console.log(t('actions.clippy.rollback_success', { count: restored }));
+      return true;
+    } catch (error) {
+      console.error(t('actions.clippy.rollback_failed'), error);
+      return false;
+    }
+  }
+
+  private async hasClippyConfig(workspaceRoot: string): Promise<boolean> {
+    for (const file of CLIPPY_CONFIG_FILES) {
+      if (await this.fileExists(path.join(workspaceRoot, file))) {
+        return true;
+      }
+    }
+    return false;
+  }
+
+  /**
+   * Decide which manifests get a [lints] table. Workspaces define the lints
+   * once in [workspace.lints] and let every member inherit them; manifests
+   * that already configure lints are left alone.
+   */
+  private async planLintEdits(
+    workspaceRoot: string
+  ): Promise<Array<{ manifestPath: string; tables: string }>> {
+    const rootPath = path.join(workspaceRoot, 'Cargo.toml');
+    const root = await this.resolver.loadManifest(rootPath);
+    if (!root || hasLintsTable(root)) {
+      return [];
+    }
+    if (!supportsLintsTable(root)) {
+      console.log(
+        t('actions.clippy.lints_skipped_msrv', {
+          version: getRustVersion(root),
+        })
+      );
+      return [];
+    }
+
+    if (!isTomlTable(root.workspace)) {
+      return [{ manifestPath: rootPath, tables: renderLintTables('package') }];
+    }
+
+    const rootTables = [renderLintTables('workspace')];
+    if (isTomlTable(root.package)) {
+      rootTables.push(INHERIT_WORKSPACE_LINTS);
+    }
+    const edits = [{ manifestPath: rootPath, tables: rootTables.join('\n\n') }];
+
+    const members = await new CargoManifestReader(
+      this.resolver
+    ).collectPackageManifests(rootPath, root);
+    for (const [manifestPath, member] of members) {
+      if (manifestPath === rootPath || !this.canInherit(member)) continue;
+      edits.push({ manifestPath, tables: INHERIT_WORKSPACE_LINTS });
+    }
+
+    return edits;
+  }
+
+  private canInherit(member: TomlTable): boolean {
+    return !hasLintsTable(member) && supportsLintsTable(member);
+  }
+}
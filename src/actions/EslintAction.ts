@@ -30,11 +30,16 @@ export class EslintAction extends BaseAction {
         return true;
       }
 
-      // Create backups
-      if (!options.skipBackup) {
-        await this.createBackup(packageJsonPath);
-        await this.createBackup(eslintrcPath);
+      // Record backups of the files this action writes
+      const journal = this.createJournal();
+      for (const file of [
+        packageJsonPath,
+        path.join(projectPath, 'package-lock.json'),
+        eslintrcPath,
+      ]) {
+        await this.trackFile(journal, projectPath, file, options.skipBackup);
       }
+      await this.saveJournal(projectPath, journal);
 
       // Detect project characteristics
       const packageJson = await this.readJsonFile<PackageJson>(packageJsonPath);
@@ -156,26 +161,9 @@ export class EslintAction extends BaseAction {
 
   async rollback(projectPath: string): Promise<boolean> {
     try {
-      const fs = await import('fs-extra');
-      const glob = await import('glob');
-
-      // Find backup files
-      const backupFiles = await glob.glob(
-        path.join(projectPath, '*.woaru-backup-*')
-      );
-
-      for (const backupFile of backupFiles) {
-        const originalFile = backupFile.replace(/\.woaru-backup-\d+$/, '');
-        await fs.move(backupFile, originalFile, { overwrite: true });
-      }
-
-      // Remove created files
-      const filesToRemove = ['.eslintrc.json'];
-      for (const file of filesToRemove) {
-        const filePath = path.join(projectPath, file);
-        if (await fs.pathExists(filePath)) {
-          await fs.remove(filePath);
-        }
+      if ((await this.restoreJournal(projectPath)) === null) {
+        console.log(t('actions.eslint.nothing_to_rollback'));
+        return false;
       }
 
       console.log(t('actions.eslint.rollback_success'));
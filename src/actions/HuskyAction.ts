@@ -31,10 +31,16 @@ export class HuskyAction extends BaseAction {
         return true;
       }
 
-      // Create backups
-      if (!options.skipBackup) {
-        await this.createBackup(packageJsonPath);
+      // Record backups of the files this action writes
+      const journal = this.createJournal();
+      for (const file of [
+        packageJsonPath,
+        path.join(projectPath, 'package-lock.json'),
+        huskyDir,
+      ]) {
+        await this.trackFile(journal, projectPath, file, options.skipBackup);
       }
+      await this.saveJournal(projectPath, journal);
 
       // Install packages
       const packages = ['husky', 'lint-staged'];
@@ -134,23 +140,9 @@ export class HuskyAction extends BaseAction {
 
   async rollback(projectPath: string): Promise<boolean> {
     try {
-      const fs = await import('fs-extra');
-      const glob = await import('glob');
-
-      // Find backup files
-      const backupFiles = await glob.glob(
-        path.join(projectPath, '*.woaru-backup-*')
-      );
-
-      for (const backupFile of backupFiles) {
-        const originalFile = backupFile.replace(/\.woaru-backup-\d+$/, '');
-        await fs.move(backupFile, originalFile, { overwrite: true });
-      }
-
-      // Remove .husky directory
-      const huskyDir = path.join(projectPath, '.husky');
-      if (await fs.pathExists(huskyDir)) {
-        await fs.remove(huskyDir);
+      if ((await this.restoreJournal(projectPath)) === null) {
+        console.log(t('actions.husky.nothing_to_rollback'));
+        return false;
       }
 
       console.log(t('actions.husky.rollback_success'));
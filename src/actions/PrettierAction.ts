@@ -33,10 +33,17 @@ export class PrettierAction extends BaseAction {
         return true;
       }
 
-      // Create backups
-      if (!options.skipBackup) {
-        await this.createBackup(packageJsonPath);
+      // Record backups of the files this action writes
+      const journal = this.createJournal();
+      for (const file of [
+        packageJsonPath,
+        path.join(projectPath, 'package-lock.json'),
+        prettierrcPath,
+        prettierIgnorePath,
+      ]) {
+        await this.trackFile(journal, projectPath, file, options.skipBackup);
       }
+      await this.saveJournal(projectPath, journal);
 
       // Detect if Tailwind is present
       const packageJson = await this.readJsonFile<PackageJson>(packageJsonPath);
@@ -121,26 +128,9 @@ Thumbs.db
 
   async rollback(projectPath: string): Promise<boolean> {
     try {
-      const fs = await import('fs-extra');
-      const glob = await import('glob');
-
-      // Find backup files
-      const backupFiles = await glob.glob(
-        path.join(projectPath, '*.woaru-backup-*')
-      );
-
-      for (const backupFile of backupFiles) {
-        const originalFile = backupFile.replace(/\.woaru-backup-\d+$/, '');
-        await fs.move(backupFile, originalFile, { overwrite: true });
-      }
-
-      // Remove created files
-      const filesToRemove = ['.prettierrc', '.prettierignore'];
-      for (const file of filesToRemove) {
-        const filePath = path.join(projectPath, file);
-        if (await fs.pathExists(filePath)) {
-          await fs.remove(filePath);
-        }
+      if ((await this.restoreJournal(projectPath)) === null) {
+        console.log(t('actions.prettier.nothing_to_rollback'));
+        return false;
       }
 
       console.log(t('actions.prettier.rollback_success'));
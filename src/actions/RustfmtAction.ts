@@ -0,0 +1,126 @@
+import { CargoAction } from './CargoAction';
+import { SetupOptions } from '../types';
+import { t } from '../config/i18n';
+import { isTomlTable } from '../utils/tomlParser';
+import * as path from 'path';
+
+const RUSTFMT_CONFIG_FILES = ['rustfmt.toml', '.rustfmt.toml'];
+
+export class RustfmtAction extends CargoAction {
+  name = 'rustfmt';
+  description = 'Configure rustfmt and optionally format the workspace';
+
+  async canExecute(projectPath: string): Promise<boolean> {
+    if (!(await this.isCargoProject(projectPath))) {
+      return false;
+    }
+
+    const workspaceRoot = await this.getWorkspaceRoot(projectPath);
+    for (const file of RUSTFMT_CONFIG_FILES) {
+      if (await this.fileExists(path.join(workspaceRoot, file))) {
+        return false;
+      }
+    }
+    return true;
+  }
+
+  async execute(projectPath: string, options: SetupOptions): Promise<boolean> {
+    try {
+      if (options.dryRun) {
+        console.log(t('actions.rustfmt.dry_run_create_config'));
+        if (options.fix) {
+          console.log(t('actions.rustfmt.dry_run_format'));
+        }
+        return true;
+      }
+
+      const workspaceRoot = await this.getWorkspaceRoot(projectPath);
+      const journal = this.createJournal();
+      const configPath = path.join(workspaceRoot, 'rustfmt.toml');
+
+      await this.trackFile(
+        journal,
+        workspaceRoot,
+        configPath,
+        options.skipBackup
+      );
+      const fs = await import('fs-extra');
+      await fs.writeFile(
+        configPath,
+        this.renderConfig(await this.getEdition(workspaceRoot))
+      );
+
+      if (options.fix) {
+        const result = await this.runCargoWithBackups(
+          workspaceRoot,
+          ['fmt', '--all'],
+          journal,
+          options.skipBackup
+        );
+        if (result.success) {
+          console.log(
+            t('actions.rustfmt.formatted', { count: result.changed })
+          );
+        } else {
+          console.log(
+            t('actions.rustfmt.format_failed', { error: result.error })
+          );
+        }
+      }
+
+      await this.saveJournal(workspaceRoot, journal);
+      console.log(t('actions.rustfmt.setup_success'));
+      return true;
+    } catch (error) {
+      console.error(t('actions.rustfmt.setup_failed'), error);
+      return false;
+    }
+  }
+
+  async rollback(projectPath: string): Promise<boolean> {
+    try {
+      const restored = await this.restoreJournal(
+        await this.getWorkspaceRoot(projectPath)
+      );
+      if (restored === null) {
+        console.log(t('actions.rustfmt.nothing_to_rollback'));
+        return false;
+      }
+
+      console.log(t('actions.rustfmt.rollback_success', { count: restored }));
+      return true;
+    } catch (error) {
+      console.error(t('actions.rustfmt.rollback_failed'), error);
+      return false;
+    }
+  }
+
+  /**
+   * rustfmt uses the edition for parsing when invoked outside cargo (e.g. by
+   * editors), so pin it to the one declared in Cargo.toml
+   */
+  private async getEdition(workspaceRoot: string): Promise<string> {
+    const manifest =
+      (await this.resolver.loadManifest(
+        path.join(workspaceRoot, 'Cargo.toml')
+      )) || {};
+    const pkg = isTomlTable(manifest.package) ? manifest.package : {};
+    const workspace = isTomlTable(manifest.workspace) ? manifest.workspace : {};
+    const workspacePackage = isTomlTable(workspace.package)
+      ? workspace.package
+      : {};
+    const edition = pkg.edition ?? workspacePackage.edition;
+    return typeof edition === 'string' ? edition : '2021';
+  }
+
+  private renderConfig(edition: string): string {
+    return [
+      '# Generated by WOARU - only stable rustfmt options',
+      `edition = "${edition}"`,
+      'newline_style = "Unix"',
+      'use_field_init_shorthand = true',
+      'use_try_shorthand = true',
+      '',
+    ].join('\n');
+  }
+}
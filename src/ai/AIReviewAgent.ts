@@ -1,7 +1,10 @@
-// Removed unused fs and path imports
+import * as path from 'path';
 import axios, { AxiosResponse } from 'axios';
 import { safeJsonParse } from '../utils/safeJsonParser';
-import { PromptManager } from './PromptManager';
+import {
+  PromptManager,
+  type PromptTemplate as LanguagePromptTemplate,
+} from './PromptManager';
 import {
   LLMProviderConfig,
   AIReviewConfig,
@@ -20,6 +23,18 @@ import {
   type ErrorHookData,
 } from '../core/HookSystem';
 
+// Languages with review prompt variants are matched by file extension first
+const LANGUAGE_BY_EXTENSION: Record<string, string> = {
+  '.js': 'javascript',
+  '.jsx': 'javascript',
+  '.ts': 'typescript',
+  '.tsx': 'typescript',
+  '.py': 'python',
+  '.go': 'go',
+  '.rs': 'rust',
+  '.java': 'java',
+};
+
 /**
  * AIReviewAgent - Multi-LLM powered code review and analysis system
  *
@@ -280,10 +295,17 @@ export class AIReviewAgent {
         `🧠 Starting AI Code Review with ${this.enabledProviders.length} LLM providers...`
       );
 
+      const languagePrompts = await this.loadLanguagePrompts(context);
+
       // Run LLM requests (parallel or sequential based on config)
       if (this.config.parallelRequests) {
         const promises = this.enabledProviders.map(provider =>
-          this.callLLMProvider(provider, code, context)
+          this.callLLMProvider(
+            provider,
+            code,
+            context,
+            languagePrompts[provider.id]
+          )
         );
 
         const responses = await Promise.allSettled(promises);
@@ -312,7 +334,8 @@ export class AIReviewAgent {
             const response = await this.callLLMProvider(
               provider,
               code,
-              context
+              context,
+              languagePrompts[provider.id]
             );
             results[provider.id] = response.findings;
             responseTimesMs[provider.id] = response.responseTime;
@@ -396,13 +419,57 @@ export class AIReviewAgent {
     }
   }
 
+  /**
+   * Language of the reviewed file, from its extension or the context
+   */
+  private detectReviewLanguage(context: CodeContext): string {
+    const ext = path.extname(context.filePath || '').toLowerCase();
+    return LANGUAGE_BY_EXTENSION[ext] || context.language || 'unknown';
+  }
+
+  /**
+   * Load language variants of the default review prompt (e.g.
+   * default_review.rust.yaml) per provider. Agents created with custom
+   * prompt templates keep using those; providers without a variant fall
+   * back to the generic prompt.
+   */
+  private async loadLanguagePrompts(
+    context: CodeContext
+  ): Promise<Record<string, LanguagePromptTemplate>> {
+    const prompts: Record<string, LanguagePromptTemplate> = {};
+    if (Object.keys(this.promptTemplates).length > 0) {
+      return prompts;
+    }
+
+    const language = this.detectReviewLanguage(context);
+    const promptManager = PromptManager.getInstance();
+    for (const provider of this.enabledProviders) {
+      try {
+        const template = await promptManager.loadLanguagePrompt(
+          provider.id,
+          'default_review',
+          language
+        );
+        if (template) {
+          prompts[provider.id] = template;
+        }
+      } catch (error) {
+        console.warn(
+          `⚠️ Invalid ${language} review prompt for ${provider.id}, using the generic prompt: ${error instanceof Error ? error.message : String(error)}`
+        );
+      }
+    }
+    return prompts;
+  }
+
   /**
    * Call a specific LLM provider
    */
   private async callLLMProvider(
     provider: LLMProviderConfig,
     code: string,
-    context: CodeContext
+    context: CodeContext,
+    languagePrompt?: LanguagePromptTemplate
   ): Promise<LLMResponse> {
     const startTime = Date.now();
 
@@ -410,7 +477,12 @@ export class AIReviewAgent {
       console.log(`  🤖 Calling ${provider.id} (${provider.model})...`);
 
       // Build provider-specific prompt
-      const prompt = this.buildPromptForProvider(provider, code, context);
+      const prompt = this.buildPromptForProvider(
+        provider,
+        code,
+        context,
+        languagePrompt
+      );
 
       // Get API key from environment
       const apiKey = process.env[provider.apiKeyEnvVar];
@@ -773,10 +845,11 @@ export class AIReviewAgent {
   private buildPromptForProvider(
     provider: LLMProviderConfig,
     code: string,
-    context: CodeContext
+    context: CodeContext,
+    languagePrompt?: LanguagePromptTemplate
   ): string {
-    // Check if we have a custom prompt template for this provider
-    const customTemplate = this.promptTemplates[provider.id];
+    // Check if we have a custom or language-specific template for this provider
+    const customTemplate = this.promptTemplates[provider.id] || languagePrompt;
 
     if (customTemplate) {
       // Use custom template with variable interpolation
@@ -9,6 +9,12 @@ import {
 } from '../types/ai-review';
 import { AIReviewAgent } from './AIReviewAgent';
 import { APP_CONFIG } from '../config/constants';
+import {
+  extractRustDocItems,
+  hasDocCommentAbove,
+  renderRustDocComment,
+  RustDocItem,
+} from '../rust/RustDocComments';
 
 export interface DocumentationResult {
   filePath: string;
@@ -26,6 +32,7 @@ export interface CodeFunction {
   content: string;
   hasExistingDoc: boolean;
   existingDocType?: 'nopro' | 'pro' | 'forai';
+  rustItem?: RustDocItem;
 }
 
 /**
@@ -222,13 +229,15 @@ export class DocumentationAgent {
 
     for (const element of codeElements) {
       // Skip if already has documentation of the same type
+      // Why Rust skips any existing docs: rustdoc would merge both comments
       if (
         element.hasExistingDoc &&
-        element.existingDocType === documentationType
+        (element.existingDocType === documentationType ||
+          element.rustItem !== undefined)
       ) {
         console.log(
           chalk.gray(
-            `   ⏭️  Skipping ${element.name} (already has ${documentationType} documentation)`
+            `   ⏭️  Skipping ${element.name} (already has ${element.existingDocType} documentation)`
           )
         );
         continue;
@@ -248,7 +257,13 @@ export class DocumentationAgent {
           results.push({
             filePath,
             originalContent: content,
-            generatedDoc,
+            generatedDoc: element.rustItem
+              ? renderRustDocComment(
+                  generatedDoc,
+                  element.rustItem,
+                  documentationType
+                )
+              : generatedDoc,
             insertionPoint: element.startLine,
             functionName: element.name,
             documentationType,
@@ -582,6 +597,14 @@ export class DocumentationAgent {
     for (const result of sortedResults) {
       const insertionLine = result.insertionPoint - 1; // Convert to 0-based index
 
+      // Rust items documented since the results were generated keep their docs
+      if (
+        filePath.endsWith('.rs') &&
+        hasDocCommentAbove(lines, insertionLine)
+      ) {
+        continue;
+      }
+
       // Add the documentation comment
       const docLines = result.generatedDoc.split('\n');
       lines.splice(insertionLine, 0, ...docLines);
@@ -626,6 +649,11 @@ export class DocumentationAgent {
     content: string,
     language: string
   ): CodeFunction[] {
+    // Rust items span attributes, generics and where clauses
+    if (language === 'rust') {
+      return this.extractRustElements(content);
+    }
+
     const elements: CodeFunction[] = [];
     const lines = content.split('\n');
 
@@ -658,6 +686,29 @@ export class DocumentationAgent {
     return elements;
   }
 
+  /**
+   * Extract public Rust items (`pub fn`, `pub struct`, `pub enum`,
+   * `pub trait`) and impl blocks, inserting docs above their attributes
+   *
+   * @param content - Rust source code to analyze
+   * @returns Array of detected items with their existing `///` docs
+   */
+  private extractRustElements(content: string): CodeFunction[] {
+    return extractRustDocItems(content).map(item => ({
+      name: item.name,
+      startLine: item.startLine,
+      endLine: item.endLine,
+      content: item.content,
+      hasExistingDoc: item.existingDoc !== undefined,
+      existingDocType: item.existingDoc?.includes('Explain-for-humans:')
+        ? 'nopro'
+        : item.existingDoc !== undefined
+          ? 'pro'
+          : undefined,
+      rustItem: item,
+    }));
+  }
+
   /**
    * Get regex patterns for different languages
    */
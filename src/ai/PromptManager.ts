@@ -193,6 +193,33 @@ export class PromptManager {
     }
   }
 
+  /**
+   * Load the language variant of a prompt (e.g. default_review.rust.yaml),
+   * preferring the provider's copy over the global templates. Returns
+   * undefined when no variant exists so callers can use the generic prompt.
+   */
+  public async loadLanguagePrompt(
+    providerId: string,
+    promptName: string,
+    language: string
+  ): Promise<PromptTemplate | undefined> {
+    const normalized = language.trim().toLowerCase();
+    if (!/^[a-z0-9+#-]+$/.test(normalized) || normalized === 'unknown') {
+      return undefined;
+    }
+
+    const fileName = `${promptName}.${normalized}.yaml`;
+    for (const candidate of [
+      path.join(this.promptsDir, providerId, fileName),
+      path.join(this.templatesDir, fileName),
+    ]) {
+      if (await fs.pathExists(candidate)) {
+        return await this.loadPromptFile(candidate);
+      }
+    }
+    return undefined;
+  }
+
   /**
    * Load prompt template from file
    */
@@ -4,6 +4,11 @@ import { glob } from 'glob';
 import { CodeSmellAnalyzer } from './CodeSmellAnalyzer';
 import { CodeSmellFinding } from '../types/code-smell';
 import { t, initializeI18n } from '../config/i18n';
+import {
+  UnsafeInventoryScanner,
+  totalUnsafe,
+} from '../rust/UnsafeInventoryScanner';
+import { UnsafeInventory } from '../types/rust';
 
 /**
  * Security constants for code analysis validation
@@ -92,17 +97,25 @@ export interface CodeInsight {
   patterns?: string[];
 }
 
+/**
+ * Optional inputs of a codebase analysis
+ */
+export interface CodeAnalysisOptions {
+  /** Unsafe code inventory the caller has already scanned (Rust only) */
+  unsafeInventory?: UnsafeInventory | null;
+}
+
 /**
  * CodeAnalyzer - Advanced multi-language codebase analysis with security validation
  *
  * The CodeAnalyzer class provides comprehensive code quality analysis across multiple
- * programming languages including JavaScript/TypeScript, Python, and C#. It combines
+ * programming languages including JavaScript/TypeScript, Python, C# and Rust. It combines
  * static code analysis, pattern detection, and the specialized WOARU Code Smell Analyzer
  * to generate actionable insights for improving code quality, maintainability, and
  * development workflow optimization.
  *
  * Key features:
- * - Multi-language support (JS/TS, Python, C#)
+ * - Multi-language support (JS/TS, Python, C#, Rust)
  * - Security-first analysis with input validation
  * - Integration with WOARU Code Smell Analyzer
  * - Performance metrics and timeout protection
@@ -148,7 +161,7 @@ export class CodeAnalyzer {
    *
    * Analyzes the entire codebase for code quality issues, potential improvements, and tool
    * recommendations. The analysis is performed with security-first principles, input validation,
-   * and timeout protection. Supports JavaScript, TypeScript, Python, and C# projects.
+   * and timeout protection. Supports JavaScript, TypeScript, Python, C# and Rust projects.
    *
    * The method performs the following analysis types:
    * - Formatting consistency and style issues
@@ -160,7 +173,8 @@ export class CodeAnalyzer {
    * - Language-specific best practices
    *
    * @param projectPath - Absolute path to the project directory to analyze
-   * @param language - Primary programming language of the project ('JavaScript', 'TypeScript', 'Python', 'C#')
+   * @param language - Primary programming language of the project ('JavaScript', 'TypeScript', 'Python', 'C#', 'Rust')
+   * @param options - Results of scans the caller shares, e.g. the unsafe code inventory
    * @returns Promise resolving to Map of tool names to CodeInsight recommendations
    *
    * @throws {Error} When project path is invalid or analysis encounters security issues
@@ -197,7 +211,8 @@ export class CodeAnalyzer {
    */
   async analyzeCodebase(
     projectPath: string,
-    language: string
+    language: string,
+    options: CodeAnalysisOptions = {}
   ): Promise<Map<string, CodeInsight>> {
     const startTime = Date.now();
 
@@ -221,7 +236,8 @@ export class CodeAnalyzer {
       const analysisPromise = this.performAnalysisWithTimeout(
         safePath,
         language,
-        insights
+        insights,
+        options
       );
       await analysisPromise;
 
@@ -243,7 +259,13 @@ export class CodeAnalyzer {
       return false;
     }
 
-    const supportedLanguages = ['JavaScript', 'TypeScript', 'Python', 'C#'];
+    const supportedLanguages = [
+      'JavaScript',
+      'TypeScript',
+      'Python',
+      'C#',
+      'Rust',
+    ];
     return supportedLanguages.includes(language);
   }
 
@@ -253,14 +275,15 @@ export class CodeAnalyzer {
   private async performAnalysisWithTimeout(
     projectPath: string,
     language: string,
-    insights: Map<string, CodeInsight>
+    insights: Map<string, CodeInsight>,
+    options: CodeAnalysisOptions
   ): Promise<void> {
     return new Promise((resolve, reject) => {
       const timeout = setTimeout(() => {
         reject(new Error('Code analysis timeout'));
       }, SECURITY_LIMITS.MAX_ANALYSIS_TIME);
 
-      this.performAnalysis(projectPath, language, insights)
+      this.performAnalysis(projectPath, language, insights, options)
         .then(() => {
           clearTimeout(timeout);
           resolve();
@@ -278,7 +301,8 @@ export class CodeAnalyzer {
   private async performAnalysis(
     projectPath: string,
     language: string,
-    insights: Map<string, CodeInsight>
+    insights: Map<string, CodeInsight>,
+    options: CodeAnalysisOptions
   ): Promise<void> {
     switch (language) {
       case 'JavaScript':
@@ -291,6 +315,13 @@ export class CodeAnalyzer {
       case 'C#':
         await this.analyzeCSharpProject(projectPath, insights);
         break;
+      case 'Rust':
+        await this.analyzeRustProject(
+          projectPath,
+          insights,
+          options.unsafeInventory
+        );
+        break;
     }
   }
 
@@ -560,6 +591,81 @@ export class CodeAnalyzer {
     }
   }
 
+  /**
+   * Analyze Rust project: unsafe code inventory of the workspace crates,
+   * plus their dependencies if the given inventory includes them
+   */
+  private async analyzeRustProject(
+    projectPath: string,
+    insights: Map<string, CodeInsight>,
+    unsafeInventory?: UnsafeInventory | null
+  ): Promise<void> {
+    try {
+      const inventory =
+        unsafeInventory !== undefined
+          ? unsafeInventory
+          : await new UnsafeInventoryScanner().scan(projectPath);
+      if (!inventory) {
+        return;
+      }
+
+      const workspaceCrates = inventory.crates.filter(
+        crate => crate.source === 'workspace'
+      );
+      const unsafeCrates = inventory.crates
+        .filter(crate => totalUnsafe(crate) > 0)
+        .sort((a, b) => totalUnsafe(b) - totalUnsafe(a));
+      const unsafeWorkspaceCrates = unsafeCrates.filter(
+        crate => crate.source === 'workspace'
+      );
+      const missingForbid = workspaceCrates.filter(
+        crate => !crate.forbidsUnsafe
+      );
+      if (unsafeCrates.length === 0 && missingForbid.length === 0) {
+        return;
+      }
+
+      const evidence = [
+        ...unsafeCrates.map(crate =>
+          t('code_analyzer.unsafe_crate_summary', {
+            crate: crate.version
+              ? `${crate.name} ${crate.version}`
+              : crate.name,
+            source: crate.source,
+            blocks: crate.unsafeBlocks,
+            fns: crate.unsafeFns,
+            impls: crate.unsafeImpls,
+            externs: crate.externBlocks,
+          })
+        ),
+        ...missingForbid.map(crate =>
+          t('code_analyzer.missing_forbid_unsafe', { crate: crate.name })
+        ),
+      ];
+
+      insights.set('cargo-geiger', {
+        reason: t('code_analyzer.unsafe_code_found', {
+          crates: unsafeCrates.length,
+          workspace: unsafeWorkspaceCrates.length,
+          missing: missingForbid.length,
+        }),
+        evidence: evidence.slice(0, SECURITY_LIMITS.MAX_INSIGHTS_PER_TYPE),
+        files: unsafeWorkspaceCrates
+          .flatMap(crate =>
+            crate.files.map(file => path.join(crate.rootDir, file.file))
+          )
+          .slice(0, SECURITY_LIMITS.MAX_INSIGHTS_PER_TYPE),
+        severity: unsafeWorkspaceCrates.length > 0 ? 'medium' : 'low',
+        patterns: ['unsafe', 'unsafe fn', 'unsafe impl', 'extern'],
+      });
+    } catch (error) {
+      console.error(
+        t('code_analyzer.rust_analysis_error'),
+        sanitizeError(error)
+      );
+    }
+  }
+
   /**
    * Get analysis metrics
    */
@@ -12,6 +12,15 @@ import {
   type BeforeFileAnalysisData,
   type AfterFileAnalysisData,
 } from '../core/HookSystem';
+import {
+  maskRustSource,
+  extractRustFunctions,
+  findTestRegions,
+  findUnsafeBlocks,
+  isInRegions,
+  isRustLibrarySource,
+  offsetToPosition,
+} from '../rust/RustSourceScanner';
 
 /**
  * Type definition for function metadata
@@ -31,6 +40,7 @@ interface FunctionMetadata {
  */
 interface AnalysisContext {
   filePath: string;
+  sourcePath: string; // Unsanitized path, used only for classification
   language: string;
   content: string;
   contentLength: number;
@@ -64,9 +74,10 @@ function sanitizeFunctionName(functionName: unknown): string {
     return 'anonymous';
   }
 
-  // Remove dangerous characters and limit length
+  // Remove dangerous characters and limit length (`::` is kept for Rust paths)
   return (
-    functionName.replace(/[^a-zA-Z0-9_$]/g, '').substring(0, 100) || 'anonymous'
+    functionName.replace(/[^a-zA-Z0-9_$:]/g, '').substring(0, 100) ||
+    'anonymous'
   );
 }
 
@@ -104,13 +115,21 @@ function isSecureContent(content: string, filePath?: string): boolean {
   return !suspiciousPatterns.some(pattern => pattern.test(content));
 }
 
+const RUST_LANGUAGES = ['rust', 'rs'];
+
 /**
  * Type guard for language validation
  * @param language - Language to validate
  * @returns True if language is supported
  */
 function isSupportedLanguage(language: unknown): language is string {
-  const supportedLanguages = ['javascript', 'typescript', 'js', 'ts'];
+  const supportedLanguages = [
+    'javascript',
+    'typescript',
+    'js',
+    'ts',
+    ...RUST_LANGUAGES,
+  ];
   return (
     typeof language === 'string' &&
     supportedLanguages.includes(language.toLowerCase())
@@ -121,7 +140,7 @@ function isSupportedLanguage(language: unknown): language is string {
  * CodeSmellAnalyzer - Advanced static code analysis engine for detecting code quality issues
  *
  * The CodeSmellAnalyzer is a production-ready implementation that provides comprehensive
- * static code analysis for JavaScript and TypeScript codebases. It detects various types
+ * static code analysis for JavaScript, TypeScript and Rust codebases. It detects various types
  * of code smells, anti-patterns, and quality issues using sophisticated pattern matching,
  * cyclomatic complexity analysis, and security-first validation approaches.
  *
@@ -142,6 +161,8 @@ function isSupportedLanguage(language: unknown): language is string {
  * - Function length analysis
  * - Parameter count validation
  * - Nesting depth evaluation
+ * - Rust: unwrap()/expect() in library code, panic!/todo!/unimplemented!,
+ *   oversized unsafe blocks
  *
  * @example
  * ```typescript
@@ -197,6 +218,8 @@ export class CodeSmellAnalyzer {
     APP_CONFIG.QUALITY.PARAMETER_COUNT_THRESHOLD;
   private readonly nestingDepthThreshold =
     APP_CONFIG.QUALITY.NESTING_DEPTH_THRESHOLD;
+  private readonly unsafeBlockLinesThreshold =
+    APP_CONFIG.QUALITY.UNSAFE_BLOCK_LINES_THRESHOLD;
 
   // Security limits
   private readonly MAX_FILE_SIZE = 1000000; // 1MB
@@ -236,7 +259,7 @@ export class CodeSmellAnalyzer {
    * - Input sanitization for all user-provided data
    *
    * @param filePath - Absolute path to the source file to analyze
-   * @param language - Programming language identifier ('javascript', 'typescript', 'js', 'ts')
+   * @param language - Programming language identifier ('javascript', 'typescript', 'js', 'ts', 'rust', 'rs')
    * @returns Promise resolving to array of CodeSmellFinding objects with detailed analysis results
    *
    * @throws {Error} Throws errors for invalid file paths or security validation failures
@@ -334,6 +357,7 @@ export class CodeSmellAnalyzer {
 
       const context: AnalysisContext = {
         filePath: sanitizedPath,
+        sourcePath: filePath,
         language: language.toLowerCase(),
         content,
         contentLength: content.length,
@@ -503,6 +527,10 @@ export class CodeSmellAnalyzer {
       return [];
     }
 
+    if (RUST_LANGUAGES.includes(context.language)) {
+      return this.analyzeRustContent(context);
+    }
+
     try {
       // Basic pattern-based analysis with error isolation
       findings.push(...this.checkVarKeyword(lines));
@@ -522,6 +550,30 @@ export class CodeSmellAnalyzer {
     return findings;
   }
 
+  /**
+   * Analysis for Rust content. Comments and literals are masked first so the
+   * structural checks only see code; functions are `fn` items including
+   * impl and trait methods.
+   */
+  private analyzeRustContent(context: AnalysisContext): CodeSmellFinding[] {
+    const findings: CodeSmellFinding[] = [];
+
+    try {
+      const masked = maskRustSource(context.content);
+
+      findings.push(...this.analyzeComplexity(masked, 'rust'));
+      findings.push(...this.analyzeFunctionLength(masked, 'rust'));
+      findings.push(...this.analyzeParameterCount(masked, 'rust'));
+      findings.push(...this.analyzeRustNestingDepth(masked));
+      findings.push(...this.checkRustPanics(masked, context.sourcePath));
+      findings.push(...this.checkUnsafeBlocks(masked));
+    } catch (error) {
+      console.debug(`Rust analysis error: ${this.sanitizeError(error)}`);
+    }
+
+    return findings;
+  }
+
   /**
    * Sanitize error messages for security
    */
@@ -724,11 +776,14 @@ export class CodeSmellAnalyzer {
   /**
    * Analyze cyclomatic complexity with security validation
    */
-  private analyzeComplexity(content: string): CodeSmellFinding[] {
+  private analyzeComplexity(
+    content: string,
+    language = 'javascript'
+  ): CodeSmellFinding[] {
     const findings: CodeSmellFinding[] = [];
 
     try {
-      const functions = this.extractFunctions(content);
+      const functions = this.extractFunctions(content, language);
       if (functions.length > 100) {
         console.warn(
           'Too many functions detected, limiting complexity analysis'
@@ -739,7 +794,10 @@ export class CodeSmellAnalyzer {
       functions.forEach(func => {
         try {
           const sanitizedFunctionName = sanitizeFunctionName(func.name);
-          const complexity = this.calculateCyclomaticComplexity(func.body);
+          const complexity = this.calculateCyclomaticComplexity(
+            func.body,
+            language
+          );
 
           if (complexity > this.complexityThreshold && complexity < 1000) {
             // Sanity check
@@ -775,11 +833,14 @@ export class CodeSmellAnalyzer {
   /**
    * Analyze function length with security validation
    */
-  private analyzeFunctionLength(content: string): CodeSmellFinding[] {
+  private analyzeFunctionLength(
+    content: string,
+    language = 'javascript'
+  ): CodeSmellFinding[] {
     const findings: CodeSmellFinding[] = [];
 
     try {
-      const functions = this.extractFunctions(content);
+      const functions = this.extractFunctions(content, language);
       if (functions.length > 100) {
         console.warn('Too many functions detected, limiting length analysis');
         return [];
@@ -824,11 +885,14 @@ export class CodeSmellAnalyzer {
   /**
    * Analyze parameter count with security validation
    */
-  private analyzeParameterCount(content: string): CodeSmellFinding[] {
+  private analyzeParameterCount(
+    content: string,
+    language = 'javascript'
+  ): CodeSmellFinding[] {
     const findings: CodeSmellFinding[] = [];
 
     try {
-      const functions = this.extractFunctions(content);
+      const functions = this.extractFunctions(content, language);
       if (functions.length > 100) {
         console.warn(
           'Too many functions detected, limiting parameter analysis'
@@ -948,7 +1012,10 @@ export class CodeSmellAnalyzer {
   /**
    * Extract functions with security validation and enhanced parsing
    */
-  private extractFunctions(content: string): FunctionMetadata[] {
+  private extractFunctions(
+    content: string,
+    language = 'javascript'
+  ): FunctionMetadata[] {
     const functions: FunctionMetadata[] = [];
 
     try {
@@ -963,6 +1030,10 @@ export class CodeSmellAnalyzer {
         return [];
       }
 
+      if (language === 'rust') {
+        return this.extractRustFunctionMetadata(content);
+      }
+
       // Enhanced regex for function detection with security considerations
       const functionRegex =
         /(?:function\s+(\w+)\s*\(([^)]*)\)|(\w+)\s*[:=]\s*(?:function\s*)?\(([^)]*)\)\s*=>?|(\w+)\s*\(([^)]*)\)\s*\{)/g;
@@ -1017,6 +1088,27 @@ export class CodeSmellAnalyzer {
     return functions;
   }
 
+  /**
+   * Map Rust `fn` items (from masked source) to function metadata
+   */
+  private extractRustFunctionMetadata(masked: string): FunctionMetadata[] {
+    return extractRustFunctions(masked)
+      .slice(0, 100)
+      .map(item => {
+        const body = masked.slice(item.bodyStart, item.bodyEnd + 1);
+        return {
+          name: sanitizeFunctionName(item.name),
+          body,
+          line: item.line,
+          column: item.column,
+          parameters: item.parameters.slice(0, 20),
+          startIndex: item.line - 1,
+          endIndex: item.line - 1 + body.split('\n').length,
+        };
+      })
+      .filter(func => func.body.length <= 50000);
+  }
+
   /**
    * Parse function parameters with security validation
    */
@@ -1111,7 +1203,10 @@ export class CodeSmellAnalyzer {
   /**
    * Calculate cyclomatic complexity with security validation
    */
-  private calculateCyclomaticComplexity(functionBody: string): number {
+  private calculateCyclomaticComplexity(
+    functionBody: string,
+    language = 'javascript'
+  ): number {
     try {
       if (typeof functionBody !== 'string' || functionBody.length > 50000) {
         return 1; // Return base complexity for invalid input
@@ -1119,6 +1214,13 @@ export class CodeSmellAnalyzer {
 
       let complexity = APP_CONFIG.QUALITY_THRESHOLDS.BASE_COMPLEXITY;
 
+      if (language === 'rust') {
+        return Math.min(
+          complexity + this.countRustDecisionPoints(functionBody),
+          999
+        );
+      }
+
       // Count decision points with safety limits
       const decisionPoints = [
         /\bif\b/g,
@@ -1157,6 +1259,190 @@ export class CodeSmellAnalyzer {
     }
   }
 
+  /**
+   * Count Rust decision points: `if`/`if let`, loops, match arms beyond the
+   * first and short-circuit operators. Closure pipes (`||`, `|x|`) are not
+   * mistaken for boolean operators.
+   */
+  private countRustDecisionPoints(functionBody: string): number {
+    const count = (pattern: RegExp) =>
+      (functionBody.match(pattern) || []).length;
+
+    const branches =
+      count(/\bif\b/g) + count(/\bwhile\b/g) + count(/\bfor\b(?!\s*<)/g);
+
+    // A match with n arms adds n - 1 paths
+    const matchArms = Math.max(0, count(/=>/g) - count(/\bmatch\b/g));
+
+    // Binary && / || follow an operand; closures follow `(`, `,`, `=`, `move`
+    const operators = Array.from(
+      functionBody.matchAll(/([\w)\]}?]+)\s*(?:&&|\|\|)/g)
+    ).filter(match => !['move', 'return', 'in', 'else'].includes(match[1]));
+
+    return branches + matchArms + operators.length;
+  }
+
+  /**
+   * Rust nesting depth per function. The function body is level 1 and a
+   * match arm block (`=> {`) shares the level of its `match`.
+   */
+  private analyzeRustNestingDepth(masked: string): CodeSmellFinding[] {
+    const findings: CodeSmellFinding[] = [];
+
+    try {
+      extractRustFunctions(masked)
+        .slice(0, 100)
+        .forEach(func => {
+          const levels: boolean[] = [true];
+          let maxDepth = 1;
+          let maxDepthOffset = func.bodyStart;
+
+          for (let i = func.bodyStart + 1; i < func.bodyEnd; i++) {
+            if (masked[i] === '{') {
+              const isArmBlock = /=>\s*$/.test(
+                masked.slice(Math.max(func.bodyStart, i - 50), i)
+              );
+              levels.push(!isArmBlock);
+              const depth = levels.filter(Boolean).length;
+              if (depth > maxDepth) {
+                maxDepth = depth;
+                maxDepthOffset = i;
+              }
+            } else if (masked[i] === '}' && levels.length > 1) {
+              levels.pop();
+            }
+          }
+
+          if (maxDepth > this.nestingDepthThreshold && maxDepth < 50) {
+            findings.push({
+              type: 'nested-depth',
+              message: t('code_smell_analyzer.excessive_nesting_message', {
+                maxDepth: maxDepth.toString(),
+              }),
+              severity: 'warning',
+              line: offsetToPosition(masked, maxDepthOffset).line,
+              column: 1,
+              rule: 'max-depth',
+              suggestion: t('code_smell_analyzer.extract_nested_logic'),
+            });
+          }
+        });
+    } catch (error) {
+      console.debug(
+        `Error in analyzeRustNestingDepth: ${this.sanitizeError(error)}`
+      );
+    }
+
+    return findings;
+  }
+
+  /**
+   * Check for code paths that panic: `.unwrap()`/`.expect()` in library
+   * code and `panic!`/`todo!`/`unimplemented!` outside of tests
+   */
+  private checkRustPanics(
+    masked: string,
+    sourcePath: string
+  ): CodeSmellFinding[] {
+    const findings: CodeSmellFinding[] = [];
+
+    try {
+      const testRegions = findTestRegions(masked);
+      const isLibrary = isRustLibrarySource(sourcePath);
+      let lineOffset = 0;
+
+      masked.split('\n').forEach((line, index) => {
+        const offset = lineOffset;
+        lineOffset += line.length + 1;
+        if (line.length > 1000) return;
+
+        if (isLibrary) {
+          for (const match of line.matchAll(/\.\s*(unwrap|expect)\s*\(/g)) {
+            const column = (match.index || 0) + match[0].indexOf(match[1]);
+            if (isInRegions(testRegions, offset + column)) continue;
+
+            findings.push({
+              type: 'unwrap-usage',
+              message: t('code_smell_analyzer.unwrap_message', {
+                method: match[1],
+              }),
+              severity: match[1] === 'unwrap' ? 'warning' : 'info',
+              line: index + 1,
+              column: column + 1,
+              rule: `clippy::${match[1]}_used`,
+              suggestion: t('code_smell_analyzer.unwrap_suggestion'),
+            });
+          }
+        }
+
+        for (const match of line.matchAll(
+          /\b(panic|todo|unimplemented)\s*!(?!=)/g
+        )) {
+          const column = match.index || 0;
+          if (isInRegions(testRegions, offset + column)) continue;
+
+          const isPanic = match[1] === 'panic';
+          findings.push({
+            type: 'panic-macro',
+            message: t(
+              isPanic
+                ? 'code_smell_analyzer.panic_macro_message'
+                : 'code_smell_analyzer.unfinished_code_message',
+              { macro: match[1] }
+            ),
+            severity: 'warning',
+            line: index + 1,
+            column: column + 1,
+            rule: `clippy::${match[1]}`,
+            suggestion: t(
+              isPanic
+                ? 'code_smell_analyzer.panic_macro_suggestion'
+                : 'code_smell_analyzer.unfinished_code_suggestion'
+            ),
+          });
+        }
+      });
+    } catch (error) {
+      console.debug(`Error in checkRustPanics: ${this.sanitizeError(error)}`);
+    }
+
+    return findings;
+  }
+
+  /**
+   * Check for `unsafe { ... }` blocks spanning more lines than the threshold
+   */
+  private checkUnsafeBlocks(masked: string): CodeSmellFinding[] {
+    const findings: CodeSmellFinding[] = [];
+
+    try {
+      findUnsafeBlocks(masked).forEach(block => {
+        const start = offsetToPosition(masked, block.start);
+        const end = offsetToPosition(masked, block.end);
+        const length = end.line - start.line + 1;
+
+        if (length > this.unsafeBlockLinesThreshold) {
+          findings.push({
+            type: 'unsafe-block',
+            message: t('code_smell_analyzer.unsafe_block_message', {
+              length: length.toString(),
+              limit: this.unsafeBlockLinesThreshold.toString(),
+            }),
+            severity: 'warning',
+            line: start.line,
+            column: start.column,
+            rule: 'max-unsafe-block-lines',
+            suggestion: t('code_smell_analyzer.unsafe_block_suggestion'),
+          });
+        }
+      });
+    } catch (error) {
+      console.debug(`Error in checkUnsafeBlocks: ${this.sanitizeError(error)}`);
+    }
+
+    return findings;
+  }
+
   /**
    * Get analysis performance metrics
    */
@@ -1,6 +1,7 @@
 import fs from 'fs-extra';
 import * as path from 'path';
 import { glob } from 'glob';
+import { CargoManifestReader } from '../rust/CargoManifestReader';
 
 /**
  * Language information structure for comprehensive programming language support
@@ -142,7 +143,18 @@ export class LanguageDetector {
         configFiles: ['Cargo.toml', 'Cargo.lock'],
         packageManagers: ['cargo'],
         buildFiles: ['Cargo.toml'],
-        frameworks: ['actix', 'rocket', 'tokio', 'serde'],
+        frameworks: [
+          'axum',
+          'actix',
+          'rocket',
+          'tokio',
+          'async-std',
+          'bevy',
+          'tauri',
+          'diesel',
+          'sqlx',
+          'serde',
+        ],
       },
     ],
     [
@@ -398,6 +410,14 @@ export class LanguageDetector {
         }
         break;
       }
+
+      case 'rust': {
+        // Frameworks come from resolved [dependencies], including members
+        // of a workspace and workspace-inherited crates
+        const cargo = await new CargoManifestReader().readProject(projectPath);
+        if (cargo) detectedFrameworks.push(...cargo.frameworks);
+        break;
+      }
     }
 
     return detectedFrameworks;
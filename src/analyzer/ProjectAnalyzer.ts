@@ -2,7 +2,11 @@ import fs from 'fs-extra';
 import * as path from 'path';
 import { glob } from 'glob';
 import { ProjectAnalysis } from '../types';
+import { CargoDependencyKind } from '../types/rust';
 import { LanguageDetector } from './LanguageDetector';
+import { CargoManifestReader } from '../rust/CargoManifestReader';
+import { RustToolchainInspector } from '../rust/RustToolchainInspector';
+import { AsyncBlockingDetector } from '../rust/AsyncBlockingDetector';
 
 /**
  * ProjectAnalyzer - Comprehensive project analysis for code structure, dependencies, and frameworks
@@ -110,6 +114,19 @@ export class ProjectAnalyzer {
       detectedLanguages: allLanguages,
     };
 
+    if (primaryLanguage === 'rust') {
+      analysis.cargo =
+        (await new CargoManifestReader().readProject(projectPath)) ||
+        undefined;
+      analysis.rustToolchain =
+        (await new RustToolchainInspector().inspect(projectPath)) || undefined;
+      if (analysis.cargo?.frameworks.includes('tokio')) {
+        analysis.asyncBlocking = await new AsyncBlockingDetector().scan(
+          projectPath
+        );
+      }
+    }
+
     return analysis;
   }
 
@@ -270,6 +287,12 @@ export class ProjectAnalyzer {
       'tailwind.config.{js,ts}',
       'postcss.config.{js,ts}',
       '.env*',
+      'Cargo.toml',
+      'Cargo.lock',
+      '{.,}rustfmt.toml',
+      '{.,}clippy.toml',
+      'deny.toml',
+      'rust-toolchain{,.toml}',
     ];
 
     const configFiles: string[] = [];
@@ -309,6 +332,10 @@ export class ProjectAnalyzer {
         return await this.getCSharpDependencies(projectPath);
       }
 
+      case 'rust': {
+        return await this.getCargoDependencies(projectPath, ['normal']);
+      }
+
       default:
         return [];
     }
@@ -334,6 +361,10 @@ export class ProjectAnalyzer {
         return await this.getPythonDevDependencies(projectPath);
       }
 
+      case 'rust': {
+        return await this.getCargoDependencies(projectPath, ['dev', 'build']);
+      }
+
       default:
         return [];
     }
@@ -450,6 +481,25 @@ export class ProjectAnalyzer {
     return scripts;
   }
 
+  private async getCargoDependencies(
+    projectPath: string,
+    kinds: CargoDependencyKind[]
+  ): Promise<string[]> {
+    const cargo = await new CargoManifestReader().readProject(projectPath);
+    if (!cargo) {
+      return [];
+    }
+
+    // Crate names rather than manifest aliases, so renamed crates still match
+    return [
+      ...new Set(
+        cargo.dependencies
+          .filter(dep => kinds.includes(dep.kind))
+          .map(dep => dep.name)
+      ),
+    ];
+  }
+
   private async getCSharpDependencies(projectPath: string): Promise<string[]> {
     const dependencies: string[] = [];
 
@@ -11,7 +11,8 @@ jest.mock('../../config/constants', () => ({
       COMPLEXITY_THRESHOLD: 10,
       FUNCTION_LENGTH_THRESHOLD: 50,
       PARAMETER_COUNT_THRESHOLD: 5,
-      NESTING_DEPTH_THRESHOLD: 4
+      NESTING_DEPTH_THRESHOLD: 4,
+      UNSAFE_BLOCK_LINES_THRESHOLD: 10
     },
     QUALITY_THRESHOLDS: {
       BASE_COMPLEXITY: 1,
@@ -42,7 +43,11 @@ jest.mock('../../config/i18n', () => ({
       'code_smell_analyzer.too_many_parameters_message': 'Function "{{functionName}}" has too many parameters ({{paramCount}}). Consider using an options object.',
       'code_smell_analyzer.use_options_object': 'Use an options object or break down the function',
       'code_smell_analyzer.excessive_nesting_message': 'Excessive nesting depth ({{maxDepth}} levels). Consider refactoring.',
-      'code_smell_analyzer.extract_nested_logic': 'Extract nested logic into separate functions'
+      'code_smell_analyzer.extract_nested_logic': 'Extract nested logic into separate functions',
+      'code_smell_analyzer.unwrap_message': '".{{method}}()" can panic in library code',
+      'code_smell_analyzer.panic_macro_message': '"{{macro}}!" aborts the current thread when reached',
+      'code_smell_analyzer.unfinished_code_message': '"{{macro}}!" marks unfinished code that panics when reached',
+      'code_smell_analyzer.unsafe_block_message': 'unsafe block spans {{length}} lines (limit {{limit}})'
     };
     
     let result = translations[key] || key;
@@ -468,6 +473,170 @@ describe('CodeSmellAnalyzer - Production Quality Tests', () => {
     });
   });
 
+  describe('Rust Analysis', () => {
+    it('should flag unwrap/expect in library code but not in tests or comments', async () => {
+      // Arrange
+      const content = [
+        'pub fn load(path: &str) -> Config {',
+        '    // calling .unwrap() here would be bad',
+        '    let raw = std::fs::read_to_string(path).unwrap();',
+        '    parse(&raw).expect("valid config")',
+        '}',
+        '',
+        '#[cfg(test)]',
+        'mod tests {',
+        '    #[test]',
+        '    fn loads() {',
+        '        load("a").unwrap();',
+        '    }',
+        '}',
+      ].join('\n');
+      mockedFs.readFile.mockResolvedValue(content);
+
+      // Act
+      const result = await analyzer.analyzeFile('/project/src/config.rs', 'rust');
+
+      // Assert
+      const unwraps = result.filter(f => f.type === 'unwrap-usage');
+      expect(unwraps.map(f => [f.line, f.rule])).toEqual([
+        [3, 'clippy::unwrap_used'],
+        [4, 'clippy::expect_used'],
+      ]);
+      expect(unwraps[0].column).toBe(45);
+    });
+
+    it('should not flag unwrap in binaries', async () => {
+      // Arrange
+      mockedFs.readFile.mockResolvedValue('fn main() {\n    run().unwrap();\n}');
+
+      // Act
+      const result = await analyzer.analyzeFile('/project/src/main.rs', 'rust');
+
+      // Assert
+      expect(result.filter(f => f.type === 'unwrap-usage')).toHaveLength(0);
+    });
+
+    it('should detect panic!, todo! and unimplemented!', async () => {
+      // Arrange
+      const content = [
+        'fn a() { panic!("boom") }',
+        'fn b() { todo!() }',
+        'fn c() { unimplemented!() }',
+        'fn d() { let msg = "todo!()"; }',
+      ].join('\n');
+      mockedFs.readFile.mockResolvedValue(content);
+
+      // Act
+      const result = await analyzer.analyzeFile('/project/src/lib.rs', 'rust');
+
+      // Assert
+      const panics = result.filter(f => f.type === 'panic-macro');
+      expect(panics.map(f => f.rule)).toEqual([
+        'clippy::panic',
+        'clippy::todo',
+        'clippy::unimplemented',
+      ]);
+      expect(panics[1].message).toContain('unfinished code');
+    });
+
+    it('should detect oversized unsafe blocks', async () => {
+      // Arrange
+      const content = [
+        'pub fn copy(src: *const u8, dst: *mut u8) {',
+        '    unsafe {',
+        ...Array(12).fill('        step(src, dst);'),
+        '    }',
+        '    unsafe { small() }',
+        '}',
+      ].join('\n');
+      mockedFs.readFile.mockResolvedValue(content);
+
+      // Act
+      const result = await analyzer.analyzeFile('/project/src/lib.rs', 'rust');
+
+      // Assert
+      const unsafeFindings = result.filter(f => f.type === 'unsafe-block');
+      expect(unsafeFindings).toHaveLength(1);
+      expect(unsafeFindings[0].line).toBe(2);
+      expect(unsafeFindings[0].message).toContain('14 lines');
+    });
+
+    it('should count match arms toward complexity of impl methods', async () => {
+      // Arrange
+      const arms = Array.from({ length: 12 }, (_, i) => `            ${i} => ${i},`);
+      const content = [
+        'impl Parser {',
+        '    pub fn classify(&self, n: u8) -> u8 {',
+        '        match n {',
+        ...arms,
+        '            _ => 0,',
+        '        }',
+        '    }',
+        '}',
+      ].join('\n');
+      mockedFs.readFile.mockResolvedValue(content);
+
+      // Act
+      const result = await analyzer.analyzeFile('/project/src/lib.rs', 'rust');
+
+      // Assert
+      const complexity = result.filter(f => f.type === 'complexity');
+      expect(complexity).toHaveLength(1);
+      expect(complexity[0].message).toContain('Parser::classify');
+      expect(complexity[0].message).toContain('(13)');
+    });
+
+    it('should count parameters without self and ignore generic commas', async () => {
+      // Arrange
+      const content = [
+        'impl Store {',
+        '    fn put(',
+        '        &mut self,',
+        '        a: HashMap<String, u8>,',
+        '        b: u8,',
+        '        c: u8,',
+        '        d: u8,',
+        '        e: impl Fn(u8, u8) -> u8,',
+        '    ) {}',
+        '    fn small(&self, a: HashMap<String, Vec<(u8, u8)>>) {}',
+        '}',
+      ].join('\n');
+      mockedFs.readFile.mockResolvedValue(content);
+
+      // Act
+      const result = await analyzer.analyzeFile('/project/src/lib.rs', 'rust');
+
+      // Assert
+      expect(result.filter(f => f.type === 'parameter-count')).toHaveLength(0);
+    });
+
+    it('should not treat match arm blocks and closures as extra nesting', async () => {
+      // Arrange
+      const content = [
+        'fn handle(e: Event) {',
+        '    match e {',
+        '        Event::A => {',
+        '            items.iter().for_each(|x| {',
+        '                if x.ok() {',
+        '                    run(|| x.go());',
+        '                }',
+        '            });',
+        '        }',
+        '        _ => {}',
+        '    }',
+        '}',
+      ].join('\n');
+      mockedFs.readFile.mockResolvedValue(content);
+
+      // Act
+      const result = await analyzer.analyzeFile('/project/src/lib.rs', 'rust');
+
+      // Assert
+      expect(result.filter(f => f.type === 'nested-depth')).toHaveLength(0);
+      expect(result.filter(f => f.type === 'complexity')).toHaveLength(0);
+    });
+  });
+
   describe('Security Tests', () => {
     it('should handle malicious code content securely', async () => {
       // Arrange
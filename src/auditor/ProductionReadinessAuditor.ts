@@ -10,6 +10,26 @@ import { ToolsDatabaseManager } from '../database/ToolsDatabaseManager';
 import { QualityRunner } from '../quality/QualityRunner';
 import { NotificationManager } from '../supervisor/NotificationManager';
 import { t, initializeI18n } from '../config/i18n';
+import {
+  BuildScriptRiskKind,
+  CargoDenyCheck,
+  CargoDenyDiagnostic,
+  CompileTimeCrate,
+  CargoManifestSummary,
+  FuzzCandidate,
+  UnsafeCrateInventory,
+  UnsafeInventory,
+} from '../types/rust';
+import { BuildScriptAnalyzer } from '../rust/BuildScriptAnalyzer';
+import { CargoManifestReader } from '../rust/CargoManifestReader';
+import { CargoWorkspaceResolver } from '../rust/CargoWorkspaceResolver';
+import { FuzzReadinessScanner } from '../rust/FuzzReadinessScanner';
+import { findTestRegions, maskRustSource } from '../rust/RustSourceScanner';
+import {
+  UnsafeInventoryScanner,
+  totalUnsafe,
+} from '../rust/UnsafeInventoryScanner';
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
 
 /**
  * Security constants for input validation
@@ -106,6 +126,103 @@ function isSecureProjectPath(projectPath: string): boolean {
   }
 }
 
+/**
+ * Files whose changes can alter the cargo-deny verdict
+ */
+const CARGO_POLICY_FILES = ['Cargo.toml', 'Cargo.lock', 'deny.toml'];
+
+/**
+ * Logging facades and the crates that actually emit their records
+ */
+const RUST_LOGGING_CRATES = {
+  facades: ['tracing', 'log', 'slog'],
+  backends: [
+    'tracing-subscriber',
+    'env_logger',
+    'pretty_env_logger',
+    'simple_logger',
+    'fern',
+    'log4rs',
+    'flexi_logger',
+    'slog-term',
+    'slog-async',
+  ],
+} as const;
+
+/**
+ * Crates that load configuration from .env files or the environment
+ */
+const RUST_ENV_CRATES = ['dotenvy', 'dotenv', 'config', 'figment', 'envy'];
+
+const RUST_SOURCE_SCAN = {
+  MAX_DEPTH: 6,
+  SKIPPED_DIRS: ['target', 'node_modules', '.git'],
+};
+
+/**
+ * ProductionAudit category and remediation hint per cargo-deny check
+ */
+const CARGO_DENY_AUDITS: Record<
+  CargoDenyCheck,
+  { category: ProductionAudit['category']; icon: string; fix: string }
+> = {
+  licenses: {
+    category: 'license-compliance',
+    icon: '⚖️',
+    fix: 'Erlaube die Lizenz in deny.toml unter [licenses].allow (nach rechtlicher Prüfung) oder ersetze das Crate.',
+  },
+  bans: {
+    category: 'banned-crates',
+    icon: '🚫',
+    fix: 'Entferne gesperrte Crates bzw. vereinheitliche doppelte Versionen (cargo tree -d) oder passe [bans] in deny.toml an.',
+  },
+  sources: {
+    category: 'untrusted-sources',
+    icon: '🔗',
+    fix: 'Beziehe Crates nur aus vertrauenswürdigen Registries/Git-Repos oder erlaube die Quelle explizit unter [sources] in deny.toml.',
+  },
+  advisories: {
+    category: 'security',
+    icon: '🛡️',
+    fix: 'Aktualisiere betroffene Crates (cargo update -p <crate>). Details: cargo deny check advisories.',
+  },
+};
+
+/**
+ * Audit text per kind of risky build script behaviour
+ */
+const BUILD_SCRIPT_RISKS: Record<
+  BuildScriptRiskKind,
+  {
+    priority: ProductionAudit['priority'];
+    icon: string;
+    message: string;
+    recommendation: string;
+  }
+> = {
+  network: {
+    priority: 'high',
+    icon: '🌐',
+    message: 'greifen auf das Netzwerk zu',
+    recommendation:
+      'Downloads im Build sind nicht reproduzierbar und ein Einfallstor für Supply-Chain-Angriffe. Bevorzuge Crates, die Artefakte mitliefern, oder baue mit "cargo build --offline" in einer Sandbox.',
+  },
+  process: {
+    priority: 'medium',
+    icon: '⚙️',
+    message: 'starten externe Prozesse',
+    recommendation:
+      'Prüfe, welche Programme aufgerufen werden (Compiler, pkg-config, Shell) und ob dafür Umgebungsvariablen oder PATH manipuliert werden können.',
+  },
+  env: {
+    priority: 'low',
+    icon: '🔑',
+    message: 'lesen Umgebungsvariablen außerhalb von CARGO_*',
+    recommendation:
+      'Build-Skripte können so Secrets aus der CI-Umgebung lesen. Stelle sicher, dass Builds ohne Tokens in der Umgebung laufen, und deklariere benötigte Variablen mit cargo:rerun-if-env-changed.',
+  },
+};
+
 interface Tool {
   name: string;
   languages: string[];
@@ -143,7 +260,12 @@ export interface ProductionAudit {
     | 'testing'
     | 'containerization'
     | 'security'
-    | 'config';
+    | 'config'
+    | 'license-compliance'
+    | 'banned-crates'
+    | 'untrusted-sources'
+    | 'unsafe-code'
+    | 'build-performance';
   check: string;
   status: 'missing' | 'found' | 'partial';
   priority: 'critical' | 'high' | 'medium' | 'low';
@@ -162,6 +284,10 @@ export interface AuditConfig {
 export class ProductionReadinessAuditor {
   private projectPath: string;
   private databaseManager: ToolsDatabaseManager;
+  private cargoResolver = new CargoWorkspaceResolver();
+  private cargoManifestReader = new CargoManifestReader(this.cargoResolver);
+  private cargoProject?: Promise<CargoManifestSummary | null>;
+  private unsafeInventory?: Promise<UnsafeInventory | null>;
   private auditMetrics = {
     auditsPerformed: 0,
     totalIssuesFound: 0,
@@ -205,6 +331,9 @@ export class ProductionReadinessAuditor {
         this.auditContainerization(config),
         this.auditSecurity(config),
         this.auditEnvironmentConfig(config),
+        this.auditCargoPolicies(config),
+        this.auditUnsafeCode(config),
+        this.auditRustReleaseProfile(config),
       ];
 
       const results = await Promise.allSettled(
@@ -334,19 +463,41 @@ export class ProductionReadinessAuditor {
       // Categorize changed files
       const relevantFiles = this.categorizeRelevantFiles(sanitizedFiles);
 
-      // Only audit categories that are relevant to the changed files
+      // Collect the categories relevant to the changed files first, so a
+      // category shared by several file kinds is audited only once
+      const categories = new Set<
+        (config: AuditConfig) => Promise<ProductionAudit[]>
+      >();
+
       if (relevantFiles.packageJson.length > 0) {
-        audits.push(...(await this.auditErrorMonitoring(config)));
-        audits.push(...(await this.auditTestingFramework(config)));
-        audits.push(...(await this.auditSecurity(config)));
+        categories.add(this.auditErrorMonitoring);
+        categories.add(this.auditTestingFramework);
+        categories.add(this.auditSecurity);
+      }
+
+      if (relevantFiles.cargo.length > 0) {
+        categories.add(this.auditErrorMonitoring);
+        categories.add(this.auditEnvironmentConfig);
+        categories.add(this.auditSecurity);
+        categories.add(this.auditCargoPolicies);
+        categories.add(this.auditUnsafeCode);
+        categories.add(this.auditRustReleaseProfile);
       }
 
       if (relevantFiles.docker.length > 0) {
-        audits.push(...(await this.auditContainerization(config)));
+        categories.add(this.auditContainerization);
       }
 
       if (relevantFiles.config.length > 0) {
-        audits.push(...(await this.auditEnvironmentConfig(config)));
+        categories.add(this.auditEnvironmentConfig);
+      }
+
+      if (relevantFiles.source.some(file => file.endsWith('.rs'))) {
+        categories.add(this.auditUnsafeCode);
+      }
+
+      for (const audit of categories) {
+        audits.push(...(await audit.call(this, config)));
       }
 
       if (relevantFiles.source.length > 0) {
@@ -370,6 +521,11 @@ export class ProductionReadinessAuditor {
   private async auditErrorMonitoring(
     config: AuditConfig
   ): Promise<ProductionAudit[]> {
+    // Rust crates are not part of the (npm-centric) tools database
+    if (config.language.toLowerCase() === 'rust') {
+      return this.auditRustErrorMonitoring(config);
+    }
+
     const audits: ProductionAudit[] = [];
 
     try {
@@ -611,6 +767,11 @@ export class ProductionReadinessAuditor {
       }
     }
 
+    if (config.language.toLowerCase() === 'rust') {
+      audits.push(...(await this.auditRustTesting(config)));
+      audits.push(...(await this.auditFuzzReadiness(config)));
+    }
+
     return audits;
   }
 
@@ -952,9 +1113,848 @@ export class ProductionReadinessAuditor {
       }
     }
 
+    // Rust: RustSec advisories via cargo-audit, build scripts and proc-macros
+    if (config.language.toLowerCase() === 'rust') {
+      audits.push(...(await this.auditRustAdvisories()));
+      audits.push(...(await this.auditCompileTimeCode()));
+    }
+
+    return audits;
+  }
+
+  /**
+   * Run cargo-audit against the local advisory-db and turn RustSec
+   * advisories into production audits
+   */
+  private async auditRustAdvisories(): Promise<ProductionAudit[]> {
+    const audits: ProductionAudit[] = [];
+    const notificationManager = new NotificationManager({
+      terminal: false,
+      desktop: false,
+    });
+    const qualityRunner = new QualityRunner(notificationManager);
+    const result = await qualityRunner.runCargoAuditCheck({
+      projectPath: this.projectPath,
+    });
+
+    if (!result || result.error) {
+      audits.push({
+        category: 'security',
+        check: 'cargo-audit-setup',
+        status: 'missing',
+        priority: 'critical',
+        message: '🚨 cargo-audit nicht einsatzbereit',
+        recommendation: result?.error
+          ? sanitizeError(result.error)
+          : 'Installiere cargo-audit: cargo install cargo-audit --locked',
+        packages: ['cargo-audit'],
+      });
+      return audits;
+    }
+
+    const urgent = result.findings.filter(
+      f => f.severity === 'critical' || f.severity === 'high'
+    );
+    if (urgent.length > 0) {
+      const critical = urgent.some(f => f.severity === 'critical');
+      audits.push({
+        category: 'security',
+        check: 'rustsec-vulnerabilities',
+        status: 'missing',
+        priority: critical ? 'critical' : 'high',
+        message: `🚨 ${urgent.length} RustSec-Advisories mit hohem Risiko in Cargo.lock`,
+        recommendation: `${urgent.filter(f => f.fixedIn).length} können durch "cargo update" behoben werden. Führe "cargo audit" für Details aus.`,
+        packages: [
+          ...new Set(
+            urgent.map(f => sanitizePackageName(f.package)).slice(0, 5)
+          ),
+        ],
+      });
+
+      urgent.slice(0, 3).forEach(finding => {
+        audits.push({
+          category: 'security',
+          check: `vuln-${finding.advisoryId}`,
+          status: 'missing',
+          priority: finding.severity === 'critical' ? 'critical' : 'high',
+          message: `🔴 ${finding.title} in ${finding.package}@${finding.version}`,
+          recommendation:
+            finding.recommendation ||
+            'Kein direkter Fix verfügbar. Erwäge Alternative oder warte auf Patch.',
+          packages: finding.fixedIn
+            ? [sanitizePackageName(finding.package)]
+            : [],
+        });
+      });
+    }
+
+    const warnings = result.findings.filter(
+      f => f.severity === 'medium' || f.severity === 'low'
+    );
+    if (warnings.length > 0) {
+      audits.push({
+        category: 'security',
+        check: 'rustsec-warnings',
+        status: 'partial',
+        priority: 'medium',
+        message: `🔵 ${warnings.length} RustSec-Warnungen (unsound, unmaintained oder yanked)`,
+        recommendation:
+          'Prüfe betroffene Crates mit "cargo audit" und plane Updates oder Alternativen ein.',
+        packages: [],
+      });
+    }
+
+    return audits;
+  }
+
+  /**
+   * List dependencies running code at compile time (build.rs, proc-macros)
+   * and flag build scripts using the network, processes or foreign env vars
+   */
+  private async auditCompileTimeCode(): Promise<ProductionAudit[]> {
+    const report = await new BuildScriptAnalyzer()
+      .analyze(this.projectPath)
+      .catch(() => null);
+    if (!report) {
+      return [];
+    }
+
+    if (report.error) {
+      return [
+        {
+          category: 'security',
+          check: 'build-script-analysis',
+          status: 'partial',
+          priority: 'low',
+          message:
+            '⚠️ Build-Skripte und Proc-Macros konnten nicht analysiert werden',
+          recommendation: `${sanitizeError(report.error)} - führe "cargo fetch" aus und prüfe, ob Cargo.lock aktuell ist.`,
+          files: ['Cargo.lock'],
+        },
+      ];
+    }
+
+    const audits: ProductionAudit[] = [];
+    const describe = (crate: CompileTimeCrate) =>
+      `${sanitizePackageName(crate.name)}@${crate.version}`;
+    const dependencies = report.crates.filter(
+      crate => crate.source !== 'workspace'
+    );
+    const buildScripts = dependencies.filter(crate => crate.buildScript);
+    const procMacros = dependencies.filter(crate => crate.procMacro);
+
+    if (dependencies.length > 0) {
+      audits.push({
+        category: 'security',
+        check: 'compile-time-code',
+        status: 'partial',
+        priority: 'low',
+        message: `🏗️ ${dependencies.length} Abhängigkeit(en) führen beim Kompilieren Code aus (${buildScripts.length} build.rs, ${procMacros.length} Proc-Macros)`,
+        recommendation:
+          'Build-Skripte und Proc-Macros laufen mit den Rechten des Entwicklers bzw. der CI. Prüfe neue oder aktualisierte Crates mit cargo vet oder cargo crev und baue in einer Sandbox ohne Secrets.',
+        // The complete list is the inventory to review, so it is not cut
+        packages: dependencies.map(crate => {
+          const kinds = [
+            crate.buildScript ? 'build.rs' : '',
+            crate.procMacro ? 'proc-macro' : '',
+          ].filter(Boolean);
+          return `${describe(crate)} (${kinds.join(', ')})`;
+        }),
+      });
+    }
+
+    // Workspace build scripts are flagged too: they run in every CI job
+    const risks: BuildScriptRiskKind[] = ['network', 'process', 'env'];
+    for (const kind of risks) {
+      const risk = BUILD_SCRIPT_RISKS[kind];
+      const findingsOf = (crate: CompileTimeCrate) =>
+        crate.findings.filter(finding => finding.kind === kind);
+      const affected = report.crates.filter(
+        crate => findingsOf(crate).length > 0
+      );
+      if (affected.length === 0) {
+        continue;
+      }
+
+      audits.push({
+        category: 'security',
+        check: `build-script-${kind}`,
+        status: 'missing',
+        priority: risk.priority,
+        message: `${risk.icon} ${affected.length} Build-Skript(e) ${risk.message}`,
+        recommendation: risk.recommendation,
+        packages: affected
+          .map(crate => {
+            const details = new Set(findingsOf(crate).map(f => f.detail));
+            return `${describe(crate)}: ${[...details].join(', ')}`;
+          })
+          .slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+        // Dependency sources are shown relative to their crate
+        files: affected
+          .flatMap(crate =>
+            findingsOf(crate).map(finding => {
+              const file =
+                crate.source === 'workspace'
+                  ? path.relative(this.projectPath, finding.file)
+                  : path.join(
+                      describe(crate),
+                      path.relative(
+                        path.dirname(crate.manifestPath),
+                        finding.file
+                      )
+                    );
+              return `${file}:${finding.line}`;
+            })
+          )
+          .slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+      });
+    }
+
+    return audits;
+  }
+
+  /**
+   * Run cargo-deny (licenses, bans, advisories, sources) and bucket its
+   * diagnostics into one audit per check and diagnostic code
+   */
+  private async auditCargoPolicies(
+    config: AuditConfig
+  ): Promise<ProductionAudit[]> {
+    if (config.language.toLowerCase() !== 'rust') {
+      return [];
+    }
+
+    const notificationManager = new NotificationManager({
+      terminal: false,
+      desktop: false,
+    });
+    const qualityRunner = new QualityRunner(notificationManager);
+    const result = await qualityRunner.runCargoDenyChecks({
+      projectPath: this.projectPath,
+    });
+
+    if (!result) {
+      return [
+        {
+          category: 'license-compliance',
+          check: 'cargo-deny-setup',
+          status: 'missing',
+          priority: 'medium',
+          message: '⚖️ cargo-deny nicht installiert',
+          recommendation:
+            'Installiere cargo-deny für Lizenz-, Ban- und Quellen-Prüfungen: cargo install cargo-deny --locked',
+          packages: ['cargo-deny'],
+        },
+      ];
+    }
+
+    if (!result.configPath) {
+      return [
+        {
+          category: 'license-compliance',
+          check: 'cargo-deny-config',
+          status: 'missing',
+          priority: 'medium',
+          message: '⚖️ Keine cargo-deny Konfiguration (deny.toml) gefunden',
+          recommendation:
+            'Führe "woaru setup" aus, um eine deny.toml aus den Lizenzen in Cargo.lock zu erzeugen',
+          files: ['deny.toml'],
+        },
+      ];
+    }
+
+    const audits: ProductionAudit[] = [];
+    if (result.error) {
+      audits.push({
+        category: 'license-compliance',
+        check: 'cargo-deny-error',
+        status: 'partial',
+        priority: 'medium',
+        message: '⚠️ cargo-deny konnte nicht vollständig ausgeführt werden',
+        recommendation: sanitizeError(result.error),
+        files: [path.basename(result.configPath)],
+      });
+    }
+
+    // Notes/help (e.g. accepted licenses) are informational only
+    const groups = new Map<string, CargoDenyDiagnostic[]>();
+    for (const diagnostic of result.diagnostics) {
+      if (
+        diagnostic.severity !== 'error' &&
+        diagnostic.severity !== 'warning'
+      ) {
+        continue;
+      }
+      const key = `${diagnostic.check}:${diagnostic.code}`;
+      groups.set(key, [...(groups.get(key) || []), diagnostic]);
+    }
+
+    for (const diagnostics of groups.values()) {
+      const { check, code, message } = diagnostics[0];
+      const { category, icon, fix } = CARGO_DENY_AUDITS[check];
+      const isError = diagnostics.some(d => d.severity === 'error');
+      const crates = [
+        ...new Set(
+          diagnostics
+            .filter(d => d.crate)
+            .map(d => sanitizePackageName(d.crate))
+        ),
+      ];
+
+      audits.push({
+        category,
+        check: `cargo-deny-${check}-${code}`,
+        status: isError ? 'missing' : 'partial',
+        priority: isError ? 'high' : 'low',
+        message: `${icon} cargo-deny ${check}: ${message} (${diagnostics.length}×)`,
+        recommendation: fix,
+        packages: crates.slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+        files: [path.basename(result.configPath)],
+      });
+    }
+
+    return audits;
+  }
+
+  /**
+   * Inventory unsafe code of the workspace crates (and of dependencies, if
+   * the inventory includes them) and flag crates that do not forbid it
+   */
+  private async auditUnsafeCode(
+    config: AuditConfig
+  ): Promise<ProductionAudit[]> {
+    if (config.language.toLowerCase() !== 'rust') {
+      return [];
+    }
+
+    const inventory = await this.readUnsafeInventory();
+    if (!inventory) {
+      return [];
+    }
+
+    const audits: ProductionAudit[] = [];
+    const describe = (crate: UnsafeCrateInventory) =>
+      `${sanitizePackageName(crate.name)} (${totalUnsafe(crate)})`;
+    const byUnsafe = (a: UnsafeCrateInventory, b: UnsafeCrateInventory) =>
+      totalUnsafe(b) - totalUnsafe(a);
+    const workspaceCrates = inventory.crates.filter(
+      crate => crate.source === 'workspace'
+    );
+    const unsafeCrates = workspaceCrates
+      .filter(crate => totalUnsafe(crate) > 0)
+      .sort(byUnsafe);
+    const unguardedCrates = workspaceCrates.filter(
+      crate => totalUnsafe(crate) === 0 && !crate.forbidsUnsafe
+    );
+    const unsafeDependencies = inventory.crates
+      .filter(crate => crate.source !== 'workspace' && totalUnsafe(crate) > 0)
+      .sort(byUnsafe);
+
+    if (unsafeCrates.length > 0) {
+      const occurrences = unsafeCrates.reduce(
+        (sum, crate) => sum + totalUnsafe(crate),
+        0
+      );
+      const files = unsafeCrates.flatMap(crate =>
+        crate.files.map(file =>
+          path.relative(this.projectPath, path.join(crate.rootDir, file.file))
+        )
+      );
+      audits.push({
+        category: 'unsafe-code',
+        check: 'workspace-unsafe-code',
+        status: 'partial',
+        priority: 'medium',
+        message: `☢️ ${unsafeCrates.length} Workspace-Crate(s) enthalten unsafe-Code (${occurrences} Stellen)`,
+        recommendation:
+          'Kapsle unsafe-Code in kleinen, getesteten Modulen, begründe jeden Block mit einem "// SAFETY:"-Kommentar und aktiviere clippy::undocumented_unsafe_blocks.',
+        packages: unsafeCrates
+          .map(describe)
+          .slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+        files: files.slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+      });
+    }
+
+    if (unguardedCrates.length > 0) {
+      audits.push({
+        category: 'unsafe-code',
+        check: 'forbid-unsafe-code',
+        status: 'missing',
+        priority: 'low',
+        message: `🔒 ${unguardedCrates.length} Workspace-Crate(s) ohne unsafe-Code verbieten unsafe nicht`,
+        recommendation:
+          'Füge #![forbid(unsafe_code)] in lib.rs/main.rs hinzu oder setze unsafe_code = "forbid" unter [lints.rust] bzw. [workspace.lints.rust].',
+        packages: unguardedCrates
+          .map(crate => sanitizePackageName(crate.name))
+          .slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+      });
+    }
+
+    if (unsafeDependencies.length > 0) {
+      audits.push({
+        category: 'unsafe-code',
+        check: 'dependency-unsafe-code',
+        status: 'partial',
+        priority: 'low',
+        message: `☢️ ${unsafeDependencies.length} Abhängigkeit(en) enthalten unsafe-Code`,
+        recommendation:
+          'Bevorzuge Crates mit #![forbid(unsafe_code)] und prüfe unsafe-lastige Abhängigkeiten mit cargo geiger bzw. cargo vet.',
+        packages: unsafeDependencies
+          .map(describe)
+          .slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+      });
+    }
+
     return audits;
   }
 
+  /**
+   * Reuse an inventory the caller has already scanned; null skips the
+   * unsafe code audit
+   */
+  useUnsafeInventory(inventory: UnsafeInventory | null): void {
+    this.unsafeInventory = Promise.resolve(inventory);
+  }
+
+  /**
+   * Unsafe code inventory of the audited project (cached per auditor)
+   */
+  private readUnsafeInventory(): Promise<UnsafeInventory | null> {
+    if (!this.unsafeInventory) {
+      this.unsafeInventory = new UnsafeInventoryScanner(this.cargoResolver)
+        .scan(this.projectPath)
+        .catch(() => null);
+    }
+    return this.unsafeInventory;
+  }
+
+  /**
+   * Cargo manifest summary of the audited project (cached per auditor)
+   */
+  private readCargoProject(): Promise<CargoManifestSummary | null> {
+    if (!this.cargoProject) {
+      this.cargoProject = this.cargoManifestReader
+        .readProject(this.projectPath)
+        .catch(() => null);
+    }
+    return this.cargoProject;
+  }
+
+  /**
+   * Names of normal (non-dev, non-build) dependency crates
+   */
+  private async readRustCrates(): Promise<Set<string> | null> {
+    const cargo = await this.readCargoProject();
+    if (!cargo) return null;
+
+    return new Set(
+      cargo.dependencies
+        .filter(dep => dep.kind === 'normal')
+        .map(dep => dep.name)
+    );
+  }
+
+  private async auditRustErrorMonitoring(
+    config: AuditConfig
+  ): Promise<ProductionAudit[]> {
+    const crates = await this.readRustCrates();
+    if (!crates) return [];
+
+    const has = (names: readonly string[]) => names.some(n => crates.has(n));
+    const hasFacade = has(RUST_LOGGING_CRATES.facades);
+
+    // Libraries log through a facade and leave reporting to the application
+    if (config.projectType === 'library') {
+      return hasFacade
+        ? []
+        : [
+            {
+              category: 'error-monitoring',
+              check: 'rust-logging-facade',
+              status: 'missing',
+              priority: 'low',
+              message:
+                '💡 PRO-TIPP: Kein Logging-Facade-Crate (tracing/log) gefunden',
+              recommendation:
+                'Bibliotheken sollten über tracing oder log loggen und Ausgabe/Reporting der Anwendung überlassen: cargo add tracing',
+              packages: ['tracing', 'log'],
+            },
+          ];
+    }
+
+    const audits: ProductionAudit[] = [];
+    const hasSentry = [...crates].some(
+      crate => crate === 'sentry' || crate.startsWith('sentry-')
+    );
+
+    if (!hasSentry && !hasFacade) {
+      audits.push({
+        category: 'error-monitoring',
+        check: 'rust-error-tracking',
+        status: 'missing',
+        priority: 'critical',
+        message:
+          '🚨 KRITISCH: Weder Error-Monitoring noch Logging für Rust gefunden',
+        recommendation:
+          'Richte tracing und Sentry ein: cargo add tracing tracing-subscriber && cargo add sentry --features tracing',
+        packages: ['sentry', 'tracing', 'tracing-subscriber'],
+      });
+      return audits;
+    }
+
+    if (!hasSentry) {
+      audits.push({
+        category: 'error-monitoring',
+        check: 'rust-error-tracking',
+        status: 'partial',
+        priority: 'high',
+        message:
+          '💡 Logging vorhanden, aber kein Error-Reporting – Panics und Fehler werden nicht zentral erfasst',
+        recommendation:
+          'cargo add sentry --features tracing und sentry::init(...) beim Start aufrufen; Panics werden automatisch gemeldet, error!-Events über die tracing-Integration',
+        packages: ['sentry'],
+      });
+    }
+
+    if (hasFacade && !has(RUST_LOGGING_CRATES.backends)) {
+      audits.push({
+        category: 'error-monitoring',
+        check: 'rust-log-backend',
+        status: 'partial',
+        priority: 'medium',
+        message:
+          'tracing/log eingebunden, aber kein Subscriber bzw. Logger – Log-Ausgaben gehen verloren',
+        recommendation: crates.has('tracing')
+          ? 'cargo add tracing-subscriber und tracing_subscriber::fmt::init() in main() aufrufen'
+          : 'cargo add env_logger und env_logger::init() in main() aufrufen',
+        packages: crates.has('tracing')
+          ? ['tracing-subscriber']
+          : ['env_logger'],
+      });
+    }
+
+    return audits;
+  }
+
+  private async auditRustTesting(
+    config: AuditConfig
+  ): Promise<ProductionAudit[]> {
+    if (!(await this.readCargoProject())) return [];
+
+    const { unitTestFiles, integrationTestFiles } =
+      await this.findRustTests(this.projectPath);
+
+    if (unitTestFiles === 0 && integrationTestFiles === 0) {
+      return [
+        {
+          category: 'testing',
+          check: 'rust-tests',
+          status: 'missing',
+          priority: config.projectType === 'library' ? 'critical' : 'high',
+          message:
+            '⚠️ WARNUNG: Keine Rust-Tests gefunden (weder #[cfg(test)]-Module noch tests/)',
+          recommendation:
+            'Lege Unit-Tests in einem #[cfg(test)] mod tests neben dem Code an und Integrationstests unter tests/; ausführen mit cargo test',
+          files: ['tests/'],
+        },
+      ];
+    }
+
+    if (integrationTestFiles === 0) {
+      return [
+        {
+          category: 'testing',
+          check: 'rust-integration-tests',
+          status: 'partial',
+          priority: config.projectType === 'library' ? 'medium' : 'low',
+          message: `Nur Unit-Tests gefunden (${unitTestFiles} Datei(en)), keine Integrationstests unter tests/`,
+          recommendation:
+            'Integrationstests unter tests/ prüfen die öffentliche API so, wie ein externer Nutzer sie verwendet',
+          files: ['tests/'],
+        },
+      ];
+    }
+
+    if (unitTestFiles === 0) {
+      return [
+        {
+          category: 'testing',
+          check: 'rust-unit-tests',
+          status: 'partial',
+          priority: 'low',
+          message:
+            'Nur Integrationstests gefunden, keine #[cfg(test)]-Module im Quellcode',
+          recommendation:
+            'Unit-Tests in #[cfg(test)] mod tests können auch private Funktionen direkt testen',
+        },
+      ];
+    }
+
+    return [];
+  }
+
+  /**
+   * Flag public functions taking `&[u8]`/`&str` that no cargo-fuzz target
+   * exercises and libraries without property-based tests
+   */
+  private async auditFuzzReadiness(
+    config: AuditConfig
+  ): Promise<ProductionAudit[]> {
+    const report = await new FuzzReadinessScanner()
+      .scan(this.projectPath)
+      .catch(() => null);
+    if (!report || report.candidates.length === 0) {
+      return [];
+    }
+
+    const audits: ProductionAudit[] = [];
+    const describe = (candidate: FuzzCandidate) =>
+      `${sanitizePackageName(candidate.crate)}::${candidate.name}(${candidate.input})`;
+    const locate = (candidate: FuzzCandidate) =>
+      `${path.relative(this.projectPath, candidate.file)}:${candidate.line}`;
+    const unfuzzed = report.candidates.filter(candidate => !candidate.fuzzed);
+
+    if (report.fuzzTargets.length === 0) {
+      const example = report.candidates[0];
+      const harness =
+        example.input === '&str'
+          ? `if let Ok(s) = std::str::from_utf8(data) { let _ = ${example.crate.replace(/-/g, '_')}::${example.name}(s); }`
+          : `let _ = ${example.crate.replace(/-/g, '_')}::${example.name}(data);`;
+      audits.push({
+        category: 'testing',
+        check: 'fuzz-targets',
+        status: 'missing',
+        priority: config.projectType === 'library' ? 'medium' : 'low',
+        message: `🐛 ${report.candidates.length} öffentliche Funktion(en) verarbeiten &[u8]/&str, aber es gibt keine cargo-fuzz-Targets`,
+        recommendation: `Parser und Decoder für externe Eingaben sollten gefuzzt werden: cargo install cargo-fuzz && cargo fuzz init, dann pro Funktion cargo fuzz add <name> mit einem Harness wie fuzz_target!(|data: &[u8]| { ${harness} }); und cargo +nightly fuzz run <name>`,
+        packages: ['cargo-fuzz', 'libfuzzer-sys'],
+        files: report.candidates
+          .map(locate)
+          .slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+      });
+    } else if (unfuzzed.length > 0) {
+      audits.push({
+        category: 'testing',
+        check: 'fuzz-coverage',
+        status: 'partial',
+        priority: 'low',
+        message: `🐛 ${unfuzzed.length} von ${report.candidates.length} öffentlichen Funktion(en) mit &[u8]/&str werden von keinem der ${report.fuzzTargets.length} Fuzz-Targets aufgerufen`,
+        recommendation:
+          'Lege mit cargo fuzz add <name> weitere Harnesses an oder rufe die Funktionen aus bestehenden Fuzz-Targets auf.',
+        packages: unfuzzed
+          .map(describe)
+          .slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+        files: unfuzzed
+          .map(locate)
+          .slice(0, SECURITY_LIMITS.MAX_VULNERABILITIES_DISPLAY),
+      });
+    }
+
+    if (report.propertyTesting.length === 0) {
+      audits.push({
+        category: 'testing',
+        check: 'property-tests',
+        status: 'missing',
+        priority: 'low',
+        message:
+          '🎲 Keine Property-Based-Tests gefunden (weder proptest noch quickcheck als Dev-Dependency)',
+        recommendation:
+          'Property-Tests finden Randfälle, an die Beispieltests nicht denken: cargo add --dev proptest und z.B. proptest! { #[test] fn roundtrip(s in ".*") { prop_assert_eq!(decode(&encode(&s))?, s); } }',
+        packages: ['proptest', 'quickcheck'],
+      });
+    }
+
+    return audits;
+  }
+
+  /**
+   * Count Rust files containing unit tests (`#[cfg(test)]` or `#[test]`
+   * items) and integration test files below a `tests/` directory
+   */
+  private async findRustTests(
+    dir: string,
+    depth = 0,
+    inTestsDir = false
+  ): Promise<{ unitTestFiles: number; integrationTestFiles: number }> {
+    const counts = { unitTestFiles: 0, integrationTestFiles: 0 };
+    if (depth > RUST_SOURCE_SCAN.MAX_DEPTH) return counts;
+
+    const entries = await fs
+      .readdir(dir, { withFileTypes: true })
+      .catch(() => []);
+
+    for (const entry of entries) {
+      const entryPath = path.join(dir, entry.name);
+
+      if (entry.isDirectory()) {
+        if (RUST_SOURCE_SCAN.SKIPPED_DIRS.includes(entry.name)) continue;
+        const nested = await this.findRustTests(
+          entryPath,
+          depth + 1,
+          inTestsDir || (entry.name === 'tests' && path.basename(dir) !== 'src')
+        );
+        counts.unitTestFiles += nested.unitTestFiles;
+        counts.integrationTestFiles += nested.integrationTestFiles;
+        continue;
+      }
+
+      if (!entry.name.endsWith('.rs')) continue;
+      if (inTestsDir) {
+        counts.integrationTestFiles++;
+        continue;
+      }
+
+      try {
+        const stats = await fs.stat(entryPath);
+        if (stats.size > SECURITY_LIMITS.MAX_FILE_SIZE) continue;
+        const content = await fs.readFile(entryPath, 'utf-8');
+        if (
+          content.includes('test') &&
+          findTestRegions(maskRustSource(content)).length > 0
+        ) {
+          counts.unitTestFiles++;
+        }
+      } catch {
+        // Unreadable files are skipped
+      }
+    }
+
+    return counts;
+  }
+
+  private async auditRustEnvironmentConfig(
+    config: AuditConfig,
+    hasEnvFiles: boolean
+  ): Promise<ProductionAudit[]> {
+    const crates = await this.readRustCrates();
+    if (!crates) return [];
+
+    const audits: ProductionAudit[] = [];
+    const hasDotenv = crates.has('dotenvy') || crates.has('dotenv');
+
+    if (crates.has('dotenv')) {
+      audits.push({
+        category: 'config',
+        check: 'rust-dotenv-unmaintained',
+        status: 'partial',
+        priority: 'medium',
+        message: 'dotenv wird nicht mehr gepflegt (RUSTSEC-2021-0141)',
+        recommendation:
+          'Ersetze es durch den API-kompatiblen Fork: cargo remove dotenv && cargo add dotenvy',
+        packages: ['dotenvy'],
+      });
+    }
+
+    if (hasDotenv && !hasEnvFiles) {
+      audits.push({
+        category: 'config',
+        check: 'env-config',
+        status: 'partial',
+        priority: 'medium',
+        message: 'dotenvy eingebunden, aber keine .env-Dateien gefunden',
+        recommendation:
+          'Erstelle .env für lokale Konfiguration und .env.example als Template.',
+        files: ['.env', '.env.example'],
+      });
+    }
+
+    if (
+      hasEnvFiles &&
+      config.projectType !== 'library' &&
+      !RUST_ENV_CRATES.some(crate => crates.has(crate))
+    ) {
+      audits.push({
+        category: 'config',
+        check: 'rust-env-loading',
+        status: 'partial',
+        priority: 'low',
+        message: '.env-Dateien gefunden, aber kein Crate lädt sie',
+        recommendation:
+          'cargo add dotenvy und dotenvy::dotenv().ok() zu Beginn von main() aufrufen – oder config/figment für geschichtete Konfiguration nutzen',
+        packages: ['dotenvy', 'config'],
+      });
+    }
+
+    return audits;
+  }
+
+  /**
+   * Check [profile.release] of the workspace root manifest (profiles in
+   * member manifests are ignored by Cargo)
+   */
+  private async auditRustReleaseProfile(
+    config: AuditConfig
+  ): Promise<ProductionAudit[]> {
+    const cargo = await this.readCargoProject();
+    // Dependents build libraries with their own profile
+    if (!cargo || config.projectType === 'library') return [];
+
+    const manifest = await this.cargoResolver.loadManifest(
+      cargo.workspaceManifestPath
+    );
+    const profile =
+      manifest &&
+      isTomlTable(manifest.profile) &&
+      isTomlTable(manifest.profile.release)
+        ? manifest.profile.release
+        : null;
+    const release: TomlTable = profile || {};
+    const hints: string[] = [];
+
+    const lto = release.lto;
+    if (lto === undefined || lto === false || lto === 'off') {
+      hints.push('lto = "thin" (oder "fat") für Cross-Crate-Optimierung');
+    }
+
+    const codegenUnits = release['codegen-units'];
+    if (typeof codegenUnits !== 'number' || codegenUnits > 1) {
+      hints.push('codegen-units = 1 für bessere Optimierung');
+    }
+
+    const usesTokio = cargo.frameworks.includes('tokio');
+    if (release.panic === 'abort' && usesTokio) {
+      hints.push(
+        'panic = "abort" beendet bei einer Panic in einem tokio-Task den ganzen Prozess – "unwind" verwenden'
+      );
+    } else if (release.panic === undefined && !usesTokio) {
+      hints.push(
+        'panic = "abort" festlegen, falls kein catch_unwind benötigt wird (kleineres Binary)'
+      );
+    }
+
+    const debug = release.debug;
+    if (
+      debug === true ||
+      debug === 1 ||
+      debug === 2 ||
+      debug === 'limited' ||
+      debug === 'full'
+    ) {
+      hints.push(
+        'debug = "line-tables-only" statt voller Debug-Infos (kleineres Binary, Backtraces bleiben lesbar)'
+      );
+    }
+
+    if (hints.length === 0) return [];
+
+    return [
+      {
+        category: 'build-performance',
+        check: 'rust-release-profile',
+        status: profile ? 'partial' : 'missing',
+        priority: profile ? 'low' : 'medium',
+        message: profile
+          ? `⚙️ [profile.release] unvollständig (${hints.length} Hinweise)`
+          : '⚙️ Kein [profile.release] in Cargo.toml – Release-Builds nutzen die Standardwerte',
+        recommendation: hints.join('; '),
+        files: [
+          path.relative(this.projectPath, cargo.workspaceManifestPath) ||
+            'Cargo.toml',
+        ],
+      },
+    ];
+  }
+
   /**
    * Helper to get all project files (for comprehensive Snyk scan)
    */
@@ -1003,6 +2003,15 @@ export class ProductionReadinessAuditor {
       }
     }
 
+    if (config.language.toLowerCase() === 'rust') {
+      audits.push(
+        ...(await this.auditRustEnvironmentConfig(
+          config,
+          hasEnv || hasEnvExample
+        ))
+      );
+    }
+
     return audits;
   }
 
@@ -1027,12 +2036,14 @@ export class ProductionReadinessAuditor {
   // Helper methods for changed file analysis
   private categorizeRelevantFiles(changedFiles: string[]): {
     packageJson: string[];
+    cargo: string[];
     docker: string[];
     config: string[];
     source: string[];
   } {
     const result = {
       packageJson: [] as string[],
+      cargo: [] as string[],
       docker: [] as string[],
       config: [] as string[],
       source: [] as string[],
@@ -1044,6 +2055,8 @@ export class ProductionReadinessAuditor {
 
       if (filename === 'package.json') {
         result.packageJson.push(file);
+      } else if (CARGO_POLICY_FILES.includes(filename)) {
+        result.cargo.push(file);
       } else if (
         filename.startsWith('Dockerfile') ||
         filename === '.dockerignore'
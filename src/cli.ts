@@ -46,7 +46,10 @@ function defineCommands(program: Command) {
   program
     .command('init')
     .description('Initialize a new project with best practices')
-    .option('-t, --template <type>', 'Project template (nextjs, python-fastapi)')
+    .option(
+      '-t, --template <type>',
+      'Project template (nextjs, python-fastapi, rust-axum, rust-cli, rust-library)'
+    )
     .option('-d, --directory <path>', 'Target directory')
     .option('-f, --features <features>', 'Comma-separated list of features')
     .option('--skip-install', 'Skip dependency installation')
@@ -237,6 +240,7 @@ function defineCommands(program: Command) {
             'woaru docu nopro --local',
             'woaru docu nopro --git main',
             'woaru docu nopro --path-only src/',
+            'woaru docu nopro --missing-only',
             'woaru docu nopro --preview'
           ],
           'docu pro': [
@@ -244,6 +248,7 @@ function defineCommands(program: Command) {
             'woaru docu pro --local',
             'woaru docu pro --git develop',
             'woaru docu pro --path-only lib/',
+            'woaru docu pro --missing-only',
             'woaru docu pro --force'
           ],
           'docu forai': [
@@ -277,7 +282,8 @@ function defineCommands(program: Command) {
           ],
           'rollback': [
             'woaru rollback eslint',
-            'woaru rollback prettier'
+            'woaru rollback prettier',
+            'woaru rollback clippy'
           ],
           'message': [
             'woaru message --webhook https://hooks.slack.com/...',
@@ -365,6 +371,10 @@ function defineCommands(program: Command) {
       'Show what would be done without actually doing it'
     )
     .option('-i, --interactive', 'Interactive mode with prompts', true)
+    .option(
+      '--fix',
+      'Let tools rewrite code after configuring them (cargo fmt, cargo clippy --fix)'
+    )
     .action(async options => {
       try {
         console.log(chalk.cyan('Starting WOARU setup...'));
@@ -394,6 +404,7 @@ function defineCommands(program: Command) {
           await engine.setupProject(process.cwd(), {
             dryRun: false,
             interactive: options.interactive,
+            fix: options.fix,
           });
           console.log(chalk.green('Setup completed!'));
         }
@@ -634,6 +645,10 @@ function defineCommands(program: Command) {
     .option('--local', 'Document uncommitted changes only')
     .option('--git <branch>', 'Document changes since specified branch')
     .option('--path-only <path>', 'Document specific files or directories')
+    .option(
+      '--missing-only',
+      'Document only Rust modules with undocumented public items (rustdoc coverage)'
+    )
     .option('--preview', 'Preview changes without applying them')
     .option('--force', 'Apply documentation without confirmation')
     .action(async (options) => {
@@ -662,6 +677,10 @@ function defineCommands(program: Command) {
     .option('--local', 'Document uncommitted changes only')
     .option('--git <branch>', 'Document changes since specified branch')
     .option('--path-only <path>', 'Document specific files or directories')
+    .option(
+      '--missing-only',
+      'Document only Rust modules with undocumented public items (rustdoc coverage)'
+    )
     .option('--preview', 'Preview changes without applying them')
     .option('--force', 'Apply documentation without confirmation')
     .action(async (options) => {
@@ -725,7 +744,7 @@ function defineCommands(program: Command) {
     });
 
   // Review Command
-  program
+  const reviewCmd = program
     .command('review')
     .description(t('commands.review.description'))
     .argument('[path]', 'Path to review', process.cwd())
@@ -804,17 +823,413 @@ function defineCommands(program: Command) {
       }
     });
 
+  // Review changes since a base branch (quality, security, audits, coverage)
+  reviewCmd
+    .command('git')
+    .description('Review changes since a base branch and write a report')
+    .option('-b, --branch <branch>', 'Base branch to compare against', 'main')
+    .option('--json', 'Output the report as JSON')
+    .option('-o, --output <file>', 'Write the report to this file')
+    .option(
+      '--coverage <file>',
+      'Coverage report (lcov, Cobertura XML or tarpaulin JSON), auto-detected if omitted'
+    )
+    .option('--no-tests', 'Skip running the test suite (Rust projects)')
+    .option(
+      '--slow-test-threshold <ms>',
+      'Report tests running at least this long as slow',
+      '1000'
+    )
+    .option(
+      '--feature-check [mode]',
+      'Run cargo check over Cargo feature combinations: each-feature (default) or powerset'
+    )
+    .option(
+      '--feature-depth <n>',
+      'Maximum number of features combined in powerset mode',
+      '2'
+    )
+    .option(
+      '--no-semver-checks',
+      'Skip comparing the public API of changed library crates with the base branch'
+    )
+    .option(
+      '--rustdoc-coverage',
+      'Measure documentation coverage with rustdoc --show-coverage (nightly build) instead of a source scan'
+    )
+    .option(
+      '--strict-semver',
+      'Also fail the review when new public API comes without a minor version bump'
+    )
+    .option(
+      '--no-unsafe-inventory',
+      'Skip the inventory of unsafe code in the workspace crates'
+    )
+    .option(
+      '--unsafe-dependencies',
+      'Include the registry dependencies from Cargo.lock in the unsafe code inventory'
+    )
+    .option(
+      '--bench',
+      'Run cargo bench before comparing Criterion results (otherwise the results of the last run are used)'
+    )
+    .option(
+      '--bench-threshold <percent>',
+      'Report benchmarks at least this much slower than the baseline',
+      '5'
+    )
+    .action(async options => {
+      try {
+        const projectPath = process.cwd();
+        const { GitDiffAnalyzer } = await import('./utils/GitDiffAnalyzer');
+        const gitAnalyzer = new GitDiffAnalyzer(projectPath);
+        if (!(await gitAnalyzer.isGitRepository())) {
+          console.error(chalk.red('❌ Not a git repository'));
+          process.exitCode = 1;
+          return;
+        }
+
+        console.log(
+          chalk.cyan(`Reviewing changes since ${options.branch}...`)
+        );
+        const gitDiff = await gitAnalyzer.getChangedFilesSince(options.branch);
+        const [currentBranch, commits, changedLines] = await Promise.all([
+          gitAnalyzer.getCurrentBranch(),
+          gitAnalyzer.getCommitsSince(options.branch),
+          gitAnalyzer.getChangedLinesSince(options.branch),
+        ]);
+
+        const { ProjectAnalyzer } = await import('./analyzer/ProjectAnalyzer');
+        const analysis = await new ProjectAnalyzer().analyzeProject(
+          projectPath
+        );
+
+        const { NotificationManager } = await import(
+          './supervisor/NotificationManager'
+        );
+        const { QualityRunner } = await import('./quality/QualityRunner');
+        const qualityRunner = new QualityRunner(
+          new NotificationManager({ terminal: false, desktop: false })
+        );
+        const qualityResults = await qualityRunner.runChecksOnFileList(
+          gitDiff.changedFiles
+        );
+
+        // Opt-in: feature combinations nobody builds (cargo-hack or fallback)
+        if (options.featureCheck && analysis.language === 'Rust') {
+          const mode =
+            options.featureCheck === true
+              ? 'each-feature'
+              : options.featureCheck;
+          if (mode !== 'each-feature' && mode !== 'powerset') {
+            console.error(
+              chalk.red(
+                `❌ Unknown feature check mode "${mode}" (use each-feature or powerset)`
+              )
+            );
+            process.exitCode = 1;
+            return;
+          }
+          console.log(chalk.cyan(`Checking feature combinations (${mode})...`));
+          qualityResults.push(
+            ...(await qualityRunner.runFeatureCombinationCheck(projectPath, {
+              mode,
+              depth: Number(options.featureDepth) || undefined,
+            }))
+          );
+        }
+
+        const securityResults = await qualityRunner.runSecurityChecksForReview(
+          gitDiff.changedFiles,
+          { projectPath }
+        );
+
+        // Scanned once and shared with the production audit
+        let unsafeInventory;
+        if (analysis.language === 'Rust' && options.unsafeInventory) {
+          const { UnsafeInventoryScanner } = await import(
+            './rust/UnsafeInventoryScanner'
+          );
+          unsafeInventory =
+            (await new UnsafeInventoryScanner().scan(projectPath, {
+              includeDependencies: Boolean(options.unsafeDependencies),
+            })) || undefined;
+        }
+
+        const { ProductionReadinessAuditor } = await import(
+          './auditor/ProductionReadinessAuditor'
+        );
+        const { determineRustProjectType } = await import(
+          './rust/CargoManifestReader'
+        );
+        const auditor = new ProductionReadinessAuditor(projectPath);
+        if (analysis.language === 'Rust') {
+          auditor.useUnsafeInventory(unsafeInventory || null);
+        }
+        const productionAudits = await auditor.auditChangedFiles(
+          gitDiff.changedFiles,
+          {
+            language: analysis.language,
+            frameworks: analysis.framework,
+            // Rust crates such as serde or tokio say nothing about the type
+            projectType: analysis.cargo
+              ? determineRustProjectType(analysis.cargo)
+              : analysis.framework.length > 0
+                ? 'fullstack'
+                : 'library',
+          }
+        );
+
+        const { CoverageReader, formatLineRanges } = await import(
+          './quality/CoverageReader'
+        );
+        const coverageReport = await new CoverageReader(projectPath).read(
+          options.coverage
+        );
+        if (options.coverage && !coverageReport) {
+          console.log(
+            chalk.yellow(
+              `⚠️ Coverage report ${options.coverage} could not be read`
+            )
+          );
+        }
+        const coverage = coverageReport
+          ? CoverageReader.forChanges(coverageReport, changedLines)
+          : undefined;
+
+        let testResults;
+        if (analysis.language === 'Rust' && options.tests) {
+          console.log(chalk.cyan('Running tests...'));
+          const { RustTestRunner } = await import('./quality/RustTestRunner');
+          testResults =
+            (await new RustTestRunner(projectPath).run(
+              Number(options.slowTestThreshold) || undefined
+            )) || undefined;
+        }
+
+        let semverChecks;
+        if (analysis.language === 'Rust' && options.semverChecks) {
+          console.log(
+            chalk.cyan(`Checking public API against ${options.branch}...`)
+          );
+          const { SemverChecker } = await import('./rust/SemverChecker');
+          try {
+            semverChecks = await new SemverChecker().check(
+              projectPath,
+              gitDiff.changedFiles,
+              options.branch
+            );
+          } catch (error) {
+            const errorMessage =
+              error instanceof Error ? error.message : String(error);
+            console.log(
+              chalk.yellow(`⚠️ Semver checks skipped: ${errorMessage}`)
+            );
+          }
+        }
+
+        let benchmarks;
+        if (analysis.language === 'Rust') {
+          if (options.bench) {
+            console.log(chalk.cyan('Running benchmarks...'));
+          }
+          const { CriterionBenchmarkTracker } = await import(
+            './quality/CriterionBenchmarks'
+          );
+          try {
+            const [commit, commitTime, baseCommit] = await Promise.all([
+              gitAnalyzer.getHeadCommit(),
+              gitAnalyzer.getHeadCommitTime().catch(() => undefined),
+              gitAnalyzer.getMergeBase(options.branch).catch(() => undefined),
+            ]);
+            const threshold = Number(options.benchThreshold);
+            benchmarks =
+              (await new CriterionBenchmarkTracker(projectPath).track({
+                commit,
+                commitTime,
+                branch: currentBranch || undefined,
+                baseCommit,
+                runBenchmarks: Boolean(options.bench),
+                thresholdPercent:
+                  isNaN(threshold) || threshold < 0 ? undefined : threshold,
+              })) || undefined;
+          } catch (error) {
+            const errorMessage =
+              error instanceof Error ? error.message : String(error);
+            console.log(
+              chalk.yellow(`⚠️ Benchmarks skipped: ${errorMessage}`)
+            );
+          }
+        }
+
+        let docCoverage;
+        if (analysis.language === 'Rust') {
+          const { RustDocCoverageAnalyzer } = await import(
+            './rust/RustDocCoverage'
+          );
+          docCoverage =
+            (await new RustDocCoverageAnalyzer()
+              .analyze(projectPath, {
+                rustdoc: Boolean(options.rustdocCoverage),
+              })
+              .catch(() => null)) || undefined;
+        }
+
+        const { ReviewReportGenerator } = await import(
+          './reports/ReviewReportGenerator'
+        );
+        const generator = new ReviewReportGenerator();
+        const reportData = {
+          context: {
+            type: 'git',
+            description: `Changes since ${options.branch}`,
+          },
+          gitDiff,
+          qualityResults,
+          securityResults,
+          productionAudits,
+          coverage,
+          testResults,
+          semverChecks,
+          benchmarks,
+          unsafeInventory,
+          docCoverage,
+          currentBranch,
+          commits,
+        };
+
+        if (options.json) {
+          const json = generator.generateJsonReport(reportData);
+          if (options.output) {
+            await fs.writeFile(options.output, json);
+            console.log(chalk.green(`📄 Report written to ${options.output}`));
+          } else {
+            console.log(json);
+          }
+        } else {
+          const reportPath = await generator.generateMarkdownReport(
+            reportData,
+            options.output
+          );
+          console.log(generator.getReportSummary(reportData));
+          console.log(chalk.green(`📄 Report written to ${reportPath}`));
+        }
+
+        // Changed lines no test executes
+        if (coverage && coverage.uncoveredChanges.length > 0) {
+          console.log(
+            chalk.yellow('\n⚠️ Changed lines without test coverage:')
+          );
+          coverage.uncoveredChanges.forEach(change => {
+            console.log(
+              chalk.yellow(
+                `  • ${path.relative(projectPath, change.file)}: ${formatLineRanges(change.lines)}`
+              )
+            );
+          });
+        }
+
+        // Slower benchmarks are reported, but do not fail the review
+        if (benchmarks && benchmarks.regressions.length > 0) {
+          console.log(
+            chalk.yellow(
+              `\n⚠️ ${benchmarks.regressions.length} benchmark(s) more than ${benchmarks.thresholdPercent}% slower than the ${benchmarks.baselineSource === 'base-branch' ? 'base branch' : 'previous run'}:`
+            )
+          );
+          benchmarks.regressions.forEach(regression => {
+            console.log(
+              chalk.yellow(`  • ${regression.id}: +${regression.changePercent}%`)
+            );
+          });
+        }
+
+        // Failing tests (or a test build that failed) fail the review
+        if (testResults && !testResults.success) {
+          console.log(
+            chalk.red(
+              testResults.failed > 0
+                ? `\n❌ ${testResults.failed} failing test(s):`
+                : `\n❌ Tests could not be run (${testResults.runner})`
+            )
+          );
+          testResults.failedTests.forEach(test => {
+            console.log(chalk.red(`  • ${test.name}`));
+          });
+          process.exitCode = 1;
+        }
+
+        // Breaking API changes without a matching version bump; additions
+        // without a minor bump only fail with --strict-semver
+        const semverViolations = (semverChecks || []).filter(
+          check =>
+            check.violation || (options.strictSemver && check.bumpTooSmall)
+        );
+        const semverNotices = (semverChecks || []).filter(
+          check => check.bumpTooSmall && !semverViolations.includes(check)
+        );
+        if (semverNotices.length > 0) {
+          console.log(
+            chalk.blue('\nℹ️ New public API without a minor version bump:')
+          );
+          semverNotices.forEach(check => {
+            console.log(
+              chalk.blue(
+                `  • ${check.packageName} ${check.baseVersion} → ${check.currentVersion}: ${check.requiredBump} bump suggested`
+              )
+            );
+          });
+        }
+        if (semverViolations.length > 0) {
+          console.log(
+            chalk.red('\n❌ Version bump too small for API changes:')
+          );
+          semverViolations.forEach(check => {
+            console.log(
+              chalk.red(
+                `  • ${check.packageName} ${check.baseVersion} → ${check.currentVersion}: ${check.requiredBump} bump required (${check.changes.filter(change => change.level === check.requiredBump).length} ${check.requiredBump === 'major' ? 'breaking' : 'additive'} change(s))`
+              )
+            );
+          });
+          process.exitCode = 1;
+        }
+      } catch (error) {
+        console.error(chalk.red('Review failed:'), error);
+        process.exitCode = 1;
+      }
+    });
+
   // Analyze Command with AI subcommand
   const analyzeCmd = program
     .command('analyze')
     .description(t('commands.analyze.description'))
     .option('-p, --path <path>', 'Path to analyze', process.cwd())
+    .option(
+      '--msrv-check',
+      'Rust: run cargo check on the installed toolchain matching rust-version'
+    )
+    .option(
+      '--udeps',
+      'Rust: fall back to cargo-udeps (nightly build) when cargo-machete is not installed'
+    )
+    .option(
+      '--rustdoc-coverage',
+      'Rust: measure documentation coverage with rustdoc --show-coverage (nightly build) instead of a source scan'
+    )
+    .option(
+      '--unsafe-dependencies',
+      'Rust: include the registry dependencies from Cargo.lock in the unsafe code inventory'
+    )
     .action(async options => {
       try {
         console.log(chalk.cyan(t('woaru_engine.analyzing_project')));
         const { WOARUEngine } = await import('./core/WOARUEngine');
         const engine = new WOARUEngine();
-        const result = await engine.analyzeProject(options.path);
+        const result = await engine.analyzeProject(options.path, {
+          msrvCheck: Boolean(options.msrvCheck),
+          udeps: Boolean(options.udeps),
+          rustdocCoverage: Boolean(options.rustdocCoverage),
+          unsafeDependencies: Boolean(options.unsafeDependencies),
+        });
         
         // Fix audit configuration for production readiness audit
         if (result && typeof result === 'object') {
@@ -867,6 +1282,130 @@ function defineCommands(program: Command) {
         } else {
           console.log(chalk.green(t('woaru_engine.project_well_configured')));
         }
+
+        // Findings tied to a source line, e.g. blocking calls in async code
+        const locatedSuggestions = result.refactor_suggestions.filter(
+          suggestion => suggestion.line !== undefined
+        );
+        if (locatedSuggestions.length > 0) {
+          console.log(chalk.cyan('\nCode Findings:'));
+          locatedSuggestions.forEach(suggestion => {
+            console.log(
+              `  • ${suggestion.filename}:${suggestion.line} ${suggestion.suggestion}`
+            );
+          });
+        }
+
+        // Line coverage from lcov / Cobertura / tarpaulin reports
+        if (result.test_coverage) {
+          const coverage = result.test_coverage;
+          console.log(
+            chalk.cyan(
+              `\nTest Coverage (${coverage.format}, ${coverage.report}): ${coverage.percentage}%`
+            )
+          );
+          coverage.least_covered_files.forEach(file => {
+            console.log(chalk.gray(`  • ${file.file}: ${file.percentage}%`));
+          });
+        }
+
+        // Public items with doc comments per crate and module (Rust)
+        if (result.doc_coverage) {
+          const docs = result.doc_coverage;
+          console.log(
+            chalk.cyan(
+              `\nDocumentation Coverage (${docs.source}): ${docs.percentage}% (${docs.documented}/${docs.total} public items)`
+            )
+          );
+          docs.crates.forEach(crate => {
+            console.log(
+              `  • ${crate.name}: ${crate.percentage}% (${crate.documented}/${crate.total})`
+            );
+            crate.modules
+              .filter(module => module.documented < module.total)
+              .forEach(module => {
+                console.log(
+                  chalk.gray(
+                    `     ${module.module}: ${module.percentage}% (${module.documented}/${module.total})`
+                  )
+                );
+              });
+          });
+          if (docs.documented < docs.total) {
+            console.log(
+              chalk.gray(
+                '     → Fill the gaps with "woaru docu pro --missing-only"'
+              )
+            );
+          }
+        }
+
+        // Dependency policy findings from cargo-deny (Rust projects)
+        const policyAudits = (result.production_audits || []).filter(audit =>
+          ['license-compliance', 'banned-crates', 'untrusted-sources'].includes(
+            audit.category
+          )
+        );
+        if (policyAudits.length > 0) {
+          console.log(chalk.cyan('\nDependency Policy (cargo-deny):'));
+          policyAudits.forEach((audit, index) => {
+            console.log(`  ${index + 1}. ${audit.message}`);
+            console.log(chalk.gray(`     → ${audit.recommendation}`));
+          });
+        }
+
+        // Unsafe code inventory (Rust projects)
+        const unsafeAudits = (result.production_audits || []).filter(
+          audit => audit.category === 'unsafe-code'
+        );
+        if (unsafeAudits.length > 0) {
+          console.log(chalk.cyan('\nUnsafe Code:'));
+          unsafeAudits.forEach((audit, index) => {
+            console.log(`  ${index + 1}. ${audit.message}`);
+            if (audit.packages?.length) {
+              console.log(chalk.gray(`     ${audit.packages.join(', ')}`));
+            }
+            console.log(chalk.gray(`     → ${audit.recommendation}`));
+          });
+        }
+
+        // Crates locked in several semver-incompatible versions (Rust)
+        const duplicates =
+          result.detailed_security?.duplicate_dependencies || [];
+        if (duplicates.length > 0) {
+          console.log(
+            chalk.cyan(`\nDuplicate Crate Versions (${duplicates.length}):`)
+          );
+          duplicates.forEach(duplicate => {
+            console.log(
+              `  • ${duplicate.name}: ${duplicate.versions.map(v => v.version).join(', ')}`
+            );
+            duplicate.versions.forEach(version => {
+              version.paths.forEach(chain => {
+                console.log(chalk.gray(`     ${chain.join(' → ')}`));
+              });
+            });
+          });
+          console.log(
+            chalk.gray('     → Align versions with "cargo update -p <crate>"')
+          );
+        }
+
+        // Declared but unused dependencies (Rust)
+        const unused = result.detailed_security?.unused_dependencies;
+        if (unused?.dependencies.length) {
+          console.log(
+            chalk.cyan(
+              `\nUnused Dependencies (${unused.tool}, ${unused.dependencies.length}):`
+            )
+          );
+          unused.dependencies.forEach(dependency => {
+            const kind = dependency.kind ? ` [${dependency.kind}]` : '';
+            console.log(
+              `  • ${dependency.packageName}: ${dependency.dependency}${kind}`
+            );
+          });
+        }
       } catch (error) {
         console.error(chalk.red('Analysis failed:'), error);
       }
@@ -974,12 +1513,18 @@ function defineCommands(program: Command) {
     .command('rollback')
     .description(t('commands.rollback.description'))
     .argument('<tool>', 'Tool to rollback')
-    .action(tool => {
-      console.log(
-        chalk.yellow(
-          `Rollback command not implemented yet. Would rollback: ${tool}`
-        )
-      );
+    .action(async tool => {
+      try {
+        const { ActionManager } = await import('./actions/ActionManager');
+        const actionManager = new ActionManager();
+        const success = await actionManager.rollbackTool(process.cwd(), tool);
+        if (!success) {
+          process.exitCode = 1;
+        }
+      } catch (error) {
+        console.error(chalk.red('Rollback failed:'), error);
+        process.exitCode = 1;
+      }
     });
 
   // Message Command - Send WOARU reports via terminal or webhook
@@ -1187,10 +1732,35 @@ async function runDocumentationCommand(
       const { glob } = await import('glob');
       filesToDocument = await glob('**/*.{js,ts,jsx,tsx,py,java,go,rs,php,rb}', {
         cwd: projectPath,
-        ignore: ['node_modules/**', '.git/**', 'dist/**', 'build/**', 'coverage/**']
+        ignore: ['node_modules/**', '.git/**', 'dist/**', 'build/**', 'coverage/**', 'target/**']
       });
     }
 
+    // Keep only modules with undocumented public items (Rust)
+    if (options.missingOnly) {
+      const { RustDocCoverageAnalyzer, filesMissingDocs } = await import(
+        './rust/RustDocCoverage'
+      );
+      const coverage = await new RustDocCoverageAnalyzer().analyze(projectPath);
+      if (!coverage) {
+        console.log(
+          chalk.yellow(
+            '⚠️ --missing-only needs a Rust library crate (Cargo.toml with src/lib.rs)'
+          )
+        );
+        return;
+      }
+      const missing = new Set(filesMissingDocs(coverage));
+      filesToDocument = filesToDocument.filter(file =>
+        missing.has(path.resolve(projectPath, file))
+      );
+      console.log(
+        chalk.gray(
+          `📚 ${coverage.total - coverage.documented} of ${coverage.total} public items undocumented (${coverage.source})`
+        )
+      );
+    }
+
     if (filesToDocument.length === 0) {
       console.log(chalk.yellow('📂 No files found to document.'));
       return;
@@ -26,6 +26,7 @@ export const APP_CONFIG = {
     CACHE: '.woaru/cache',
     SAVEPOINTS: '.woaru/savepoints',
     SENT_REPORTS: '.woaru/sent-reports',
+    BENCHMARKS: '.woaru/benchmarks',
     HOME_BASE: '~/.woaru',
     HOME_LOGS: '~/.woaru/logs',
     HOME_CACHE: '~/.woaru/cache',
@@ -97,6 +98,7 @@ export const APP_CONFIG = {
     FUNCTION_LENGTH_THRESHOLD: 50,
     PARAMETER_COUNT_THRESHOLD: 5,
     NESTING_DEPTH_THRESHOLD: 4,
+    UNSAFE_BLOCK_LINES_THRESHOLD: 10,
 
     // SOLID principle thresholds
     SOLID: {
@@ -200,6 +202,13 @@ export const APP_CONFIG = {
     NPM: 'package-lock.json',
   },
 
+  // Rust/Cargo tooling
+  RUST: {
+    // Local RustSec advisory-db checkout used by cargo-audit (never fetched)
+    ADVISORY_DB_PATH: '~/.cargo/advisory-db',
+    ADVISORY_DB_ENV: 'WOARU_ADVISORY_DB',
+  },
+
   // Documentation system constants
   DOCUMENTATION: {
     SCHEMA_VERSION: '1.0',
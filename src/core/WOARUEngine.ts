@@ -9,6 +9,21 @@ import { ActionManager } from '../actions/ActionManager';
 import { ProductionReadinessAuditor } from '../auditor/ProductionReadinessAuditor';
 import { QualityRunner } from '../quality/QualityRunner';
 import { NotificationManager } from '../supervisor/NotificationManager';
+import { CoverageReader } from '../quality/CoverageReader';
+import { RustToolchainInspector } from '../rust/RustToolchainInspector';
+import { determineRustProjectType } from '../rust/CargoManifestReader';
+import { findDuplicateVersions, readCargoLock } from '../rust/CargoLockfile';
+import { UnusedDependencyDetector } from '../rust/UnusedDependencyDetector';
+import { UnsafeInventoryScanner } from '../rust/UnsafeInventoryScanner';
+import {
+  docCoveragePercent,
+  RustDocCoverageAnalyzer,
+} from '../rust/RustDocCoverage';
+import {
+  DuplicateCrate,
+  RustDocCoverageReport,
+  UnusedDependencyReport,
+} from '../types/rust';
 import { SecurityScanResult } from '../types/security';
 import {
   AnalysisResult,
@@ -30,6 +45,24 @@ import { t } from '../config/i18n';
 
 const execAsync = promisify(exec);
 
+/**
+ * Dependency hygiene findings of a Cargo workspace
+ */
+interface CargoDependencyFindings {
+  duplicates: DuplicateCrate[];
+  unused: UnusedDependencyReport | null;
+}
+
+/**
+ * Opt-in checks of analyzeProject that build or compile the project
+ */
+export interface AnalyzeOptions {
+  msrvCheck?: boolean; // cargo check on the MSRV toolchain
+  udeps?: boolean; // cargo-udeps (nightly build) for unused dependencies
+  rustdocCoverage?: boolean; // rustdoc --show-coverage (nightly build)
+  unsafeDependencies?: boolean; // Registry crates in the unsafe inventory
+}
+
 /**
  * WOARUEngine - Core orchestration engine for the WOARU project analysis and setup system
  *
@@ -94,6 +127,7 @@ export class WOARUEngine {
    * 5. Production readiness assessment
    *
    * @param {string} projectPath - Absolute or relative path to the project directory
+   * @param {AnalyzeOptions} options - Opt-in checks that build the project
    * @returns {Promise<AnalysisResult>} Complete analysis results including recommendations,
    *                                     security findings, and improvement suggestions
    * @throws {Error} If the project path is invalid or analysis fails
@@ -106,7 +140,10 @@ export class WOARUEngine {
    * }
    * ```
    */
-  async analyzeProject(projectPath: string): Promise<AnalysisResult> {
+  async analyzeProject(
+    projectPath: string,
+    options: AnalyzeOptions = {}
+  ): Promise<AnalysisResult> {
     try {
       console.log(chalk.blue(t('woaru_engine.analyzing_project')));
 
@@ -136,13 +173,63 @@ export class WOARUEngine {
         )
       );
 
+      // Scanned once and shared by the code analysis and production audit
+      const unsafeInventory = analysis.cargo
+        ? await new UnsafeInventoryScanner()
+            .scan(projectPath, {
+              includeDependencies: Boolean(options.unsafeDependencies),
+            })
+            .catch(() => null)
+        : undefined;
+
       // Analyze code for specific insights
       console.log(chalk.blue(t('woaru_engine.analyzing_codebase')));
       const codeInsights = await this.codeAnalyzer.analyzeCodebase(
         projectPath,
-        analysis.language
+        analysis.language,
+        { unsafeInventory }
       );
 
+      // Report whether the MSRV toolchain is installed; building on it
+      // (cargo check) only on request
+      const toolchain = analysis.rustToolchain;
+      if (analysis.cargo && toolchain?.msrv) {
+        const inspector = new RustToolchainInspector();
+        await inspector.detectMsrvToolchain(toolchain);
+        if (options.msrvCheck && toolchain.msrvToolchain) {
+          console.log(
+            chalk.blue(
+              t('woaru_engine.msrv_check', {
+                toolchain: toolchain.msrvToolchain,
+              })
+            )
+          );
+          toolchain.msrvCheck = await inspector.checkMsrv(
+            analysis.cargo.workspaceManifestPath,
+            toolchain.msrvToolchain
+          );
+        }
+      }
+
+      // Duplicate crate versions and unused dependencies
+      let cargoDependencies: CargoDependencyFindings | null = null;
+      if (analysis.cargo) {
+        console.log(chalk.blue(t('woaru_engine.dependency_analysis')));
+        cargoDependencies = await this.analyzeCargoDependencies(
+          analysis.cargo.workspaceManifestPath,
+          Boolean(options.udeps)
+        );
+      }
+
+      // Documentation coverage of public items in library crates
+      let docCoverage: RustDocCoverageReport | null = null;
+      if (analysis.cargo) {
+        console.log(chalk.blue(t('woaru_engine.doc_coverage')));
+        docCoverage = await new RustDocCoverageAnalyzer()
+          .analyze(projectPath, { rustdoc: options.rustdocCoverage })
+          .catch(() => null);
+      }
+
       // Get recommendations from plugins with code insights
       const recommendations =
         this.pluginManager.getAllRecommendations(analysis);
@@ -171,6 +258,9 @@ export class WOARUEngine {
       // Run production-readiness audit (including security)
       console.log(chalk.blue(t('woaru_engine.production_audit')));
       const productionAuditor = new ProductionReadinessAuditor(projectPath);
+      if (unsafeInventory !== undefined) {
+        productionAuditor.useUnsafeInventory(unsafeInventory);
+      }
       const auditConfig = {
         language: analysis.language,
         frameworks: analysis.framework,
@@ -218,6 +308,11 @@ export class WOARUEngine {
         );
       }
 
+      // Read coverage produced by cargo tarpaulin / cargo llvm-cov (if any)
+      const coverage = await new CoverageReader(projectPath)
+        .read()
+        .catch(() => null);
+
       // Generate Claude automation suggestions
       const claudeAutomations = this.generateClaudeAutomations(analysis);
 
@@ -248,6 +343,43 @@ export class WOARUEngine {
           packages: audit.packages || [],
           files: audit.files || [],
         })),
+        test_coverage: coverage
+          ? {
+              format: coverage.format,
+              report: path.relative(projectPath, coverage.reportPath),
+              percentage: coverage.percentage,
+              lines_found: coverage.linesFound,
+              lines_hit: coverage.linesHit,
+              least_covered_files: [...coverage.files]
+                .sort((a, b) => a.percentage - b.percentage)
+                .slice(0, 5)
+                .map(file => ({
+                  file: path.relative(projectPath, file.file),
+                  percentage: file.percentage,
+                })),
+            }
+          : undefined,
+        doc_coverage: docCoverage
+          ? {
+              source: docCoverage.source,
+              percentage: docCoveragePercent(docCoverage),
+              documented: docCoverage.documented,
+              total: docCoverage.total,
+              crates: docCoverage.crates.map(crate => ({
+                name: crate.name,
+                percentage: docCoveragePercent(crate),
+                documented: crate.documented,
+                total: crate.total,
+                modules: crate.modules.map(module => ({
+                  module: module.module,
+                  file: path.relative(projectPath, module.file),
+                  percentage: docCoveragePercent(module),
+                  documented: module.documented,
+                  total: module.total,
+                })),
+              })),
+            }
+          : undefined,
         security_summary: {
           total_issues: allSecurityFindings.total + securityAudits.length,
           critical: totalCritical,
@@ -273,7 +405,8 @@ export class WOARUEngine {
         detailed_security: {
           dependency_vulnerabilities: securityResults.flatMap(r =>
             r.findings.map(f => ({
-              id: f.cve || `${f.tool}-${f.file || f.package}`,
+              id:
+                f.cve || f.advisoryId || `${f.tool}-${f.file || f.package}`,
               title: f.title,
               severity: (f.severity === 'info'
                 ? 'low'
@@ -290,6 +423,8 @@ export class WOARUEngine {
               recommendation: f.recommendation || '',
             }))
           ),
+          duplicate_dependencies: cargoDependencies?.duplicates,
+          unused_dependencies: cargoDependencies?.unused || undefined,
           infrastructure_security: infrastructureResults || undefined,
           configuration_audits: securityAudits,
         },
@@ -609,9 +744,36 @@ export class WOARUEngine {
     });
   }
 
+  /**
+   * Crates locked in several semver-incompatible versions and declared
+   * dependencies that are never used, for a Cargo workspace
+   */
+  private async analyzeCargoDependencies(
+    workspaceManifestPath: string,
+    udeps: boolean
+  ): Promise<CargoDependencyFindings> {
+    const workspaceRoot = path.dirname(workspaceManifestPath);
+    const packages = await readCargoLock(
+      path.join(workspaceRoot, 'Cargo.lock')
+    );
+    const unused = await new UnusedDependencyDetector()
+      .detect(workspaceRoot, { udeps })
+      .catch(() => null);
+
+    return {
+      duplicates: packages ? findDuplicateVersions(packages) : [],
+      unused,
+    };
+  }
+
   private determineProjectType(
     analysis: ProjectAnalysis
   ): 'frontend' | 'backend' | 'fullstack' | 'library' | 'cli' {
+    // Cargo targets and crates, as the checks below only know npm packages
+    if (analysis.cargo) {
+      return determineRustProjectType(analysis.cargo);
+    }
+
     // Check for frontend frameworks
     const frontendFrameworks = ['react', 'vue', 'angular', 'svelte', 'next'];
     const hasFrontend = analysis.framework.some(f =>
@@ -679,7 +841,7 @@ export class WOARUEngine {
    * Run comprehensive security analysis using multiple tools
    */
   private async runComprehensiveSecurityAnalysis(
-    _projectPath: string
+    projectPath: string
   ): Promise<SecurityScanResult[]> {
     console.log(
       chalk.gray(t('woaru_engine.security_scan.running_snyk_gitleaks'))
@@ -691,7 +853,9 @@ export class WOARUEngine {
       // For comprehensive analysis, we can scan the entire project
 
       const securityResults =
-        await this.qualityRunner.runSecurityChecksForReview(allFiles);
+        await this.qualityRunner.runSecurityChecksForReview(allFiles, {
+          projectPath,
+        });
 
       // Log summary of findings
       let criticalFindings = 0;
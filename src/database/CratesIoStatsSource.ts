@@ -0,0 +1,181 @@
+import axios from 'axios';
+import fs from 'fs-extra';
+import * as path from 'path';
+import * as semver from 'semver';
+import { safeJsonParse } from '../utils/safeJsonParser';
+
+export interface CratesIoSourceOptions {
+  indexDir?: string; // Local mirror with the layout of index.crates.io
+  fixtureDir?: string; // Recorded /api/v1/crates/<crate> responses as <crate>.json
+  apiUrl?: string;
+}
+
+export interface CrateStats {
+  name: string;
+  version?: string; // Newest non-yanked release, preferring stable versions
+  downloads: number; // Recent (90 day) downloads when known
+  totalDownloads?: number;
+  lastRelease?: string;
+  repository?: string;
+  yanked: boolean; // Every published version is yanked
+}
+
+// One line of a sparse-index file
+interface IndexEntry {
+  name: string;
+  vers: string;
+  yanked: boolean;
+  pubtime?: string; // Only in entries published since the field was added
+}
+
+// Subset of the crates.io /api/v1/crates/<crate> response
+interface CrateResponse {
+  crate: {
+    name: string;
+    downloads?: number;
+    recent_downloads?: number | null;
+    updated_at?: string;
+    max_stable_version?: string | null;
+    max_version?: string;
+    repository?: string | null;
+  };
+  versions?: Array<{ num: string; yanked: boolean; created_at: string }>;
+}
+
+const CRATES_IO_API = 'https://crates.io/api/v1';
+
+// crates.io rejects requests without an identifying User-Agent
+const USER_AGENT = 'woaru-tools-updater (https://github.com/iamthamanic/WOARU-WorkaroundUltra)';
+
+/**
+ * Path of a crate's file inside a sparse index: `1/a`, `2/ab`, `3/a/abc`,
+ * `ca/rg/cargo-deny`
+ */
+export function sparseIndexPath(crateName: string): string {
+  const name = crateName.toLowerCase();
+  switch (name.length) {
+    case 1:
+      return path.join('1', name);
+    case 2:
+      return path.join('2', name);
+    case 3:
+      return path.join('3', name[0], name);
+    default:
+      return path.join(name.slice(0, 2), name.slice(2, 4), name);
+  }
+}
+
+/**
+ * Download and freshness statistics for crates. Reads a local sparse-index
+ * mirror and/or recorded crates.io API responses when configured, so
+ * updates can run offline; otherwise queries the crates.io API.
+ */
+export class CratesIoStatsSource {
+  constructor(private options: CratesIoSourceOptions = {}) {}
+
+  get isOffline(): boolean {
+    return Boolean(this.options.indexDir || this.options.fixtureDir);
+  }
+
+  /**
+   * @returns null if the crate is unknown to the configured sources
+   */
+  async getStats(crateName: string): Promise<CrateStats | null> {
+    if (!this.isOffline) {
+      const response = await axios.get<CrateResponse>(
+        `${this.options.apiUrl || CRATES_IO_API}/crates/${encodeURIComponent(crateName)}`,
+        { headers: { 'User-Agent': USER_AGENT } }
+      );
+      return this.fromApiResponse(response.data);
+    }
+
+    const [recorded, indexed] = await Promise.all([
+      this.readFixture(crateName),
+      this.readIndex(crateName),
+    ]);
+    if (!recorded && !indexed) {
+      return null;
+    }
+
+    // The index knows every version and yank; only the API knows downloads
+    const stats: CrateStats = recorded || {
+      name: crateName,
+      downloads: 0,
+      yanked: false,
+    };
+    return indexed
+      ? {
+          ...stats,
+          version: indexed.version ?? stats.version,
+          lastRelease: indexed.lastRelease ?? stats.lastRelease,
+          yanked: indexed.yanked,
+        }
+      : stats;
+  }
+
+  private async readFixture(crateName: string): Promise<CrateStats | null> {
+    if (!this.options.fixtureDir) return null;
+
+    const file = path.join(
+      this.options.fixtureDir,
+      `${crateName.toLowerCase()}.json`
+    );
+    if (!(await fs.pathExists(file))) return null;
+
+    const response = safeJsonParse<CrateResponse>(
+      await fs.readFile(file, 'utf-8')
+    );
+    return response?.crate ? this.fromApiResponse(response) : null;
+  }
+
+  private async readIndex(
+    crateName: string
+  ): Promise<Pick<CrateStats, 'version' | 'lastRelease' | 'yanked'> | null> {
+    if (!this.options.indexDir) return null;
+
+    const file = path.join(this.options.indexDir, sparseIndexPath(crateName));
+    if (!(await fs.pathExists(file))) return null;
+
+    const entries = (await fs.readFile(file, 'utf-8'))
+      .split('\n')
+      .filter(line => line.trim())
+      .map(line => safeJsonParse<IndexEntry>(line))
+      .filter((entry): entry is IndexEntry => Boolean(entry?.vers));
+    if (entries.length === 0) return null;
+
+    const latest = newestRelease(entries.filter(entry => !entry.yanked));
+    return {
+      version: latest?.vers,
+      lastRelease: latest?.pubtime,
+      yanked: !latest,
+    };
+  }
+
+  private fromApiResponse(response: CrateResponse): CrateStats {
+    const { crate, versions = [] } = response;
+    const version = crate.max_stable_version || crate.max_version;
+    const release = versions.find(candidate => candidate.num === version);
+
+    return {
+      name: crate.name,
+      version,
+      downloads: crate.recent_downloads ?? crate.downloads ?? 0,
+      totalDownloads: crate.downloads,
+      lastRelease: release?.created_at || crate.updated_at,
+      repository: crate.repository || undefined,
+      yanked:
+        versions.length > 0 && versions.every(candidate => candidate.yanked),
+    };
+  }
+}
+
+/**
+ * Highest version, preferring stable releases over pre-releases
+ */
+function newestRelease(entries: IndexEntry[]): IndexEntry | undefined {
+  const valid = entries.filter(entry => semver.valid(entry.vers));
+  const stable = valid.filter(entry => !semver.prerelease(entry.vers));
+  return (stable.length > 0 ? stable : valid).sort((a, b) =>
+    semver.rcompare(a.vers, b.vers)
+  )[0];
+}
@@ -2,6 +2,10 @@ import axios from 'axios';
 import fs from 'fs-extra';
 import * as path from 'path';
 import { ToolsDatabase } from '../types';
+import {
+  CratesIoSourceOptions,
+  CratesIoStatsSource,
+} from './CratesIoStatsSource';
 // import * as semver from 'semver'; // Currently unused
 
 interface PackageStats {
@@ -9,12 +13,15 @@ interface PackageStats {
   downloads: number;
   stars: number;
   lastUpdate: string;
+  version?: string;
+  lastRelease?: string; // Publish date of the latest version, when known
   deprecated?: boolean;
   successor?: string;
 }
 
 interface ToolConfig {
   description?: string;
+  registry?: string;
   metadata?: {
     popularity: number;
     lastChecked: string;
@@ -29,6 +36,15 @@ export class ToolsUpdater {
   private readonly npmRegistry = 'https://registry.npmjs.org';
   private readonly githubApi = 'https://api.github.com';
   private readonly updateInterval = 7 * 24 * 60 * 60 * 1000; // 7 days
+  private readonly cratesIo: CratesIoStatsSource;
+
+  /**
+   * @param cratesIoOptions - Local sparse-index mirror or recorded crates.io
+   * responses to read crate stats from instead of the crates.io API
+   */
+  constructor(cratesIoOptions: CratesIoSourceOptions = {}) {
+    this.cratesIo = new CratesIoStatsSource(cratesIoOptions);
+  }
 
   async checkForUpdates(currentDb: ToolsDatabase): Promise<boolean> {
     const lastUpdate = new Date(currentDb.lastUpdated);
@@ -55,6 +71,8 @@ export class ToolsUpdater {
           return await this.getPyPiStats(toolName);
         case 'nuget':
           return await this.getNuGetStats(toolName);
+        case 'crates':
+          return await this.getCratesIoStats(toolName);
         default:
           return null;
       }
@@ -76,27 +94,15 @@ export class ToolsUpdater {
     );
 
     // Get GitHub stars if repository is listed
-    let stars = 0;
-    if (packageData.repository?.url) {
-      const repoUrl = packageData.repository.url;
-      const match = repoUrl.match(/github\.com\/([^/]+)\/([^/]+)/);
-      if (match) {
-        try {
-          const ghResponse = await axios.get(
-            `${this.githubApi}/repos/${match[1]}/${match[2]}`,
-            { headers: { Accept: 'application/vnd.github.v3+json' } }
-          );
-          stars = ghResponse.data.stargazers_count;
-        } catch {
-          // GitHub API might be rate limited
-        }
-      }
-    }
+    const stars = packageData.repository?.url
+      ? await this.getGitHubStars(packageData.repository.url)
+      : 0;
 
     return {
       name: packageName,
       downloads: downloads.data.downloads || 0,
       stars,
+      version: latest,
       lastUpdate: packageData.time || new Date().toISOString(),
       deprecated: packageData.deprecated || false,
     };
@@ -134,6 +140,51 @@ export class ToolsUpdater {
     };
   }
 
+  private async getCratesIoStats(
+    crateName: string
+  ): Promise<PackageStats | null> {
+    const stats = await this.cratesIo.getStats(crateName);
+    if (!stats) {
+      return null;
+    }
+
+    // Offline sources must not fall back to the network for stars
+    const stars =
+      stats.repository && !this.cratesIo.isOffline
+        ? await this.getGitHubStars(stats.repository)
+        : 0;
+
+    return {
+      name: crateName,
+      downloads: stats.downloads, // Last 90 days, not last month as for npm
+      stars,
+      lastUpdate: stats.lastRelease || new Date().toISOString(),
+      version: stats.version,
+      lastRelease: stats.lastRelease,
+      deprecated: stats.yanked,
+    };
+  }
+
+  private async getGitHubStars(repoUrl: string): Promise<number> {
+    const match = repoUrl.match(
+      /github\.com\/([^/]+)\/([^/#?]+?)(?:\.git)?(?:[/#?]|$)/
+    );
+    if (!match) {
+      return 0;
+    }
+
+    try {
+      const ghResponse = await axios.get(
+        `${this.githubApi}/repos/${match[1]}/${match[2]}`,
+        { headers: { Accept: 'application/vnd.github.v3+json' } }
+      );
+      return ghResponse.data.stargazers_count;
+    } catch {
+      // GitHub API might be rate limited
+      return 0;
+    }
+  }
+
   async findBetterAlternatives(
     toolName: string,
     _category: string
@@ -150,6 +201,12 @@ export class ToolsUpdater {
       bower: ['npm', 'yarn'],
       coffeescript: ['typescript'],
       'node-sass': ['sass', 'dart-sass'],
+      // Crates
+      'cargo-kcov': ['cargo-llvm-cov', 'cargo-tarpaulin'],
+      structopt: ['clap'],
+      failure: ['anyhow', 'thiserror'],
+      'error-chain': ['anyhow', 'thiserror'],
+      tempdir: ['tempfile'],
     };
 
     if (knownReplacements[toolName.toLowerCase()]) {
@@ -173,13 +230,18 @@ export class ToolsUpdater {
         categoryTools as Record<string, ToolConfig>
       )) {
         // Get latest stats
-        const stats = await this.updateToolStats(toolName, 'npm');
+        const stats = await this.updateToolStats(
+          toolName,
+          toolConfig.registry || 'npm'
+        );
 
         if (stats) {
           (toolConfig as Record<string, unknown>).metadata = {
             popularity: stats.downloads,
             lastChecked: new Date().toISOString(),
             githubStars: stats.stars,
+            latestVersion: stats.version,
+            lastRelease: stats.lastRelease,
             deprecated: stats.deprecated,
             alternatives: await this.findBetterAlternatives(
               toolName,
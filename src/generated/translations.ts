@@ -673,6 +673,22 @@ export const bundledTranslations = {
           'Exzessive Verschachtelungstiefe ({{maxDepth}} Ebenen). Erwage Refactoring.',
         extract_nested_logic:
           'Verschachtelte Logik in separate Funktionen extrahieren',
+        unwrap_message:
+          '".{{method}}()" kann in Bibliothekscode eine Panic auslösen',
+        unwrap_suggestion:
+          'Ein Result zurückgeben und den Fehler mit "?" weiterreichen oder den None/Err-Fall explizit behandeln',
+        panic_macro_message:
+          '"{{macro}}!" bricht den aktuellen Thread ab, sobald es erreicht wird',
+        panic_macro_suggestion:
+          'Einen Fehler zurückgeben statt eine Panic auszulösen',
+        unfinished_code_message:
+          '"{{macro}}!" markiert unfertigen Code, der beim Erreichen eine Panic auslöst',
+        unfinished_code_suggestion:
+          'Fehlenden Code vor dem Release implementieren',
+        unsafe_block_message:
+          'unsafe-Block umfasst {{length}} Zeilen (Limit {{limit}})',
+        unsafe_block_suggestion:
+          'unsafe-Blöcke minimal halten, Invarianten in einem "// SAFETY:"-Kommentar dokumentieren und in eine sichere Abstraktion kapseln',
       },
       production_auditor: {
         invalid_changed_files_config:
@@ -737,6 +753,12 @@ export const bundledTranslations = {
           'Gitleaks nicht installiert. Überspringe Secret-Erkennung.',
         semgrep_not_available:
           'Semgrep nicht verfügbar, verwende musterbasierte Analyse.',
+        cargo_audit_not_installed:
+          'cargo-audit nicht installiert. Überspringe RustSec-Advisory-Prüfung.',
+        cargo_deny_not_installed:
+          'cargo-deny nicht installiert. Überspringe Dependency-Policy-Prüfungen.',
+        advisory_db_missing:
+          'RustSec-Advisory-Datenbank nicht gefunden unter {{path}}. Klone https://github.com/rustsec/advisory-db dorthin oder setze {{env}}.',
         analysis_failed: 'Sicherheitsanalyse fehlgeschlagen:',
         xss_vulnerability: 'Potenzielle XSS-Schwachstelle erkannt',
         sql_injection: 'Potenzielle SQL-Injection-Schwachstelle erkannt',
@@ -888,8 +910,62 @@ export const bundledTranslations = {
           'Potenzielle async/await Probleme gefunden. SonarAnalyzer kann diese automatisch erkennen.',
         csharp_analysis_error: 'C# Analyse-Fehler:',
         inconsistent_indentation: 'Inkonsistente Einrückung: {{styles}}',
+        unsafe_code_found:
+          '{{crates}} Crates enthalten unsafe-Code ({{workspace}} in diesem Workspace), {{missing}} Workspace-Crates verbieten unsafe-Code nicht. cargo-geiger und #![forbid(unsafe_code)] halten ihn unter Kontrolle.',
+        unsafe_crate_summary:
+          '{{crate}} ({{source}}): {{blocks}} unsafe-Blöcke, {{fns}} unsafe fns, {{impls}} unsafe impls, {{externs}} extern-Blöcke',
+        missing_forbid_unsafe: '{{crate}}: #![forbid(unsafe_code)] fehlt',
+        rust_analysis_error: 'Rust-Analysefehler:',
       },
       actions: {
+        cargo_deny: {
+          dry_run_create_config:
+            'Würde deny.toml aus den Lizenzen in Cargo.lock erstellen',
+          lockfile_missing:
+            'Cargo.lock nicht gefunden. Führe zuerst "cargo generate-lockfile" aus.',
+          unknown_licenses:
+            '{{count}} Crates sind nicht im lokalen Registry-Cache (führe "cargo fetch" aus); sie sind in deny.toml zur Prüfung aufgeführt',
+          setup_success:
+            '✅ deny.toml mit {{count}} erlaubten Lizenzen erstellt',
+          setup_failed: '❌ deny.toml konnte nicht erstellt werden:',
+          rollback_success: '✅ deny.toml-Änderungen zurückgesetzt',
+          rollback_failed: '❌ Zurücksetzen von deny.toml fehlgeschlagen:',
+          nothing_to_rollback:
+            'Keine von WOARU aufgezeichneten deny.toml-Änderungen',
+        },
+        rustfmt: {
+          dry_run_create_config: 'Würde rustfmt.toml erstellen',
+          dry_run_format: 'Würde cargo fmt --all ausführen',
+          formatted: '✅ cargo fmt hat {{count}} Dateien formatiert',
+          format_failed: '⚠️ cargo fmt fehlgeschlagen: {{error}}',
+          setup_success: '✅ rustfmt konfiguriert',
+          setup_failed: '❌ rustfmt-Setup fehlgeschlagen:',
+          rollback_success:
+            '✅ rustfmt-Änderungen zurückgesetzt ({{count}} Dateien wiederhergestellt)',
+          rollback_failed:
+            '❌ Zurücksetzen der rustfmt-Änderungen fehlgeschlagen:',
+          nothing_to_rollback:
+            'Keine von WOARU aufgezeichneten rustfmt-Änderungen',
+        },
+        clippy: {
+          dry_run_create_config: 'Würde clippy.toml erstellen',
+          dry_run_lints:
+            'Würde {{count}} Cargo.toml-Dateien eine [lints]-Tabelle hinzufügen',
+          dry_run_fix: 'Würde cargo clippy --fix --allow-dirty ausführen',
+          lints_added: '✅ [lints]-Tabelle zu {{file}} hinzugefügt',
+          lints_skipped_msrv:
+            '[lints] erfordert Rust 1.74 (rust-version ist {{version}}); Lint-Level werden nicht in Cargo.toml eingetragen',
+          fix_applied: '✅ cargo clippy --fix hat {{count}} Dateien geändert',
+          fix_failed: '⚠️ cargo clippy --fix fehlgeschlagen: {{error}}',
+          setup_success: '✅ clippy konfiguriert',
+          setup_failed: '❌ clippy-Setup fehlgeschlagen:',
+          rollback_success:
+            '✅ clippy-Änderungen zurückgesetzt ({{count}} Dateien wiederhergestellt)',
+          rollback_failed:
+            '❌ Zurücksetzen der clippy-Änderungen fehlgeschlagen:',
+          nothing_to_rollback:
+            'Keine von WOARU aufgezeichneten clippy-Änderungen',
+        },
         eslint: {
           dry_run_install: 'Würde ESLint-Pakete installieren',
           dry_run_create_config: 'Würde .eslintrc.json-Konfiguration erstellen',
@@ -899,6 +975,8 @@ export const bundledTranslations = {
           setup_failed: '❌ ESLint-Setup fehlgeschlagen:',
           rollback_success: '✅ ESLint-Setup erfolgreich zurückgesetzt',
           rollback_failed: '❌ ESLint-Setup-Rücksetzung fehlgeschlagen:',
+          nothing_to_rollback:
+            'Keine von WOARU aufgezeichneten ESLint-Änderungen',
         },
         husky: {
           dry_run_install: 'Würde husky und lint-staged-Pakete installieren',
@@ -912,6 +990,8 @@ export const bundledTranslations = {
           setup_failed: '❌ Husky-Setup fehlgeschlagen:',
           rollback_success: '✅ Husky-Setup erfolgreich zurückgesetzt',
           rollback_failed: '❌ Husky-Setup-Rücksetzung fehlgeschlagen:',
+          nothing_to_rollback:
+            'Keine von WOARU aufgezeichneten Husky-Änderungen',
         },
         prettier: {
           dry_run_install: 'Würde prettier-Pakete installieren',
@@ -923,6 +1003,8 @@ export const bundledTranslations = {
           setup_failed: '❌ Prettier-Setup fehlgeschlagen:',
           rollback_success: '✅ Prettier-Setup erfolgreich zurückgesetzt',
           rollback_failed: '❌ Prettier-Setup-Rücksetzung fehlgeschlagen:',
+          nothing_to_rollback:
+            'Keine von WOARU aufgezeichneten Prettier-Änderungen',
         },
       },
       general: {
@@ -996,6 +1078,12 @@ export const bundledTranslations = {
         frameworks_info: '⚡ Frameworks: {{frameworks}}',
         none_detected: 'Keine erkannt',
         analyzing_codebase: '🔬 Analysiere Codebase auf Einblicke...',
+        msrv_check:
+          '🦀 Prüfe den Workspace mit der MSRV-Toolchain ({{toolchain}})...',
+        dependency_analysis:
+          '📦 Prüfe Cargo.lock auf doppelte Crate-Versionen und ungenutzte Abhängigkeiten...',
+        doc_coverage:
+          '📚 Messe die rustdoc-Abdeckung öffentlicher Elemente...',
         security_analysis: '🔒 Führe umfassende Sicherheitsanalyse durch...',
         infrastructure_audit:
           '🛡️ Führe Infrastruktur-Sicherheitsaudit durch...',
@@ -1640,6 +1728,19 @@ export const bundledTranslations = {
         excessive_nesting_message:
           'Excessive nesting depth ({{maxDepth}} levels). Consider refactoring.',
         extract_nested_logic: 'Extract nested logic into separate functions',
+        unwrap_message: '".{{method}}()" can panic in library code',
+        unwrap_suggestion:
+          'Return a Result and propagate the error with "?", or handle the None/Err case explicitly',
+        panic_macro_message:
+          '"{{macro}}!" aborts the current thread when reached',
+        panic_macro_suggestion: 'Return an error instead of panicking',
+        unfinished_code_message:
+          '"{{macro}}!" marks unfinished code that panics when reached',
+        unfinished_code_suggestion: 'Implement the missing code before release',
+        unsafe_block_message:
+          'unsafe block spans {{length}} lines (limit {{limit}})',
+        unsafe_block_suggestion:
+          'Keep unsafe blocks minimal, document invariants in a "// SAFETY:" comment and wrap them in a safe abstraction',
       },
       production_auditor: {
         invalid_changed_files_config:
@@ -1700,6 +1801,12 @@ export const bundledTranslations = {
           'Gitleaks not installed. Skipping secret detection.',
         semgrep_not_available:
           'Semgrep not available, using pattern-based analysis.',
+        cargo_audit_not_installed:
+          'cargo-audit not installed. Skipping RustSec advisory check.',
+        cargo_deny_not_installed:
+          'cargo-deny not installed. Skipping dependency policy checks.',
+        advisory_db_missing:
+          'RustSec advisory database not found at {{path}}. Clone https://github.com/rustsec/advisory-db there or set {{env}}.',
         analysis_failed: 'Security analysis failed:',
         xss_vulnerability: 'Potential XSS vulnerability detected',
         sql_injection: 'Potential SQL injection vulnerability detected',
@@ -1846,8 +1953,56 @@ export const bundledTranslations = {
           'Potential async/await issues found. SonarAnalyzer can automatically detect these.',
         csharp_analysis_error: 'C# analysis error:',
         inconsistent_indentation: 'Inconsistent indentation: {{styles}}',
+        unsafe_code_found:
+          '{{crates}} crates contain unsafe code ({{workspace}} in this workspace), {{missing}} workspace crates do not forbid unsafe code. cargo-geiger and #![forbid(unsafe_code)] keep it under control.',
+        unsafe_crate_summary:
+          '{{crate}} ({{source}}): {{blocks}} unsafe blocks, {{fns}} unsafe fns, {{impls}} unsafe impls, {{externs}} extern blocks',
+        missing_forbid_unsafe: '{{crate}}: #![forbid(unsafe_code)] missing',
+        rust_analysis_error: 'Rust analysis error:',
       },
       actions: {
+        cargo_deny: {
+          dry_run_create_config:
+            'Would create deny.toml from the licenses in Cargo.lock',
+          lockfile_missing:
+            'Cargo.lock not found. Run "cargo generate-lockfile" first.',
+          unknown_licenses:
+            '{{count}} crates are not in the local registry cache (run "cargo fetch"); they are listed in deny.toml for review',
+          setup_success: '✅ deny.toml created with {{count}} allowed licenses',
+          setup_failed: '❌ Failed to create deny.toml:',
+          rollback_success: '✅ deny.toml changes rolled back',
+          rollback_failed: '❌ Failed to roll back deny.toml:',
+          nothing_to_rollback: 'No deny.toml changes recorded by WOARU',
+        },
+        rustfmt: {
+          dry_run_create_config: 'Would create rustfmt.toml',
+          dry_run_format: 'Would run cargo fmt --all',
+          formatted: '✅ cargo fmt reformatted {{count}} files',
+          format_failed: '⚠️ cargo fmt failed: {{error}}',
+          setup_success: '✅ rustfmt configured',
+          setup_failed: '❌ Failed to set up rustfmt:',
+          rollback_success:
+            '✅ rustfmt changes rolled back ({{count}} files restored)',
+          rollback_failed: '❌ Failed to roll back rustfmt changes:',
+          nothing_to_rollback: 'No rustfmt changes recorded by WOARU',
+        },
+        clippy: {
+          dry_run_create_config: 'Would create clippy.toml',
+          dry_run_lints:
+            'Would add a [lints] table to {{count}} Cargo.toml files',
+          dry_run_fix: 'Would run cargo clippy --fix --allow-dirty',
+          lints_added: '✅ [lints] table added to {{file}}',
+          lints_skipped_msrv:
+            '[lints] requires Rust 1.74 (rust-version is {{version}}); lint levels are not added to Cargo.toml',
+          fix_applied: '✅ cargo clippy --fix changed {{count}} files',
+          fix_failed: '⚠️ cargo clippy --fix failed: {{error}}',
+          setup_success: '✅ clippy configured',
+          setup_failed: '❌ Failed to set up clippy:',
+          rollback_success:
+            '✅ clippy changes rolled back ({{count}} files restored)',
+          rollback_failed: '❌ Failed to roll back clippy changes:',
+          nothing_to_rollback: 'No clippy changes recorded by WOARU',
+        },
         eslint: {
           dry_run_install: 'Would install ESLint packages',
           dry_run_create_config: 'Would create .eslintrc.json configuration',
@@ -1857,6 +2012,7 @@ export const bundledTranslations = {
           setup_failed: '❌ Failed to setup ESLint:',
           rollback_success: '✅ ESLint setup rolled back successfully',
           rollback_failed: '❌ Failed to rollback ESLint setup:',
+          nothing_to_rollback: 'No ESLint changes recorded by WOARU',
         },
         husky: {
           dry_run_install: 'Would install husky and lint-staged packages',
@@ -1870,6 +2026,7 @@ export const bundledTranslations = {
           setup_failed: '❌ Failed to setup Husky:',
           rollback_success: '✅ Husky setup rolled back successfully',
           rollback_failed: '❌ Failed to rollback Husky setup:',
+          nothing_to_rollback: 'No Husky changes recorded by WOARU',
         },
         prettier: {
           dry_run_install: 'Would install prettier packages',
@@ -1881,6 +2038,7 @@ export const bundledTranslations = {
           setup_failed: '❌ Failed to setup Prettier:',
           rollback_success: '✅ Prettier setup rolled back successfully',
           rollback_failed: '❌ Failed to rollback Prettier setup:',
+          nothing_to_rollback: 'No Prettier changes recorded by WOARU',
         },
       },
       general: {
@@ -1948,6 +2106,11 @@ export const bundledTranslations = {
         frameworks_info: '⚡ Frameworks: {{frameworks}}',
         none_detected: 'None detected',
         analyzing_codebase: '🔬 Analyzing codebase for insights...',
+        msrv_check:
+          '🦀 Checking the workspace on its MSRV toolchain ({{toolchain}})...',
+        dependency_analysis:
+          '📦 Checking Cargo.lock for duplicate crate versions and unused dependencies...',
+        doc_coverage: '📚 Measuring rustdoc coverage of public items...',
         security_analysis: '🔒 Running comprehensive security analysis...',
         infrastructure_audit: '🛡️ Running infrastructure security audit...',
         production_audit: '🏗️ Running production readiness audit...',
@@ -2109,8 +2272,8 @@ export const translationMetadata = {
   generatedAt: '2025-08-06T13:40:16.255Z',
   languages: ['de', 'en'],
   stats: {
-    de: 662,
-    en: 599,
+    de: 706,
+    en: 643,
   },
   totalLanguages: 2,
   buildVersion: '5.3.9',
@@ -240,6 +240,7 @@ export class InitCommand {
       pip: { command: 'pip', args: ['install', '-r', 'requirements.txt'] },
       poetry: { command: 'poetry', args: ['install'] },
       pipenv: { command: 'pipenv', args: ['install'] },
+      cargo: { command: 'cargo', args: ['fetch'] },
     };
 
     return commands[packageManager as keyof typeof commands] || commands.npm;
@@ -421,6 +421,8 @@ export class ProjectSelector implements InteractivePrompts {
       fullstack: '🚀',
       mobile: '📱',
       desktop: '🖥️',
+      cli: '⌨️',
+      library: '📚',
     };
     return icons[category] || '📦';
   }
@@ -461,6 +463,7 @@ export class ProjectSelector implements InteractivePrompts {
       pip: '(Python Package Installer)',
       poetry: '(Python dependency management)',
       pipenv: '(Python dev workflow)',
+      cargo: '(Rust package manager)',
     };
     return descriptions[pm] || '';
   }
@@ -15,6 +15,7 @@ import {
   TemplateVariables,
   ConditionalRule,
 } from './types';
+import { RUST_TEMPLATE_FILES } from './rustTemplates';
 
 export class TemplateEngine {
   private handlebars: any;
@@ -607,6 +608,9 @@ disallow_untyped_defs = true`,
     rev: v1.7.1
     hooks:
       - id: mypy`,
+
+      // Rust templates (axum, CLI, library)
+      ...RUST_TEMPLATE_FILES,
     };
 
     return (
@@ -672,6 +676,11 @@ disallow_untyped_defs = true`,
         ? str.toLowerCase().replace(/\s+/g, '-')
         : str;
     });
+
+    // Snake case helper (Rust crate names are imported with underscores)
+    this.handlebars.registerHelper('snake', function (str: any) {
+      return typeof str === 'string' ? str.replace(/[\s-]+/g, '_') : str;
+    });
   }
 
   /**
@@ -710,7 +719,9 @@ disallow_untyped_defs = true`,
               ? 'pip install -r requirements.txt'
               : config.packageManager === 'poetry'
                 ? 'poetry install'
-                : 'npm install';
+                : config.packageManager === 'cargo'
+                  ? 'cargo fetch'
+                  : 'npm install';
       nextSteps.push(installCmd);
     }
 
@@ -721,6 +732,13 @@ disallow_untyped_defs = true`,
     } else if (template.id === 'python-fastapi') {
       nextSteps.push('uvicorn src.main:app --reload');
       nextSteps.push('Open http://localhost:8000');
+    } else if (template.id === 'rust-axum') {
+      nextSteps.push('cargo run');
+      nextSteps.push('Open http://localhost:3000/health');
+    } else if (template.id === 'rust-cli') {
+      nextSteps.push('cargo run -- --help');
+    } else if (template.id === 'rust-library') {
+      nextSteps.push('cargo test');
     }
 
     return {
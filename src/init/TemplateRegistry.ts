@@ -7,6 +7,7 @@ import {
   TemplateRegistry as ITemplateRegistry,
   ValidationResult,
 } from './types';
+import { createRustTemplates } from './rustTemplates';
 import fs from 'fs-extra';
 import * as path from 'path';
 import chalk from 'chalk';
@@ -133,6 +134,8 @@ export class TemplateRegistry implements ITemplateRegistry {
       'fullstack',
       'mobile',
       'desktop',
+      'cli',
+      'library',
     ];
     if (!validCategories.includes(template.category)) {
       errors.push({
@@ -149,6 +152,7 @@ export class TemplateRegistry implements ITemplateRegistry {
       'pip',
       'poetry',
       'pipenv',
+      'cargo',
     ];
     if (!validPackageManagers.includes(template.packageManager)) {
       errors.push({
@@ -470,5 +474,8 @@ export class TemplateRegistry implements ITemplateRegistry {
 
     this.register(nextjsTemplate);
     this.register(fastapiTemplate);
+
+    // Rust Templates (axum service, clap CLI, library crate)
+    createRustTemplates().forEach(template => this.register(template));
   }
 }
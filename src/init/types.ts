@@ -6,7 +6,14 @@ export interface ProjectTemplate {
   id: string;
   name: string;
   description: string;
-  category: 'frontend' | 'backend' | 'fullstack' | 'mobile' | 'desktop';
+  category:
+    | 'frontend'
+    | 'backend'
+    | 'fullstack'
+    | 'mobile'
+    | 'desktop'
+    | 'cli'
+    | 'library';
   language: string;
   frameworks: string[];
   packageManager: PackageManager;
@@ -94,7 +101,8 @@ export type PackageManager =
   | 'pnpm'
   | 'pip'
   | 'poetry'
-  | 'pipenv';
+  | 'pipenv'
+  | 'cargo';
 
 export interface ProjectConfig {
   name: string;
@@ -4,14 +4,28 @@ import {
   SetupRecommendation,
   RefactorSuggestion,
 } from '../types';
+import { CargoDependency } from '../types/rust';
+import { compareRustVersions } from '../rust/RustToolchainInspector';
 
 export class RustPlugin extends BasePlugin {
   name = 'Rust';
-  frameworks = ['rust', 'actix', 'rocket', 'tokio'];
+  frameworks = [
+    'rust',
+    'axum',
+    'actix',
+    'rocket',
+    'tokio',
+    'async-std',
+    'bevy',
+    'tauri',
+    'diesel',
+    'sqlx',
+  ];
 
   canHandle(analysis: ProjectAnalysis): boolean {
     return (
       analysis.language === 'Rust' ||
+      analysis.cargo !== undefined ||
       analysis.configFiles.some(
         file => file.includes('Cargo.toml') || file.includes('Cargo.lock')
       )
@@ -63,15 +77,18 @@ export class RustPlugin extends BasePlugin {
       priority: 'high',
     });
 
-    // cargo-deny for dependency management
-    recommendations.push({
-      tool: 'cargo-deny',
-      category: 'dependency-management',
-      reason: 'Lint your dependencies for licenses, duplicates, and security',
-      packages: [],
-      configFiles: ['deny.toml'],
-      priority: 'medium',
-    });
+    // cargo-deny for dependency management (`woaru setup` writes deny.toml)
+    if (!this.hasConfigFile(analysis, 'deny.toml')) {
+      recommendations.push({
+        tool: 'cargo-deny',
+        category: 'dependency-management',
+        reason:
+          'Lint your dependencies for licenses, duplicates, and security',
+        packages: [],
+        configFiles: ['deny.toml'],
+        priority: 'medium',
+      });
+    }
 
     // cargo-tarpaulin for test coverage
     if (!this.hasPackage(analysis, 'tarpaulin')) {
@@ -95,9 +112,232 @@ export class RustPlugin extends BasePlugin {
       priority: 'low',
     });
 
+    recommendations.push(...this.getFrameworkRecommendations(analysis));
+    recommendations.push(...this.getToolchainRecommendations(analysis));
+
+    return recommendations;
+  }
+
+  /**
+   * MSRV declarations, toolchain pinning and the result of building on
+   * the declared MSRV
+   */
+  private getToolchainRecommendations(
+    analysis: ProjectAnalysis
+  ): SetupRecommendation[] {
+    const toolchain = analysis.rustToolchain;
+    if (!toolchain) {
+      return [];
+    }
+
+    const recommendations: SetupRecommendation[] = [];
+    const { crates, msrv, toolchainFile, msrvCheck } = toolchain;
+
+    const librariesWithoutMsrv = crates.filter(
+      crate => crate.library && !crate.rustVersion
+    );
+    if (librariesWithoutMsrv.length > 0) {
+      recommendations.push({
+        tool: 'cargo-msrv',
+        category: 'compatibility',
+        reason:
+          'Declare rust-version in Cargo.toml so dependents on older compilers get a clear error instead of obscure build failures (find it with `cargo msrv find`)',
+        packages: [],
+        configFiles: ['Cargo.toml'],
+        priority: 'medium',
+        evidence: `Library crates without rust-version: ${librariesWithoutMsrv.map(crate => crate.name).join(', ')}`,
+      });
+    }
+
+    const binaries = crates.filter(crate => crate.binary);
+    if (binaries.length > 0 && !toolchainFile?.pinned) {
+      const binaryNames = binaries.map(crate => crate.name).join(', ');
+      recommendations.push({
+        tool: 'rust-toolchain',
+        category: 'compatibility',
+        reason:
+          'Pin the toolchain in rust-toolchain.toml (channel = "1.x.y") so local builds, CI and releases use the same compiler',
+        packages: [],
+        configFiles: ['rust-toolchain.toml'],
+        priority: 'medium',
+        evidence: toolchainFile
+          ? `channel = "${toolchainFile.channel || ''}" is not pinned (binary crates: ${binaryNames})`
+          : `No rust-toolchain.toml for binary crates: ${binaryNames}`,
+      });
+    }
+
+    if (
+      msrv &&
+      toolchainFile?.pinned &&
+      toolchainFile.channel &&
+      /^\d/.test(toolchainFile.channel) &&
+      compareRustVersions(toolchainFile.channel, msrv) < 0
+    ) {
+      recommendations.push({
+        tool: 'rust-toolchain',
+        category: 'compatibility',
+        reason: `The pinned toolchain ${toolchainFile.channel} is older than the declared MSRV ${msrv}; update rust-toolchain.toml or lower rust-version`,
+        packages: [],
+        configFiles: ['rust-toolchain.toml', 'Cargo.toml'],
+        priority: 'high',
+        evidence: `channel = "${toolchainFile.channel}", rust-version = "${msrv}"`,
+      });
+    }
+
+    if (msrv && toolchain.installedToolchains && !toolchain.msrvToolchain) {
+      recommendations.push({
+        tool: 'msrv-toolchain',
+        category: 'compatibility',
+        reason: `Install the MSRV toolchain to verify rust-version locally: rustup toolchain install ${msrv} --profile minimal`,
+        packages: [],
+        configFiles: [],
+        priority: 'low',
+        evidence: `rust-version = "${msrv}"; installed: ${toolchain.installedToolchains.join(', ') || 'none'}`,
+      });
+    }
+
+    if (msrvCheck && !msrvCheck.success) {
+      recommendations.push({
+        tool: 'msrv-check',
+        category: 'compatibility',
+        reason: `cargo check fails on the declared MSRV (${msrvCheck.toolchain}); raise rust-version or avoid newer language and library features`,
+        packages: [],
+        configFiles: ['Cargo.toml'],
+        priority: 'high',
+        evidence: msrvCheck.errors.join('; '),
+      });
+    }
+
     return recommendations;
   }
 
+  /**
+   * Recommendations driven by the crates declared in Cargo.toml
+   */
+  private getFrameworkRecommendations(
+    analysis: ProjectAnalysis
+  ): SetupRecommendation[] {
+    const recommendations: SetupRecommendation[] = [];
+
+    const tokio = this.getCargoDependency(analysis, 'tokio');
+    if (tokio && !this.hasPackage(analysis, 'console-subscriber')) {
+      recommendations.push({
+        tool: 'tokio-console',
+        category: 'debugging',
+        reason:
+          'Inspect running tokio tasks, wakers and resource contention (requires console-subscriber and RUSTFLAGS="--cfg tokio_unstable")',
+        packages: ['console-subscriber'],
+        configFiles: ['.cargo/config.toml'],
+        priority: 'medium',
+        evidence: this.describeDependency(tokio),
+      });
+    }
+
+    const sqlx = this.getCargoDependency(analysis, 'sqlx');
+    if (sqlx) {
+      recommendations.push({
+        tool: 'sqlx-cli',
+        category: 'database',
+        reason:
+          'Run `cargo sqlx prepare` and commit .sqlx/ so query! macros are checked offline (SQLX_OFFLINE=true) in CI',
+        packages: [],
+        configFiles: ['.sqlx'],
+        priority: this.hasConfigFile(analysis, '.sqlx') ? 'low' : 'high',
+        evidence: this.describeDependency(sqlx),
+      });
+    }
+
+    const diesel = this.getCargoDependency(analysis, 'diesel');
+    if (diesel) {
+      recommendations.push({
+        tool: 'diesel_cli',
+        category: 'database',
+        reason:
+          'Manage migrations and keep src/schema.rs in sync with `diesel migration run`',
+        packages: [],
+        configFiles: ['diesel.toml'],
+        priority: 'medium',
+        evidence: this.describeDependency(diesel),
+      });
+    }
+
+    const webFramework = ['axum', 'actix-web', 'rocket']
+      .map(name => this.getCargoDependency(analysis, name))
+      .find(Boolean);
+    if (webFramework && !this.hasPackage(analysis, 'tracing')) {
+      recommendations.push({
+        tool: 'tracing',
+        category: 'logging',
+        reason: `Structured, span-based request logging for ${webFramework.name} services`,
+        packages: ['tracing', 'tracing-subscriber'],
+        configFiles: [],
+        priority: 'medium',
+        evidence: this.describeDependency(webFramework),
+      });
+    }
+
+    const asyncStd = this.getCargoDependency(analysis, 'async-std');
+    if (asyncStd && tokio) {
+      recommendations.push({
+        tool: 'single-async-runtime',
+        category: 'dependency-management',
+        reason:
+          'Both tokio and async-std are dependencies; mixing runtimes duplicates executors and can panic outside the matching runtime',
+        packages: [],
+        configFiles: ['Cargo.toml'],
+        priority: 'medium',
+        evidence: `${this.describeDependency(tokio)}; ${this.describeDependency(asyncStd)}`,
+      });
+    }
+
+    const bevy = this.getCargoDependency(analysis, 'bevy');
+    if (bevy && !bevy.features.includes('dynamic_linking')) {
+      recommendations.push({
+        tool: 'bevy-fast-compile',
+        category: 'development',
+        reason:
+          'Enable the dynamic_linking feature and opt-level = 1 for dev builds to cut Bevy iteration times',
+        packages: [],
+        configFiles: ['Cargo.toml', '.cargo/config.toml'],
+        priority: 'low',
+        evidence: this.describeDependency(bevy),
+      });
+    }
+
+    const tauri = this.getCargoDependency(analysis, 'tauri');
+    if (tauri) {
+      recommendations.push({
+        tool: 'tauri-cli',
+        category: 'development',
+        reason:
+          'Use `cargo tauri dev`/`cargo tauri build` and review capabilities in tauri.conf.json',
+        packages: [],
+        configFiles: ['tauri.conf.json'],
+        priority: 'medium',
+        evidence: this.describeDependency(tauri),
+      });
+    }
+
+    return recommendations;
+  }
+
+  private getCargoDependency(
+    analysis: ProjectAnalysis,
+    crateName: string
+  ): CargoDependency | undefined {
+    return analysis.cargo?.dependencies.find(
+      dep => dep.name === crateName && dep.kind === 'normal'
+    );
+  }
+
+  private describeDependency(dep: CargoDependency): string {
+    const version = dep.version ? ` ${dep.version}` : '';
+    const features =
+      dep.features.length > 0 ? ` (features: ${dep.features.join(', ')})` : '';
+    const inherited = dep.workspaceInherited ? ' [workspace]' : '';
+    return `Cargo.toml: ${dep.name}${version}${features}${inherited}`;
+  }
+
   getRefactorSuggestions(analysis: ProjectAnalysis): RefactorSuggestion[] {
     const suggestions: RefactorSuggestion[] = [];
 
@@ -125,16 +365,6 @@ export class RustPlugin extends BasePlugin {
         }
       }
 
-      // Async suggestions
-      if (analysis.framework.includes('tokio')) {
-        suggestions.push({
-          filename: file,
-          suggestion:
-            'Use async/await syntax consistently throughout async functions',
-          type: 'performance',
-        });
-      }
-
       // Test suggestions
       if (file.includes('test') || file.includes('tests')) {
         suggestions.push({
@@ -170,6 +400,20 @@ export class RustPlugin extends BasePlugin {
       }
     });
 
+    // Blocking calls and std lock guards inside async code (tokio)
+    (analysis.asyncBlocking || []).forEach(finding => {
+      const problem =
+        finding.kind === 'mutex-across-await'
+          ? `${finding.call} guard is held across .await in ${finding.context}`
+          : `${finding.call} blocks the async runtime in ${finding.context}`;
+      suggestions.push({
+        filename: finding.file,
+        line: finding.line,
+        suggestion: `${problem}: ${finding.alternative}`,
+        type: 'performance',
+      });
+    });
+
     return suggestions;
   }
 
@@ -0,0 +1,319 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { safeJsonParse } from '../utils/safeJsonParser';
+import {
+  CoverageFormat,
+  CoverageReport,
+  FileCoverage,
+  ReviewCoverage,
+  UncoveredChange,
+} from '../types/coverage';
+
+/**
+ * Where cargo-tarpaulin, cargo-llvm-cov and JS coverage tools write their
+ * reports by default (or by common convention), relative to the project
+ */
+export const COVERAGE_REPORT_CANDIDATES = [
+  'lcov.info',
+  'coverage/lcov.info',
+  'target/llvm-cov/lcov.info',
+  'target/coverage/lcov.info',
+  'cobertura.xml',
+  'coverage/cobertura.xml',
+  'coverage/cobertura-coverage.xml',
+  'target/llvm-cov/cobertura.xml',
+  'tarpaulin-report.json',
+  'target/tarpaulin/tarpaulin-report.json',
+];
+
+// Tarpaulin embeds every source file in its JSON report
+const MAX_REPORT_SIZE = 200 * 1024 * 1024;
+
+interface TarpaulinTrace {
+  line: number;
+  stats?: { Line?: number };
+}
+
+interface TarpaulinFile {
+  path: string[] | string;
+  traces?: TarpaulinTrace[];
+}
+
+/**
+ * Per-line hit counts collected while parsing, keyed by absolute file path
+ */
+type LineHits = Map<string, Map<number, number>>;
+
+/**
+ * Parses lcov (`cargo llvm-cov --lcov`, `cargo tarpaulin --out Lcov`),
+ * Cobertura XML (`--cobertura` / `--out Xml`) and tarpaulin JSON reports
+ * into per-file line coverage.
+ */
+export class CoverageParser {
+  static detectFormat(
+    reportPath: string,
+    content: string
+  ): CoverageFormat | null {
+    const trimmed = content.trimStart();
+    if (trimmed.startsWith('{')) return 'tarpaulin';
+    if (trimmed.startsWith('<') && trimmed.includes('<coverage')) {
+      return 'cobertura';
+    }
+    if (/^(TN|SF):/m.test(content) || reportPath.endsWith('.info')) {
+      return 'lcov';
+    }
+    return null;
+  }
+
+  /**
+   * @param content - Report file content
+   * @param baseDir - Directory relative source paths are resolved against
+   */
+  static parse(
+    format: CoverageFormat,
+    content: string,
+    baseDir: string
+  ): FileCoverage[] {
+    switch (format) {
+      case 'lcov':
+        return this.toFileCoverage(this.parseLcov(content, baseDir));
+      case 'cobertura':
+        return this.toFileCoverage(this.parseCobertura(content, baseDir));
+      case 'tarpaulin':
+        return this.toFileCoverage(this.parseTarpaulin(content, baseDir));
+    }
+  }
+
+  private static parseLcov(content: string, baseDir: string): LineHits {
+    const hits: LineHits = new Map();
+    let current: Map<number, number> | null = null;
+
+    for (const rawLine of content.split(/\r?\n/)) {
+      const line = rawLine.trim();
+      if (line.startsWith('SF:')) {
+        current = this.fileHits(hits, path.resolve(baseDir, line.slice(3)));
+      } else if (line.startsWith('DA:') && current) {
+        // DA:<line>,<hits>[,<checksum>]
+        const [lineNo, count] = line.slice(3).split(',').map(Number);
+        if (lineNo > 0 && !isNaN(count)) {
+          current.set(lineNo, (current.get(lineNo) || 0) + count);
+        }
+      } else if (line === 'end_of_record') {
+        current = null;
+      }
+    }
+
+    return hits;
+  }
+
+  private static parseCobertura(content: string, baseDir: string): LineHits {
+    const hits: LineHits = new Map();
+    const sources = [...content.matchAll(/<source>([^<]*)<\/source>/g)].map(
+      match => this.decodeXml(match[1].trim())
+    );
+    const sourceRoot = sources.find(source => path.isAbsolute(source));
+
+    const classPattern = /<class\b([^>]*)>([\s\S]*?)<\/class>/g;
+    for (const [, attributes, body] of content.matchAll(classPattern)) {
+      const filename = this.xmlAttribute(attributes, 'filename');
+      if (!filename) continue;
+
+      const file = path.resolve(sourceRoot || baseDir, filename);
+      const fileHits = this.fileHits(hits, file);
+      for (const [, lineAttributes] of body.matchAll(/<line\b([^>]*?)\/?>/g)) {
+        const lineNo = Number(this.xmlAttribute(lineAttributes, 'number'));
+        const count = Number(this.xmlAttribute(lineAttributes, 'hits'));
+        if (lineNo > 0 && !isNaN(count)) {
+          // Several <class> entries may describe the same file
+          fileHits.set(lineNo, Math.max(fileHits.get(lineNo) || 0, count));
+        }
+      }
+    }
+
+    return hits;
+  }
+
+  private static parseTarpaulin(content: string, baseDir: string): LineHits {
+    const hits: LineHits = new Map();
+    const report = safeJsonParse<{ files?: TarpaulinFile[] }>(content, {
+      maxSize: MAX_REPORT_SIZE,
+    });
+
+    for (const entry of report?.files || []) {
+      // Paths are serialized as component arrays: ["/", "home", ..., "lib.rs"]
+      const filePath = Array.isArray(entry.path)
+        ? path.join(...entry.path)
+        : entry.path;
+      if (!filePath) continue;
+
+      const fileHits = this.fileHits(hits, path.resolve(baseDir, filePath));
+      for (const trace of entry.traces || []) {
+        const count = trace.stats?.Line;
+        if (trace.line > 0 && typeof count === 'number') {
+          fileHits.set(trace.line, (fileHits.get(trace.line) || 0) + count);
+        }
+      }
+    }
+
+    return hits;
+  }
+
+  private static fileHits(
+    hits: LineHits,
+    file: string
+  ): Map<number, number> {
+    let fileHits = hits.get(file);
+    if (!fileHits) {
+      fileHits = new Map();
+      hits.set(file, fileHits);
+    }
+    return fileHits;
+  }
+
+  private static toFileCoverage(hits: LineHits): FileCoverage[] {
+    return Array.from(hits.entries())
+      .filter(([, lines]) => lines.size > 0)
+      .map(([file, lines]) => {
+        const uncoveredLines = Array.from(lines.entries())
+          .filter(([, count]) => count === 0)
+          .map(([line]) => line)
+          .sort((a, b) => a - b);
+        const linesHit = lines.size - uncoveredLines.length;
+        return {
+          file,
+          linesFound: lines.size,
+          linesHit,
+          percentage: percentage(linesHit, lines.size),
+          uncoveredLines,
+        };
+      })
+      .sort((a, b) => a.file.localeCompare(b.file));
+  }
+
+  private static xmlAttribute(
+    attributes: string,
+    name: string
+  ): string | undefined {
+    const match = attributes.match(new RegExp(`\\b${name}="([^"]*)"`));
+    return match ? this.decodeXml(match[1]) : undefined;
+  }
+
+  private static decodeXml(value: string): string {
+    return value
+      .replace(/&lt;/g, '<')
+      .replace(/&gt;/g, '>')
+      .replace(/&quot;/g, '"')
+      .replace(/&apos;/g, "'")
+      .replace(/&amp;/g, '&');
+  }
+}
+
+/**
+ * Locates and reads coverage reports of a project
+ */
+export class CoverageReader {
+  constructor(private projectPath: string) {}
+
+  /**
+   * Read the given report, or the most recent one found at a default
+   * location
+   * @returns null if no (parseable) report exists
+   */
+  async read(reportPath?: string): Promise<CoverageReport | null> {
+    const resolved = reportPath
+      ? path.resolve(this.projectPath, reportPath)
+      : await this.findLatestReport();
+    if (!resolved || !(await fs.pathExists(resolved))) {
+      return null;
+    }
+
+    const content = await fs.readFile(resolved, 'utf-8');
+    const format = CoverageParser.detectFormat(resolved, content);
+    if (!format) {
+      return null;
+    }
+
+    const files = CoverageParser.parse(format, content, this.projectPath);
+    const linesFound = files.reduce((sum, file) => sum + file.linesFound, 0);
+    const linesHit = files.reduce((sum, file) => sum + file.linesHit, 0);
+
+    return {
+      format,
+      reportPath: resolved,
+      generatedAt: (await fs.stat(resolved)).mtime,
+      files,
+      linesFound,
+      linesHit,
+      percentage: percentage(linesHit, linesFound),
+    };
+  }
+
+  /**
+   * Relate a coverage report to the lines changed in a review
+   * @param changedLines - Added/modified line numbers per absolute file path
+   */
+  static forChanges(
+    report: CoverageReport,
+    changedLines: Map<string, number[]>
+  ): ReviewCoverage {
+    const byFile = new Map(report.files.map(file => [file.file, file]));
+    const changedFiles: FileCoverage[] = [];
+    const uncoveredChanges: UncoveredChange[] = [];
+
+    for (const [file, lines] of changedLines) {
+      const coverage = byFile.get(path.resolve(file));
+      if (!coverage) continue;
+
+      changedFiles.push(coverage);
+      const uncovered = new Set(coverage.uncoveredLines);
+      const uncoveredChanged = lines.filter(line => uncovered.has(line));
+      if (uncoveredChanged.length > 0) {
+        uncoveredChanges.push({
+          file: coverage.file,
+          lines: uncoveredChanged,
+          fileCoverage: coverage.percentage,
+        });
+      }
+    }
+
+    return { report, changedFiles, uncoveredChanges };
+  }
+
+  private async findLatestReport(): Promise<string | null> {
+    let latest: { file: string; mtime: number } | null = null;
+
+    for (const candidate of COVERAGE_REPORT_CANDIDATES) {
+      const file = path.join(this.projectPath, candidate);
+      const stat = await fs.stat(file).catch(() => null);
+      if (stat?.isFile() && (!latest || stat.mtimeMs > latest.mtime)) {
+        latest = { file, mtime: stat.mtimeMs };
+      }
+    }
+
+    return latest?.file || null;
+  }
+}
+
+/**
+ * Compress sorted line numbers into ranges for display, e.g. "3-5, 9"
+ */
+export function formatLineRanges(lines: number[]): string {
+  const ranges: string[] = [];
+  let start = lines[0];
+  let previous = lines[0];
+
+  for (const line of [...lines.slice(1), NaN]) {
+    if (line === previous + 1) {
+      previous = line;
+      continue;
+    }
+    ranges.push(start === previous ? `${start}` : `${start}-${previous}`);
+    start = previous = line;
+  }
+
+  return lines.length > 0 ? ranges.join(', ') : '';
+}
+
+function percentage(hit: number, found: number): number {
+  return found === 0 ? 100 : Math.round((hit / found) * 1000) / 10;
+}
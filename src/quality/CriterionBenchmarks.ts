@@ -0,0 +1,388 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { glob } from 'glob';
+import { APP_CONFIG } from '../config/constants';
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { ToolExecutor } from '../utils/toolExecutor';
+import { CargoWorkspaceResolver } from '../rust/CargoWorkspaceResolver';
+import {
+  BenchmarkComparison,
+  BenchmarkEstimate,
+  BenchmarkReport,
+  BenchmarkRun,
+} from '../types/benchmarks';
+
+export const DEFAULT_REGRESSION_THRESHOLD = 5; // Percent slower than baseline
+
+// Only full or abbreviated hashes are used as history file names
+const COMMIT_PATTERN = /^[0-9a-f]{7,64}$/;
+
+interface CriterionStatistic {
+  point_estimate?: number;
+}
+
+// target/criterion/<id>/new/estimates.json, times in nanoseconds
+interface CriterionEstimates {
+  mean?: CriterionStatistic;
+  median?: CriterionStatistic;
+  std_dev?: CriterionStatistic;
+}
+
+/**
+ * Parse a Criterion `estimates.json`
+ * @returns null if the file carries no mean estimate
+ */
+export function parseCriterionEstimates(
+  id: string,
+  content: string
+): BenchmarkEstimate | null {
+  let estimates: CriterionEstimates | null;
+  try {
+    estimates = safeJsonParse<CriterionEstimates>(content);
+  } catch {
+    return null;
+  }
+  const mean = estimates?.mean?.point_estimate;
+  if (typeof mean !== 'number' || !isFinite(mean)) {
+    return null;
+  }
+
+  const median = estimates?.median?.point_estimate;
+  const stdDev = estimates?.std_dev?.point_estimate;
+  return {
+    id,
+    meanNs: mean,
+    medianNs: typeof median === 'number' ? median : undefined,
+    stdDevNs: typeof stdDev === 'number' ? stdDev : undefined,
+  };
+}
+
+/**
+ * Compare the mean time of each benchmark with its baseline; changes
+ * beyond the threshold (in percent) count as regression or improvement
+ */
+export function compareBenchmarkRuns(
+  current: BenchmarkRun,
+  baseline: BenchmarkRun | undefined,
+  thresholdPercent: number
+): Pick<
+  BenchmarkReport,
+  'regressions' | 'improvements' | 'unchanged' | 'added'
+> {
+  const baselineById = new Map(
+    (baseline?.benchmarks || []).map(benchmark => [benchmark.id, benchmark])
+  );
+  const result = {
+    regressions: [] as BenchmarkComparison[],
+    improvements: [] as BenchmarkComparison[],
+    unchanged: 0,
+    added: [] as string[],
+  };
+
+  for (const benchmark of current.benchmarks) {
+    const previous = baselineById.get(benchmark.id);
+    if (!previous || previous.meanNs <= 0) {
+      result.added.push(benchmark.id);
+      continue;
+    }
+
+    const changePercent =
+      Math.round(
+        ((benchmark.meanNs - previous.meanNs) / previous.meanNs) * 1000
+      ) / 10;
+    const comparison = {
+      id: benchmark.id,
+      baselineNs: previous.meanNs,
+      currentNs: benchmark.meanNs,
+      changePercent,
+    };
+    if (changePercent > thresholdPercent) {
+      result.regressions.push(comparison);
+    } else if (changePercent < -thresholdPercent) {
+      result.improvements.push(comparison);
+    } else {
+      result.unchanged++;
+    }
+  }
+
+  result.regressions.sort((a, b) => b.changePercent - a.changePercent);
+  result.improvements.sort((a, b) => a.changePercent - b.changePercent);
+  return result;
+}
+
+/**
+ * Benchmark results per commit in `.woaru/benchmarks/<commit>.json`
+ */
+export class BenchmarkHistory {
+  private historyDir: string;
+
+  constructor(projectPath: string) {
+    this.historyDir = path.join(projectPath, APP_CONFIG.DIRECTORIES.BENCHMARKS);
+  }
+
+  async save(run: BenchmarkRun): Promise<void> {
+    if (!COMMIT_PATTERN.test(run.commit)) {
+      return;
+    }
+    await fs.outputJson(
+      path.join(this.historyDir, `${run.commit}.json`),
+      run,
+      { spaces: 2 }
+    );
+  }
+
+  async load(commit: string): Promise<BenchmarkRun | null> {
+    if (!COMMIT_PATTERN.test(commit)) {
+      return null;
+    }
+    return this.read(path.join(this.historyDir, `${commit}.json`));
+  }
+
+  /**
+   * Most recently measured run, ignoring runs measured at `exceptMeasuredAt`
+   * (results of the same `cargo bench` invocation)
+   */
+  async latest(exceptMeasuredAt?: string): Promise<BenchmarkRun | null> {
+    const files = await glob('*.json', {
+      cwd: this.historyDir,
+      absolute: true,
+      nodir: true,
+    });
+
+    let latest: BenchmarkRun | null = null;
+    for (const file of files) {
+      const run = await this.read(file);
+      if (
+        run &&
+        run.measuredAt !== exceptMeasuredAt &&
+        (!latest || run.measuredAt > latest.measuredAt)
+      ) {
+        latest = run;
+      }
+    }
+    return latest;
+  }
+
+  private async read(file: string): Promise<BenchmarkRun | null> {
+    try {
+      const run = safeJsonParse<BenchmarkRun>(
+        await fs.readFile(file, 'utf-8')
+      );
+      return run && Array.isArray(run.benchmarks) ? run : null;
+    } catch {
+      return null;
+    }
+  }
+}
+
+/**
+ * Reads Criterion results after `cargo bench`, records them per commit and
+ * compares them with the run of the base branch or, without one, the
+ * previous run
+ */
+export class CriterionBenchmarkTracker {
+  private history: BenchmarkHistory;
+
+  constructor(
+    private projectPath: string,
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {
+    this.history = new BenchmarkHistory(projectPath);
+  }
+
+  /**
+   * @param options.runBenchmarks Run cargo bench first instead of reading
+   * the results of an earlier run
+   * @param options.commitTime Time of `commit`; results of an earlier run
+   * are only recorded for the commit when none of them is older
+   * @param options.baseCommit Merge base with the base branch
+   * @returns null if there are no Criterion results
+   */
+  async track(options: {
+    commit: string;
+    commitTime?: Date;
+    branch?: string;
+    baseCommit?: string;
+    runBenchmarks?: boolean;
+    thresholdPercent?: number;
+  }): Promise<BenchmarkReport | null> {
+    const manifestPath = path.join(
+      path.resolve(this.projectPath),
+      'Cargo.toml'
+    );
+    if (!(await fs.pathExists(manifestPath))) {
+      return null;
+    }
+    const cargoPackage = await this.resolver.resolveForFile(manifestPath);
+    const workspaceManifest =
+      cargoPackage?.workspaceManifestPath || manifestPath;
+
+    // Whole seconds, as some file systems store coarse modification times
+    const startedAt = Math.floor(Date.now() / 1000) * 1000;
+    if (options.runBenchmarks) {
+      const { stderr, exitCode } =
+        await ToolExecutor.runCargoBench(workspaceManifest);
+      if (exitCode !== 0) {
+        throw new Error(
+          `cargo bench exited with code ${exitCode}: ${stderr.trim().split('\n').slice(-5).join('\n')}`
+        );
+      }
+    }
+
+    const current = await this.readResults(
+      this.criterionDir(path.dirname(workspaceManifest)),
+      options.commit,
+      options.branch,
+      // A filtered cargo bench leaves older results of other benchmarks
+      options.runBenchmarks ? startedAt : undefined
+    );
+    if (!current) {
+      return null;
+    }
+
+    // Results of an earlier run may have been measured on another commit
+    const stale =
+      !options.runBenchmarks &&
+      (!options.commitTime ||
+        (await this.oldestResult(
+          this.criterionDir(path.dirname(workspaceManifest))
+        )) < options.commitTime.getTime());
+
+    // Never compare a run with itself, e.g. when HEAD is the merge base
+    const baseRun =
+      options.baseCommit && options.baseCommit !== options.commit
+        ? await this.history.load(options.baseCommit)
+        : null;
+    const baseline =
+      baseRun && baseRun.measuredAt !== current.measuredAt
+        ? baseRun
+        : await this.history.latest(current.measuredAt);
+    if (!stale) {
+      await this.history.save(current);
+    }
+
+    const thresholdPercent =
+      options.thresholdPercent ?? DEFAULT_REGRESSION_THRESHOLD;
+    return {
+      current,
+      baseline: baseline || undefined,
+      baselineSource: baseline
+        ? baseline === baseRun
+          ? 'base-branch'
+          : 'previous-run'
+        : undefined,
+      thresholdPercent,
+      ...compareBenchmarkRuns(current, baseline || undefined, thresholdPercent),
+      stale: stale || undefined,
+    };
+  }
+
+  /**
+   * Collect `<id>/new/estimates.json` below the Criterion output directory
+   * @param since Skip results last written before this time (ms)
+   */
+  async readResults(
+    criterionDir: string,
+    commit: string,
+    branch?: string,
+    since?: number
+  ): Promise<BenchmarkRun | null> {
+    const files = await this.estimateFiles(criterionDir);
+
+    const benchmarks: BenchmarkEstimate[] = [];
+    let measuredAt = 0;
+    for (const file of files.sort()) {
+      const newDir = path.join(criterionDir, path.dirname(file));
+      try {
+        const stat = await fs.stat(path.join(criterionDir, file));
+        if (since !== undefined && stat.mtimeMs < since) {
+          continue;
+        }
+        const estimate = parseCriterionEstimates(
+          await this.benchmarkId(newDir, criterionDir),
+          await fs.readFile(path.join(criterionDir, file), 'utf-8')
+        );
+        if (estimate) {
+          benchmarks.push(estimate);
+          measuredAt = Math.max(measuredAt, stat.mtimeMs);
+        }
+      } catch {
+        // Unreadable results are skipped
+      }
+    }
+    if (benchmarks.length === 0) {
+      return null;
+    }
+
+    return {
+      commit,
+      branch,
+      measuredAt: new Date(measuredAt).toISOString(),
+      benchmarks,
+    };
+  }
+
+  /**
+   * Modification time (ms) of the oldest result
+   */
+  private async oldestResult(criterionDir: string): Promise<number> {
+    let oldest = Infinity;
+    for (const file of await this.estimateFiles(criterionDir)) {
+      try {
+        const stat = await fs.stat(path.join(criterionDir, file));
+        oldest = Math.min(oldest, stat.mtimeMs);
+      } catch {
+        // Unreadable results are skipped
+      }
+    }
+    return oldest;
+  }
+
+  private estimateFiles(criterionDir: string): Promise<string[]> {
+    return glob('**/new/estimates.json', { cwd: criterionDir, nodir: true });
+  }
+
+  /**
+   * Criterion's `full_id` from benchmark.json, falling back to the
+   * directory (which replaces characters unsafe in file names)
+   */
+  private async benchmarkId(
+    newDir: string,
+    criterionDir: string
+  ): Promise<string> {
+    const fallback = path
+      .relative(criterionDir, path.dirname(newDir))
+      .split(path.sep)
+      .join('/');
+    try {
+      const benchmark = safeJsonParse<{ full_id?: string }>(
+        await fs.readFile(path.join(newDir, 'benchmark.json'), 'utf-8')
+      );
+      return typeof benchmark?.full_id === 'string'
+        ? benchmark.full_id
+        : fallback;
+    } catch {
+      return fallback;
+    }
+  }
+
+  private criterionDir(workspaceRoot: string): string {
+    if (process.env.CRITERION_HOME) {
+      return path.resolve(workspaceRoot, process.env.CRITERION_HOME);
+    }
+    const targetDir = process.env.CARGO_TARGET_DIR
+      ? path.resolve(workspaceRoot, process.env.CARGO_TARGET_DIR)
+      : path.join(workspaceRoot, 'target');
+    return path.join(targetDir, 'criterion');
+  }
+}
+
+/**
+ * Human readable duration of a nanosecond estimate
+ */
+export function formatNanoseconds(ns: number): string {
+  if (ns >= 1e9) return `${(ns / 1e9).toFixed(2)} s`;
+  if (ns >= 1e6) return `${(ns / 1e6).toFixed(2)} ms`;
+  if (ns >= 1e3) return `${(ns / 1e3).toFixed(2)} µs`;
+  return `${ns.toFixed(1)} ns`;
+}
@@ -22,6 +22,29 @@ import { SOLIDCheckResult } from '../solid/types/solid-types';
 import { CodeSmellAnalyzer } from '../analyzer/CodeSmellAnalyzer';
 import { CodeSmellFinding } from '../types/code-smell';
 import { CodeIssue } from '../supervisor/types';
+import {
+  CargoDenyDiagnostic,
+  CargoDenyResult,
+  FeatureCheckResult,
+  RustDiagnostic,
+} from '../types/rust';
+import { ClippyDiagnosticsParser } from '../rust/ClippyDiagnosticsParser';
+import { CargoWorkspaceResolver } from '../rust/CargoWorkspaceResolver';
+import { CargoAuditParser } from '../rust/CargoAuditParser';
+import { resolveAdvisoryDbPath } from '../rust/rustPaths';
+import {
+  CARGO_DENY_CHECKS,
+  CargoDenyParser,
+} from '../rust/CargoDenyParser';
+import {
+  describeCombination,
+  FeatureCheckOptions,
+  FeatureCombinationChecker,
+} from '../rust/FeatureCombinationChecker';
+import {
+  RustCheckRun,
+  RustCheckScheduler,
+} from '../rust/RustCheckScheduler';
 import {
   SnykVulnerability as ImportedSnykVulnerability,
   SnykResult as ImportedSnykResult,
@@ -50,6 +73,7 @@ export interface QualityCheckResult {
   explanation?: string; // Human-readable explanation of the problems
   solidResult?: SOLIDCheckResult; // SOLID analysis results
   codeSmellFindings?: CodeSmellFinding[]; // Internal code smell analysis
+  rustDiagnostics?: RustDiagnostic[]; // Structured clippy/rustc diagnostics
 }
 
 export interface SnykVulnerability {
@@ -102,6 +126,10 @@ export class QualityRunner {
   private corePlugins: Map<string, Record<string, unknown>>;
   private solidChecker: SOLIDChecker;
   private codeSmellAnalyzer: CodeSmellAnalyzer;
+  private codeIssueListener?: (file: string, issues: CodeIssue[]) => void;
+  private cargoResolver: CargoWorkspaceResolver;
+  private rustCheckScheduler: RustCheckScheduler;
+  private rustFilesWithIssues = new Set<string>(); // Cleared on a clean run
 
   constructor(notificationManager: NotificationManager) {
     this.notificationManager = notificationManager;
@@ -109,6 +137,11 @@ export class QualityRunner {
     this.corePlugins = new Map();
     this.solidChecker = new SOLIDChecker();
     this.codeSmellAnalyzer = new CodeSmellAnalyzer();
+    this.cargoResolver = new CargoWorkspaceResolver();
+    this.rustCheckScheduler = new RustCheckScheduler(
+      run => this.reportRustCheckRun(run),
+      this.cargoResolver
+    );
 
     // Initialize core plugins
     this.initializeCorePlugins();
@@ -131,6 +164,35 @@ export class QualityRunner {
     }
   }
 
+  /**
+   * Register a listener for per-line issues from structured tool output
+   * (e.g. clippy JSON diagnostics), used by the supervisor's StateManager
+   */
+  onCodeIssues(listener: (file: string, issues: CodeIssue[]) => void): void {
+    this.codeIssueListener = listener;
+  }
+
+  /**
+   * Forget cached file → Cargo package mappings after a Cargo.toml change
+   */
+  invalidateRustWorkspaceCache(): void {
+    this.cargoResolver.invalidate();
+    this.rustCheckScheduler.invalidate();
+  }
+
+  /**
+   * Cancel queued and running watch-mode clippy runs
+   */
+  cancelRustChecks(): void {
+    this.rustCheckScheduler.cancelAll();
+  }
+
+  private reportCodeIssues(file: string, issues: CodeIssue[]): void {
+    if (this.codeIssueListener) {
+      this.codeIssueListener(file, issues);
+    }
+  }
+
   /**
    * 🗂️ Hilfsmethode: Sprache aus Dateierweiterung ableiten
    */
@@ -673,35 +735,12 @@ export class QualityRunner {
             results.push(eslintResult);
           } else if (codeSmellFindings.length > 0) {
             // No ESLint but we have code smell findings - create a result
-            const issues = codeSmellFindings.map(
-              finding =>
-                `Line ${finding.line}:${finding.column} - ${finding.message}`
-            );
-            const fixes = codeSmellFindings
-              .filter(finding => finding.suggestion)
-              .map(finding => finding.suggestion || 'No suggestion available');
-            const criticalFindings = codeSmellFindings.filter(
-              f => f.severity === 'error'
-            );
-            const severity =
-              criticalFindings.length > 0
-                ? ('error' as const)
-                : ('warning' as const);
-
-            // Add SOLID analysis
             const solidResult = await this.runSOLIDCheckForReview(
               filePath,
               language
             );
-
             results.push({
-              filePath: relativePath,
-              tool: 'WOARU Code Smell Analyzer',
-              severity,
-              issues,
-              fixes: fixes.length > 0 ? fixes : undefined,
-              explanation: `WOARU internal analysis found ${codeSmellFindings.length} code quality issues`,
-              codeSmellFindings,
+              ...this.createCodeSmellResult(relativePath, codeSmellFindings),
               solidResult,
             });
           }
@@ -721,8 +760,19 @@ export class QualityRunner {
 
         // Rust files
         if (ext === '.rs') {
+          const codeSmellFindings = await this.codeSmellAnalyzer.analyzeFile(
+            filePath,
+            'rust'
+          );
           const result = await this.runRustCheckForReview(relativePath);
-          if (result) results.push(result);
+          if (result) {
+            result.codeSmellFindings = codeSmellFindings;
+            results.push(result);
+          } else if (codeSmellFindings.length > 0) {
+            results.push(
+              this.createCodeSmellResult(relativePath, codeSmellFindings)
+            );
+          }
         }
 
         // C# files
@@ -857,8 +907,11 @@ export class QualityRunner {
     }
   }
 
+  /**
+   * Check formatting right away and queue clippy for the owning crate;
+   * saves are coalesced per workspace and reported by reportRustCheckRun
+   */
   private async runRustCheck(filePath: string): Promise<void> {
-    let hasErrors = false;
     let errorOutput = '';
 
     try {
@@ -873,7 +926,6 @@ export class QualityRunner {
           ?.toString()
           ?.includes('Diff in')
       ) {
-        hasErrors = true;
         errorOutput += `Formatting issues found. Run 'rustfmt ${filePath}' to fix.\n\n`;
         errorOutput +=
           (fmtError as Record<string, unknown>)?.stdout ||
@@ -883,39 +935,86 @@ export class QualityRunner {
       }
     }
 
-    try {
-      // Run clippy for linting
-      await execAsync(
-        `cargo clippy --manifest-path=$(dirname "${filePath}")/Cargo.toml -- -D warnings 2>&1`
+    if (errorOutput) {
+      this.notificationManager.showCriticalQualityError(
+        filePath,
+        'rustfmt',
+        errorOutput
       );
+    }
 
-      if (!hasErrors) {
-        this.notificationManager.showQualitySuccess(
-          filePath,
-          'Rust (rustfmt + clippy)'
-        );
-      }
-    } catch (clippyError: unknown) {
-      hasErrors = true;
-      const clippyOutput =
-        (clippyError as Record<string, unknown>)?.stdout ||
-        (clippyError as Record<string, unknown>)?.stderr ||
-        (clippyError as Record<string, unknown>)?.message ||
-        'Unknown error';
-
-      // Add separator if we already have formatting errors
-      if (errorOutput) {
-        errorOutput += '\n\n--- Clippy Warnings ---\n\n';
-      }
-      errorOutput += clippyOutput;
+    const scheduled = await this.rustCheckScheduler.schedule(
+      path.resolve(filePath)
+    );
+    if (!scheduled && !errorOutput) {
+      this.notificationManager.showQualitySuccess(filePath, 'rustfmt');
+    }
+  }
+
+  /**
+   * Report a watch-mode clippy run for the changed files, for files of
+   * dependent crates that now have diagnostics and for files whose earlier
+   * diagnostics are gone
+   */
+  private async reportRustCheckRun(run: RustCheckRun): Promise<void> {
+    const tool = 'Rust (clippy)';
+    if (run.error) {
+      run.changedFiles.forEach(file =>
+        this.notificationManager.showCriticalQualityError(
+          path.relative(process.cwd(), file),
+          tool,
+          run.error as string
+        )
+      );
+      return;
     }
 
-    if (hasErrors) {
-      this.notificationManager.showCriticalQualityError(
-        filePath,
-        'Rust (rustfmt + clippy)',
-        errorOutput
+    const byFile = new Map<string, RustDiagnostic[]>();
+    run.diagnostics.forEach(diagnostic => {
+      const file = path.resolve(diagnostic.file);
+      byFile.set(file, [...(byFile.get(file) || []), diagnostic]);
+    });
+    // Earlier findings in the re-checked packages that are now fixed
+    const fixedFiles: string[] = [];
+    for (const file of this.rustFilesWithIssues) {
+      if (byFile.has(file)) continue;
+      const cargoPackage = await this.cargoResolver.resolveForFile(file);
+      if (
+        cargoPackage?.workspaceRoot === run.workspaceRoot &&
+        run.packages.includes(cargoPackage.name)
+      ) {
+        fixedFiles.push(file);
+      }
+    }
+    const files = new Set([
+      ...run.changedFiles,
+      ...byFile.keys(),
+      ...fixedFiles,
+    ]);
+
+    for (const file of files) {
+      const diagnostics = byFile.get(file) || [];
+      const relativeFile = path.relative(process.cwd(), file);
+      this.reportCodeIssues(
+        file,
+        ClippyDiagnosticsParser.toCodeIssues(diagnostics, file)
       );
+
+      if (diagnostics.length > 0) {
+        this.rustFilesWithIssues.add(file);
+        this.notificationManager.showCriticalQualityError(
+          relativeFile,
+          tool,
+          diagnostics
+            .map(diagnostic => ClippyDiagnosticsParser.formatIssue(diagnostic))
+            .join('\n')
+        );
+      } else {
+        this.rustFilesWithIssues.delete(file);
+        if (run.changedFiles.includes(file)) {
+          this.notificationManager.showQualitySuccess(relativeFile, tool);
+        }
+      }
     }
   }
 
@@ -1156,10 +1255,30 @@ export class QualityRunner {
   ): Promise<QualityCheckResult | null> {
     try {
       await ToolExecutor.runRustFmt(filePath);
-      await execAsync(
-        `cargo clippy --manifest-path "${path.dirname(filePath)}/Cargo.toml" -- -D warnings`
+      const diagnostics = await this.runClippyDiagnostics(filePath);
+      if (diagnostics.length === 0) {
+        return null; // No issues found
+      }
+
+      const hasErrors = diagnostics.some(
+        d => d.level === 'error' || d.level === 'ice'
       );
-      return null; // No issues found
+      const fixes = ClippyDiagnosticsParser.formatFixes(diagnostics);
+
+      return {
+        filePath,
+        tool: 'Rust (rustfmt + clippy)',
+        severity: hasErrors ? 'error' : 'warning',
+        issues: diagnostics.map(diagnostic =>
+          ClippyDiagnosticsParser.formatIssue(diagnostic)
+        ),
+        fixes: fixes.length > 0 ? fixes : undefined,
+        raw_output: diagnostics
+          .map(diagnostic => diagnostic.rendered || '')
+          .filter(Boolean)
+          .join('\n'),
+        rustDiagnostics: diagnostics,
+      };
     } catch (error: unknown) {
       const output = String(
         (error as Record<string, unknown>)?.stdout ||
@@ -1176,6 +1295,92 @@ export class QualityRunner {
     }
   }
 
+  /**
+   * Run clippy with --message-format=json for the package owning the file
+   * and return the diagnostics that belong to the file
+   */
+  private async runClippyDiagnostics(
+    filePath: string
+  ): Promise<RustDiagnostic[]> {
+    const cargoPackage = await this.cargoResolver.resolveForFile(filePath);
+    if (!cargoPackage) {
+      return []; // Not part of any Cargo package
+    }
+
+    // Lint only the owning package, even inside a larger workspace
+    const { stdout, stderr, exitCode } = await ToolExecutor.runCargoClippy(
+      cargoPackage.workspaceManifestPath,
+      ['-p', cargoPackage.name]
+    );
+
+    const diagnostics = ClippyDiagnosticsParser.parse(
+      stdout,
+      cargoPackage.workspaceRoot
+    );
+
+    // Non-zero exit without any compiler message means cargo itself failed
+    if (exitCode !== 0 && diagnostics.length === 0) {
+      throw new Error(stderr || `cargo clippy exited with code ${exitCode}`);
+    }
+
+    return ClippyDiagnosticsParser.filterByFile(diagnostics, filePath);
+  }
+
+  /**
+   * Opt-in check that every Cargo package compiles for its feature
+   * combinations (cargo-hack or the internal fallback)
+   * @returns One result per package with failing combinations, reported
+   * against the package's Cargo.toml
+   */
+  async runFeatureCombinationCheck(
+    projectPath: string,
+    options: FeatureCheckOptions = {}
+  ): Promise<QualityCheckResult[]> {
+    const checker = new FeatureCombinationChecker(this.cargoResolver);
+    const results = (await checker.check(projectPath, options)) || [];
+
+    return results
+      .filter(result => result.failures.length > 0)
+      .map(result => this.createFeatureCheckResult(projectPath, result));
+  }
+
+  private createFeatureCheckResult(
+    projectPath: string,
+    result: FeatureCheckResult
+  ): QualityCheckResult {
+    const diagnostics: RustDiagnostic[] = result.failures.map(failure => ({
+      file: result.manifestPath,
+      line: failure.line,
+      column: 1,
+      endLine: failure.line,
+      endColumn: 1,
+      level: 'error',
+      message: `\`cargo check ${describeCombination(failure.combination)}\` fails: ${failure.errors[0]}`,
+      lint: 'feature-combination',
+      suggestions: [],
+      rendered: failure.errors.join('\n'),
+      packageId: result.packageName,
+    }));
+
+    return {
+      filePath: path.relative(projectPath, result.manifestPath),
+      tool: `Cargo features (${result.runner})`,
+      severity: 'error',
+      issues: diagnostics.map(diagnostic =>
+        ClippyDiagnosticsParser.formatIssue(diagnostic)
+      ),
+      explanation: `${result.failures.length} of ${result.checked} feature combinations of ${result.packageName} do not compile${result.truncated ? ' (combination limit reached, not all were checked)' : ''}`,
+      fixes: [
+        'Gate code that needs an optional dependency or feature behind #[cfg(feature = "...")]',
+        'Enable the features a feature relies on in its [features] entry',
+      ],
+      raw_output: diagnostics
+        .map(diagnostic => diagnostic.rendered)
+        .join('\n'),
+      rustDiagnostics: diagnostics,
+    };
+  }
+
   private async runCSharpCheckForReview(
     filePath: string
   ): Promise<QualityCheckResult | null> {
@@ -1620,6 +1825,12 @@ export class QualityRunner {
       results.push(gitleaksResult);
     }
 
+    // Run cargo-audit for RustSec advisories of Rust dependencies
+    const cargoAuditResult = await this.runCargoAuditCheck(options);
+    if (cargoAuditResult) {
+      results.push(cargoAuditResult);
+    }
+
     // Run basic security analysis for XSS and other vulnerabilities
     const basicSecurityResult = await this.runBasicSecurityAnalysis(
       filePaths,
@@ -1643,6 +1854,164 @@ export class QualityRunner {
     return results;
   }
 
+  /**
+   * Run cargo-audit against the local RustSec advisory database
+   * @returns null if the project is not a Cargo project or cargo-audit is
+   * not installed
+   */
+  public async runCargoAuditCheck(
+    options: SecurityCheckOptions = {}
+  ): Promise<SecurityScanResult | null> {
+    const projectPath = path.resolve(options.projectPath || process.cwd());
+    const manifestPath = path.join(projectPath, 'Cargo.toml');
+    if (!(await fs.pathExists(manifestPath))) {
+      return null;
+    }
+
+    // Member crates share the Cargo.lock of their workspace
+    const cargoPackage = await this.cargoResolver.resolveForFile(manifestPath);
+    const lockfilePath = path.join(
+      cargoPackage?.workspaceRoot || projectPath,
+      'Cargo.lock'
+    );
+    if (!(await fs.pathExists(lockfilePath))) {
+      return CargoAuditParser.createResult(
+        [],
+        'Cargo.lock not found. Run "cargo generate-lockfile" first.'
+      );
+    }
+
+    const advisoryDbPath = resolveAdvisoryDbPath(options.advisoryDbPath);
+    if (!(await fs.pathExists(advisoryDbPath))) {
+      return CargoAuditParser.createResult(
+        [],
+        i18next.t('security_analysis.advisory_db_missing', {
+          path: advisoryDbPath,
+          env: APP_CONFIG.RUST.ADVISORY_DB_ENV,
+        })
+      );
+    }
+
+    try {
+      const { stdout, stderr, exitCode } = await ToolExecutor.runCargoAudit(
+        lockfilePath,
+        advisoryDbPath,
+        options.timeout ? { timeout: options.timeout } : {}
+      );
+
+      // cargo-audit exits with 1 when vulnerabilities were found
+      const result = CargoAuditParser.parse(stdout);
+      if (result) {
+        return result;
+      }
+
+      if (stderr.includes('no such command')) {
+        console.log(
+          `⚠️  ${i18next.t('security_analysis.cargo_audit_not_installed')}`
+        );
+        return null;
+      }
+
+      return CargoAuditParser.createResult(
+        [],
+        stderr || `cargo audit exited with code ${exitCode}`
+      );
+    } catch (error) {
+      if ((error as NodeJS.ErrnoException).code === 'ENOENT') {
+        console.log(
+          `⚠️  ${i18next.t('security_analysis.cargo_audit_not_installed')}`
+        );
+        return null;
+      }
+      return CargoAuditParser.createResult(
+        [],
+        error instanceof Error ? error.message : String(error)
+      );
+    }
+  }
+
+  /**
+   * Run all cargo-deny checks (advisories, bans, licenses, sources)
+   * @returns null if the project is not a Cargo project or cargo-deny is
+   * not installed; `configPath` is null when there is no deny.toml
+   */
+  public async runCargoDenyChecks(
+    options: SecurityCheckOptions = {}
+  ): Promise<CargoDenyResult | null> {
+    const projectPath = path.resolve(options.projectPath || process.cwd());
+    const manifestPath = path.join(projectPath, 'Cargo.toml');
+    if (!(await fs.pathExists(manifestPath))) {
+      return null;
+    }
+
+    // Without a config cargo-deny rejects every license, so don't run it
+    const cargoPackage = await this.cargoResolver.resolveForFile(manifestPath);
+    const workspaceRoot = cargoPackage?.workspaceRoot || projectPath;
+    const configPath = await this.findDenyConfig(workspaceRoot);
+    if (!configPath) {
+      return { configPath: null, diagnostics: [] };
+    }
+
+    const diagnostics: CargoDenyDiagnostic[] = [];
+    const errors: string[] = [];
+    try {
+      for (const check of CARGO_DENY_CHECKS) {
+        const { stdout, stderr, exitCode } = await ToolExecutor.runCargoDeny(
+          manifestPath,
+          check,
+          options.timeout ? { timeout: options.timeout } : {}
+        );
+
+        if (stderr.includes('no such command')) {
+          console.log(
+            `⚠️  ${i18next.t('security_analysis.cargo_deny_not_installed')}`
+          );
+          return null;
+        }
+
+        const output = `${stdout}\n${stderr}`;
+        const checkDiagnostics = CargoDenyParser.parse(output, check);
+        diagnostics.push(...checkDiagnostics);
+        // e.g. an invalid deny.toml: no diagnostics, only error logs
+        if (exitCode !== 0 && checkDiagnostics.length === 0) {
+          const logErrors = CargoDenyParser.parseErrors(output);
+          errors.push(
+            ...(logErrors.length > 0
+              ? logErrors
+              : [stderr || `cargo deny exited with code ${exitCode}`])
+          );
+        }
+      }
+    } catch (error) {
+      if ((error as NodeJS.ErrnoException).code === 'ENOENT') {
+        console.log(
+          `⚠️  ${i18next.t('security_analysis.cargo_deny_not_installed')}`
+        );
+        return null;
+      }
+      errors.push(error instanceof Error ? error.message : String(error));
+    }
+
+    return {
+      configPath,
+      diagnostics,
+      error: errors.length > 0 ? [...new Set(errors)].join('\n') : undefined,
+    };
+  }
+
+  /**
+   * Locations cargo-deny reads its configuration from
+   */
+  private async findDenyConfig(workspaceRoot: string): Promise<string | null> {
+    for (const candidate of ['deny.toml', '.deny.toml', '.cargo/deny.toml']) {
+      const configPath = path.join(workspaceRoot, candidate);
+      if (await fs.pathExists(configPath)) {
+        return configPath;
+      }
+    }
+    return null;
+  }
+
   /**
    * Run Gitleaks to detect secrets in code
    */
@@ -2044,6 +2413,36 @@ export class QualityRunner {
     return this.solidChecker.supportsLanguage(language);
   }
 
+  /**
+   * Build a review result from internal code smell findings alone
+   */
+  private createCodeSmellResult(
+    filePath: string,
+    codeSmellFindings: CodeSmellFinding[]
+  ): QualityCheckResult {
+    const issues = codeSmellFindings.map(
+      finding => `Line ${finding.line}:${finding.column} - ${finding.message}`
+    );
+    const fixes = codeSmellFindings
+      .filter(finding => finding.suggestion)
+      .map(finding => finding.suggestion || 'No suggestion available');
+    const criticalFindings = codeSmellFindings.filter(
+      f => f.severity === 'error'
+    );
+    const severity =
+      criticalFindings.length > 0 ? ('error' as const) : ('warning' as const);
+
+    return {
+      filePath,
+      tool: 'WOARU Code Smell Analyzer',
+      severity,
+      issues,
+      fixes: fixes.length > 0 ? fixes : undefined,
+      explanation: `WOARU internal analysis found ${codeSmellFindings.length} code quality issues`,
+      codeSmellFindings,
+    };
+  }
+
   /**
    * Phase 0: Run internal code smell analysis (no external dependencies)
    */
@@ -2052,7 +2451,7 @@ export class QualityRunner {
     fileExtension: string
   ): Promise<void> {
     try {
-      // Only analyze JavaScript/TypeScript files for now
+      // Only analyze JavaScript/TypeScript and Rust files for now
       const supportedExtensions = [
         '.js',
         '.jsx',
@@ -2060,6 +2459,7 @@ export class QualityRunner {
         '.tsx',
         '.mjs',
         '.cjs',
+        '.rs',
       ];
       if (!supportedExtensions.includes(fileExtension)) {
         return;
@@ -2114,6 +2514,17 @@ export class QualityRunner {
         'magic-number',
       ];
     }
+    if (language === 'rust') {
+      return [
+        'complexity',
+        'function-length',
+        'parameter-count',
+        'nested-depth',
+        'unwrap-usage',
+        'panic-macro',
+        'unsafe-block',
+      ];
+    }
     return [];
   }
 
@@ -2121,7 +2532,11 @@ export class QualityRunner {
    * Check if code smell analysis is supported for a language
    */
   supportsCodeSmellAnalysis(language: string): boolean {
-    return language === 'javascript' || language === 'typescript';
+    return (
+      language === 'javascript' ||
+      language === 'typescript' ||
+      language === 'rust'
+    );
   }
 
   /**
@@ -0,0 +1,406 @@
+import * as os from 'os';
+import * as path from 'path';
+import fs from 'fs-extra';
+import { glob } from 'glob';
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { ToolExecutor } from '../utils/toolExecutor';
+import { CargoWorkspaceResolver } from '../rust/CargoWorkspaceResolver';
+import {
+  TestCaseResult,
+  TestRunner,
+  TestRunResult,
+} from '../types/test-results';
+
+export const DEFAULT_SLOW_TEST_MS = 1000;
+
+// Profile written to a temporary nextest config, so the JUnit report ends up
+// in target/nextest/woaru/junit.xml
+const NEXTEST_PROFILE = 'woaru';
+const NEXTEST_CONFIG = `[profile.${NEXTEST_PROFILE}]
+fail-fast = false
+
+[profile.${NEXTEST_PROFILE}.junit]
+path = "junit.xml"
+store-success-output = false
+store-failure-output = true
+`;
+
+const MAX_MESSAGE_LENGTH = 2000;
+
+interface LibtestEvent {
+  type: 'suite' | 'test' | 'bench';
+  event: string; // started, ok, failed, ignored, timeout
+  name?: string;
+  exec_time?: number; // Seconds, with --report-time
+  stdout?: string;
+  message?: string;
+}
+
+/**
+ * Parses cargo-nextest JUnit reports, libtest JSON events
+ * (`-Z unstable-options --format json`) and plain libtest output into
+ * per-test results.
+ */
+export class RustTestParser {
+  static parseJUnit(xml: string): TestCaseResult[] {
+    const tests: TestCaseResult[] = [];
+    const suitePattern = /<testsuite\b([^>]*)>([\s\S]*?)<\/testsuite>/g;
+    const casePattern =
+      /<testcase\b([^>]*?)(?:\/>|>([\s\S]*?)<\/testcase>)/g;
+
+    for (const [, suiteAttributes, suiteBody] of xml.matchAll(suitePattern)) {
+      const suite = xmlAttribute(suiteAttributes, 'name');
+      const cases = suiteBody.matchAll(casePattern);
+      for (const [, attributes, body = ''] of cases) {
+        const name = xmlAttribute(attributes, 'name');
+        if (!name) continue;
+
+        const time = Number(xmlAttribute(attributes, 'time'));
+        // Retried-then-passed tests carry <flakyFailure>, not <failure>
+        const failure = body.match(
+          /<(failure|error)\b([^>]*?)(?:\/>|>([\s\S]*?)<\/\1>)/
+        );
+        const message = failure
+          ? (failure[3] || '').trim() || xmlAttribute(failure[2], 'message')
+          : undefined;
+        tests.push({
+          name,
+          suite,
+          outcome: failure
+            ? 'failed'
+            : /<skipped\b/.test(body)
+              ? 'ignored'
+              : 'passed',
+          durationMs: isNaN(time) ? undefined : Math.round(time * 1000),
+          message: message ? truncate(decodeXml(message)) : undefined,
+        });
+      }
+    }
+
+    return tests;
+  }
+
+  static parseLibtestJson(output: string): TestCaseResult[] {
+    const tests: TestCaseResult[] = [];
+
+    for (const rawLine of output.split('\n')) {
+      const line = rawLine.trim();
+      if (!line.startsWith('{')) continue;
+
+      const event = safeJsonParse<LibtestEvent>(line);
+      if (!event || event.type !== 'test' || !event.name) continue;
+
+      const outcome =
+        event.event === 'ok'
+          ? 'passed'
+          : event.event === 'failed' || event.event === 'timeout'
+            ? 'failed'
+            : event.event === 'ignored'
+              ? 'ignored'
+              : null; // started
+      if (!outcome) continue;
+
+      tests.push({
+        name: event.name,
+        outcome,
+        durationMs:
+          typeof event.exec_time === 'number'
+            ? Math.round(event.exec_time * 1000)
+            : undefined,
+        message:
+          outcome === 'failed'
+            ? truncate((event.stdout || event.message || '').trim())
+            : undefined,
+      });
+    }
+
+    return tests;
+  }
+
+  static parseLibtestOutput(output: string): TestCaseResult[] {
+    const tests: TestCaseResult[] = [];
+    const failures = new Map<string, string[]>();
+    let captured: string[] | null = null;
+
+    for (const line of output.split(/\r?\n/)) {
+      // test tests::add ... ok | FAILED | ignored[, reason]
+      const result = line.match(/^test (.+?) \.\.\. (ok|FAILED|ignored\b.*)$/);
+      if (result) {
+        tests.push({
+          name: result[1],
+          outcome:
+            result[2] === 'ok'
+              ? 'passed'
+              : result[2] === 'FAILED'
+                ? 'failed'
+                : 'ignored',
+        });
+        continue;
+      }
+
+      // ---- tests::add stdout ---- starts the captured output of a failure
+      const header = line.match(/^---- (.+?) stdout ----$/);
+      if (header) {
+        captured = [];
+        failures.set(header[1], captured);
+      } else if (/^(failures|successes):$/.test(line)) {
+        captured = null;
+      } else if (captured) {
+        captured.push(line);
+      }
+    }
+
+    return tests.map(test =>
+      test.outcome === 'failed' && failures.has(test.name)
+        ? {
+            ...test,
+            message: truncate(failures.get(test.name)!.join('\n').trim()),
+          }
+        : test
+    );
+  }
+
+  /**
+   * @param exitCode Exit code of the test command; a run only succeeds if
+   * it exited cleanly, as results can be missing (e.g. a crashed binary)
+   */
+  static summarize(
+    runner: TestRunner,
+    tests: TestCaseResult[],
+    exitCode: number,
+    slowThresholdMs: number = DEFAULT_SLOW_TEST_MS
+  ): TestRunResult {
+    const failedTests = tests.filter(test => test.outcome === 'failed');
+    const ignoredTests = tests.filter(test => test.outcome === 'ignored');
+    const timed = tests.filter(test => test.durationMs !== undefined);
+
+    return {
+      runner,
+      success: exitCode === 0 && failedTests.length === 0,
+      passed: tests.filter(test => test.outcome === 'passed').length,
+      failed: failedTests.length,
+      ignored: ignoredTests.length,
+      durationMs:
+        timed.length > 0
+          ? timed.reduce((sum, test) => sum + test.durationMs!, 0)
+          : undefined,
+      failedTests,
+      ignoredTests,
+      slowTests: timed
+        .filter(test => test.outcome !== 'ignored')
+        .filter(test => test.durationMs! >= slowThresholdMs)
+        .sort((a, b) => b.durationMs! - a.durationMs!),
+      slowThresholdMs,
+    };
+  }
+}
+
+/**
+ * Runs the tests of a Cargo workspace with cargo-nextest (JUnit report),
+ * falling back to libtest JSON on nightly and to plain `cargo test` output
+ */
+export class RustTestRunner {
+  constructor(
+    private projectPath: string,
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {}
+
+  /**
+   * @returns null if the project is not a Cargo project
+   */
+  async run(
+    slowThresholdMs: number = DEFAULT_SLOW_TEST_MS
+  ): Promise<TestRunResult | null> {
+    const manifestPath = path.join(
+      path.resolve(this.projectPath),
+      'Cargo.toml'
+    );
+    if (!(await fs.pathExists(manifestPath))) {
+      return null;
+    }
+    const cargoPackage = await this.resolver.resolveForFile(manifestPath);
+    const workspaceManifest =
+      cargoPackage?.workspaceManifestPath || manifestPath;
+
+    return (
+      (await this.runNextest(workspaceManifest, slowThresholdMs)) ||
+      (await this.runLibtest(workspaceManifest, slowThresholdMs))
+    );
+  }
+
+  /**
+   * Read the most recent JUnit report left by cargo-nextest (any profile)
+   */
+  async readLatestJUnit(
+    slowThresholdMs: number = DEFAULT_SLOW_TEST_MS
+  ): Promise<TestRunResult | null> {
+    const reports = await glob('nextest/*/*.xml', {
+      cwd: this.targetDir(path.resolve(this.projectPath)),
+      absolute: true,
+      nodir: true,
+    });
+
+    let latest: { file: string; mtime: number } | null = null;
+    for (const file of reports) {
+      const stat = await fs.stat(file).catch(() => null);
+      if (stat && (!latest || stat.mtimeMs > latest.mtime)) {
+        latest = { file, mtime: stat.mtimeMs };
+      }
+    }
+    if (!latest) {
+      return null;
+    }
+
+    const xml = await fs.readFile(latest.file, 'utf-8');
+    // A saved report carries no exit code: judge it by its results
+    return RustTestParser.summarize(
+      'nextest',
+      RustTestParser.parseJUnit(xml),
+      0,
+      slowThresholdMs
+    );
+  }
+
+  /**
+   * @returns null if cargo-nextest is not installed
+   */
+  private async runNextest(
+    manifestPath: string,
+    slowThresholdMs: number
+  ): Promise<TestRunResult | null> {
+    // Layered on top of the repository's .config/nextest.toml as a tool
+    // config file, so its settings (test groups, overrides) still apply
+    const configDir = await fs.mkdtemp(
+      path.join(os.tmpdir(), 'woaru-nextest-')
+    );
+    const configFile = path.join(configDir, 'nextest.toml');
+    const junitPath = path.join(
+      this.targetDir(path.dirname(manifestPath)),
+      'nextest',
+      NEXTEST_PROFILE,
+      'junit.xml'
+    );
+
+    try {
+      await fs.writeFile(configFile, NEXTEST_CONFIG);
+      await fs.remove(junitPath);
+
+      const { stderr, exitCode } = await ToolExecutor.runCargoNextest(
+        manifestPath,
+        configFile,
+        NEXTEST_PROFILE
+      );
+      if (stderr.includes('no such command')) {
+        return null;
+      }
+
+      if (!(await fs.pathExists(junitPath))) {
+        return this.failedRun('nextest', exitCode, stderr, slowThresholdMs);
+      }
+      const xml = await fs.readFile(junitPath, 'utf-8');
+      return RustTestParser.summarize(
+        'nextest',
+        RustTestParser.parseJUnit(xml),
+        exitCode,
+        slowThresholdMs
+      );
+    } finally {
+      await fs.remove(configDir);
+    }
+  }
+
+  private async runLibtest(
+    manifestPath: string,
+    slowThresholdMs: number
+  ): Promise<TestRunResult> {
+    const json = await ToolExecutor.runCargoTest(manifestPath, [
+      '-Z',
+      'unstable-options',
+      '--format',
+      'json',
+      '--report-time',
+    ]);
+    // Stable toolchains reject -Z: rerun with human-readable output
+    if (!/only accepted on the nightly compiler/.test(json.stderr)) {
+      const tests = RustTestParser.parseLibtestJson(json.stdout);
+      return tests.length > 0 || json.exitCode === 0
+        ? RustTestParser.summarize(
+            'libtest-json',
+            tests,
+            json.exitCode,
+            slowThresholdMs
+          )
+        : this.failedRun(
+            'libtest-json',
+            json.exitCode,
+            json.stderr,
+            slowThresholdMs
+          );
+    }
+
+    const plain = await ToolExecutor.runCargoTest(manifestPath);
+    const tests = RustTestParser.parseLibtestOutput(plain.stdout);
+    return tests.length > 0 || plain.exitCode === 0
+      ? RustTestParser.summarize(
+          'libtest',
+          tests,
+          plain.exitCode,
+          slowThresholdMs
+        )
+      : this.failedRun(
+          'libtest',
+          plain.exitCode,
+          plain.stderr,
+          slowThresholdMs
+        );
+  }
+
+  /**
+   * A run that produced no test results, e.g. because the build failed
+   */
+  private failedRun(
+    runner: TestRunner,
+    exitCode: number,
+    stderr: string,
+    slowThresholdMs: number
+  ): TestRunResult {
+    const errors = stderr
+      .split('\n')
+      .filter(line => /^error(\[E\d+\])?:/.test(line))
+      .slice(0, 5);
+    return {
+      ...RustTestParser.summarize(runner, [], exitCode, slowThresholdMs),
+      error:
+        errors.length > 0
+          ? errors.join('\n')
+          : `${runner} exited with code ${exitCode}`,
+    };
+  }
+
+  private targetDir(workspaceRoot: string): string {
+    return process.env.CARGO_TARGET_DIR
+      ? path.resolve(workspaceRoot, process.env.CARGO_TARGET_DIR)
+      : path.join(workspaceRoot, 'target');
+  }
+}
+
+function xmlAttribute(attributes: string, name: string): string | undefined {
+  const match = attributes.match(new RegExp(`\\b${name}="([^"]*)"`));
+  return match ? decodeXml(match[1]) : undefined;
+}
+
+function decodeXml(value: string): string {
+  return value
+    .replace(/&lt;/g, '<')
+    .replace(/&gt;/g, '>')
+    .replace(/&quot;/g, '"')
+    .replace(/&apos;/g, "'")
+    .replace(/&#10;/g, '\n')
+    .replace(/&amp;/g, '&');
+}
+
+function truncate(message: string): string {
+  return message.length > MAX_MESSAGE_LENGTH
+    ? `${message.slice(0, MAX_MESSAGE_LENGTH)}…`
+    : message;
+}
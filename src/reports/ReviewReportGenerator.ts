@@ -7,6 +7,20 @@ import { SecurityScanResult, SecurityFinding } from '../types/security';
 import { SOLIDCheckResult, SOLIDViolation } from '../solid/types/solid-types';
 import { CodeSmellFinding } from '../types/code-smell';
 import { MultiLLMReviewResult, AIReviewFinding } from '../types/ai-review';
+import { ReviewCoverage } from '../types/coverage';
+import { TestCaseResult, TestRunResult } from '../types/test-results';
+import { formatLineRanges } from '../quality/CoverageReader';
+import { BenchmarkComparison, BenchmarkReport } from '../types/benchmarks';
+import { formatNanoseconds } from '../quality/CriterionBenchmarks';
+import {
+  RustDocCoverageReport,
+  SemverChange,
+  SemverCheckResult,
+  UnsafeCrateInventory,
+  UnsafeInventory,
+} from '../types/rust';
+import { totalUnsafe } from '../rust/UnsafeInventoryScanner';
+import { docCoveragePercent } from '../rust/RustDocCoverage';
 import { FilenameHelper } from '../utils/filenameHelper';
 import { t, initializeI18n } from '../config/i18n';
 
@@ -92,6 +106,12 @@ export interface ReviewReportData {
   snykResults?: SnykResult[]; // Legacy support
   productionAudits: ProductionAudit[];
   aiReviewResults?: MultiLLMReviewResult; // AI review results from runAIReviewOnFiles
+  coverage?: ReviewCoverage; // From lcov/Cobertura/tarpaulin reports
+  unsafeInventory?: UnsafeInventory; // Rust projects only
+  testResults?: TestRunResult; // cargo nextest / cargo test run
+  semverChecks?: SemverCheckResult[]; // Changed library crates vs. base
+  docCoverage?: RustDocCoverageReport; // Rust library crates only
+  benchmarks?: BenchmarkReport; // Criterion results vs. base or last run
   currentBranch: string;
   commits: string[];
 }
@@ -203,6 +223,107 @@ export class ReviewReportGenerator {
         commits: data.commits,
       };
 
+      if (data.coverage) {
+        const { report, changedFiles, uncoveredChanges } = data.coverage;
+        Object.assign(result.summary as object, {
+          coveragePercentage: report.percentage,
+          uncoveredChangedLines: uncoveredChanges.reduce(
+            (sum, change) => sum + change.lines.length,
+            0
+          ),
+        });
+        result.coverage = {
+          format: report.format,
+          report: path.basename(report.reportPath),
+          generatedAt: report.generatedAt,
+          percentage: report.percentage,
+          linesFound: report.linesFound,
+          linesHit: report.linesHit,
+          changedFiles: changedFiles.map(file => ({
+            file: path.relative(process.cwd(), file.file),
+            percentage: file.percentage,
+            linesFound: file.linesFound,
+            linesHit: file.linesHit,
+          })),
+          uncoveredChanges: uncoveredChanges.map(change => ({
+            ...change,
+            file: path.relative(process.cwd(), change.file),
+          })),
+        };
+      }
+
+      if (data.testResults) {
+        const { passed, failed, ignored } = data.testResults;
+        Object.assign(result.summary as object, {
+          testsPassed: passed,
+          testsFailed: failed,
+          testsIgnored: ignored,
+        });
+        result.testResults = data.testResults;
+      }
+
+      if (data.semverChecks) {
+        Object.assign(result.summary as object, {
+          semverViolations: data.semverChecks.filter(
+            check => check.violation
+          ).length,
+        });
+        result.semverChecks = data.semverChecks.map(check => ({
+          ...check,
+          manifestPath: path.relative(process.cwd(), check.manifestPath),
+        }));
+      }
+
+      if (data.benchmarks) {
+        Object.assign(result.summary as object, {
+          benchmarkRegressions: data.benchmarks.regressions.length,
+        });
+        result.benchmarks = data.benchmarks;
+      }
+
+      if (data.docCoverage) {
+        Object.assign(result.summary as object, {
+          docCoveragePercentage: docCoveragePercent(data.docCoverage),
+        });
+        result.docCoverage = {
+          ...data.docCoverage,
+          crates: data.docCoverage.crates.map(crate => ({
+            ...crate,
+            manifestPath: path.relative(process.cwd(), crate.manifestPath),
+            percentage: docCoveragePercent(crate),
+            modules: crate.modules.map(module => ({
+              ...module,
+              file: path.relative(process.cwd(), module.file),
+              percentage: docCoveragePercent(module),
+            })),
+          })),
+        };
+      }
+
+      if (data.unsafeInventory) {
+        const { crates, unresolvedDependencies } = data.unsafeInventory;
+        const workspaceCrates = crates.filter(
+          crate => crate.source === 'workspace'
+        );
+        Object.assign(result.summary as object, {
+          workspaceUnsafeCount: workspaceCrates.reduce(
+            (sum, crate) => sum + totalUnsafe(crate),
+            0
+          ),
+          cratesWithoutForbidUnsafe: workspaceCrates.filter(
+            crate => !crate.forbidsUnsafe
+          ).length,
+        });
+        result.unsafeInventory = {
+          crates: crates.map(({ rootDir, ...crate }) => ({
+            ...crate,
+            rootDir: path.relative(process.cwd(), rootDir),
+            total: totalUnsafe(crate),
+          })),
+          unresolvedDependencies,
+        };
+      }
+
       // Add AI review data if available
       if (data.aiReviewResults) {
         interface ResultSummaryExtended {
@@ -573,6 +694,36 @@ export class ReviewReportGenerator {
     // Code Smell Analysis
     this.addCodeSmellAnalysisSection(lines, data.qualityResults);
 
+    // Test Coverage
+    if (data.coverage) {
+      this.addCoverageSection(lines, data.coverage);
+    }
+
+    // Test Results
+    if (data.testResults) {
+      this.addTestResultsSection(lines, data.testResults);
+    }
+
+    // Benchmark regressions (Criterion)
+    if (data.benchmarks) {
+      this.addPerformanceSection(lines, data.benchmarks);
+    }
+
+    // Public API semver checks (Rust)
+    if (data.semverChecks && data.semverChecks.length > 0) {
+      this.addSemverSection(lines, data.semverChecks);
+    }
+
+    // Unsafe Code Inventory (Rust)
+    if (data.unsafeInventory) {
+      this.addUnsafeInventorySection(lines, data.unsafeInventory);
+    }
+
+    // Documentation coverage of public items (Rust)
+    if (data.docCoverage) {
+      this.addDocCoverageSection(lines, data.docCoverage);
+    }
+
     // AI Code Review Analysis
     if (data.aiReviewResults) {
       this.addAIReviewSection(lines, data.aiReviewResults);
@@ -713,12 +864,20 @@ export class ReviewReportGenerator {
     const highPriorityAudits = data.productionAudits.filter(
       a => a.priority === 'high' || a.priority === 'critical'
     ).length;
+    const failedTests = data.testResults?.failed || 0;
+    const semverViolations = (data.semverChecks || []).filter(
+      check => check.violation
+    ).length;
+    const benchmarkRegressions = data.benchmarks?.regressions.length || 0;
 
     if (
       criticalIssues === 0 &&
       securitySummary.critical === 0 &&
       securitySummary.high === 0 &&
-      highPriorityAudits === 0
+      highPriorityAudits === 0 &&
+      failedTests === 0 &&
+      semverViolations === 0 &&
+      benchmarkRegressions === 0
     ) {
       return t('report_generator.no_critical_issues');
     }
@@ -735,6 +894,15 @@ export class ReviewReportGenerator {
     if (highPriorityAudits > 0) {
       issues.push(`${highPriorityAudits} Produktions-Empfehlungen`);
     }
+    if (failedTests > 0) {
+      issues.push(`${failedTests} fehlgeschlagene Tests`);
+    }
+    if (semverViolations > 0) {
+      issues.push(`${semverViolations} SemVer-Verstöße`);
+    }
+    if (benchmarkRegressions > 0) {
+      issues.push(`${benchmarkRegressions} langsamere Benchmarks`);
+    }
 
     return `⚠️ Gefunden: ${issues.join(', ')}`;
   }
@@ -772,6 +940,9 @@ export class ReviewReportGenerator {
             );
           }
           lines.push(`- **Schweregrad:** KRITISCH`);
+          if (finding.advisoryId) {
+            lines.push(`- **Advisory:** ${finding.advisoryId}`);
+          }
           if (finding.cve) {
             lines.push(`- **CVE:** ${finding.cve}`);
           }
@@ -801,6 +972,14 @@ export class ReviewReportGenerator {
             );
           }
           lines.push(`- **Schweregrad:** HOCH`);
+          if (finding.advisoryId) {
+            lines.push(`- **Advisory:** ${finding.advisoryId}`);
+          }
+          if (finding.fixedIn) {
+            lines.push(
+              `- **✅ Fix verfügbar:** Upgrade auf ${finding.fixedIn}`
+            );
+          }
           if (finding.recommendation) {
             lines.push(`- **Empfehlung:** ${finding.recommendation}`);
           }
@@ -875,6 +1054,8 @@ export class ReviewReportGenerator {
         return '🕵️';
       case 'trivy':
         return '🛡️';
+      case 'cargo-audit':
+        return '🦀';
       default:
         return '🔒';
     }
@@ -916,6 +1097,7 @@ export class ReviewReportGenerator {
       result.tool.toLowerCase().includes('eslint') ||
       result.tool.toLowerCase().includes('prettier') ||
       result.tool.toLowerCase().includes('ruff') ||
+      result.tool.toLowerCase().includes('clippy') ||
       (result.raw_output !== undefined && result.raw_output.length < 1000)
     ); // Don't show very long outputs
   }
@@ -1357,6 +1539,422 @@ export class ReviewReportGenerator {
     lines.push('');
   }
 
+  /**
+   * Add test coverage of the changed files and flag changed lines that no
+   * test executes
+   */
+  private addCoverageSection(lines: string[], coverage: ReviewCoverage): void {
+    const { report, changedFiles, uncoveredChanges } = coverage;
+
+    lines.push('## 🧪 Testabdeckung');
+    lines.push('');
+    lines.push(
+      `📊 **Gesamt: ${report.percentage}%** (${report.linesHit}/${report.linesFound} Zeilen, ${report.format}: \`${path.basename(report.reportPath)}\`, Stand ${report.generatedAt.toLocaleString('de-DE')})`
+    );
+    lines.push('');
+
+    if (changedFiles.length > 0) {
+      lines.push('### 📄 Geänderte Dateien:');
+      [...changedFiles]
+        .sort((a, b) => a.percentage - b.percentage)
+        .forEach(file => {
+          const icon =
+            file.percentage >= 80
+              ? '🟢'
+              : file.percentage >= 50
+                ? '🟡'
+                : '🔴';
+          lines.push(
+            `- ${icon} \`${path.relative(process.cwd(), file.file)}\`: ${file.percentage}% (${file.linesHit}/${file.linesFound})`
+          );
+        });
+      lines.push('');
+    }
+
+    if (uncoveredChanges.length > 0) {
+      const total = uncoveredChanges.reduce(
+        (sum, change) => sum + change.lines.length,
+        0
+      );
+      lines.push(
+        `### ⚠️ Nicht getestete Änderungen (${total} Zeilen in ${uncoveredChanges.length} Dateien):`
+      );
+      uncoveredChanges.forEach(change => {
+        lines.push(
+          `- \`${path.relative(process.cwd(), change.file)}\` Zeilen ${formatLineRanges(change.lines)}`
+        );
+      });
+      lines.push('');
+      lines.push(
+        '💡 Tests für diese Zeilen ergänzen und den Coverage-Report neu erzeugen (z.B. `cargo llvm-cov --lcov --output-path lcov.info`)'
+      );
+      lines.push('');
+    } else if (changedFiles.length > 0) {
+      lines.push('✅ Alle geänderten, instrumentierten Zeilen sind getestet');
+      lines.push('');
+    }
+
+    lines.push('---');
+    lines.push('');
+  }
+
+  /**
+   * Add the outcome of the test run: failed tests with their output,
+   * ignored tests and tests slower than the threshold
+   */
+  private addTestResultsSection(
+    lines: string[],
+    results: TestRunResult
+  ): void {
+    const describe = (test: TestCaseResult) =>
+      test.suite ? `\`${test.name}\` (${test.suite})` : `\`${test.name}\``;
+
+    lines.push('## ✅ Testergebnisse');
+    lines.push('');
+
+    if (results.error && results.passed + results.failed === 0) {
+      lines.push(
+        `❌ **Tests konnten nicht ausgeführt werden** (${results.runner}):`
+      );
+      lines.push('```');
+      lines.push(results.error);
+      lines.push('```');
+      lines.push('');
+      lines.push('---');
+      lines.push('');
+      return;
+    }
+
+    const duration =
+      results.durationMs !== undefined
+        ? `, ${(results.durationMs / 1000).toFixed(1)}s`
+        : '';
+    lines.push(
+      `${results.success ? '🟢' : '🔴'} **${results.passed} bestanden, ${results.failed} fehlgeschlagen, ${results.ignored} ignoriert** (${results.runner}${duration})`
+    );
+    lines.push('');
+
+    if (results.failedTests.length > 0) {
+      lines.push('### ❌ Fehlgeschlagene Tests:');
+      results.failedTests.forEach(test => {
+        lines.push(`- ${describe(test)}`);
+        if (test.message) {
+          lines.push('  ```');
+          test.message
+            .split('\n')
+            .slice(0, 20)
+            .forEach(line => lines.push(`  ${line}`));
+          lines.push('  ```');
+        }
+      });
+      lines.push('');
+    }
+
+    if (results.slowTests.length > 0) {
+      lines.push(`### 🐢 Langsame Tests (≥ ${results.slowThresholdMs} ms):`);
+      results.slowTests.slice(0, 10).forEach(test => {
+        lines.push(`- ${describe(test)}: ${test.durationMs} ms`);
+      });
+      lines.push('');
+    }
+
+    if (results.ignoredTests.length > 0) {
+      lines.push(
+        `### ⏭️ Ignorierte Tests (${results.ignoredTests.length}):`
+      );
+      results.ignoredTests.slice(0, 20).forEach(test => {
+        lines.push(`- ${describe(test)}`);
+      });
+      lines.push('');
+    }
+
+    lines.push('---');
+    lines.push('');
+  }
+
+  /**
+   * Add unsafe blocks, fns, impls and extern blocks per crate, the files of
+   * workspace crates that contain them and crates missing a forbid guard
+   */
+  private addUnsafeInventorySection(
+    lines: string[],
+    inventory: UnsafeInventory
+  ): void {
+    const workspaceCrates = inventory.crates.filter(
+      crate => crate.source === 'workspace'
+    );
+    const dependencies = inventory.crates
+      .filter(crate => crate.source !== 'workspace' && totalUnsafe(crate) > 0)
+      .sort((a, b) => totalUnsafe(b) - totalUnsafe(a));
+    const safeDependencies =
+      inventory.crates.length - workspaceCrates.length - dependencies.length;
+    const row = (crate: UnsafeCrateInventory) =>
+      `| ${[crate.name, crate.version].filter(Boolean).join(' ')} | ${crate.source} | ${crate.unsafeBlocks} | ${crate.unsafeFns} | ${crate.unsafeImpls} | ${crate.externBlocks} | ${crate.forbidsUnsafe ? '✅' : '❌'} |`;
+    const header = [
+      '| Crate | Quelle | unsafe-Blöcke | unsafe fn | unsafe impl | extern | forbid |',
+      '|-------|--------|---------------|-----------|-------------|--------|--------|',
+    ];
+
+    lines.push('## ☢️ Unsafe-Code Inventar');
+    lines.push('');
+
+    lines.push('### 📦 Workspace-Crates:');
+    lines.push('');
+    lines.push(...header);
+    workspaceCrates.forEach(crate => lines.push(row(crate)));
+    lines.push('');
+
+    workspaceCrates
+      .filter(crate => crate.files.length > 0)
+      .forEach(crate => {
+        lines.push(`**${crate.name}:**`);
+        crate.files.forEach(file => {
+          lines.push(
+            `- \`${path.relative(process.cwd(), path.join(crate.rootDir, file.file))}\`: ${file.unsafeBlocks} Blöcke, ${file.unsafeFns} fn, ${file.unsafeImpls} impl, ${file.externBlocks} extern`
+          );
+        });
+        lines.push('');
+      });
+
+    const unguarded = workspaceCrates.filter(crate => !crate.forbidsUnsafe);
+    if (unguarded.length > 0) {
+      lines.push(
+        `⚠️ **Ohne \`#![forbid(unsafe_code)]\`:** ${unguarded.map(crate => crate.name).join(', ')}`
+      );
+      lines.push('');
+    }
+
+    if (dependencies.length > 0) {
+      lines.push(
+        `### 🔗 Abhängigkeiten mit unsafe-Code (${dependencies.length}, ${safeDependencies} ohne):`
+      );
+      lines.push('');
+      lines.push(...header);
+      dependencies.forEach(crate => lines.push(row(crate)));
+      lines.push('');
+    }
+
+    const unresolved = inventory.unresolvedDependencies;
+    if (unresolved.length > 0) {
+      const shown = unresolved.slice(0, 10).join(', ');
+      lines.push(
+        `ℹ️ ${unresolved.length} Abhängigkeiten nicht lokal verfügbar (\`cargo fetch\` oder \`cargo vendor\` ausführen): ${shown}${unresolved.length > 10 ? ', …' : ''}`
+      );
+      lines.push('');
+    }
+
+    lines.push('---');
+    lines.push('');
+  }
+
+  /**
+   * Add benchmarks that got slower or faster than the threshold compared
+   * with the base branch or the previous run
+   */
+  private addPerformanceSection(
+    lines: string[],
+    report: BenchmarkReport
+  ): void {
+    const row = (comparison: BenchmarkComparison) =>
+      `| \`${comparison.id}\` | ${formatNanoseconds(comparison.baselineNs)} | ${formatNanoseconds(comparison.currentNs)} | ${comparison.changePercent > 0 ? '+' : ''}${comparison.changePercent}% |`;
+
+    lines.push('## ⏱️ Performance');
+    lines.push('');
+
+    if (report.stale) {
+      lines.push(
+        '⚠️ Die Criterion-Ergebnisse sind älter als der aktuelle Commit und wurden nicht für ihn gespeichert - für aktuelle Werte mit `--bench` ausführen.'
+      );
+      lines.push('');
+    }
+
+    if (!report.baseline) {
+      lines.push(
+        report.stale
+          ? `ℹ️ ${report.current.benchmarks.length} Benchmark(s) gefunden - noch kein früherer Lauf zum Vergleich vorhanden.`
+          : `ℹ️ ${report.current.benchmarks.length} Benchmark(s) gemessen und als Vergleichsbasis gespeichert - noch kein früherer Lauf vorhanden.`
+      );
+      lines.push('');
+      lines.push('---');
+      lines.push('');
+      return;
+    }
+
+    const baseline =
+      report.baselineSource === 'base-branch'
+        ? `Basis-Branch (${report.baseline.commit.slice(0, 8)})`
+        : `vorheriger Lauf (${report.baseline.commit.slice(0, 8)}, ${report.baseline.measuredAt})`;
+    lines.push(
+      `${report.regressions.length > 0 ? '🔴' : '🟢'} **${report.regressions.length} langsamer, ${report.improvements.length} schneller, ${report.unchanged} unverändert** (Schwelle ±${report.thresholdPercent}%, Vergleich: ${baseline})`
+    );
+    lines.push('');
+
+    const table = (title: string, comparisons: BenchmarkComparison[]) => {
+      lines.push(title);
+      lines.push('');
+      lines.push('| Benchmark | Vorher | Jetzt | Änderung |');
+      lines.push('|-----------|--------|-------|----------|');
+      comparisons.slice(0, 20).forEach(comparison => {
+        lines.push(row(comparison));
+      });
+      if (comparisons.length > 20) {
+        lines.push(`| … ${comparisons.length - 20} weitere | | | |`);
+      }
+      lines.push('');
+    };
+    if (report.regressions.length > 0) {
+      table('### 🐢 Langsamer geworden:', report.regressions);
+    }
+    if (report.improvements.length > 0) {
+      table('### 🚀 Schneller geworden:', report.improvements);
+    }
+    if (report.added.length > 0) {
+      lines.push(
+        `🆕 Ohne Vergleichswert: ${report.added
+          .slice(0, 20)
+          .map(id => `\`${id}\``)
+          .join(', ')}`
+      );
+      lines.push('');
+    }
+
+    lines.push('---');
+    lines.push('');
+  }
+
+  /**
+   * Add the share of documented public items per crate and the modules
+   * that still lack documentation
+   */
+  private addDocCoverageSection(
+    lines: string[],
+    coverage: RustDocCoverageReport
+  ): void {
+    const source =
+      coverage.source === 'rustdoc' ? 'rustdoc --show-coverage' : 'Quellscan';
+
+    lines.push('## 📚 Dokumentationsabdeckung');
+    lines.push('');
+    lines.push(
+      `**Gesamt:** ${docCoveragePercent(coverage)}% (${coverage.documented}/${coverage.total} öffentliche Elemente dokumentiert, Quelle: ${source})`
+    );
+    lines.push('');
+    // The two sources count different items, so their numbers differ
+    lines.push(
+      coverage.source === 'rustdoc'
+        ? '_rustdoc zählt alle öffentlichen Elemente inklusive Module, Felder, Varianten und Methoden; der Quellscan zählt nur `pub fn`, `pub struct`, `pub enum` und `pub trait` – die Werte sind nicht direkt vergleichbar._'
+        : '_Der Quellscan zählt nur `pub fn`, `pub struct`, `pub enum` und `pub trait`; rustdoc (`--rustdoc-coverage`) zählt zusätzlich Module, Felder, Varianten und Methoden – die Werte sind nicht direkt vergleichbar._'
+    );
+    lines.push('');
+    lines.push('| Crate | Abdeckung | Dokumentiert | Gesamt |');
+    lines.push('|-------|-----------|--------------|--------|');
+    coverage.crates.forEach(crate => {
+      lines.push(
+        `| ${crate.name} | ${docCoveragePercent(crate)}% | ${crate.documented} | ${crate.total} |`
+      );
+    });
+    lines.push('');
+
+    const incomplete = coverage.crates
+      .flatMap(crate =>
+        crate.modules
+          .filter(module => module.documented < module.total)
+          .map(module => ({ crate: crate.name, ...module }))
+      )
+      .sort((a, b) => docCoveragePercent(a) - docCoveragePercent(b));
+    if (incomplete.length > 0) {
+      lines.push('### 📝 Module mit fehlender Dokumentation:');
+      lines.push('');
+      incomplete.slice(0, 20).forEach(module => {
+        lines.push(
+          `- **${module.crate}** \`${module.module}\` (\`${path.relative(process.cwd(), module.file)}\`): ${module.documented}/${module.total} (${docCoveragePercent(module)}%)`
+        );
+      });
+      if (incomplete.length > 20) {
+        lines.push(`- … ${incomplete.length - 20} weitere`);
+      }
+      lines.push('');
+      lines.push(
+        '💡 Fehlende Dokumentation ergänzen: `woaru docu pro --missing-only`'
+      );
+      lines.push('');
+    }
+
+    lines.push('---');
+    lines.push('');
+  }
+
+  /**
+   * Add the public API changes of each changed library crate and whether
+   * its version bump covers them
+   */
+  private addSemverSection(
+    lines: string[],
+    checks: SemverCheckResult[]
+  ): void {
+    const labels: Record<SemverChange['kind'], string> = {
+      removed: 'Entfernt',
+      'signature-changed': 'Signatur geändert',
+      'trait-item-added': 'Neues Pflicht-Element im Trait',
+      added: 'Hinzugefügt',
+    };
+
+    lines.push('## 🔖 SemVer-Prüfung der öffentlichen API');
+    lines.push('');
+
+    checks.forEach(check => {
+      const versions = `${check.baseVersion} → ${check.currentVersion}`;
+      if (check.error) {
+        lines.push(
+          `⚠️ **${check.packageName}** (${versions}): Prüfung nicht möglich (${check.runner}): ${check.error}`
+        );
+        lines.push('');
+        return;
+      }
+
+      const actual =
+        check.actualBump === 'none'
+          ? 'keinen'
+          : `einen ${check.actualBump}-Sprung`;
+      lines.push(
+        check.violation
+          ? `🔴 **${check.packageName}** (${versions}): Änderungen erfordern einen **${check.requiredBump}**-Versionssprung, Cargo.toml enthält ${actual} (${check.runner})`
+          : check.bumpTooSmall
+            ? `ℹ️ **${check.packageName}** (${versions}): Neue öffentliche API – ein **${check.requiredBump}**-Versionssprung wird empfohlen, Cargo.toml enthält ${actual} (${check.runner})`
+            : `🟢 **${check.packageName}** (${versions}): Versionssprung passt zu den API-Änderungen (${check.runner})`
+      );
+      lines.push('');
+
+      const breaking = check.changes.filter(
+        change => change.level === 'major'
+      );
+      const additions = check.changes.filter(
+        change => change.level !== 'major'
+      );
+      breaking.slice(0, 30).forEach(change => {
+        const detail = change.detail ? ` – ${change.detail}` : '';
+        lines.push(
+          `- ❌ ${labels[change.kind]}: \`${change.path}\`${change.itemKind ? ` (${change.itemKind})` : ''}${detail}`
+        );
+      });
+      if (breaking.length > 30) {
+        lines.push(`- … ${breaking.length - 30} weitere`);
+      }
+      if (additions.length > 0) {
+        lines.push(
+          `- ➕ ${additions.length} neue öffentliche Elemente: ${additions
+            .slice(0, 10)
+            .map(change => `\`${change.path}\``)
+            .join(', ')}${additions.length > 10 ? ', …' : ''}`
+        );
+      }
+      if (check.changes.length > 0) lines.push('');
+    });
+
+    lines.push('---');
+    lines.push('');
+  }
+
   /**
    * Add list of code smell findings
    */
@@ -1394,6 +1992,9 @@ export class ReviewReportGenerator {
       'magic-number': '🔢',
       'duplicate-code': '📋',
       'dead-code': '💀',
+      'unwrap-usage': '💥',
+      'panic-macro': '🚧',
+      'unsafe-block': '☢️',
     };
     return icons[type] || '⚠️';
   }
@@ -1448,6 +2049,21 @@ export class ReviewReportGenerator {
             `🔢 Extrahiere ${count} magische Zahlen in benannte Konstanten`
           );
           break;
+        case 'unwrap-usage':
+          recommendations.push(
+            `💥 Ersetze ${count} \`unwrap()\`/\`expect()\` Aufrufe in Library-Code durch Fehlerbehandlung mit \`?\``
+          );
+          break;
+        case 'panic-macro':
+          recommendations.push(
+            `🚧 Entferne ${count} \`panic!\`/\`todo!\`/\`unimplemented!\` Aufrufe aus produktiven Code-Pfaden`
+          );
+          break;
+        case 'unsafe-block':
+          recommendations.push(
+            `☢️ Verkleinere ${count} große \`unsafe\` Blöcke und kapsle sie in sichere Abstraktionen`
+          );
+          break;
       }
     });
 
@@ -0,0 +1,267 @@
+import * as path from 'path';
+import {
+  extractRustFunctions,
+  findMatchingBrace,
+  findTestRegions,
+  isInRegions,
+  maskRustSource,
+  offsetToPosition,
+} from './RustSourceScanner';
+import { listRustSources, readRustSource } from './rustPaths';
+import { AsyncBlockingFinding, AsyncBlockingKind } from '../types/rust';
+
+const ALTERNATIVES: Record<AsyncBlockingKind, string> = {
+  'thread-sleep': 'use tokio::time::sleep(duration).await',
+  'std-fs':
+    'use the tokio::fs equivalent with .await or move the work into tokio::task::spawn_blocking',
+  'reqwest-blocking': 'use the async reqwest::Client with .await',
+  'mutex-across-await':
+    'drop the guard before the .await (e.g. in an inner block) or use tokio::sync::Mutex',
+};
+
+// Closures passed to these run off the async worker threads
+const BLOCKING_SECTIONS =
+  /\b(?:spawn_blocking|block_in_place|thread::spawn)\s*\(/g;
+
+// `let guard = m.lock().unwrap();` - std locks return a Result, tokio's don't
+const STD_LOCK_GUARD =
+  /\blet\s+(?:mut\s+)?(\w+)(?:\s*:[^=;]+)?\s*=\s*[^;{}]*?\.(lock|read|write)\(\)\s*(?:\.unwrap\(\)|\.expect\([^)]*\))\s*;/g;
+
+interface BlockingCallPattern {
+  kind: AsyncBlockingKind;
+  regex: RegExp;
+  call: string; // $1 is replaced with the first capture group
+}
+
+interface CodeRegion {
+  start: number;
+  end: number;
+  context?: string; // Set for async regions
+}
+
+/**
+ * Offset of the `)` matching the `(` at `openOffset`, or -1
+ */
+function findClosingParen(masked: string, openOffset: number): number {
+  let depth = 0;
+  for (let i = openOffset; i < masked.length; i++) {
+    if (masked[i] === '(') depth++;
+    else if (masked[i] === ')' && --depth === 0) return i;
+  }
+  return -1;
+}
+
+/**
+ * Function bodies, async blocks and blocking sections; the innermost region
+ * around an offset decides whether code there runs on the async runtime
+ */
+function findCodeRegions(masked: string): CodeRegion[] {
+  const lineStarts = [0];
+  for (let i = 0; i < masked.length; i++) {
+    if (masked[i] === '\n') lineStarts.push(i + 1);
+  }
+
+  const regions: CodeRegion[] = [];
+  for (const fn of extractRustFunctions(masked)) {
+    const fnOffset = lineStarts[fn.line - 1] + fn.column - 1;
+    const qualifiers = masked.slice(Math.max(0, fnOffset - 40), fnOffset);
+    const isAsync = /\basync\s+(?:unsafe\s+)?$/.test(qualifiers);
+    regions.push({
+      start: fn.bodyStart,
+      end: fn.bodyEnd,
+      context: isAsync ? `async fn ${fn.name}` : undefined,
+    });
+  }
+
+  for (const match of masked.matchAll(/\basync\s+(?:move\s+)?\{/g)) {
+    const open = (match.index || 0) + match[0].length - 1;
+    const end = findMatchingBrace(masked, open);
+    if (end !== -1) {
+      regions.push({ start: open, end, context: 'async block' });
+    }
+  }
+
+  for (const match of masked.matchAll(BLOCKING_SECTIONS)) {
+    const open = (match.index || 0) + match[0].length - 1;
+    const end = findClosingParen(masked, open);
+    if (end !== -1) {
+      regions.push({ start: open, end });
+    }
+  }
+
+  return regions;
+}
+
+function innermostRegion(
+  regions: CodeRegion[],
+  offset: number
+): CodeRegion | undefined {
+  return regions
+    .filter(region => offset > region.start && offset < region.end)
+    .sort((a, b) => b.start - a.start)[0];
+}
+
+/**
+ * Blocking call patterns of a file, widened by its `use` declarations
+ * (e.g. `use std::fs;` makes `fs::read(..)` a std call)
+ */
+function blockingCallPatterns(masked: string): BlockingCallPattern[] {
+  const stdImports = [...masked.matchAll(/\buse\s+std::([^;]*);/g)].map(
+    match => match[1]
+  );
+  const importsFromStd = (regex: RegExp) =>
+    stdImports.some(imported => regex.test(imported));
+
+  const patterns: BlockingCallPattern[] = [
+    {
+      kind: 'thread-sleep',
+      regex: /\b(?:std::)?thread::sleep\s*\(/g,
+      call: 'std::thread::sleep',
+    },
+    {
+      kind: 'std-fs',
+      regex: /\bstd::fs::((?:(?:File|OpenOptions)::)?\w+)\s*\(/g,
+      call: 'std::fs::$1',
+    },
+    {
+      kind: 'reqwest-blocking',
+      regex: /\breqwest::blocking::(\w+)/g,
+      call: 'reqwest::blocking::$1',
+    },
+  ];
+
+  if (importsFromStd(/\bthread::(?:\{[^}]*\bsleep\b|sleep\b)/)) {
+    patterns.push({
+      kind: 'thread-sleep',
+      regex: /(?<![\w:.]|fn\s+)sleep\s*\(/g,
+      call: 'std::thread::sleep',
+    });
+  }
+  if (importsFromStd(/(?:^|[{,\s])fs\s*(?:[,}]|$)|\bfs::\{[^}]*\bself\b/)) {
+    patterns.push({
+      kind: 'std-fs',
+      regex: /(?<![\w:])fs::((?:(?:File|OpenOptions)::)?\w+)\s*\(/g,
+      call: 'std::fs::$1',
+    });
+  }
+  if (importsFromStd(/\bfs::(?:\{[^}]*\bFile\b|File\b)/)) {
+    patterns.push({
+      kind: 'std-fs',
+      regex: /(?<![\w:])(File::(?:open|create|options))\s*\(/g,
+      call: 'std::fs::$1',
+    });
+  }
+  if (/\buse\s+reqwest::blocking\s*;/.test(masked)) {
+    patterns.push({
+      kind: 'reqwest-blocking',
+      regex: /(?<![\w:])blocking::(\w+)/g,
+      call: 'reqwest::blocking::$1',
+    });
+  }
+
+  return patterns;
+}
+
+/**
+ * Offset of the `}` closing the innermost block around an offset
+ */
+function findBlockEnd(masked: string, offset: number): number {
+  let depth = 0;
+  for (let i = offset; i < masked.length; i++) {
+    if (masked[i] === '{') depth++;
+    else if (masked[i] === '}' && --depth < 0) return i;
+  }
+  return masked.length;
+}
+
+/**
+ * Find blocking calls (std::thread::sleep, std::fs, reqwest::blocking) and
+ * std lock guards held across `.await` inside async functions and blocks.
+ * Closures passed to spawn_blocking/block_in_place and test code are
+ * skipped.
+ */
+export function findAsyncBlocking(
+  content: string,
+  file: string
+): AsyncBlockingFinding[] {
+  const masked = maskRustSource(content);
+  if (!/\basync\b/.test(masked)) {
+    return [];
+  }
+
+  const regions = findCodeRegions(masked);
+  const testRegions = findTestRegions(masked);
+  const findings = new Map<string, AsyncBlockingFinding>();
+  const asyncContext = (offset: number) =>
+    isInRegions(testRegions, offset)
+      ? undefined
+      : innermostRegion(regions, offset)?.context;
+  const add = (
+    kind: AsyncBlockingKind,
+    offset: number,
+    context: string,
+    call: string
+  ) => {
+    const { line } = offsetToPosition(content, offset);
+    const key = `${kind}:${line}`;
+    if (!findings.has(key)) {
+      findings.set(key, {
+        kind,
+        file,
+        line,
+        context,
+        call,
+        alternative: ALTERNATIVES[kind],
+      });
+    }
+  };
+
+  for (const { kind, regex, call } of blockingCallPatterns(masked)) {
+    for (const match of masked.matchAll(regex)) {
+      const offset = match.index || 0;
+      const context = asyncContext(offset);
+      if (context) {
+        add(kind, offset, context, call.replace('$1', match[1] || ''));
+      }
+    }
+  }
+
+  for (const match of masked.matchAll(STD_LOCK_GUARD)) {
+    const offset = match.index || 0;
+    const context = asyncContext(offset);
+    if (!context) continue;
+
+    // The guard lives until the end of its block or an explicit drop()
+    const letEnd = offset + match[0].length;
+    const scope = findBlockEnd(masked, offset);
+    const drop = masked
+      .slice(letEnd, scope)
+      .search(new RegExp(`\\bdrop\\s*\\(\\s*${match[1]}\\s*\\)`));
+    const liveUntil = drop === -1 ? scope : letEnd + drop;
+    if (/\.await\b/.test(masked.slice(letEnd, liveUntil))) {
+      const lock = match[2] === 'lock' ? 'Mutex::lock' : `RwLock::${match[2]}`;
+      add('mutex-across-await', offset, context, `std::sync::${lock}`);
+    }
+  }
+
+  return [...findings.values()].sort((a, b) => a.line - b.line);
+}
+
+/**
+ * Scans the Rust sources of a project for code that blocks the threads of
+ * an async runtime such as tokio
+ */
+export class AsyncBlockingDetector {
+  async scan(projectPath: string): Promise<AsyncBlockingFinding[]> {
+    const findings: AsyncBlockingFinding[] = [];
+    for (const file of await listRustSources(projectPath, ['vendor/**'])) {
+      const content = await readRustSource(file);
+      if (content !== null) {
+        findings.push(
+          ...findAsyncBlocking(content, path.relative(projectPath, file))
+        );
+      }
+    }
+    return findings;
+  }
+}
@@ -0,0 +1,283 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { ToolExecutor } from '../utils/toolExecutor';
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { maskRustSource, offsetToPosition } from './RustSourceScanner';
+import { readRustSource } from './rustPaths';
+import {
+  BuildScriptFinding,
+  BuildScriptRiskKind,
+  CompileTimeCodeReport,
+  CompileTimeCrate,
+} from '../types/rust';
+
+// Subset of `cargo metadata --format-version 1` including dependencies
+interface CargoMetadata {
+  packages: Array<{
+    id: string;
+    name: string;
+    version: string;
+    source: string | null; // null for path and workspace packages
+    manifest_path: string;
+    targets: Array<{ kind: string[]; src_path: string }>;
+  }>;
+  workspace_members: string[];
+}
+
+// The resolved graph of large workspaces easily exceeds the default 10MB
+const MAX_METADATA_SIZE = 100 * 1024 * 1024;
+
+// Set by Cargo for every build script in addition to CARGO_*
+const CARGO_BUILD_ENV = new Set([
+  'OUT_DIR',
+  'TARGET',
+  'HOST',
+  'NUM_JOBS',
+  'OPT_LEVEL',
+  'DEBUG',
+  'PROFILE',
+  'RUSTC',
+  'RUSTDOC',
+  'RUSTC_LINKER',
+  'RUSTC_WRAPPER',
+  'RUSTC_WORKSPACE_WRAPPER',
+]);
+
+const RISK_PATTERNS: Array<{ kind: BuildScriptRiskKind; regex: RegExp }> = [
+  {
+    kind: 'network',
+    regex: /\b(?:std::)?net::(?:TcpStream|TcpListener|UdpSocket)\b/g,
+  },
+  { kind: 'network', regex: /\b(?:TcpStream|UdpSocket)::(?:connect|bind)\b/g },
+  {
+    kind: 'network',
+    regex: /\b(?:reqwest|ureq|curl|hyper|attohttpc|minreq|isahc)::\w+/g,
+  },
+  { kind: 'process', regex: /\b(?:std::)?process::Command\b/g },
+  { kind: 'process', regex: /\bCommand::new\b/g },
+  { kind: 'process', regex: /\b(?:duct|xshell)::\w+/g },
+  // Reading the whole environment can't be attributed to single variables
+  { kind: 'env', regex: /\benv::vars(?:_os)?\s*\(/g },
+];
+
+// env::var("X"), env::var_os("X"), env!("X") and option_env!("X")
+const ENV_READ = /\b(?:env::var(?:_os)?\s*\(|(?:option_)?env!\s*\()/g;
+const ENV_NAME_ARGUMENT = /^\s*&?\s*(?:format!\s*\(\s*)?"([^"\\]*)"/;
+
+// `mod name;` (inline `mod name { ... }` needs no file)
+const MOD_DECLARATION = /\bmod\s+([A-Za-z_]\w*)\s*;/g;
+const PATH_ATTRIBUTE =
+  /#\[\s*path\s*=\s*"([^"\\]*)"\s*\]\s*(?:pub(?:\s*\([^)]*\))?\s+)?$/;
+
+function isCargoProvidedEnv(name: string): boolean {
+  // DEP_<links>_<key>: metadata from build scripts of `links` dependencies
+  return (
+    name.startsWith('CARGO_') ||
+    name.startsWith('DEP_') ||
+    CARGO_BUILD_ENV.has(name)
+  );
+}
+
+/**
+ * Out-of-line module declarations of a source file, with the file named by
+ * a `#[path]` attribute if there is one
+ */
+export function findModuleDeclarations(
+  content: string
+): Array<{ name: string; path?: string }> {
+  const masked = maskRustSource(content);
+  return [...masked.matchAll(MOD_DECLARATION)].map(match => {
+    // Masking blanks literals, so read the path from the original
+    const attribute = content.slice(0, match.index).match(PATH_ATTRIBUTE);
+    return { name: match[1], path: attribute?.[1] };
+  });
+}
+
+function packageSource(source: string | null): CompileTimeCrate['source'] {
+  if (!source) return 'path';
+  return source.startsWith('git+') ? 'git' : 'registry';
+}
+
+/**
+ * Find network access, spawned processes and reads of environment variables
+ * Cargo does not set (comments and string literals are ignored). At most
+ * one finding per kind and line is reported.
+ */
+export function scanBuildScript(
+  content: string,
+  file: string
+): BuildScriptFinding[] {
+  const masked = maskRustSource(content);
+  const findings = new Map<string, BuildScriptFinding>();
+  const add = (kind: BuildScriptRiskKind, offset: number, detail: string) => {
+    const { line } = offsetToPosition(content, offset);
+    const key = `${kind}:${line}`;
+    if (!findings.has(key)) {
+      findings.set(key, { kind, file, line, detail });
+    }
+  };
+
+  for (const { kind, regex } of RISK_PATTERNS) {
+    for (const match of masked.matchAll(regex)) {
+      add(kind, match.index || 0, match[0].replace(/\s*\($/, '()'));
+    }
+  }
+
+  for (const match of masked.matchAll(ENV_READ)) {
+    const offset = match.index || 0;
+    // Masking blanks literals, so read the variable name from the original
+    const argument = content
+      .slice(offset + match[0].length)
+      .match(ENV_NAME_ARGUMENT);
+    const call = match[0].replace(/\s*\($/, '');
+    if (!argument) {
+      add('env', offset, `${call}(<dynamisch>)`);
+    } else if (!isCargoProvidedEnv(argument[1])) {
+      add('env', offset, `${call}("${argument[1]}")`);
+    }
+  }
+
+  return [...findings.values()].sort((a, b) => a.line - b.line);
+}
+
+/**
+ * Packages with a build script or proc-macro target from cargo metadata
+ * @returns null if the output is not valid cargo metadata
+ */
+export function findCompileTimeCrates(json: string): CompileTimeCrate[] | null {
+  let metadata: CargoMetadata | null = null;
+  try {
+    metadata = safeJsonParse<CargoMetadata>(json, {
+      maxSize: MAX_METADATA_SIZE,
+    });
+  } catch {
+    return null;
+  }
+  if (!metadata || !Array.isArray(metadata.packages)) {
+    return null;
+  }
+
+  const members = new Set(metadata.workspace_members || []);
+  const crates: CompileTimeCrate[] = [];
+  for (const pkg of metadata.packages) {
+    const targets = pkg.targets || [];
+    const buildScript = targets.find(target =>
+      target.kind.includes('custom-build')
+    );
+    const procMacro = targets.some(target =>
+      target.kind.includes('proc-macro')
+    );
+    if (!buildScript && !procMacro) {
+      continue;
+    }
+
+    crates.push({
+      name: pkg.name,
+      version: pkg.version,
+      source: members.has(pkg.id) ? 'workspace' : packageSource(pkg.source),
+      manifestPath: pkg.manifest_path,
+      buildScript: buildScript?.src_path,
+      procMacro,
+      findings: [],
+    });
+  }
+
+  return crates.sort(
+    (a, b) => a.name.localeCompare(b.name) || a.version.localeCompare(b.version)
+  );
+}
+
+/**
+ * Lists the packages of a Cargo project - workspace crates and all resolved
+ * dependencies - that run code at compile time, and statically scans their
+ * build scripts in the locally fetched or vendored sources. Proc-macros are
+ * only listed: their whole crate is compile-time code.
+ */
+export class BuildScriptAnalyzer {
+  /**
+   * @returns null if the project is not a Cargo project
+   */
+  async analyze(projectPath: string): Promise<CompileTimeCodeReport | null> {
+    const manifestPath = path.join(path.resolve(projectPath), 'Cargo.toml');
+    if (!(await fs.pathExists(manifestPath))) {
+      return null;
+    }
+
+    let crates: CompileTimeCrate[] | null;
+    try {
+      const { stdout, stderr, exitCode } =
+        await ToolExecutor.runCargoMetadataWithDependencies(manifestPath);
+      if (exitCode !== 0) {
+        return {
+          crates: [],
+          error: stderr || `cargo metadata exited with code ${exitCode}`,
+        };
+      }
+      crates = findCompileTimeCrates(stdout);
+    } catch (error) {
+      return {
+        crates: [],
+        error: error instanceof Error ? error.message : String(error),
+      };
+    }
+    if (!crates) {
+      return { crates: [], error: 'Invalid cargo metadata output' };
+    }
+
+    for (const crate of crates) {
+      if (crate.buildScript) {
+        crate.findings = await this.scanBuildScriptSources(crate.buildScript);
+      }
+    }
+
+    return { crates };
+  }
+
+  /**
+   * Scan the build script and the modules it declares (e.g. build/main.rs
+   * with `mod find;`), but no other files next to it
+   */
+  private async scanBuildScriptSources(
+    buildScript: string
+  ): Promise<BuildScriptFinding[]> {
+    const findings: BuildScriptFinding[] = [];
+    const seen = new Set<string>();
+    // moduleDir: where `mod x;` in this file looks for x.rs and x/mod.rs
+    const queue = [{ file: buildScript, moduleDir: path.dirname(buildScript) }];
+
+    while (queue.length > 0) {
+      const { file, moduleDir } = queue.shift()!;
+      if (seen.has(file)) continue;
+      seen.add(file);
+
+      // null for generated scripts and dependencies that were never fetched
+      const content = await readRustSource(file);
+      if (content === null) {
+        continue;
+      }
+      findings.push(...scanBuildScript(content, file));
+
+      for (const module of findModuleDeclarations(content)) {
+        const candidates = module.path
+          ? [path.resolve(path.dirname(file), module.path)]
+          : [
+              path.join(moduleDir, `${module.name}.rs`),
+              path.join(moduleDir, module.name, 'mod.rs'),
+            ];
+        for (const candidate of candidates) {
+          if (await fs.pathExists(candidate)) {
+            queue.push({
+              file: candidate,
+              moduleDir:
+                module.path || path.basename(candidate) === 'mod.rs'
+                  ? path.dirname(candidate)
+                  : path.join(moduleDir, module.name),
+            });
+            break;
+          }
+        }
+      }
+    }
+    return findings;
+  }
+}
@@ -0,0 +1,234 @@
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { SecurityFinding, SecurityScanResult } from '../types/security';
+import {
+  CargoAuditReport,
+  CargoAuditVersions,
+  CargoAuditWarning,
+  RustSecAdvisory,
+} from '../types/rust';
+
+type Severity = SecurityFinding['severity'];
+
+// CVSS v3.x base metric weights (FIRST specification, section 7.4)
+const CVSS_WEIGHTS: Record<string, Record<string, number>> = {
+  AV: { N: 0.85, A: 0.62, L: 0.55, P: 0.2 },
+  AC: { L: 0.77, H: 0.44 },
+  UI: { N: 0.85, R: 0.62 },
+  CIA: { H: 0.56, L: 0.22, N: 0 },
+};
+const CVSS_PRIVILEGES: Record<string, { U: number; C: number }> = {
+  N: { U: 0.85, C: 0.85 },
+  L: { U: 0.62, C: 0.68 },
+  H: { U: 0.27, C: 0.5 },
+};
+
+// Severity of cargo-audit warnings without their own CVSS score
+const WARNING_SEVERITY: Record<string, Severity> = {
+  unsound: 'medium',
+  unmaintained: 'low',
+  yanked: 'low',
+  notice: 'info',
+};
+
+/**
+ * Maps `cargo audit --json` reports onto WOARU security findings, one per
+ * RustSec advisory (vulnerabilities) or warning (unmaintained, unsound,
+ * yanked crates).
+ */
+export class CargoAuditParser {
+  /**
+   * Parse the cargo-audit JSON report
+   * @param output - Raw stdout of `cargo audit --json`
+   * @returns null if the output is not a cargo-audit report
+   */
+  static parse(output: string): SecurityScanResult | null {
+    const trimmed = output.trim();
+    if (!trimmed.startsWith('{')) {
+      return null; // e.g. cargo error text when cargo-audit is missing
+    }
+
+    const report = safeJsonParse<CargoAuditReport>(trimmed);
+    if (!report || !report.vulnerabilities) {
+      return null;
+    }
+
+    const findings: SecurityFinding[] = (
+      report.vulnerabilities.list || []
+    ).map(vulnerability =>
+      this.toFinding(
+        vulnerability.advisory,
+        vulnerability.package.name,
+        vulnerability.package.version,
+        vulnerability.versions,
+        this.severityFromCvss(vulnerability.advisory.cvss) ?? 'high'
+      )
+    );
+
+    for (const [kind, warnings] of Object.entries(report.warnings || {})) {
+      for (const warning of warnings) {
+        findings.push(this.warningToFinding(kind, warning));
+      }
+    }
+
+    return this.createResult(findings);
+  }
+
+  /**
+   * Build a scan result with the severity summary expected by the reports
+   */
+  static createResult(
+    findings: SecurityFinding[],
+    error?: string
+  ): SecurityScanResult {
+    const summary = {
+      total: findings.length,
+      critical: 0,
+      high: 0,
+      medium: 0,
+      low: 0,
+      info: 0,
+    };
+    findings.forEach(finding => summary[finding.severity]++);
+
+    return {
+      tool: 'cargo-audit',
+      scanTime: new Date(),
+      findings,
+      summary,
+      error,
+    };
+  }
+
+  /**
+   * Severity bucket of a CVSS v3 vector, following the NVD rating scale
+   * @returns null for missing or unsupported (e.g. CVSS v4) vectors
+   */
+  static severityFromCvss(vector?: string | null): Severity | null {
+    const score = vector ? this.cvssBaseScore(vector) : null;
+    if (score === null) return null;
+    if (score >= 9) return 'critical';
+    if (score >= 7) return 'high';
+    if (score >= 4) return 'medium';
+    return score > 0 ? 'low' : 'info';
+  }
+
+  /**
+   * Base score of a `CVSS:3.x/AV:N/AC:L/...` vector
+   */
+  static cvssBaseScore(vector: string): number | null {
+    const parts = vector.split('/');
+    if (!/^CVSS:3\.[01]$/.test(parts[0])) {
+      return null;
+    }
+
+    const metrics: Record<string, string> = {};
+    for (const part of parts.slice(1)) {
+      const [key, value] = part.split(':');
+      metrics[key] = value;
+    }
+
+    const scope = metrics.S;
+    const av = CVSS_WEIGHTS.AV[metrics.AV];
+    const ac = CVSS_WEIGHTS.AC[metrics.AC];
+    const ui = CVSS_WEIGHTS.UI[metrics.UI];
+    const pr = CVSS_PRIVILEGES[metrics.PR]?.[scope as 'U' | 'C'];
+    const [c, i, a] = ['C', 'I', 'A'].map(m => CVSS_WEIGHTS.CIA[metrics[m]]);
+    if ([av, ac, ui, pr, c, i, a].some(weight => weight === undefined)) {
+      return null;
+    }
+
+    const iss = 1 - (1 - c) * (1 - i) * (1 - a);
+    const impact =
+      scope === 'U'
+        ? 6.42 * iss
+        : 7.52 * (iss - 0.029) - 3.25 * Math.pow(iss - 0.02, 15);
+    if (impact <= 0) {
+      return 0;
+    }
+
+    const exploitability = 8.22 * av * ac * pr * ui;
+    const base =
+      scope === 'U'
+        ? impact + exploitability
+        : 1.08 * (impact + exploitability);
+    return roundUp(Math.min(base, 10));
+  }
+
+  private static warningToFinding(
+    kind: string,
+    warning: CargoAuditWarning
+  ): SecurityFinding {
+    const { package: cratePackage, advisory } = warning;
+    const severity =
+      this.severityFromCvss(advisory?.cvss) ??
+      WARNING_SEVERITY[warning.kind || kind] ??
+      'low';
+
+    if (advisory) {
+      return this.toFinding(
+        advisory,
+        cratePackage.name,
+        cratePackage.version,
+        warning.versions,
+        severity
+      );
+    }
+
+    // Yanked crates come without an advisory
+    return {
+      tool: 'cargo-audit',
+      type: 'vulnerability',
+      severity,
+      title: `${cratePackage.name} ${cratePackage.version} is ${warning.kind || kind}`,
+      description: `The locked version of ${cratePackage.name} was ${warning.kind || kind} on crates.io`,
+      package: cratePackage.name,
+      version: cratePackage.version,
+      recommendation: `Run "cargo update -p ${cratePackage.name}" to move to a non-yanked release`,
+    };
+  }
+
+  private static toFinding(
+    advisory: RustSecAdvisory,
+    crateName: string,
+    version: string,
+    versions: CargoAuditVersions | null | undefined,
+    severity: Severity
+  ): SecurityFinding {
+    const patched = versions?.patched || [];
+    const cve = advisory.aliases?.find(alias => alias.startsWith('CVE-'));
+    const references = [
+      `https://rustsec.org/advisories/${advisory.id}`,
+      ...(advisory.url ? [advisory.url] : []),
+      ...(advisory.references || []),
+    ];
+
+    return {
+      tool: 'cargo-audit',
+      type: 'vulnerability',
+      severity,
+      title: `${advisory.id}: ${advisory.title}`,
+      description: advisory.description,
+      package: crateName,
+      version,
+      advisoryId: advisory.id,
+      fixedIn: patched.length > 0 ? patched.join(', ') : undefined,
+      cve,
+      recommendation:
+        patched.length > 0
+          ? `Upgrade ${crateName} to ${patched.join(' or ')} (cargo update -p ${crateName})`
+          : `No patched release of ${crateName} available; consider replacing the crate`,
+      references: [...new Set(references)],
+    };
+  }
+}
+
+/**
+ * CVSS v3.1 "Roundup": smallest number with one decimal >= value, computed
+ * on integers to avoid floating point artifacts
+ */
+function roundUp(value: number): number {
+  const scaled = Math.round(value * 100000);
+  return scaled % 10000 === 0
+    ? scaled / 100000
+    : (Math.floor(scaled / 10000) + 1) / 10;
+}
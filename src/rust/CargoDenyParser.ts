@@ -0,0 +1,102 @@
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { CargoDenyCheck, CargoDenyDiagnostic } from '../types/rust';
+
+interface CargoDenyLabel {
+  message?: string;
+  span?: string;
+}
+
+interface CargoDenyGraph {
+  Krate?: { name: string; version: string };
+}
+
+interface CargoDenyLine {
+  type: string; // diagnostic, summary or log
+  fields?: {
+    code?: string;
+    severity?: string;
+    level?: string;
+    message?: string;
+    labels?: CargoDenyLabel[];
+    notes?: string[];
+    graphs?: CargoDenyGraph[];
+  };
+}
+
+const SEVERITIES: CargoDenyDiagnostic['severity'][] = [
+  'error',
+  'warning',
+  'note',
+  'help',
+];
+
+export const CARGO_DENY_CHECKS: CargoDenyCheck[] = [
+  'advisories',
+  'bans',
+  'licenses',
+  'sources',
+];
+
+/**
+ * Parses the JSON-lines output of `cargo deny --format json check <check>`.
+ * cargo-deny writes its diagnostics to stderr, so callers pass both streams.
+ */
+export class CargoDenyParser {
+  /**
+   * @param output - Combined stdout/stderr of a single-check run
+   * @param check - The check the run was restricted to
+   */
+  static parse(output: string, check: CargoDenyCheck): CargoDenyDiagnostic[] {
+    const diagnostics: CargoDenyDiagnostic[] = [];
+
+    for (const rawLine of output.split('\n')) {
+      const line = rawLine.trim();
+      if (!line.startsWith('{')) continue;
+
+      const parsed = safeJsonParse<CargoDenyLine>(line);
+      if (parsed?.type !== 'diagnostic' || !parsed.fields) continue;
+
+      const { fields } = parsed;
+      const severity = SEVERITIES.find(s => s === fields.severity) ?? 'error';
+      const krate = fields.graphs?.find(graph => graph.Krate)?.Krate;
+
+      diagnostics.push({
+        check,
+        code: fields.code || 'unknown',
+        severity,
+        message: fields.message || '',
+        crate: krate?.name,
+        version: krate?.version,
+        labels: (fields.labels || [])
+          .map(label =>
+            [label.message, label.span].filter(Boolean).join(': ')
+          )
+          .filter(Boolean),
+        notes: fields.notes || [],
+      });
+    }
+
+    return diagnostics;
+  }
+
+  /**
+   * Error-level `log` lines, e.g. a broken deny.toml or missing advisory-db
+   */
+  static parseErrors(output: string): string[] {
+    const errors: string[] = [];
+    for (const rawLine of output.split('\n')) {
+      const line = rawLine.trim();
+      if (!line.startsWith('{')) continue;
+
+      const parsed = safeJsonParse<CargoDenyLine>(line);
+      if (
+        parsed?.type === 'log' &&
+        parsed.fields?.level?.toLowerCase() === 'error' &&
+        parsed.fields.message
+      ) {
+        errors.push(parsed.fields.message);
+      }
+    }
+    return errors;
+  }
+}
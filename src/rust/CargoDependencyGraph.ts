@@ -0,0 +1,88 @@
+import { safeJsonParse } from '../utils/safeJsonParser';
+
+// Subset of `cargo metadata --format-version 1 --no-deps`
+interface CargoMetadata {
+  packages: Array<{
+    name: string;
+    dependencies: Array<{
+      name: string; // Package name, also for renamed dependencies
+      kind: 'dev' | 'build' | null;
+      path?: string; // Set for path (and thus workspace) dependencies
+    }>;
+  }>;
+}
+
+/**
+ * Dependencies between the packages of a Cargo workspace, used to find the
+ * crates that have to be re-checked after one of them changed
+ */
+export class CargoDependencyGraph {
+  // package -> workspace packages depending on it
+  private dependents = new Map<string, Set<string>>();
+
+  constructor(edges: Array<[dependent: string, dependency: string]>) {
+    for (const [dependent, dependency] of edges) {
+      const set = this.dependents.get(dependency) || new Set<string>();
+      set.add(dependent);
+      this.dependents.set(dependency, set);
+    }
+  }
+
+  /**
+   * @returns null if the output is not valid cargo metadata
+   */
+  static fromMetadata(json: string): CargoDependencyGraph | null {
+    const metadata = safeJsonParse<CargoMetadata>(json);
+    if (!metadata || !Array.isArray(metadata.packages)) {
+      return null;
+    }
+
+    const members = new Set(metadata.packages.map(pkg => pkg.name));
+    const edges: Array<[string, string]> = [];
+    for (const pkg of metadata.packages) {
+      for (const dependency of pkg.dependencies || []) {
+        // Dev-dependencies only affect test targets, which clippy skips
+        if (
+          dependency.kind !== 'dev' &&
+          dependency.path &&
+          members.has(dependency.name)
+        ) {
+          edges.push([pkg.name, dependency.name]);
+        }
+      }
+    }
+
+    return new CargoDependencyGraph(edges);
+  }
+
+  /**
+   * Workspace packages depending on a package, directly or transitively
+   */
+  reverseDependencies(packageName: string): string[] {
+    const found = new Set<string>();
+    const queue = [packageName];
+    while (queue.length > 0) {
+      const current = queue.shift() as string;
+      for (const dependent of this.dependents.get(current) || []) {
+        if (dependent !== packageName && !found.has(dependent)) {
+          found.add(dependent);
+          queue.push(dependent);
+        }
+      }
+    }
+    return [...found];
+  }
+
+  /**
+   * The given packages followed by everything depending on them
+   */
+  withReverseDependencies(packageNames: string[]): string[] {
+    const result = new Set(packageNames);
+    for (const name of packageNames) {
+      this.reverseDependencies(name).forEach(dependent =>
+        result.add(dependent)
+      );
+    }
+    return [...result];
+  }
+}
@@ -0,0 +1,135 @@
+import fs from 'fs-extra';
+import * as semver from 'semver';
+import { isTomlTable, parseToml } from '../utils/tomlParser';
+import { CargoLockPackage, DuplicateCrate } from '../types/rust';
+
+/**
+ * Parse the `[[package]]` entries of a Cargo.lock (format v1 to v4)
+ * @throws TomlParseError for malformed lockfiles
+ */
+export function parseCargoLock(content: string): CargoLockPackage[] {
+  const lock = parseToml(content);
+  const entries = Array.isArray(lock.package) ? lock.package : [];
+
+  return entries.filter(isTomlTable).map(entry => ({
+    name: String(entry.name),
+    version: String(entry.version),
+    source: typeof entry.source === 'string' ? entry.source : undefined,
+    checksum: typeof entry.checksum === 'string' ? entry.checksum : undefined,
+    dependencies: Array.isArray(entry.dependencies)
+      ? entry.dependencies.filter((d): d is string => typeof d === 'string')
+      : [],
+  }));
+}
+
+/**
+ * Read and parse a Cargo.lock
+ * @returns null if the lockfile is missing or unreadable
+ */
+export async function readCargoLock(
+  lockfilePath: string
+): Promise<CargoLockPackage[] | null> {
+  try {
+    return parseCargoLock(await fs.readFile(lockfilePath, 'utf-8'));
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Whether a locked package comes from a crates.io-style registry
+ */
+export function isRegistryPackage(pkg: CargoLockPackage): boolean {
+  return (
+    pkg.source?.startsWith('registry+') === true ||
+    pkg.source?.startsWith('sparse+') === true
+  );
+}
+
+/**
+ * Versions Cargo may unify share a key: 1.2.0 and 1.4.1 are compatible,
+ * 0.1.0 and 0.2.0 (and 0.0.1 and 0.0.2) are not
+ */
+export function semverCompatibilityKey(version: string): string {
+  const [major = '0', minor = '0', patch = '0'] = version
+    .replace(/[-+].*$/, '')
+    .split('.');
+  if (major !== '0') return major;
+  return minor !== '0' ? `0.${minor}` : `0.0.${patch}`;
+}
+
+/**
+ * Find crates locked in several semver-incompatible versions, with the
+ * shortest dependency chains from a workspace crate to each version
+ * (one chain per direct dependent, at most `maxPaths`)
+ */
+export function findDuplicateVersions(
+  packages: CargoLockPackage[],
+  maxPaths = 3
+): DuplicateCrate[] {
+  const id = (pkg: CargoLockPackage) => `${pkg.name}@${pkg.version}`;
+  const byName = new Map<string, CargoLockPackage[]>();
+  for (const pkg of packages) {
+    byName.set(pkg.name, [...(byName.get(pkg.name) || []), pkg]);
+  }
+
+  // References are "name", "name version" or "name version (source)"
+  const resolve = (reference: string) => {
+    const [name, version] = reference.split(' ');
+    const candidates = byName.get(name) || [];
+    return version
+      ? candidates.find(candidate => candidate.version === version)
+      : candidates[0];
+  };
+
+  const dependents = new Map<string, CargoLockPackage[]>();
+  for (const pkg of packages) {
+    for (const reference of pkg.dependencies) {
+      const dependency = resolve(reference);
+      if (dependency) {
+        const key = id(dependency);
+        dependents.set(key, [...(dependents.get(key) || []), pkg]);
+      }
+    }
+  }
+
+  // Breadth-first from the workspace crates yields the shortest chains
+  const chains = new Map<string, string[]>();
+  const queue = packages.filter(pkg => !pkg.source);
+  queue.forEach(pkg => chains.set(id(pkg), [id(pkg)]));
+  for (let i = 0; i < queue.length; i++) {
+    const chain = chains.get(id(queue[i])) as string[];
+    for (const reference of queue[i].dependencies) {
+      const dependency = resolve(reference);
+      if (dependency && !chains.has(id(dependency))) {
+        chains.set(id(dependency), [...chain, id(dependency)]);
+        queue.push(dependency);
+      }
+    }
+  }
+
+  const duplicates: DuplicateCrate[] = [];
+  for (const [name, versions] of byName) {
+    const keys = new Set(
+      versions.map(pkg => semverCompatibilityKey(pkg.version))
+    );
+    if (keys.size < 2) continue;
+
+    duplicates.push({
+      name,
+      versions: [...versions]
+        .sort((a, b) => semver.compare(a.version, b.version))
+        .map(pkg => ({
+          version: pkg.version,
+          paths: (dependents.get(id(pkg)) || [])
+            .map(dependent => chains.get(id(dependent)))
+            .filter((chain): chain is string[] => chain !== undefined)
+            .sort((a, b) => a.length - b.length)
+            .slice(0, maxPaths)
+            .map(chain => [...chain, id(pkg)]),
+        })),
+    });
+  }
+
+  return duplicates.sort((a, b) => a.name.localeCompare(b.name));
+}
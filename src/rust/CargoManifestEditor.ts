@@ -0,0 +1,90 @@
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
+
+/**
+ * Lint levels written by `woaru setup` (kept to warnings so an existing
+ * build never starts failing just because the table was added)
+ */
+export const DEFAULT_RUST_LINTS: Record<'rust' | 'clippy', string[]> = {
+  rust: ['unsafe_code = "warn"'],
+  clippy: [
+    'all = { level = "warn", priority = -1 }',
+    'dbg_macro = "warn"',
+    'todo = "warn"',
+  ],
+};
+
+// [lints] was stabilized in Cargo 1.74
+const LINTS_MIN_RUST_VERSION: [number, number] = [1, 74];
+
+/**
+ * Whether the manifest already configures lints (for a workspace root this
+ * includes [workspace.lints])
+ */
+export function hasLintsTable(manifest: TomlTable): boolean {
+  return (
+    manifest.lints !== undefined ||
+    (isTomlTable(manifest.workspace) && manifest.workspace.lints !== undefined)
+  );
+}
+
+/**
+ * Whether the package's declared rust-version (MSRV) understands [lints];
+ * packages without a rust-version are assumed to use a current toolchain
+ */
+export function supportsLintsTable(manifest: TomlTable): boolean {
+  const rustVersion = getRustVersion(manifest);
+  if (!rustVersion) return true;
+
+  const [major = 0, minor = 0] = rustVersion.split('.').map(Number);
+  const [minMajor, minMinor] = LINTS_MIN_RUST_VERSION;
+  return major > minMajor || (major === minMajor && minor >= minMinor);
+}
+
+export function getRustVersion(manifest: TomlTable): string | undefined {
+  const pkg = isTomlTable(manifest.package) ? manifest.package : {};
+  const workspacePackage =
+    isTomlTable(manifest.workspace) && isTomlTable(manifest.workspace.package)
+      ? manifest.workspace.package
+      : {};
+  const version =
+    typeof pkg['rust-version'] === 'string'
+      ? pkg['rust-version']
+      : workspacePackage['rust-version'];
+  return typeof version === 'string' ? version : undefined;
+}
+
+/**
+ * Lint tables for a package (`[lints.*]`) or a workspace root
+ * (`[workspace.lints.*]`)
+ */
+export function renderLintTables(
+  scope: 'package' | 'workspace',
+  lints: Record<'rust' | 'clippy', string[]> = DEFAULT_RUST_LINTS
+): string {
+  const prefix = scope === 'workspace' ? 'workspace.lints' : 'lints';
+  return (['rust', 'clippy'] as const)
+    .map(tool => [`[${prefix}.${tool}]`, ...lints[tool]].join('\n'))
+    .join('\n\n');
+}
+
+/**
+ * Opt a workspace member into the workspace lint configuration
+ */
+export const INHERIT_WORKSPACE_LINTS = '[lints]\nworkspace = true';
+
+/**
+ * Append TOML tables to a manifest without touching the existing text, so
+ * comments, ordering and formatting are preserved. Line endings follow the
+ * file's existing convention.
+ */
+export function appendTomlTables(content: string, tables: string): string {
+  const eol = content.includes('\r\n') ? '\r\n' : '\n';
+  let result = content;
+
+  if (result.length > 0) {
+    if (!result.endsWith('\n')) result += eol;
+    if (!/\n\s*\n$/.test(result)) result += eol;
+  }
+
+  return result + tables.trimEnd().split('\n').join(eol) + eol;
+}
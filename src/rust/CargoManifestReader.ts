@@ -0,0 +1,353 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { isTomlTable, TomlTable, TomlValue } from '../utils/tomlParser';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import {
+  CargoDependency,
+  CargoDependencyKind,
+  CargoManifestSummary,
+} from '../types/rust';
+
+const MANIFEST_FILE = 'Cargo.toml';
+
+const DEPENDENCY_TABLES: Array<[string, CargoDependencyKind]> = [
+  ['dependencies', 'normal'],
+  ['dev-dependencies', 'dev'],
+  ['build-dependencies', 'build'],
+];
+
+/**
+ * Crates that identify a framework or runtime, mapped to the framework id
+ * used in `ProjectAnalysis.framework`
+ */
+export const RUST_FRAMEWORK_CRATES: ReadonlyMap<string, string> = new Map([
+  ['axum', 'axum'],
+  ['actix-web', 'actix'],
+  ['rocket', 'rocket'],
+  ['warp', 'warp'],
+  ['poem', 'poem'],
+  ['tonic', 'tonic'],
+  ['tokio', 'tokio'],
+  ['async-std', 'async-std'],
+  ['smol', 'smol'],
+  ['bevy', 'bevy'],
+  ['tauri', 'tauri'],
+  ['leptos', 'leptos'],
+  ['yew', 'yew'],
+  ['dioxus', 'dioxus'],
+  ['diesel', 'diesel'],
+  ['sqlx', 'sqlx'],
+  ['sea-orm', 'sea-orm'],
+  ['serde', 'serde'],
+  ['clap', 'clap'],
+]);
+
+// Frameworks whose presence in a binary means it serves requests
+const RUST_SERVER_FRAMEWORKS = [
+  'axum',
+  'actix',
+  'rocket',
+  'warp',
+  'poem',
+  'tonic',
+];
+
+// Directories never searched for workspace members
+const SKIPPED_DIRS = new Set(['target', 'node_modules', '.git']);
+const MAX_MEMBER_DEPTH = 4;
+
+/**
+ * Reads Cargo manifests of a project (or every member of a workspace) and
+ * returns their dependencies, features and the frameworks they imply.
+ */
+export class CargoManifestReader {
+  constructor(
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {}
+
+  /**
+   * Summarize the Cargo project rooted at `projectPath`
+   * @returns null if there is no readable Cargo.toml
+   */
+  async readProject(
+    projectPath: string
+  ): Promise<CargoManifestSummary | null> {
+    const manifestPath = path.join(path.resolve(projectPath), MANIFEST_FILE);
+    const manifest = await this.resolver.loadManifest(manifestPath);
+    if (!manifest) {
+      return null;
+    }
+
+    const workspaceManifestPath = await this.findWorkspaceManifest(
+      manifestPath,
+      manifest
+    );
+    const workspaceManifest =
+      workspaceManifestPath === manifestPath
+        ? manifest
+        : await this.resolver.loadManifest(workspaceManifestPath);
+    const workspaceTable: TomlTable =
+      workspaceManifest && isTomlTable(workspaceManifest.workspace)
+        ? workspaceManifest.workspace
+        : {};
+    const workspaceDependencies: TomlTable = isTomlTable(
+      workspaceTable.dependencies
+    )
+      ? workspaceTable.dependencies
+      : {};
+
+    const packageManifests = await this.collectPackageManifests(
+      manifestPath,
+      manifest
+    );
+
+    const summary: CargoManifestSummary = {
+      manifestPath,
+      workspaceManifestPath,
+      packages: [],
+      dependencies: [],
+      features: {},
+      frameworks: [],
+      binaries: [],
+    };
+
+    for (const [packagePath, packageManifest] of packageManifests) {
+      const packageTable = packageManifest.package as TomlTable;
+      summary.packages.push(String(packageTable.name));
+      if (await this.hasBinaryTarget(packagePath, packageManifest)) {
+        summary.binaries.push(String(packageTable.name));
+      }
+      summary.dependencies.push(
+        ...this.collectDependencies(
+          packageManifest,
+          packagePath,
+          workspaceDependencies
+        )
+      );
+      if (isTomlTable(packageManifest.features)) {
+        const features: Record<string, string[]> = {};
+        for (const [feature, enables] of Object.entries(
+          packageManifest.features
+        )) {
+          features[feature] = toStringArray(enables);
+        }
+        summary.features[String(packageTable.name)] = features;
+      }
+    }
+
+    summary.frameworks = detectRustFrameworks(summary.dependencies);
+    return summary;
+  }
+
+  /**
+   * `[[bin]]` targets or the auto-discovered src/main.rs and src/bin/
+   */
+  private async hasBinaryTarget(
+    manifestPath: string,
+    manifest: TomlTable
+  ): Promise<boolean> {
+    if (Array.isArray(manifest.bin) && manifest.bin.length > 0) {
+      return true;
+    }
+    const packageTable = manifest.package as TomlTable;
+    if (packageTable.autobins === false) {
+      return false;
+    }
+    const srcDir = path.join(path.dirname(manifestPath), 'src');
+    return (
+      (await fs.pathExists(path.join(srcDir, 'main.rs'))) ||
+      (await fs.pathExists(path.join(srcDir, 'bin')))
+    );
+  }
+
+  /**
+   * Extract dependencies of all kinds, including target-specific tables
+   */
+  collectDependencies(
+    manifest: TomlTable,
+    manifestPath: string,
+    workspaceDependencies: TomlTable = {}
+  ): CargoDependency[] {
+    const dependencies: CargoDependency[] = [];
+    const sections: Array<{ table: TomlTable; target?: string }> = [
+      { table: manifest },
+    ];
+    if (isTomlTable(manifest.target)) {
+      for (const [target, table] of Object.entries(manifest.target)) {
+        if (isTomlTable(table)) sections.push({ table, target });
+      }
+    }
+
+    for (const { table, target } of sections) {
+      for (const [tableName, kind] of DEPENDENCY_TABLES) {
+        const entries = table[tableName];
+        if (!isTomlTable(entries)) continue;
+
+        for (const [key, spec] of Object.entries(entries)) {
+          dependencies.push({
+            ...this.toDependency(key, spec, workspaceDependencies),
+            kind,
+            target,
+            manifestPath,
+          });
+        }
+      }
+    }
+
+    return dependencies;
+  }
+
+  private toDependency(
+    key: string,
+    spec: TomlValue,
+    workspaceDependencies: TomlTable
+  ): Omit<CargoDependency, 'kind' | 'target' | 'manifestPath'> {
+    const local = normalizeSpec(spec);
+    const inherited = local.workspace === true;
+    const base: TomlTable = inherited
+      ? normalizeSpec(workspaceDependencies[key])
+      : {};
+
+    // Features are additive: the member may enable more than the workspace
+    const features = [
+      ...new Set([
+        ...toStringArray(base.features),
+        ...toStringArray(local.features),
+      ]),
+    ];
+    const crateName = local.package ?? base.package;
+    const version = local.version ?? base.version;
+
+    return {
+      name: typeof crateName === 'string' ? crateName : key,
+      alias: typeof crateName === 'string' ? key : undefined,
+      version: typeof version === 'string' ? version : undefined,
+      features,
+      optional: local.optional === true,
+      workspaceInherited: inherited,
+    };
+  }
+
+  /**
+   * The manifest itself if it declares [workspace], otherwise the workspace
+   * the package belongs to (or the manifest for standalone packages)
+   */
+  private async findWorkspaceManifest(
+    manifestPath: string,
+    manifest: TomlTable
+  ): Promise<string> {
+    if (isTomlTable(manifest.workspace) || !isTomlTable(manifest.package)) {
+      return manifestPath;
+    }
+    const cargoPackage = await this.resolver.resolveForFile(manifestPath);
+    return cargoPackage?.workspaceManifestPath || manifestPath;
+  }
+
+  /**
+   * The root package plus, for workspace roots, every member package
+   */
+  async collectPackageManifests(
+    manifestPath: string,
+    manifest: TomlTable
+  ): Promise<Array<[string, TomlTable]>> {
+    const packages: Array<[string, TomlTable]> = [];
+    if (isPackageManifest(manifest)) {
+      packages.push([manifestPath, manifest]);
+    }
+
+    if (isTomlTable(manifest.workspace)) {
+      const rootDir = path.dirname(manifestPath);
+      const workspace = manifest.workspace;
+      for (const memberDir of await this.findManifestDirs(rootDir)) {
+        const relative = path.relative(rootDir, memberDir).split(path.sep);
+        if (!this.resolver.isWorkspaceMember(workspace, relative.join('/'))) {
+          continue;
+        }
+        const memberPath = path.join(memberDir, MANIFEST_FILE);
+        const member = await this.resolver.loadManifest(memberPath);
+        if (member && isPackageManifest(member)) {
+          packages.push([memberPath, member]);
+        }
+      }
+    }
+
+    return packages;
+  }
+
+  /**
+   * Directories below `rootDir` (excluding itself) containing a Cargo.toml
+   */
+  private async findManifestDirs(
+    rootDir: string,
+    depth = 0
+  ): Promise<string[]> {
+    if (depth >= MAX_MEMBER_DEPTH) {
+      return [];
+    }
+
+    const found: string[] = [];
+    const entries = await fs
+      .readdir(rootDir, { withFileTypes: true })
+      .catch(() => []);
+
+    for (const entry of entries) {
+      if (!entry.isDirectory() || SKIPPED_DIRS.has(entry.name)) continue;
+      const dir = path.join(rootDir, entry.name);
+      if (await fs.pathExists(path.join(dir, MANIFEST_FILE))) {
+        found.push(dir);
+      }
+      found.push(...(await this.findManifestDirs(dir, depth + 1)));
+    }
+
+    return found.sort();
+  }
+}
+
+/**
+ * Map dependency crates to framework ids (normal dependencies only, so that
+ * e.g. a tokio dev-dependency for tests does not mark the project as async)
+ */
+export function detectRustFrameworks(
+  dependencies: CargoDependency[]
+): string[] {
+  const frameworks = dependencies
+    .filter(dep => dep.kind === 'normal')
+    .map(dep => RUST_FRAMEWORK_CRATES.get(dep.name))
+    .filter((framework): framework is string => Boolean(framework));
+  return [...new Set(frameworks)];
+}
+
+/**
+ * Production audit project type of a Cargo project: binaries using a web or
+ * RPC framework are backends, other binaries command line tools and
+ * projects without a binary target libraries
+ */
+export function determineRustProjectType(
+  cargo: CargoManifestSummary
+): 'backend' | 'cli' | 'library' {
+  if (cargo.binaries.length === 0) {
+    return 'library';
+  }
+  return cargo.frameworks.some(framework =>
+    RUST_SERVER_FRAMEWORKS.includes(framework)
+  )
+    ? 'backend'
+    : 'cli';
+}
+
+function isPackageManifest(manifest: TomlTable): boolean {
+  return (
+    isTomlTable(manifest.package) && typeof manifest.package.name === 'string'
+  );
+}
+
+function normalizeSpec(spec: TomlValue | undefined): TomlTable {
+  if (isTomlTable(spec)) return spec;
+  return typeof spec === 'string' ? { version: spec } : {};
+}
+
+function toStringArray(value: TomlValue | undefined): string[] {
+  return Array.isArray(value)
+    ? value.filter((v): v is string => typeof v === 'string')
+    : [];
+}
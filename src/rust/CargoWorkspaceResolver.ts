@@ -0,0 +1,221 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { parseToml, isTomlTable, TomlTable } from '../utils/tomlParser';
+import { CargoPackage } from '../types/rust';
+
+const MANIFEST_FILE = 'Cargo.toml';
+
+/**
+ * Resolves the Cargo package that owns a source file.
+ *
+ * Walks up from the file to the nearest manifest with a `[package]` table,
+ * then finds the workspace it belongs to (honouring `package.workspace`,
+ * `[workspace] members` and `exclude`). Results are cached per file so that
+ * watch mode does not rescan manifests on every save; call `invalidate()`
+ * when a Cargo.toml changes.
+ */
+export class CargoWorkspaceResolver {
+  private fileCache = new Map<string, CargoPackage | null>();
+  private manifestCache = new Map<string, TomlTable | null>();
+
+  /**
+   * Find the package owning the given file
+   * @param filePath - Absolute or cwd-relative path of a file inside a crate
+   * @returns The owning package, or null if the file is not inside one
+   */
+  async resolveForFile(filePath: string): Promise<CargoPackage | null> {
+    const absolutePath = path.resolve(filePath);
+    const cached = this.fileCache.get(absolutePath);
+    if (cached !== undefined) {
+      return cached;
+    }
+
+    const resolved = await this.resolveUncached(absolutePath);
+    this.fileCache.set(absolutePath, resolved);
+    return resolved;
+  }
+
+  /**
+   * Drop cached mappings, e.g. after a Cargo.toml was edited
+   */
+  invalidate(): void {
+    this.fileCache.clear();
+    this.manifestCache.clear();
+  }
+
+  /**
+   * Read and parse a manifest, caching the result (null if missing/invalid)
+   */
+  async loadManifest(manifestPath: string): Promise<TomlTable | null> {
+    const cached = this.manifestCache.get(manifestPath);
+    if (cached !== undefined) {
+      return cached;
+    }
+
+    let manifest: TomlTable | null = null;
+    try {
+      if (await fs.pathExists(manifestPath)) {
+        manifest = parseToml(await fs.readFile(manifestPath, 'utf-8'));
+      }
+    } catch (error) {
+      console.debug(`Failed to parse ${manifestPath}: ${error}`);
+    }
+
+    this.manifestCache.set(manifestPath, manifest);
+    return manifest;
+  }
+
+  private async resolveUncached(
+    absolutePath: string
+  ): Promise<CargoPackage | null> {
+    const packageManifest = await this.findPackageManifest(
+      path.dirname(absolutePath)
+    );
+    if (!packageManifest) {
+      return null;
+    }
+
+    const { manifestPath, manifest } = packageManifest;
+    const packageTable = manifest.package as TomlTable;
+    const rootDir = path.dirname(manifestPath);
+    const workspaceManifestPath =
+      (await this.findWorkspaceManifest(rootDir, manifest)) || manifestPath;
+
+    return {
+      name: String(packageTable.name),
+      version:
+        typeof packageTable.version === 'string'
+          ? packageTable.version
+          : undefined,
+      manifestPath,
+      rootDir,
+      workspaceRoot: path.dirname(workspaceManifestPath),
+      workspaceManifestPath,
+    };
+  }
+
+  /**
+   * Walk up to the nearest manifest that declares a package
+   * (virtual workspace manifests are skipped)
+   */
+  private async findPackageManifest(
+    startDir: string
+  ): Promise<{ manifestPath: string; manifest: TomlTable } | null> {
+    for (const dir of this.ancestors(startDir)) {
+      const manifestPath = path.join(dir, MANIFEST_FILE);
+      const manifest = await this.loadManifest(manifestPath);
+      if (
+        manifest &&
+        isTomlTable(manifest.package) &&
+        typeof manifest.package.name === 'string'
+      ) {
+        return { manifestPath, manifest };
+      }
+    }
+    return null;
+  }
+
+  /**
+   * Locate the workspace root manifest for a package, or null if standalone
+   */
+  private async findWorkspaceManifest(
+    packageDir: string,
+    packageManifest: TomlTable
+  ): Promise<string | null> {
+    // The package manifest is itself the workspace root
+    if (isTomlTable(packageManifest.workspace)) {
+      return path.join(packageDir, MANIFEST_FILE);
+    }
+
+    // Explicit `package.workspace = "../.."` pointer
+    const packageTable = packageManifest.package as TomlTable;
+    if (typeof packageTable.workspace === 'string') {
+      const explicit = path.join(
+        packageDir,
+        packageTable.workspace,
+        MANIFEST_FILE
+      );
+      return (await this.loadManifest(explicit)) ? explicit : null;
+    }
+
+    for (const dir of this.ancestors(path.dirname(packageDir))) {
+      const manifestPath = path.join(dir, MANIFEST_FILE);
+      const manifest = await this.loadManifest(manifestPath);
+      if (!manifest || !isTomlTable(manifest.workspace)) {
+        continue;
+      }
+      // Cargo uses the first workspace found upwards; membership decides
+      // whether the package belongs to it or stands alone
+      const relative = path
+        .relative(dir, packageDir)
+        .split(path.sep)
+        .join('/');
+      return this.isWorkspaceMember(manifest.workspace, relative)
+        ? manifestPath
+        : null;
+    }
+
+    return null;
+  }
+
+  /**
+   * Check `members`/`exclude` glob lists for a package path relative to the
+   * workspace root
+   */
+  isWorkspaceMember(workspace: TomlTable, relativePath: string): boolean {
+    const toPatterns = (value: unknown): string[] =>
+      Array.isArray(value)
+        ? value.filter((v): v is string => typeof v === 'string')
+        : [];
+
+    const members = toPatterns(workspace.members);
+    const exclude = toPatterns(workspace.exclude);
+
+    const matches = (pattern: string) => {
+      const normalized = pattern.replace(/\/+$/, '');
+      return (
+        globToRegExp(normalized).test(relativePath) ||
+        // Excluding a directory excludes everything below it
+        relativePath.startsWith(`${normalized}/`)
+      );
+    };
+
+    if (exclude.some(matches)) {
+      return false;
+    }
+    return members.some(pattern =>
+      globToRegExp(pattern.replace(/\/+$/, '')).test(relativePath)
+    );
+  }
+
+  private *ancestors(startDir: string): Generator<string> {
+    let dir = path.resolve(startDir);
+    for (;;) {
+      yield dir;
+      const parent = path.dirname(dir);
+      if (parent === dir) return;
+      dir = parent;
+    }
+  }
+}
+
+/**
+ * Convert a Cargo workspace glob (`*`, `**`, `?`) into an anchored RegExp
+ */
+function globToRegExp(pattern: string): RegExp {
+  let source = '';
+  for (let i = 0; i < pattern.length; i++) {
+    const ch = pattern[i];
+    if (ch === '*' && pattern[i + 1] === '*') {
+      source += '.*';
+      i++;
+    } else if (ch === '*') {
+      source += '[^/]*';
+    } else if (ch === '?') {
+      source += '[^/]';
+    } else {
+      source += ch.replace(/[.+^${}()|[\]\\]/g, '\\$&');
+    }
+  }
+  return new RegExp(`^${source}$`);
+}
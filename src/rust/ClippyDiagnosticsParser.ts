@@ -0,0 +1,199 @@
+import * as path from 'path';
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { CodeIssue } from '../supervisor/types';
+import {
+  CargoCompilerDiagnostic,
+  CargoCompilerMessage,
+  CargoCompilerSpan,
+  RustDiagnostic,
+  RustDiagnosticLevel,
+  RustSuggestion,
+} from '../types/rust';
+
+const KNOWN_LEVELS: RustDiagnosticLevel[] = [
+  'error',
+  'warning',
+  'note',
+  'help',
+  'ice',
+];
+
+/**
+ * Parses `cargo clippy --message-format=json` output into one diagnostic per
+ * compiler message, so Rust findings can be handled line by line.
+ */
+export class ClippyDiagnosticsParser {
+  /**
+   * Parse the JSON-lines stream emitted by cargo
+   * @param output - Raw stdout of cargo with --message-format=json
+   * @param workspaceRoot - Directory cargo reported span paths relative to
+   */
+  static parse(output: string, workspaceRoot?: string): RustDiagnostic[] {
+    const diagnostics: RustDiagnostic[] = [];
+    const seen = new Set<string>();
+
+    for (const rawLine of output.split('\n')) {
+      const line = rawLine.trim();
+      if (!line.startsWith('{')) continue;
+
+      const parsed = safeJsonParse<CargoCompilerMessage>(line);
+      if (!parsed || parsed.reason !== 'compiler-message' || !parsed.message) {
+        continue;
+      }
+
+      const diagnostic = this.toDiagnostic(
+        parsed.message,
+        workspaceRoot,
+        parsed.package_id
+      );
+      if (!diagnostic) continue;
+
+      // Cargo repeats messages when a file belongs to several targets (lib + test)
+      const key = `${diagnostic.file}:${diagnostic.line}:${diagnostic.column}:${diagnostic.lint}:${diagnostic.message}`;
+      if (seen.has(key)) continue;
+      seen.add(key);
+
+      diagnostics.push(diagnostic);
+    }
+
+    return diagnostics;
+  }
+
+  /**
+   * Keep only the diagnostics whose primary span points at the given file
+   */
+  static filterByFile(
+    diagnostics: RustDiagnostic[],
+    filePath: string
+  ): RustDiagnostic[] {
+    const target = path.resolve(filePath);
+    return diagnostics.filter(d => path.resolve(d.file) === target);
+  }
+
+  /**
+   * Human-readable single-line issue, matching the ESLint/Ruff issue format
+   */
+  static formatIssue(diagnostic: RustDiagnostic): string {
+    const lint = diagnostic.lint ? ` (Lint: ${diagnostic.lint})` : '';
+    return `Line ${diagnostic.line}:${diagnostic.column} - ${diagnostic.level.toUpperCase()}: ${diagnostic.message}${lint}`;
+  }
+
+  /**
+   * Suggested replacements rendered as fix hints
+   */
+  static formatFixes(diagnostics: RustDiagnostic[]): string[] {
+    const fixes: string[] = [];
+    diagnostics.forEach(diagnostic => {
+      diagnostic.suggestions.forEach(suggestion => {
+        const replacement = suggestion.replacement
+          ? `\`${suggestion.replacement}\``
+          : '(remove)';
+        fixes.push(
+          `Line ${suggestion.line}:${suggestion.column} - ${suggestion.message}: ${replacement}`
+        );
+      });
+    });
+    return fixes;
+  }
+
+  /**
+   * Convert diagnostics into supervisor CodeIssues for StateManager
+   */
+  static toCodeIssues(
+    diagnostics: RustDiagnostic[],
+    file: string
+  ): CodeIssue[] {
+    return diagnostics.map(diagnostic => ({
+      type: diagnostic.lint || 'rustc',
+      severity: this.mapSeverity(diagnostic),
+      file,
+      line: diagnostic.line,
+      message: diagnostic.message,
+      tool: 'clippy',
+      autoFixable: diagnostic.suggestions.some(
+        s => s.applicability === 'MachineApplicable'
+      ),
+    }));
+  }
+
+  /**
+   * Compiler errors break the build and are critical; denied lints are high
+   */
+  static mapSeverity(diagnostic: RustDiagnostic): CodeIssue['severity'] {
+    if (diagnostic.level === 'error' || diagnostic.level === 'ice') {
+      const isLint = diagnostic.lint && !/^E\d{4}$/.test(diagnostic.lint);
+      return isLint ? 'high' : 'critical';
+    }
+    if (diagnostic.level === 'warning') return 'medium';
+    return 'low';
+  }
+
+  private static toDiagnostic(
+    message: CargoCompilerDiagnostic,
+    workspaceRoot: string | undefined,
+    packageId: string | undefined
+  ): RustDiagnostic | null {
+    const primary = message.spans.find(span => span.is_primary);
+    // Summary lines such as "aborting due to 2 previous errors" have no span
+    if (!primary) return null;
+
+    const level = KNOWN_LEVELS.includes(message.level as RustDiagnosticLevel)
+      ? (message.level as RustDiagnosticLevel)
+      : 'warning';
+
+    return {
+      file: this.resolveSpanFile(primary, workspaceRoot),
+      line: primary.line_start,
+      column: primary.column_start,
+      endLine: primary.line_end,
+      endColumn: primary.column_end,
+      level,
+      message: message.message,
+      lint: message.code?.code || undefined,
+      suggestions: this.collectSuggestions(message, workspaceRoot),
+      rendered: message.rendered || undefined,
+      packageId,
+    };
+  }
+
+  private static collectSuggestions(
+    message: CargoCompilerDiagnostic,
+    workspaceRoot: string | undefined
+  ): RustSuggestion[] {
+    const suggestions: RustSuggestion[] = [];
+    const candidates = [message, ...message.children];
+
+    candidates.forEach(candidate => {
+      candidate.spans
+        .filter(
+          span =>
+            span.suggested_replacement !== null &&
+            span.suggested_replacement !== undefined
+        )
+        .forEach(span => {
+          suggestions.push({
+            message: candidate.message,
+            replacement: span.suggested_replacement as string,
+            applicability: span.suggestion_applicability || 'Unspecified',
+            file: this.resolveSpanFile(span, workspaceRoot),
+            line: span.line_start,
+            column: span.column_start,
+            endLine: span.line_end,
+            endColumn: span.column_end,
+          });
+        });
+    });
+
+    return suggestions;
+  }
+
+  private static resolveSpanFile(
+    span: CargoCompilerSpan,
+    workspaceRoot: string | undefined
+  ): string {
+    if (path.isAbsolute(span.file_name) || !workspaceRoot) {
+      return span.file_name;
+    }
+    return path.join(workspaceRoot, span.file_name);
+  }
+}
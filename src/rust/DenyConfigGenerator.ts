@@ -0,0 +1,169 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { isTomlTable, parseToml } from '../utils/tomlParser';
+import { isRegistryPackage, readCargoLock } from './CargoLockfile';
+import { cargoHome } from './rustPaths';
+
+/**
+ * Licenses in use by the locked dependency graph
+ */
+export interface LockfileLicenses {
+  licenses: Map<string, string[]>; // SPDX id -> "crate version" using it
+  unknown: string[]; // Crates whose manifest is not in the local cache
+}
+
+// SPDX expression operators; the identifier after WITH is an exception
+const SPDX_OPERATORS = new Set(['OR', 'AND']);
+
+/**
+ * Derives a starter deny.toml from the licenses actually present in
+ * Cargo.lock, so `cargo deny check` passes for the current graph and only
+ * flags newly introduced licenses.
+ */
+export class DenyConfigGenerator {
+  constructor(
+    private registrySrcDir: string = path.join(cargoHome(), 'registry', 'src')
+  ) {}
+
+  /**
+   * Collect licenses of all registry crates in the lockfile, read from the
+   * unpacked crate sources in Cargo's registry cache (no network access)
+   */
+  async collectLicenses(lockfilePath: string): Promise<LockfileLicenses> {
+    const result: LockfileLicenses = { licenses: new Map(), unknown: [] };
+    const packages = await readCargoLock(lockfilePath);
+    if (!packages) {
+      return result;
+    }
+
+    const indexDirs = await fs.readdir(this.registrySrcDir).catch(() => []);
+
+    for (const pkg of packages.filter(isRegistryPackage)) {
+      const crate = `${pkg.name} ${pkg.version}`;
+      const expression = await this.readLicense(
+        indexDirs,
+        `${pkg.name}-${pkg.version}`
+      );
+      if (!expression) {
+        result.unknown.push(crate);
+        continue;
+      }
+
+      for (const license of extractLicenseIds(expression)) {
+        const crates = result.licenses.get(license) || [];
+        crates.push(crate);
+        result.licenses.set(license, crates);
+      }
+    }
+
+    return result;
+  }
+
+  private async readLicense(
+    indexDirs: string[],
+    crateDir: string
+  ): Promise<string | null> {
+    for (const indexDir of indexDirs) {
+      const manifestPath = path.join(
+        this.registrySrcDir,
+        indexDir,
+        crateDir,
+        'Cargo.toml'
+      );
+      if (!(await fs.pathExists(manifestPath))) continue;
+
+      try {
+        const manifest = parseToml(await fs.readFile(manifestPath, 'utf-8'));
+        const license = isTomlTable(manifest.package)
+          ? manifest.package.license
+          : undefined;
+        return typeof license === 'string' ? license : null;
+      } catch {
+        return null;
+      }
+    }
+    return null;
+  }
+}
+
+/**
+ * License identifiers of an SPDX expression, including the legacy
+ * slash-separated form ("MIT/Apache-2.0")
+ */
+export function extractLicenseIds(expression: string): string[] {
+  const ids: string[] = [];
+  const tokens = expression.replace(/[()]/g, ' ').split(/\s+/);
+
+  for (let i = 0; i < tokens.length; i++) {
+    const token = tokens[i];
+    if (!token || SPDX_OPERATORS.has(token)) continue;
+    if (token === 'WITH') {
+      i++; // Skip the exception identifier
+      continue;
+    }
+    ids.push(...token.split('/').filter(Boolean));
+  }
+
+  return [...new Set(ids)];
+}
+
+/**
+ * deny.toml (cargo-deny config version 2) allowing exactly the given licenses
+ */
+export function renderDenyToml({
+  licenses,
+  unknown,
+}: LockfileLicenses): string {
+  const allow = [...licenses.entries()]
+    .sort(([a], [b]) => a.localeCompare(b))
+    .map(
+      ([license, crates]) =>
+        `    "${license}", # ${crates.length} crate${crates.length === 1 ? '' : 's'}`
+    );
+
+  const lines = [
+    '# Generated by WOARU from the licenses found in Cargo.lock.',
+    '# Review the allow-list: every entry is already used by a dependency.',
+    '',
+    '[graph]',
+    'all-features = true',
+    '',
+    '[advisories]',
+    'version = 2',
+    // db-path is left to cargo-deny, which keeps its own clone of the
+    // advisory database under ~/.cargo/advisory-dbs
+    'yanked = "warn"',
+    '',
+    '[licenses]',
+    'version = 2',
+    'confidence-threshold = 0.8',
+    'allow = [',
+    ...allow,
+    ']',
+  ];
+
+  if (unknown.length > 0) {
+    lines.push(
+      '# License not found in the local registry cache (run `cargo fetch`):',
+      ...unknown.map(crate => `# - ${crate}`)
+    );
+  }
+
+  lines.push(
+    '',
+    '[licenses.private]',
+    'ignore = true',
+    '',
+    '[bans]',
+    'multiple-versions = "warn"',
+    'wildcards = "deny"',
+    '',
+    '[sources]',
+    'unknown-registry = "deny"',
+    'unknown-git = "deny"',
+    'allow-registry = ["https://github.com/rust-lang/crates.io-index"]',
+    ''
+  );
+
+  return lines.join('\n');
+}
@@ -0,0 +1,372 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
+import { ToolExecutor } from '../utils/toolExecutor';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import { CargoManifestReader } from './CargoManifestReader';
+import {
+  FeatureCheckMode,
+  FeatureCheckResult,
+  FeatureCombination,
+  FeatureCombinationFailure,
+} from '../types/rust';
+
+export const DEFAULT_FEATURE_DEPTH = 2;
+
+// The internal fallback runs one `cargo check` per combination
+export const MAX_FEATURE_COMBINATIONS = 64;
+
+const MAX_REPORTED_ERRORS = 5;
+
+type UnmappedFailure = Omit<FeatureCombinationFailure, 'line'>;
+
+export interface FeatureCheckOptions {
+  mode?: FeatureCheckMode;
+  depth?: number; // Largest number of features combined in powerset mode
+}
+
+/**
+ * Combinations to check, mirroring cargo-hack: `--no-default-features`
+ * alone, single features (each-feature) or every subset of up to `depth`
+ * features (powerset), and `--all-features`
+ */
+export function enumerateFeatureCombinations(
+  features: string[],
+  mode: FeatureCheckMode,
+  depth: number = DEFAULT_FEATURE_DEPTH
+): FeatureCombination[] {
+  const subsets: string[][] = [[]];
+  const maxSize = mode === 'each-feature' ? 1 : Math.max(1, depth);
+
+  const extend = (start: number, current: string[]) => {
+    if (current.length === maxSize) return;
+    for (let i = start; i < features.length; i++) {
+      const next = [...current, features[i]];
+      subsets.push(next);
+      extend(i + 1, next);
+    }
+  };
+  extend(0, []);
+
+  const combinations = subsets
+    .sort((a, b) => a.length - b.length)
+    .map(subset => ({
+      features: subset,
+      noDefaultFeatures: true,
+      allFeatures: false,
+    }));
+  // Already covered when the largest subset contains every feature
+  if (features.length > maxSize) {
+    combinations.push({
+      features: [],
+      noDefaultFeatures: false,
+      allFeatures: true,
+    });
+  }
+  return combinations;
+}
+
+/**
+ * Cargo flags selecting a combination
+ */
+export function featureArgs(combination: FeatureCombination): string[] {
+  if (combination.allFeatures) {
+    return ['--all-features'];
+  }
+  return [
+    ...(combination.noDefaultFeatures ? ['--no-default-features'] : []),
+    ...(combination.features.length > 0
+      ? ['--features', combination.features.join(',')]
+      : []),
+  ];
+}
+
+export function describeCombination(combination: FeatureCombination): string {
+  const args = featureArgs(combination);
+  return args.length > 0 ? args.join(' ') : 'default features';
+}
+
+/**
+ * Split `cargo hack check --keep-going` output into its runs and return
+ * the combinations whose run printed errors
+ */
+export function parseCargoHackOutput(stderr: string): {
+  checked: number;
+  failures: UnmappedFailure[];
+} {
+  const failures: UnmappedFailure[] = [];
+  let checked = 0;
+  let current: { combination: FeatureCombination; errors: string[] } | null =
+    null;
+
+  const finish = () => {
+    if (current && current.errors.length > 0) {
+      failures.push({
+        combination: current.combination,
+        errors: summarizeErrors(current.errors),
+      });
+    }
+  };
+
+  for (const line of stderr.split('\n')) {
+    // info: running `cargo check --features a,b ...` on pkg (2/7)
+    const run = line.match(/^info: running `cargo (.*?)` on /);
+    if (run) {
+      finish();
+      checked++;
+      current = { combination: parseFeatureFlags(run[1]), errors: [] };
+    } else if (current && /\berror(\[E\d+\])?:/.test(line)) {
+      // --keep-going repeats every failed command at the end
+      if (!/^error: failed to run `cargo /.test(line)) {
+        current.errors.push(line.trim());
+      }
+    }
+  }
+  finish();
+
+  return { checked, failures };
+}
+
+/**
+ * Line of each feature in the `[features]` table of a manifest, plus the
+ * header line itself under the empty name
+ */
+export function findFeatureLines(content: string): Map<string, number> {
+  const lines = new Map<string, number>();
+  let inFeatures = false;
+
+  content.split('\n').forEach((line, index) => {
+    const header = line.match(/^\s*\[([^\]]+)\]/);
+    if (header) {
+      inFeatures = header[1].trim() === 'features';
+      if (inFeatures) lines.set('', index + 1);
+      return;
+    }
+    const feature = line.match(/^\s*"?([\w.+-]+)"?\s*=/);
+    if (inFeatures && feature) {
+      lines.set(feature[1], index + 1);
+    }
+  });
+
+  return lines;
+}
+
+function parseFeatureFlags(command: string): FeatureCombination {
+  const features = command.match(/--features[= ](\S+)/);
+  return {
+    features: features ? features[1].split(',').filter(Boolean) : [],
+    noDefaultFeatures: command.includes('--no-default-features'),
+    allFeatures: command.includes('--all-features'),
+  };
+}
+
+/**
+ * Keep compiler errors, dropping the "could not compile" trailer unless
+ * nothing else was printed
+ */
+function summarizeErrors(errors: string[]): string[] {
+  const specific = errors.filter(
+    error => !/^error: could not compile/.test(error)
+  );
+  return (specific.length > 0 ? specific : errors).slice(
+    0,
+    MAX_REPORTED_ERRORS
+  );
+}
+
+/**
+ * Checks that every package with `[features]` compiles for each feature on
+ * its own or for the feature powerset up to a depth. Uses cargo-hack when
+ * installed and runs `cargo check` per combination otherwise.
+ */
+export class FeatureCombinationChecker {
+  private manifestReader: CargoManifestReader;
+
+  constructor(
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {
+    this.manifestReader = new CargoManifestReader(resolver);
+  }
+
+  /**
+   * @returns null if the project is not a Cargo project
+   */
+  async check(
+    projectPath: string,
+    options: FeatureCheckOptions = {}
+  ): Promise<FeatureCheckResult[] | null> {
+    const mode = options.mode || 'each-feature';
+    const depth = options.depth || DEFAULT_FEATURE_DEPTH;
+    const manifestPath = path.join(path.resolve(projectPath), 'Cargo.toml');
+    const cargoPackage = await this.resolver.resolveForFile(manifestPath);
+    const workspaceManifestPath =
+      cargoPackage?.workspaceManifestPath || manifestPath;
+    const workspaceManifest = await this.resolver.loadManifest(
+      workspaceManifestPath
+    );
+    if (!workspaceManifest) {
+      return null;
+    }
+
+    const results: FeatureCheckResult[] = [];
+    let hackInstalled = true;
+    const members = await this.manifestReader.collectPackageManifests(
+      workspaceManifestPath,
+      workspaceManifest
+    );
+
+    for (const [packageManifestPath, manifest] of members) {
+      const packageName = packageNameOf(manifest);
+      const features = isTomlTable(manifest.features)
+        ? Object.keys(manifest.features).filter(name => name !== 'default')
+        : [];
+      if (!packageName || features.length === 0) continue;
+
+      let result: FeatureCheckResult | null = null;
+      if (hackInstalled) {
+        result = await this.runCargoHack(
+          workspaceManifestPath,
+          packageName,
+          mode,
+          depth
+        );
+        hackInstalled = result !== null;
+      }
+      if (!result) {
+        result = await this.runInternal(
+          workspaceManifestPath,
+          packageName,
+          features,
+          mode,
+          depth
+        );
+      }
+
+      const featureLines = findFeatureLines(
+        await fs.readFile(packageManifestPath, 'utf-8')
+      );
+      results.push({
+        ...result,
+        manifestPath: packageManifestPath,
+        failures: result.failures.map(failure => ({
+          ...failure,
+          line: lineForCombination(failure.combination, featureLines),
+        })),
+      });
+    }
+
+    return results;
+  }
+
+  /**
+   * @returns null if cargo-hack is not installed
+   */
+  private async runCargoHack(
+    workspaceManifestPath: string,
+    packageName: string,
+    mode: FeatureCheckMode,
+    depth: number
+  ): Promise<FeatureCheckResult | null> {
+    const { stderr, exitCode } = await ToolExecutor.runCargoHackCheck(
+      workspaceManifestPath,
+      packageName,
+      mode === 'each-feature'
+        ? ['--each-feature']
+        : ['--feature-powerset', '--depth', String(depth)]
+    );
+    if (stderr.includes('no such command')) {
+      return null;
+    }
+
+    const { checked, failures } = parseCargoHackOutput(stderr);
+    // Failed before running any combination, e.g. a broken manifest
+    if (exitCode !== 0 && checked === 0) {
+      failures.push({
+        combination: {
+          features: [],
+          noDefaultFeatures: false,
+          allFeatures: false,
+        },
+        errors: summarizeErrors(
+          stderr.split('\n').filter(line => /\berror\b/.test(line))
+        ),
+      });
+    }
+
+    return {
+      packageName,
+      manifestPath: workspaceManifestPath,
+      runner: 'cargo-hack',
+      mode,
+      depth,
+      checked,
+      truncated: false,
+      failures: failures.map(failure => ({ ...failure, line: 0 })),
+    };
+  }
+
+  private async runInternal(
+    workspaceManifestPath: string,
+    packageName: string,
+    features: string[],
+    mode: FeatureCheckMode,
+    depth: number
+  ): Promise<FeatureCheckResult> {
+    const combinations = enumerateFeatureCombinations(features, mode, depth);
+    const selected = combinations.slice(0, MAX_FEATURE_COMBINATIONS);
+    const failures: FeatureCombinationFailure[] = [];
+
+    for (const combination of selected) {
+      const { stderr, exitCode } = await ToolExecutor.runCargoFeatureCheck(
+        workspaceManifestPath,
+        packageName,
+        featureArgs(combination)
+      );
+      if (exitCode === 0) continue;
+
+      const errors = stderr
+        .split('\n')
+        .filter(line => /\berror(\[E\d+\])?:/.test(line))
+        .map(line => line.trim());
+      failures.push({
+        combination,
+        errors:
+          errors.length > 0
+            ? summarizeErrors(errors)
+            : [`cargo check exited with code ${exitCode}`],
+        line: 0,
+      });
+    }
+
+    return {
+      packageName,
+      manifestPath: workspaceManifestPath,
+      runner: 'internal',
+      mode,
+      depth,
+      checked: selected.length,
+      truncated: combinations.length > selected.length,
+      failures,
+    };
+  }
+}
+
+function packageNameOf(manifest: TomlTable): string | undefined {
+  const pkg = isTomlTable(manifest.package) ? manifest.package : {};
+  return typeof pkg.name === 'string' ? pkg.name : undefined;
+}
+
+/**
+ * A combination of a single feature points at that feature's line,
+ * anything else at the `[features]` header
+ */
+function lineForCombination(
+  combination: FeatureCombination,
+  featureLines: Map<string, number>
+): number {
+  const single =
+    combination.features.length === 1
+      ? featureLines.get(combination.features[0])
+      : undefined;
+  return single || featureLines.get('') || 1;
+}
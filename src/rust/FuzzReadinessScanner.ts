@@ -0,0 +1,260 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { glob } from 'glob';
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import { CargoManifestReader } from './CargoManifestReader';
+import { extractRustDocItems } from './RustDocComments';
+import { isRustLibrarySource, maskRustSource } from './RustSourceScanner';
+import { listRustSources, readRustSource } from './rustPaths';
+import {
+  FuzzCandidate,
+  FuzzReadinessReport,
+  FuzzTarget,
+} from '../types/rust';
+
+export const PROPERTY_TEST_CRATES = [
+  'proptest',
+  'quickcheck',
+  'test-strategy',
+  'arbtest',
+];
+
+// `&[u8]`, `&'a [u8]`, `&str`, `&'a str` - but not `&mut [u8]` out-buffers
+const UNTRUSTED_INPUT = /&\s*(?:'\w+\s+)?(\[u8\]|str)(?![\w:])/;
+
+/**
+ * Parameter list of a function signature, e.g.
+ * `pub fn parse<'a>(input: &'a [u8]) -> Result<Ast>` -> `input: &'a [u8]`
+ */
+export function functionParameters(signature: string): string {
+  const name = signature.match(/\bfn\s+\w+\s*/);
+  if (!name) {
+    return '';
+  }
+
+  let i = (name.index || 0) + name[0].length;
+  let depth = 0;
+  // Generic parameters may contain `Fn(..)` bounds
+  for (; i < signature.length; i++) {
+    const ch = signature[i];
+    if (ch === '<') depth++;
+    else if (ch === '>' && signature[i - 1] !== '-') depth--;
+    else if (ch === '(' && depth === 0) break;
+  }
+
+  const start = i + 1;
+  depth = 1;
+  for (i = start; i < signature.length; i++) {
+    if (signature[i] === '(') depth++;
+    else if (signature[i] === ')' && --depth === 0) break;
+  }
+  return signature.slice(start, i);
+}
+
+/**
+ * Public functions of a library source file taking `&[u8]` or `&str`
+ */
+export function findFuzzCandidates(
+  content: string,
+  file: string,
+  crate: string
+): FuzzCandidate[] {
+  return extractRustDocItems(content)
+    .filter(item => item.kind === 'fn' && !/\bunsafe\b/.test(item.signature))
+    .flatMap(item => {
+      const match = functionParameters(item.signature).match(UNTRUSTED_INPUT);
+      if (!match) {
+        return [];
+      }
+      const input: FuzzCandidate['input'] =
+        match[1] === 'str' ? '&str' : '&[u8]';
+      return [
+        { crate, name: item.name, file, line: item.line, input, fuzzed: false },
+      ];
+    });
+}
+
+/**
+ * Whether fuzz target sources (masked) call a function by name
+ */
+export function isCalledFrom(maskedSources: string[], name: string): boolean {
+  const call = new RegExp(`\\b${name}\\s*(?:::<[^>]*>\\s*)?\\(`);
+  return maskedSources.some(source => call.test(source));
+}
+
+/**
+ * Collects the cargo-fuzz targets, property-testing dev-dependencies and the
+ * public functions taking `&[u8]`/`&str` of a Cargo workspace
+ */
+export class FuzzReadinessScanner {
+  private manifestReader: CargoManifestReader;
+
+  constructor(
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {
+    this.manifestReader = new CargoManifestReader(resolver);
+  }
+
+  /**
+   * @returns null if the project is not a Cargo project
+   */
+  async scan(projectPath: string): Promise<FuzzReadinessReport | null> {
+    const manifestPath = path.join(path.resolve(projectPath), 'Cargo.toml');
+    if (!(await fs.pathExists(manifestPath))) {
+      return null;
+    }
+    const cargoPackage = await this.resolver.resolveForFile(manifestPath);
+    const workspaceManifestPath =
+      cargoPackage?.workspaceManifestPath || manifestPath;
+    const workspaceManifest = await this.resolver.loadManifest(
+      workspaceManifestPath
+    );
+    if (!workspaceManifest) {
+      return null;
+    }
+
+    const members = (
+      await this.manifestReader.collectPackageManifests(
+        workspaceManifestPath,
+        workspaceManifest
+      )
+    ).filter(([, manifest]) => !this.isFuzzCrate(manifest));
+    const report: FuzzReadinessReport = {
+      fuzzTargets: await this.findFuzzTargets([
+        path.dirname(workspaceManifestPath),
+        ...members.map(([memberPath]) => path.dirname(memberPath)),
+      ]),
+      propertyTesting: [],
+      candidates: [],
+    };
+
+    for (const [memberPath, manifest] of members) {
+      const pkg = isTomlTable(manifest.package) ? manifest.package : {};
+      const crate =
+        typeof pkg.name === 'string'
+          ? pkg.name
+          : path.basename(path.dirname(memberPath));
+      const frameworks = this.manifestReader
+        .collectDependencies(manifest, memberPath)
+        .filter(dep => dep.kind === 'dev')
+        .map(dep => dep.name)
+        .filter(name => PROPERTY_TEST_CRATES.includes(name));
+      if (frameworks.length > 0) {
+        report.propertyTesting.push({
+          crate,
+          frameworks: [...new Set(frameworks)],
+        });
+      }
+      if (await this.hasLibrary(memberPath, manifest)) {
+        const crateDir = path.dirname(memberPath);
+        // Members nested below this crate own their sources
+        const nested = members
+          .map(([other]) => path.relative(crateDir, path.dirname(other)))
+          .filter(dir => dir && !dir.startsWith('..') && !path.isAbsolute(dir));
+        report.candidates.push(
+          ...(await this.scanLibrary(crateDir, crate, nested))
+        );
+      }
+    }
+
+    const targetSources = await Promise.all(
+      report.fuzzTargets.map(target =>
+        fs
+          .readFile(target.file, 'utf-8')
+          .then(maskRustSource)
+          .catch(() => '')
+      )
+    );
+    for (const candidate of report.candidates) {
+      candidate.fuzzed = isCalledFrom(targetSources, candidate.name);
+    }
+
+    return report;
+  }
+
+  private isFuzzCrate(manifest: TomlTable): boolean {
+    const pkg = isTomlTable(manifest.package) ? manifest.package : {};
+    const metadata = isTomlTable(pkg.metadata) ? pkg.metadata : {};
+    return metadata['cargo-fuzz'] === true;
+  }
+
+  private async hasLibrary(
+    manifestPath: string,
+    manifest: TomlTable
+  ): Promise<boolean> {
+    return (
+      isTomlTable(manifest.lib) ||
+      (await fs.pathExists(
+        path.join(path.dirname(manifestPath), 'src', 'lib.rs')
+      ))
+    );
+  }
+
+  /**
+   * Targets of `fuzz/Cargo.toml` next to the workspace or a member crate:
+   * its `[[bin]]` entries or, without those, `fuzz_targets/*.rs`
+   */
+  private async findFuzzTargets(dirs: string[]): Promise<FuzzTarget[]> {
+    const targets = new Map<string, FuzzTarget>();
+
+    for (const dir of new Set(dirs)) {
+      const fuzzDir = path.join(dir, 'fuzz');
+      const manifestPath = path.join(fuzzDir, 'Cargo.toml');
+      const manifest = await this.resolver.loadManifest(manifestPath);
+      if (!manifest) continue;
+
+      const bins = Array.isArray(manifest.bin)
+        ? manifest.bin.filter(isTomlTable)
+        : [];
+      const declared = bins
+        .filter(bin => typeof bin.name === 'string')
+        .map(bin => ({
+          name: String(bin.name),
+          file: path.resolve(
+            fuzzDir,
+            typeof bin.path === 'string'
+              ? bin.path
+              : path.join('fuzz_targets', `${bin.name}.rs`)
+          ),
+        }));
+      const found =
+        declared.length > 0
+          ? declared
+          : (
+              await glob('fuzz_targets/*.rs', {
+                cwd: fuzzDir,
+                absolute: true,
+                nodir: true,
+              })
+            ).map(file => ({ name: path.basename(file, '.rs'), file }));
+
+      for (const target of found) {
+        targets.set(target.file, { ...target, manifestPath });
+      }
+    }
+
+    return [...targets.values()].sort((a, b) => a.name.localeCompare(b.name));
+  }
+
+  private async scanLibrary(
+    crateDir: string,
+    crate: string,
+    nestedCrateDirs: string[]
+  ): Promise<FuzzCandidate[]> {
+    const files = await listRustSources(crateDir, [
+      'fuzz/**',
+      ...nestedCrateDirs.map(dir => `${dir.split(path.sep).join('/')}/**`),
+    ]);
+
+    const candidates: FuzzCandidate[] = [];
+    for (const file of files) {
+      if (!isRustLibrarySource(path.relative(crateDir, file))) continue;
+      const content = await readRustSource(file);
+      if (content !== null) {
+        candidates.push(...findFuzzCandidates(content, file, crate));
+      }
+    }
+    return candidates;
+  }
+}
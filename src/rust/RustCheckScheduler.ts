@@ -0,0 +1,207 @@
+import { ToolExecutor } from '../utils/toolExecutor';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import { CargoDependencyGraph } from './CargoDependencyGraph';
+import { ClippyDiagnosticsParser } from './ClippyDiagnosticsParser';
+import { RustDiagnostic } from '../types/rust';
+
+// Saves arriving within this window are checked together
+export const DEFAULT_RUST_CHECK_DEBOUNCE_MS = 300;
+
+/**
+ * Outcome of one clippy run over the changed crates of a workspace
+ */
+export interface RustCheckRun {
+  workspaceRoot: string;
+  changedPackages: string[];
+  packages: string[]; // Changed packages plus their reverse dependencies
+  changedFiles: string[];
+  diagnostics: RustDiagnostic[]; // All packages, not only the changed files
+  durationMs: number;
+  error?: string; // cargo failed without emitting diagnostics
+}
+
+interface WorkspaceQueue {
+  workspaceRoot: string;
+  manifestPath: string;
+  pendingPackages: Set<string>;
+  pendingFiles: Set<string>;
+  timer?: NodeJS.Timeout;
+  running?: {
+    controller: AbortController;
+    packages: string[];
+    files: string[];
+  };
+}
+
+/**
+ * Coalesces Rust file changes per workspace in watch mode. Saves are
+ * debounced, a newer save cancels the clippy run still in flight, and each
+ * run checks only the owning crates and the workspace crates depending on
+ * them (from `cargo metadata`) in a single cargo invocation, so runs never
+ * wait on each other's target-dir lock.
+ */
+export class RustCheckScheduler {
+  private queues = new Map<string, WorkspaceQueue>();
+  private graphs = new Map<string, CargoDependencyGraph | null>();
+
+  constructor(
+    private onRun: (run: RustCheckRun) => void | Promise<void>,
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver(),
+    private debounceMs: number = DEFAULT_RUST_CHECK_DEBOUNCE_MS
+  ) {}
+
+  /**
+   * Queue a changed file for the next run of its workspace
+   * @returns false if the file is not part of a Cargo package
+   */
+  async schedule(filePath: string): Promise<boolean> {
+    const cargoPackage = await this.resolver.resolveForFile(filePath);
+    if (!cargoPackage) {
+      return false;
+    }
+
+    let queue = this.queues.get(cargoPackage.workspaceManifestPath);
+    if (!queue) {
+      queue = {
+        workspaceRoot: cargoPackage.workspaceRoot,
+        manifestPath: cargoPackage.workspaceManifestPath,
+        pendingPackages: new Set(),
+        pendingFiles: new Set(),
+      };
+      this.queues.set(cargoPackage.workspaceManifestPath, queue);
+    }
+    queue.pendingPackages.add(cargoPackage.name);
+    queue.pendingFiles.add(filePath);
+
+    // The run in flight checks outdated sources; fold it into the next one
+    if (queue.running) {
+      this.cancelRun(queue);
+    }
+
+    if (queue.timer) {
+      clearTimeout(queue.timer);
+    }
+    const target = queue;
+    queue.timer = setTimeout(() => {
+      target.timer = undefined;
+      this.startRun(target).catch(error =>
+        console.debug(`Rust check failed: ${error}`)
+      );
+    }, this.debounceMs);
+    return true;
+  }
+
+  /**
+   * Forget dependency graphs, e.g. after a Cargo.toml change
+   */
+  invalidate(): void {
+    this.graphs.clear();
+  }
+
+  /**
+   * Drop queued changes and kill running cargo processes
+   */
+  cancelAll(): void {
+    for (const queue of this.queues.values()) {
+      if (queue.timer) {
+        clearTimeout(queue.timer);
+        queue.timer = undefined;
+      }
+      queue.running?.controller.abort();
+      queue.running = undefined;
+    }
+    this.queues.clear();
+  }
+
+  private cancelRun(queue: WorkspaceQueue): void {
+    const running = queue.running;
+    if (!running) return;
+
+    running.controller.abort();
+    running.packages.forEach(name => queue.pendingPackages.add(name));
+    running.files.forEach(file => queue.pendingFiles.add(file));
+    queue.running = undefined;
+  }
+
+  private async startRun(queue: WorkspaceQueue): Promise<void> {
+    const changedPackages = [...queue.pendingPackages];
+    const changedFiles = [...queue.pendingFiles];
+    queue.pendingPackages.clear();
+    queue.pendingFiles.clear();
+    if (changedPackages.length === 0) return;
+
+    const controller = new AbortController();
+    queue.running = {
+      controller,
+      packages: changedPackages,
+      files: changedFiles,
+    };
+    const startedAt = Date.now();
+
+    const graph = await this.loadGraph(queue.manifestPath);
+    const packages = graph
+      ? graph.withReverseDependencies(changedPackages)
+      : changedPackages;
+    let run: RustCheckRun = {
+      workspaceRoot: queue.workspaceRoot,
+      changedPackages,
+      packages,
+      changedFiles,
+      diagnostics: [],
+      durationMs: 0,
+    };
+
+    try {
+      if (controller.signal.aborted) return;
+      const { stdout, stderr, exitCode } = await ToolExecutor.runCargoClippy(
+        queue.manifestPath,
+        packages.flatMap(name => ['-p', name]),
+        { signal: controller.signal }
+      );
+      const diagnostics = ClippyDiagnosticsParser.parse(
+        stdout,
+        queue.workspaceRoot
+      );
+      run = {
+        ...run,
+        diagnostics,
+        // Non-zero exit without any compiler message means cargo failed
+        error:
+          exitCode !== 0 && diagnostics.length === 0
+            ? stderr || `cargo clippy exited with code ${exitCode}`
+            : undefined,
+      };
+    } catch (error) {
+      if (controller.signal.aborted) return;
+      run.error = error instanceof Error ? error.message : String(error);
+    } finally {
+      if (queue.running?.controller === controller) {
+        queue.running = undefined;
+      }
+    }
+
+    if (controller.signal.aborted) return;
+    await this.onRun({ ...run, durationMs: Date.now() - startedAt });
+  }
+
+  private async loadGraph(
+    manifestPath: string
+  ): Promise<CargoDependencyGraph | null> {
+    if (this.graphs.has(manifestPath)) {
+      return this.graphs.get(manifestPath) || null;
+    }
+
+    let graph: CargoDependencyGraph | null = null;
+    try {
+      const { stdout, exitCode } =
+        await ToolExecutor.runCargoMetadata(manifestPath);
+      graph =
+        exitCode === 0 ? CargoDependencyGraph.fromMetadata(stdout) : null;
+    } catch (error) {
+      console.debug(`cargo metadata failed for ${manifestPath}: ${error}`);
+    }
+    // Without a graph only the changed crates are checked
+    this.graphs.set(manifestPath, graph);
+    return graph;
+  }
+}
@@ -0,0 +1,397 @@
+/**
+ * Locates public Rust items for `woaru docu` and renders generated
+ * documentation as rustdoc `///` comments.
+ */
+
+import {
+  findDeclarationEnd,
+  findMatchingBrace,
+  findTestRegions,
+  isInRegions,
+  maskRustSource,
+} from './RustSourceScanner';
+
+export type RustDocItemKind = 'fn' | 'struct' | 'enum' | 'trait' | 'impl';
+
+/**
+ * A public item (or impl block) that rustdoc documents
+ */
+export interface RustDocItem {
+  kind: RustDocItemKind;
+  name: string; // `impl Display for Wrapper<T>` for impl blocks
+  line: number; // 1-based line of the item keyword
+  startLine: number; // 1-based first line of its attributes and docs
+  endLine: number; // 1-based line of the closing `}` or `;`
+  indent: string;
+  signature: string; // Declaration up to the body, whitespace collapsed
+  content: string; // Attributes, declaration and body
+  existingDoc?: string; // Text of the existing `///` or `#[doc]` comment
+  panics: string[]; // Panicking calls in a function body, e.g. `unwrap()`
+}
+
+// Items longer than this are cut off before being sent to the AI
+const MAX_ITEM_LINES = 200;
+
+const ITEM_REGEX =
+  /^([ \t]*)(?:pub\s+(?:(?:const|async|unsafe|extern(?:\s+"[^"]*")?)\s+)*(fn|struct|enum|trait)\s+([A-Za-z_]\w*)|(?:unsafe\s+)?(impl)\b)/;
+
+const PANIC_SOURCES: Array<[RegExp, string]> = [
+  [/\.unwrap\(\)/, 'unwrap()'],
+  [/\.expect\(/, 'expect()'],
+  [/\bpanic!/, 'panic!'],
+  [/\bunreachable!/, 'unreachable!'],
+  [/\b(?:todo|unimplemented)!/, 'todo!'],
+  [/\bassert(?:_eq|_ne)?!/, 'assert!'], // Not debug_assert!
+];
+
+// Doctest fences in AI output written for another language
+const FOREIGN_FENCE = /^```\s*(?:typescript|ts|javascript|js|python|py)\s*$/i;
+
+const SECTION_ORDER = ['Arguments', 'Errors', 'Panics', 'Safety'];
+
+/**
+ * Find `pub fn`, `pub struct`, `pub enum`, `pub trait` items and impl blocks
+ * outside of test code, including their attributes and generics
+ */
+export function extractRustDocItems(content: string): RustDocItem[] {
+  const masked = maskRustSource(content);
+  const lines = content.split('\n');
+  const maskedLines = masked.split('\n');
+  const testRegions = findTestRegions(masked);
+  const lineOffsets: number[] = [];
+  let offset = 0;
+  for (const line of maskedLines) {
+    lineOffsets.push(offset);
+    offset += line.length + 1;
+  }
+
+  const items: RustDocItem[] = [];
+  for (let i = 0; i < maskedLines.length; i++) {
+    const match = maskedLines[i].match(ITEM_REGEX);
+    if (!match || isInRegions(testRegions, lineOffsets[i])) continue;
+
+    const itemOffset = lineOffsets[i] + match[1].length;
+    const bodyStart = findDeclarationEnd(masked, itemOffset);
+    if (bodyStart === -1) continue;
+    const bodyEnd =
+      masked[bodyStart] === '{'
+        ? findMatchingBrace(masked, bodyStart)
+        : bodyStart;
+    if (bodyEnd === -1) continue;
+
+    const signature = content
+      .slice(itemOffset, bodyStart)
+      .replace(/\s+/g, ' ')
+      .trim();
+    const kind = (match[2] || match[4]) as RustDocItemKind;
+    const endLine = lineAt(lineOffsets, bodyEnd);
+    const { startIndex, docLines } = readLeadingBlock(lines, maskedLines, i);
+    const body = masked.slice(bodyStart, bodyEnd + 1);
+
+    items.push({
+      kind,
+      name: kind === 'impl' ? implName(signature) : match[3],
+      line: i + 1,
+      startLine: startIndex + 1,
+      endLine,
+      indent: match[1],
+      signature,
+      content: lines
+        .slice(startIndex, Math.min(endLine, startIndex + MAX_ITEM_LINES))
+        .join('\n'),
+      existingDoc: docLines.length > 0 ? docLines.join('\n') : undefined,
+      panics:
+        kind === 'fn'
+          ? PANIC_SOURCES.filter(([regex]) => regex.test(body)).map(
+              ([, label]) => label
+            )
+          : [],
+    });
+  }
+
+  return items;
+}
+
+/**
+ * Whether the line above `index` ends a doc comment or attribute block that
+ * already documents the item starting at `index`
+ */
+export function hasDocCommentAbove(lines: string[], index: number): boolean {
+  const masked = maskRustSource(lines.join('\n')).split('\n');
+  return readLeadingBlock(lines, masked, index).docLines.length > 0;
+}
+
+/**
+ * Render AI output (JSDoc, `//` or rustdoc style) as `///` lines for an
+ * item. Technical docs of functions that can panic get a `# Panics`
+ * section naming the panicking calls; `# Errors` and `# Examples` are only
+ * kept when the AI wrote them, so `clippy::missing_errors_doc` still
+ * flags a `Result` function whose errors nobody described.
+ */
+export function renderRustDocComment(
+  generated: string,
+  item: RustDocItem,
+  documentationType: 'nopro' | 'pro'
+): string {
+  const lines = stripCommentSyntax(generated);
+
+  if (documentationType === 'nopro') {
+    const text = lines
+      .join(' ')
+      .replace(/\s+/g, ' ')
+      .replace(/^Explain-for-humans:\s*/, '')
+      .trim();
+    return `${item.indent}/// Explain-for-humans: ${text}`;
+  }
+
+  const { description, sections } = parseSections(lines);
+  const isFunction = item.kind === 'fn';
+
+  if (isFunction && !sections.has('Panics') && item.panics.length > 0) {
+    const calls = item.panics.map(source => `\`${source}\``).join(', ');
+    sections.set('Panics', [`May panic: the implementation uses ${calls}.`]);
+  }
+
+  const ordered = [
+    ...SECTION_ORDER.filter(name => sections.has(name)),
+    ...[...sections.keys()].filter(
+      name => !SECTION_ORDER.includes(name) && name !== 'Examples'
+    ),
+    ...(sections.has('Examples') ? ['Examples'] : []),
+  ];
+  const paragraphs = [
+    description,
+    ...ordered.map(name => [`# ${name}`, '', ...sections.get(name)!]),
+  ].filter(paragraph => paragraph.length > 0);
+
+  return paragraphs
+    .flatMap((paragraph, index) => (index === 0 ? [] : ['']).concat(paragraph))
+    .map(line => `${item.indent}///${line ? ` ${line}` : ''}`)
+    .join('\n');
+}
+
+/**
+ * Walk up from an item over attributes and doc comments
+ * @returns Index of the first line of the block and the doc comment text
+ */
+function readLeadingBlock(
+  lines: string[],
+  maskedLines: string[],
+  itemIndex: number
+): { startIndex: number; docLines: string[] } {
+  const docLines: string[] = [];
+  let startIndex = itemIndex;
+
+  while (startIndex > 0) {
+    const line = lines[startIndex - 1].trim();
+
+    if (/^\/\/\/(?!\/)/.test(line)) {
+      docLines.unshift(line.replace(/^\/\/\/\s?/, ''));
+      startIndex--;
+      continue;
+    }
+
+    if (line.endsWith('*/')) {
+      const open = findLineAbove(lines, startIndex - 1, l =>
+        l.startsWith('/*')
+      );
+      // Plain /* */ comments are not documentation
+      if (open === -1 || !/^\/\*\*(?!\*)/.test(lines[open].trim())) break;
+      docLines.unshift(...lines.slice(open, startIndex).map(l => l.trim()));
+      startIndex = open;
+      continue;
+    }
+
+    if (line.endsWith(']')) {
+      const attribute = findAttributeStart(maskedLines, startIndex - 1);
+      if (attribute === -1) break;
+      const text = lines.slice(attribute, startIndex).join(' ').trim();
+      if (/^#\[\s*doc\s*=/.test(text)) {
+        docLines.unshift(text);
+      }
+      startIndex = attribute;
+      continue;
+    }
+
+    break;
+  }
+
+  return { startIndex, docLines };
+}
+
+/**
+ * First line of an outer attribute (`#[...]`, possibly spanning several
+ * lines) that ends on line `endIndex`, or -1
+ */
+function findAttributeStart(maskedLines: string[], endIndex: number): number {
+  const start = findLineAbove(maskedLines, endIndex, line =>
+    /^#\[/.test(line)
+  );
+  if (start === -1) return -1;
+
+  const text = maskedLines.slice(start, endIndex + 1).join('\n');
+  let depth = 0;
+  for (let i = 0; i < text.length; i++) {
+    if (text[i] === '[') depth++;
+    if (text[i] === ']') {
+      depth--;
+      // Closed before the last line: not a single attribute
+      if (depth === 0 && text.slice(i + 1).trim()) return -1;
+    }
+  }
+  return depth === 0 ? start : -1;
+}
+
+function findLineAbove(
+  lines: string[],
+  fromIndex: number,
+  predicate: (trimmed: string) => boolean
+): number {
+  for (let i = fromIndex; i >= 0 && i > fromIndex - MAX_ITEM_LINES; i--) {
+    if (predicate(lines[i].trim())) return i;
+  }
+  return -1;
+}
+
+function lineAt(lineOffsets: number[], offset: number): number {
+  let line = 0;
+  while (line + 1 < lineOffsets.length && lineOffsets[line + 1] <= offset) {
+    line++;
+  }
+  return line + 1;
+}
+
+/**
+ * `impl<T: Clone> From<T> for Wrapper<T> where T: Debug` →
+ * `impl From<T> for Wrapper<T>`
+ */
+function implName(signature: string): string {
+  let header = signature.replace(/^(?:unsafe\s+)?impl\s*/, '');
+  if (header.startsWith('<')) {
+    let depth = 0;
+    for (let i = 0; i < header.length; i++) {
+      if (header[i] === '<') {
+        depth++;
+      } else if (header[i] === '>' && header[i - 1] !== '-') {
+        depth--;
+        if (depth === 0) {
+          header = header.slice(i + 1);
+          break;
+        }
+      }
+    }
+  }
+  return `impl ${header.replace(/\bwhere\b.*$/, '').trim()}`;
+}
+
+/**
+ * Remove code fences around the whole answer and comment markers
+ * (`/** * *\/`, `///`, `//`) from each line
+ */
+function stripCommentSyntax(generated: string): string[] {
+  let lines = generated.trim().split('\n');
+  if (
+    lines.length > 1 &&
+    /^```/.test(lines[0].trim()) &&
+    /^```\s*$/.test(lines[lines.length - 1].trim())
+  ) {
+    lines = lines.slice(1, -1);
+  }
+
+  lines = lines.map(line =>
+    line
+      .replace(/\s*\*\/\s*$/, '')
+      .replace(/^\s*(?:\/\*\*?|\/\/[/!]?|\*)\s?/, '')
+      .trimEnd()
+  );
+
+  while (lines.length > 0 && !lines[0].trim()) lines.shift();
+  while (lines.length > 0 && !lines[lines.length - 1].trim()) lines.pop();
+  return lines;
+}
+
+/**
+ * Split documentation into its description and `# Heading` sections,
+ * translating JSDoc tags (`@param`, `@returns`, `@throws`, `@example`)
+ */
+function parseSections(lines: string[]): {
+  description: string[];
+  sections: Map<string, string[]>;
+} {
+  const description: string[] = [];
+  const sections = new Map<string, string[]>();
+  const section = (name: string) => {
+    if (!sections.has(name)) sections.set(name, []);
+    return sections.get(name)!;
+  };
+
+  let current = description;
+  let inFence = false;
+  for (const line of lines) {
+    if (/^\s*```/.test(line)) {
+      inFence = !inFence;
+      current.push(FOREIGN_FENCE.test(line.trim()) ? '```ignore' : line);
+      continue;
+    }
+    if (inFence) {
+      current.push(line);
+      continue;
+    }
+
+    // `# Examples`; inside fences `# ` hides doctest lines instead
+    const heading = line.match(/^#{1,3}\s+(\w+)\s*$/);
+    if (heading) {
+      current = section(heading[1]);
+      continue;
+    }
+
+    const tag = line.match(/^@(\w+)\s*(?:\{[^}]*\}\s*)?(.*)$/);
+    if (!tag) {
+      current.push(line);
+      continue;
+    }
+
+    const [, name, rest] = tag;
+    if (name === 'example') {
+      current = section('Examples');
+    } else if (name === 'throws' || name === 'throw') {
+      current = section('Errors');
+    } else if (name === 'param') {
+      current = section('Arguments');
+      const param = rest.match(/^\[?(\w+)\]?\s*(?:-\s*)?(.*)$/);
+      current.push(param ? `* \`${param[1]}\` - ${param[2]}` : rest);
+      continue;
+    } else if (name === 'returns' || name === 'return') {
+      current = description;
+      if (rest) current.push('', `Returns ${rest}`);
+      continue;
+    } else {
+      current = []; // @since, @see, ...: dropped
+      continue;
+    }
+    if (rest) current.push(rest);
+  }
+
+  const examples = sections.get('Examples');
+  if (examples && !examples.some(line => /^\s*```/.test(line))) {
+    sections.set('Examples', ['```ignore', ...trimBlank(examples), '```']);
+  }
+  for (const [name, content] of sections) {
+    const trimmed = trimBlank(content);
+    if (trimmed.length > 0) {
+      sections.set(name, trimmed);
+    } else {
+      sections.delete(name);
+    }
+  }
+
+  return { description: trimBlank(description), sections };
+}
+
+function trimBlank(lines: string[]): string[] {
+  let start = 0;
+  let end = lines.length;
+  while (start < end && !lines[start].trim()) start++;
+  while (end > start && !lines[end - 1].trim()) end--;
+  return lines.slice(start, end);
+}
@@ -0,0 +1,292 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { ToolExecutor } from '../utils/toolExecutor';
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import { CargoManifestReader } from './CargoManifestReader';
+import { extractRustDocItems } from './RustDocComments';
+import { listRustSources, readRustSource } from './rustPaths';
+import {
+  CrateDocCoverage,
+  DocCoverageCount,
+  ModuleDocCoverage,
+  RustDocCoverageReport,
+} from '../types/rust';
+
+// Files next to the library root that belong to other targets
+const NON_LIBRARY_FILES = [
+  'main.rs',
+  'build.rs',
+  'bin/**',
+  'tests/**',
+  'examples/**',
+  'benches/**',
+];
+
+// Output of `rustdoc --show-coverage --output-format json`, keyed by file
+type RustdocCoverageOutput = Record<
+  string,
+  { total?: number; with_docs?: number }
+>;
+
+interface LibraryCrate {
+  name: string;
+  manifestPath: string;
+  libPath: string;
+}
+
+/**
+ * Percentage of documented items, 100 when there is nothing to document
+ */
+export function docCoveragePercent(count: DocCoverageCount): number {
+  return count.total === 0
+    ? 100
+    : Math.round((count.documented / count.total) * 1000) / 10;
+}
+
+/**
+ * Module path of a source file below the library root's directory,
+ * e.g. `src/net/http.rs` or `src/net/http/mod.rs` -> `net::http`
+ */
+export function moduleNameForFile(file: string, srcDir: string): string {
+  const relative = path.relative(srcDir, file).split(path.sep).join('/');
+  if (relative.startsWith('..')) {
+    return relative;
+  }
+  const parts = relative.replace(/\.rs$/, '').split('/');
+  if (parts.length > 1 && parts[parts.length - 1] === 'mod') {
+    parts.pop();
+  }
+  return parts.length === 1 && ['lib', 'mod'].includes(parts[0])
+    ? 'crate'
+    : parts.join('::');
+}
+
+/**
+ * Count `pub fn`, `pub struct`, `pub enum` and `pub trait` items outside of
+ * test code and how many of them have a `///` or `#[doc]` comment
+ */
+export function countDocumentedItems(content: string): DocCoverageCount {
+  const items = extractRustDocItems(content).filter(
+    item => item.kind !== 'impl'
+  );
+  return {
+    documented: items.filter(item => item.existingDoc !== undefined).length,
+    total: items.length,
+  };
+}
+
+/**
+ * Parse the per-file JSON coverage table of rustdoc; relative file names
+ * are resolved against the directory cargo ran rustdoc in
+ * @returns null if the output is not a coverage table
+ */
+export function parseRustdocCoverage(
+  output: string,
+  baseDir: string,
+  srcDir: string
+): ModuleDocCoverage[] | null {
+  const start = output.indexOf('{');
+  if (start === -1) {
+    return null;
+  }
+  let parsed: RustdocCoverageOutput | null;
+  try {
+    parsed = safeJsonParse<RustdocCoverageOutput>(output.slice(start));
+  } catch {
+    return null;
+  }
+  if (!parsed || typeof parsed !== 'object' || Array.isArray(parsed)) {
+    return null;
+  }
+
+  return Object.entries(parsed)
+    .filter(([file, counts]) => file.endsWith('.rs') && counts)
+    .map(([file, counts]) => {
+      const absolute = path.resolve(baseDir, file);
+      return {
+        module: moduleNameForFile(absolute, srcDir),
+        file: absolute,
+        documented: counts.with_docs || 0,
+        total: counts.total || 0,
+      };
+    })
+    .sort((a, b) => a.module.localeCompare(b.module));
+}
+
+/**
+ * Source files of modules that contain undocumented public items
+ */
+export function filesMissingDocs(report: RustDocCoverageReport): string[] {
+  return report.crates.flatMap(crate =>
+    crate.modules
+      .filter(module => module.documented < module.total)
+      .map(module => module.file)
+  );
+}
+
+/**
+ * Measures how many public items of each library crate are documented,
+ * per crate and per module. Scans the sources by default; on request uses
+ * `rustdoc --show-coverage` (nightly build), which also counts modules,
+ * fields, variants and methods, so its numbers are not comparable.
+ */
+export class RustDocCoverageAnalyzer {
+  private manifestReader: CargoManifestReader;
+
+  constructor(
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {
+    this.manifestReader = new CargoManifestReader(resolver);
+  }
+
+  /**
+   * @param options.rustdoc Try `rustdoc --show-coverage` first
+   * @returns null if the project has no library crate
+   */
+  async analyze(
+    projectPath: string,
+    options: { rustdoc?: boolean } = {}
+  ): Promise<RustDocCoverageReport | null> {
+    const manifestPath = path.join(path.resolve(projectPath), 'Cargo.toml');
+    if (!(await fs.pathExists(manifestPath))) {
+      return null;
+    }
+    const cargoPackage = await this.resolver.resolveForFile(manifestPath);
+    const workspaceManifestPath =
+      cargoPackage?.workspaceManifestPath || manifestPath;
+    const workspaceManifest = await this.resolver.loadManifest(
+      workspaceManifestPath
+    );
+    if (!workspaceManifest) {
+      return null;
+    }
+
+    const libraries = await this.findLibraryCrates(
+      workspaceManifestPath,
+      workspaceManifest
+    );
+    if (libraries.length === 0) {
+      return null;
+    }
+
+    // One source for all crates keeps the numbers comparable
+    const crates =
+      (options.rustdoc
+        ? await this.runRustdoc(libraries, workspaceManifestPath)
+        : null) || (await this.scanSources(libraries));
+
+    return {
+      source: crates.source,
+      crates: crates.results,
+      documented: crates.results.reduce((sum, c) => sum + c.documented, 0),
+      total: crates.results.reduce((sum, c) => sum + c.total, 0),
+    };
+  }
+
+  private async findLibraryCrates(
+    workspaceManifestPath: string,
+    workspaceManifest: TomlTable
+  ): Promise<LibraryCrate[]> {
+    const members = await this.manifestReader.collectPackageManifests(
+      workspaceManifestPath,
+      workspaceManifest
+    );
+
+    const libraries: LibraryCrate[] = [];
+    for (const [manifestPath, manifest] of members) {
+      const pkg = isTomlTable(manifest.package) ? manifest.package : {};
+      const rootDir = path.dirname(manifestPath);
+      const lib = isTomlTable(manifest.lib) ? manifest.lib : undefined;
+      const libPath = path.resolve(
+        rootDir,
+        typeof lib?.path === 'string' ? lib.path : path.join('src', 'lib.rs')
+      );
+      if (await fs.pathExists(libPath)) {
+        libraries.push({
+          name:
+            typeof pkg.name === 'string' ? pkg.name : path.basename(rootDir),
+          manifestPath,
+          libPath,
+        });
+      }
+    }
+    return libraries;
+  }
+
+  private async runRustdoc(
+    libraries: LibraryCrate[],
+    workspaceManifestPath: string
+  ): Promise<{ source: 'rustdoc'; results: CrateDocCoverage[] } | null> {
+    const results: CrateDocCoverage[] = [];
+    for (const library of libraries) {
+      try {
+        // Missing nightly or a broken build: fall back to the source scan
+        const { stdout, exitCode } =
+          await ToolExecutor.runCargoRustdocCoverage(
+            workspaceManifestPath,
+            library.name
+          );
+        const modules =
+          exitCode === 0
+            ? parseRustdocCoverage(
+                stdout,
+                path.dirname(workspaceManifestPath),
+                path.dirname(library.libPath)
+              )
+            : null;
+        if (!modules) {
+          return null;
+        }
+        results.push(this.toCrateCoverage(library, modules));
+      } catch (error) {
+        console.debug(`rustdoc --show-coverage failed: ${error}`);
+        return null;
+      }
+    }
+    return { source: 'rustdoc', results };
+  }
+
+  private async scanSources(
+    libraries: LibraryCrate[]
+  ): Promise<{ source: 'source-scan'; results: CrateDocCoverage[] }> {
+    const results: CrateDocCoverage[] = [];
+    for (const library of libraries) {
+      const srcDir = path.dirname(library.libPath);
+      const modules: ModuleDocCoverage[] = [];
+      for (const file of await listRustSources(srcDir, NON_LIBRARY_FILES)) {
+        const content = await readRustSource(file);
+        if (content === null) continue;
+        const counts = countDocumentedItems(content);
+        if (counts.total > 0) {
+          modules.push({
+            module: moduleNameForFile(file, srcDir),
+            file,
+            ...counts,
+          });
+        }
+      }
+      results.push(
+        this.toCrateCoverage(
+          library,
+          modules.sort((a, b) => a.module.localeCompare(b.module))
+        )
+      );
+    }
+    return { source: 'source-scan', results };
+  }
+
+  private toCrateCoverage(
+    library: LibraryCrate,
+    modules: ModuleDocCoverage[]
+  ): CrateDocCoverage {
+    return {
+      name: library.name,
+      manifestPath: library.manifestPath,
+      modules,
+      documented: modules.reduce((sum, module) => sum + module.documented, 0),
+      total: modules.reduce((sum, module) => sum + module.total, 0),
+    };
+  }
+}
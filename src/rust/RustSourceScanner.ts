@@ -0,0 +1,457 @@
+/**
+ * Lightweight, dependency-free scanning helpers for Rust source files.
+ *
+ * These are not a Rust parser: they mask comments and literals so that simple
+ * regex and brace matching can locate items (functions, impl blocks, unsafe
+ * blocks, test modules) without being fooled by `{` inside strings or
+ * `unwrap()` inside doc comments.
+ */
+
+/**
+ * A function or method found in a Rust source file
+ */
+export interface RustFunctionItem {
+  name: string; // `Type::method` for items inside impl/trait blocks
+  line: number; // 1-based line of the `fn` keyword
+  column: number; // 1-based column of the `fn` keyword
+  parameters: string[]; // Parameter patterns, excluding `self` receivers
+  bodyStart: number; // Offset of the opening `{`
+  bodyEnd: number; // Offset of the matching `}`
+  isTest: boolean;
+}
+
+/**
+ * A brace-delimited region of a source file (offsets into the content)
+ */
+export interface RustBlockRange {
+  start: number;
+  end: number;
+}
+
+const IDENT_CHAR = /[A-Za-z0-9_]/;
+
+/**
+ * Replace comments and the contents of string/char literals with spaces.
+ * Newlines and string delimiters are kept, so offsets, line numbers and
+ * columns in the masked text match the original content.
+ */
+export function maskRustSource(content: string): string {
+  const out = content.split('');
+  const blank = (from: number, to: number) => {
+    for (let k = from; k < to && k < out.length; k++) {
+      if (out[k] !== '\n' && out[k] !== '\r') out[k] = ' ';
+    }
+  };
+
+  let i = 0;
+  while (i < content.length) {
+    const ch = content[i];
+    const next = content[i + 1];
+
+    // Line comment (including /// and //! doc comments)
+    if (ch === '/' && next === '/') {
+      const end = content.indexOf('\n', i);
+      const stop = end === -1 ? content.length : end;
+      blank(i, stop);
+      i = stop;
+      continue;
+    }
+
+    // Block comment, which nests in Rust
+    if (ch === '/' && next === '*') {
+      let depth = 1;
+      let j = i + 2;
+      while (j < content.length && depth > 0) {
+        if (content[j] === '/' && content[j + 1] === '*') {
+          depth++;
+          j += 2;
+        } else if (content[j] === '*' && content[j + 1] === '/') {
+          depth--;
+          j += 2;
+        } else {
+          j++;
+        }
+      }
+      blank(i, j);
+      i = j;
+      continue;
+    }
+
+    // Raw strings: r"..", r#".."#, br#".."#
+    if (ch === 'r' && isRawStringStart(content, i)) {
+      let j = i + 1;
+      let hashes = 0;
+      while (content[j] === '#') {
+        hashes++;
+        j++;
+      }
+      const terminator = '"' + '#'.repeat(hashes);
+      const close = content.indexOf(terminator, j + 1);
+      const stop = close === -1 ? content.length : close;
+      blank(j + 1, stop);
+      i = stop + terminator.length;
+      continue;
+    }
+
+    // Regular (and byte) strings
+    if (ch === '"') {
+      let j = i + 1;
+      while (j < content.length && content[j] !== '"') {
+        j += content[j] === '\\' ? 2 : 1;
+      }
+      blank(i + 1, j);
+      i = j + 1;
+      continue;
+    }
+
+    // Char literals; anything else starting with ' is a lifetime or label
+    if (ch === "'") {
+      const close = charLiteralEnd(content, i);
+      if (close !== -1) {
+        blank(i + 1, close);
+        i = close + 1;
+        continue;
+      }
+    }
+
+    i++;
+  }
+
+  return out.join('');
+}
+
+function isRawStringStart(content: string, index: number): boolean {
+  const before = content[index - 1];
+  const prefixOk =
+    index === 0 ||
+    !IDENT_CHAR.test(before) ||
+    (before === 'b' && (index < 2 || !IDENT_CHAR.test(content[index - 2])));
+  return prefixOk && /^#*"/.test(content.slice(index + 1, index + 260));
+}
+
+function charLiteralEnd(content: string, start: number): number {
+  if (content[start + 1] === '\\') {
+    const close = content.indexOf("'", start + 3);
+    return close !== -1 && close - start <= 12 ? close : -1;
+  }
+  const codePoint = content.codePointAt(start + 1);
+  if (codePoint === undefined || content[start + 1] === '\n') {
+    return -1;
+  }
+  const width = codePoint > 0xffff ? 2 : 1;
+  return content[start + 1 + width] === "'" ? start + 1 + width : -1;
+}
+
+/**
+ * Find the `}` matching the `{` at `openOffset` in masked source
+ * @returns Offset of the closing brace, or -1 if unbalanced
+ */
+export function findMatchingBrace(masked: string, openOffset: number): number {
+  let depth = 0;
+  for (let i = openOffset; i < masked.length; i++) {
+    if (masked[i] === '{') {
+      depth++;
+    } else if (masked[i] === '}') {
+      depth--;
+      if (depth === 0) return i;
+    }
+  }
+  return -1;
+}
+
+/**
+ * Convert an offset into a 1-based line and column
+ */
+export function offsetToPosition(
+  content: string,
+  offset: number
+): { line: number; column: number } {
+  let line = 1;
+  let lineStart = 0;
+  for (let i = 0; i < offset && i < content.length; i++) {
+    if (content[i] === '\n') {
+      line++;
+      lineStart = i + 1;
+    }
+  }
+  return { line, column: offset - lineStart + 1 };
+}
+
+/**
+ * Regions guarded by `#[cfg(test)]` or a test attribute such as `#[test]`
+ * or `#[tokio::test]`
+ */
+export function findTestRegions(masked: string): RustBlockRange[] {
+  const regions: RustBlockRange[] = [];
+  const attributeRegex =
+    /#\[\s*(?:cfg\s*\(\s*(?:all\s*\([^\]]*)?\btest\b[^\]]*\)|(?:\w+::)*test\b[^\]]*)\s*\]/g;
+
+  let match;
+  while ((match = attributeRegex.exec(masked)) !== null) {
+    if (/\bnot\s*\(/.test(match[0])) continue;
+
+    const itemEnd = findItemEnd(masked, match.index + match[0].length);
+    if (itemEnd !== -1) {
+      regions.push({ start: match.index, end: itemEnd });
+    }
+  }
+
+  return regions;
+}
+
+/**
+ * Find where the item following an attribute ends: its closing brace, or
+ * the terminating `;` for items without a body
+ */
+function findItemEnd(masked: string, from: number): number {
+  for (let i = from; i < masked.length; i++) {
+    if (masked[i] === ';') return i;
+    if (masked[i] === '{') return findMatchingBrace(masked, i);
+  }
+  return -1;
+}
+
+/**
+ * Check whether an offset falls inside any of the given regions
+ */
+export function isInRegions(
+  regions: RustBlockRange[],
+  offset: number
+): boolean {
+  return regions.some(
+    region => offset >= region.start && offset <= region.end
+  );
+}
+
+/**
+ * Locate `unsafe { ... }` blocks (not `unsafe fn`/`unsafe impl` items)
+ */
+export function findUnsafeBlocks(masked: string): RustBlockRange[] {
+  const blocks: RustBlockRange[] = [];
+  const unsafeRegex = /\bunsafe\s*\{/g;
+
+  let match;
+  while ((match = unsafeRegex.exec(masked)) !== null) {
+    const open = match.index + match[0].length - 1;
+    const close = findMatchingBrace(masked, open);
+    if (close !== -1) {
+      blocks.push({ start: match.index, end: close });
+    }
+  }
+
+  return blocks;
+}
+
+/**
+ * Extract functions and methods with their bodies. Trait method
+ * declarations without a default body are skipped.
+ */
+export function extractRustFunctions(masked: string): RustFunctionItem[] {
+  const functions: RustFunctionItem[] = [];
+  const owners = findImplOwners(masked);
+  const testRegions = findTestRegions(masked);
+  const fnRegex = /\bfn\s+([A-Za-z_]\w*)/g;
+
+  let match;
+  while ((match = fnRegex.exec(masked)) !== null) {
+    const signature = readSignature(masked, match.index + match[0].length);
+    if (!signature) continue;
+
+    const bodyEnd = findMatchingBrace(masked, signature.bodyStart);
+    if (bodyEnd === -1) continue;
+
+    const owner = owners
+      .filter(o => match!.index > o.start && match!.index < o.end)
+      .sort((a, b) => b.start - a.start)[0];
+    const position = offsetToPosition(masked, match.index);
+
+    functions.push({
+      name: owner ? `${owner.name}::${match[1]}` : match[1],
+      line: position.line,
+      column: position.column,
+      parameters: splitRustParameters(signature.parameters),
+      bodyStart: signature.bodyStart,
+      bodyEnd,
+      isTest: isInRegions(testRegions, match.index),
+    });
+  }
+
+  return functions;
+}
+
+/**
+ * Read generics, the parameter list and the position of the body after a
+ * function name; returns null for body-less declarations
+ */
+function readSignature(
+  masked: string,
+  from: number
+): { parameters: string; bodyStart: number } | null {
+  let i = from;
+  while (/\s/.test(masked[i] || '')) i++;
+
+  if (masked[i] === '<') {
+    i = skipBalanced(masked, i, '<', '>');
+    if (i === -1) return null;
+    while (/\s/.test(masked[i] || '')) i++;
+  }
+  if (masked[i] !== '(') return null;
+
+  const paramsEnd = skipBalanced(masked, i, '(', ')');
+  if (paramsEnd === -1) return null;
+  const parameters = masked.slice(i + 1, paramsEnd - 1);
+
+  const bodyStart = findDeclarationEnd(masked, paramsEnd);
+  return bodyStart !== -1 && masked[bodyStart] === '{'
+    ? { parameters, bodyStart }
+    : null;
+}
+
+/**
+ * Find the `{` or `;` ending an item declaration, skipping the contents of
+ * brackets such as `[u8; 32]` or `Vec<[u8; 4]>` in types
+ * @returns Offset into the masked source, or -1
+ */
+export function findDeclarationEnd(masked: string, from: number): number {
+  let depth = 0;
+  for (let i = from; i < masked.length; i++) {
+    const ch = masked[i];
+    if ('([<'.includes(ch)) {
+      depth++;
+    } else if (')]'.includes(ch) || (ch === '>' && masked[i - 1] !== '-')) {
+      depth = Math.max(0, depth - 1);
+    } else if ((ch === '{' || ch === ';') && depth === 0) {
+      return i;
+    }
+  }
+  return -1;
+}
+
+/**
+ * Skip a balanced bracket pair starting at `start`, returning the offset
+ * just after the closing bracket (or -1). `->` arrows are not closers.
+ */
+function skipBalanced(
+  text: string,
+  start: number,
+  open: string,
+  close: string
+): number {
+  let depth = 0;
+  for (let i = start; i < text.length; i++) {
+    const ch = text[i];
+    if (ch === open) {
+      depth++;
+    } else if (ch === close && !(close === '>' && text[i - 1] === '-')) {
+      depth--;
+      if (depth === 0) return i + 1;
+    }
+  }
+  return -1;
+}
+
+/**
+ * Split a parameter list on top-level commas and return the parameter
+ * patterns, dropping `self`, `&self`, `&mut self` and `mut self` receivers
+ */
+export function splitRustParameters(raw: string): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let current = '';
+
+  for (let i = 0; i < raw.length; i++) {
+    const ch = raw[i];
+    if ('<([{'.includes(ch)) depth++;
+    if (')]}'.includes(ch) || (ch === '>' && raw[i - 1] !== '-')) depth--;
+
+    if (ch === ',' && depth === 0) {
+      parts.push(current);
+      current = '';
+    } else {
+      current += ch;
+    }
+  }
+  parts.push(current);
+
+  return parts
+    .map(part => part.replace(/#\[[^\]]*\]/g, '').trim())
+    .filter(part => part.length > 0)
+    .filter(part => !/^(?:&\s*(?:'\w+\s+)?)?(?:mut\s+)?self\b/.test(part))
+    .map(part => {
+      const colon = part.search(/:(?!:)/);
+      return (colon === -1 ? part : part.slice(0, colon)).trim();
+    });
+}
+
+/**
+ * Find impl and trait blocks so methods can be reported as `Type::method`
+ */
+function findImplOwners(
+  masked: string
+): Array<RustBlockRange & { name: string }> {
+  const owners: Array<RustBlockRange & { name: string }> = [];
+  const itemRegex =
+    /^[ \t]*(?:pub(?:\s*\([^)]*\))?\s+)?(?:unsafe\s+)?(?:default\s+)?(impl|trait)\b/gm;
+
+  let match;
+  while ((match = itemRegex.exec(masked)) !== null) {
+    const open = masked.indexOf('{', match.index + match[0].length);
+    if (open === -1) continue;
+    const semicolon = masked.indexOf(';', match.index + match[0].length);
+    if (semicolon !== -1 && semicolon < open) continue;
+
+    const header = masked.slice(match.index + match[0].length, open);
+    const name =
+      match[1] === 'trait'
+        ? (header.match(/^\s*([A-Za-z_]\w*)/) || [])[1]
+        : implTypeName(header);
+    const close = findMatchingBrace(masked, open);
+    if (name && close !== -1) {
+      owners.push({ name, start: open, end: close });
+    }
+  }
+
+  return owners;
+}
+
+/**
+ * `<T: Clone> fmt::Display for Wrapper<T> where T: Debug` → `Wrapper`
+ */
+function implTypeName(header: string): string | undefined {
+  let text = header.trim();
+  if (text.startsWith('<')) {
+    const end = skipBalanced(text, 0, '<', '>');
+    text = end === -1 ? '' : text.slice(end);
+  }
+  text = text.replace(/\bwhere\b[\s\S]*$/, '');
+  const forIndex = text.search(/\sfor\s/);
+  if (forIndex !== -1) {
+    text = text.slice(forIndex + 5);
+  }
+  const path = text.trim().replace(/^[&*]\s*(?:'\w+\s+)?(?:mut\s+)?/, '');
+  const base = path.split('<')[0].trim().split('::').pop();
+  return base && /^[A-Za-z_]\w*$/.test(base) ? base : undefined;
+}
+
+/**
+ * Whether a path is library code rather than a binary, build script, test,
+ * example or benchmark target
+ */
+export function isRustLibrarySource(filePath: string): boolean {
+  const segments = filePath.split(/[/\\]/).filter(Boolean);
+  const fileName = segments.pop() || '';
+  if (fileName === 'main.rs' || fileName === 'build.rs') {
+    return false;
+  }
+
+  for (let i = segments.length - 1; i >= 0; i--) {
+    const segment = segments[i];
+    if (['tests', 'examples', 'benches'].includes(segment)) {
+      return false;
+    }
+    if (segment === 'src') {
+      return segments[i + 1] !== 'bin';
+    }
+  }
+  return true;
+}
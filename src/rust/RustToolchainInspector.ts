@@ -0,0 +1,295 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import {
+  isTomlTable,
+  parseToml,
+  TomlParseError,
+  TomlTable,
+} from '../utils/tomlParser';
+import { ToolExecutor } from '../utils/toolExecutor';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import { CargoManifestReader } from './CargoManifestReader';
+import { ClippyDiagnosticsParser } from './ClippyDiagnosticsParser';
+import {
+  MsrvCheckResult,
+  RustCrateMsrv,
+  RustToolchainFile,
+  RustToolchainStatus,
+} from '../types/rust';
+
+// rustup prefers rust-toolchain.toml when both files exist
+const TOOLCHAIN_FILES = ['rust-toolchain.toml', 'rust-toolchain'];
+
+const MAX_REPORTED_ERRORS = 5;
+
+/**
+ * Parse `rustup toolchain list` output into toolchain names, dropping the
+ * "(default)" / "(active)" markers
+ */
+export function parseToolchainList(output: string): string[] {
+  return output
+    .split('\n')
+    .map(line => line.trim().split(/\s+/)[0])
+    .filter(name => name && name !== 'no' && !name.startsWith('info:'));
+}
+
+/**
+ * Whether a toolchain channel names a fixed release rather than a moving
+ * channel (stable, beta, nightly)
+ */
+export function isPinnedChannel(channel: string): boolean {
+  return /^(\d+\.\d+(\.\d+)?|(nightly|beta)-\d{4}-\d{2}-\d{2})$/.test(channel);
+}
+
+/**
+ * Compare two dotted Rust versions ("1.70" equals "1.70.0")
+ */
+export function compareRustVersions(a: string, b: string): number {
+  const left = a.split('.').map(Number);
+  const right = b.split('.').map(Number);
+  for (let i = 0; i < 3; i++) {
+    const diff = (left[i] || 0) - (right[i] || 0);
+    if (diff !== 0) return diff;
+  }
+  return 0;
+}
+
+/**
+ * Installed toolchain that can build an MSRV: same minor release and at
+ * least the declared patch level (the newest patch wins)
+ */
+export function findMsrvToolchain(
+  msrv: string,
+  toolchains: string[]
+): string | undefined {
+  const [major, minor, patch = 0] = msrv.split('.').map(Number);
+  const candidates = toolchains
+    .map(name => ({ name, version: name.match(/^(\d+\.\d+(?:\.\d+)?)/)?.[1] }))
+    .filter((candidate): candidate is { name: string; version: string } => {
+      if (!candidate.version) return false;
+      const [tMajor, tMinor, tPatch = Infinity] = candidate.version
+        .split('.')
+        .map(Number);
+      return tMajor === major && tMinor === minor && tPatch >= patch;
+    })
+    .sort((a, b) => compareRustVersions(b.version, a.version));
+  return candidates[0]?.name;
+}
+
+/**
+ * Reads the declared MSRV (`rust-version`) of every workspace package and
+ * the pinned toolchain and, on request, compares them with the toolchains
+ * installed via rustup and runs `cargo check` on the MSRV toolchain.
+ */
+export class RustToolchainInspector {
+  private manifestReader: CargoManifestReader;
+
+  constructor(
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {
+    this.manifestReader = new CargoManifestReader(resolver);
+  }
+
+  /**
+   * @param options.listToolchains Also look up the installed toolchains
+   * (runs `rustup toolchain list`)
+   * @returns null if the project is not a Cargo project
+   */
+  async inspect(
+    projectPath: string,
+    options: { listToolchains?: boolean } = {}
+  ): Promise<RustToolchainStatus | null> {
+    const manifestPath = path.join(path.resolve(projectPath), 'Cargo.toml');
+    const cargoPackage = await this.resolver.resolveForFile(manifestPath);
+    const workspaceManifestPath =
+      cargoPackage?.workspaceManifestPath || manifestPath;
+    const workspaceManifest = await this.resolver.loadManifest(
+      workspaceManifestPath
+    );
+    if (!workspaceManifest) {
+      return null;
+    }
+
+    const workspaceRoot = path.dirname(workspaceManifestPath);
+    const crates = await this.readCrates(
+      workspaceManifestPath,
+      workspaceManifest
+    );
+    const declared = crates
+      .map(crate => crate.rustVersion)
+      .filter((version): version is string => Boolean(version))
+      .sort(compareRustVersions);
+
+    const status: RustToolchainStatus = {
+      crates,
+      msrv: declared[0],
+      toolchainFile: await this.readToolchainFile(
+        path.resolve(projectPath),
+        workspaceRoot
+      ),
+    };
+
+    if (options.listToolchains) {
+      await this.detectMsrvToolchain(status);
+    }
+
+    return status;
+  }
+
+  /**
+   * Fill in the installed toolchains and the one matching the MSRV
+   */
+  async detectMsrvToolchain(status: RustToolchainStatus): Promise<void> {
+    status.installedToolchains = await this.listToolchains();
+    if (status.msrv && status.installedToolchains) {
+      status.msrvToolchain = findMsrvToolchain(
+        status.msrv,
+        status.installedToolchains
+      );
+    }
+  }
+
+  private async readCrates(
+    workspaceManifestPath: string,
+    workspaceManifest: TomlTable
+  ): Promise<RustCrateMsrv[]> {
+    const members = await this.manifestReader.collectPackageManifests(
+      workspaceManifestPath,
+      workspaceManifest
+    );
+    const workspacePackage =
+      isTomlTable(workspaceManifest.workspace) &&
+      isTomlTable(workspaceManifest.workspace.package)
+        ? workspaceManifest.workspace.package
+        : {};
+
+    const crates: RustCrateMsrv[] = [];
+    for (const [manifestPath, manifest] of members) {
+      const pkg = isTomlTable(manifest.package) ? manifest.package : {};
+      const rootDir = path.dirname(manifestPath);
+      // rust-version.workspace = true
+      const rustVersion =
+        isTomlTable(pkg['rust-version']) &&
+        pkg['rust-version'].workspace === true
+          ? workspacePackage['rust-version']
+          : pkg['rust-version'];
+
+      crates.push({
+        name:
+          typeof pkg.name === 'string' ? pkg.name : path.basename(rootDir),
+        manifestPath,
+        rustVersion: typeof rustVersion === 'string' ? rustVersion : undefined,
+        library:
+          isTomlTable(manifest.lib) ||
+          (await fs.pathExists(path.join(rootDir, 'src', 'lib.rs'))),
+        binary:
+          (Array.isArray(manifest.bin) && manifest.bin.length > 0) ||
+          (await fs.pathExists(path.join(rootDir, 'src', 'main.rs'))) ||
+          (await fs.pathExists(path.join(rootDir, 'src', 'bin'))),
+      });
+    }
+
+    return crates;
+  }
+
+  private async readToolchainFile(
+    ...dirs: string[]
+  ): Promise<RustToolchainFile | undefined> {
+    for (const dir of new Set(dirs)) {
+      for (const name of TOOLCHAIN_FILES) {
+        const filePath = path.join(dir, name);
+        if (!(await fs.pathExists(filePath))) continue;
+
+        const channel = parseToolchainChannel(
+          await fs.readFile(filePath, 'utf-8')
+        );
+        return {
+          path: filePath,
+          channel,
+          pinned: channel !== undefined && isPinnedChannel(channel),
+        };
+      }
+    }
+    return undefined;
+  }
+
+  private async listToolchains(): Promise<string[] | null> {
+    try {
+      const { stdout, exitCode } = await ToolExecutor.runRustupToolchainList();
+      return exitCode === 0 ? parseToolchainList(stdout) : null;
+    } catch {
+      // rustup not installed (e.g. distro-packaged Rust)
+      return null;
+    }
+  }
+
+  /**
+   * Run `cargo check` for the workspace on the given (MSRV) toolchain
+   * @param manifestPath - Workspace root Cargo.toml
+   */
+  async checkMsrv(
+    manifestPath: string,
+    toolchain: string
+  ): Promise<MsrvCheckResult> {
+    try {
+      const { stdout, stderr, exitCode } = await ToolExecutor.runCargoCheck(
+        manifestPath,
+        toolchain
+      );
+      if (exitCode === 0) {
+        return { toolchain, success: true, errors: [] };
+      }
+
+      const workspaceRoot = path.dirname(manifestPath);
+      const diagnostics = ClippyDiagnosticsParser.parse(
+        stdout,
+        workspaceRoot
+      ).filter(diagnostic => diagnostic.level === 'error');
+      // Manifest, lockfile and feature errors are only printed to stderr
+      const errors =
+        diagnostics.length > 0
+          ? diagnostics.map(diagnostic => {
+              const file = path.relative(workspaceRoot, diagnostic.file);
+              return `${file}: ${ClippyDiagnosticsParser.formatIssue(diagnostic)}`;
+            })
+          : stderr
+              .split('\n')
+              .filter(line => line.startsWith('error'))
+              .map(line => line.trim());
+
+      return {
+        toolchain,
+        success: false,
+        errors: (errors.length > 0
+          ? errors
+          : [`cargo check exited with code ${exitCode}`]
+        ).slice(0, MAX_REPORTED_ERRORS),
+      };
+    } catch (error) {
+      return {
+        toolchain,
+        success: false,
+        errors: [error instanceof Error ? error.message : String(error)],
+      };
+    }
+  }
+}
+
+/**
+ * Channel of a toolchain file: `[toolchain] channel = "..."` or, for the
+ * legacy rust-toolchain file, the bare channel name
+ */
+export function parseToolchainChannel(content: string): string | undefined {
+  try {
+    const toml = parseToml(content);
+    if (isTomlTable(toml.toolchain)) {
+      const channel = toml.toolchain.channel;
+      return typeof channel === 'string' ? channel : undefined;
+    }
+  } catch (error) {
+    if (!(error instanceof TomlParseError)) throw error;
+  }
+
+  const bare = content.trim();
+  return /^[\w.-]+$/.test(bare) ? bare : undefined;
+}
@@ -0,0 +1,739 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import * as semver from 'semver';
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { ToolExecutor } from '../utils/toolExecutor';
+import { GitDiffAnalyzer } from '../utils/GitDiffAnalyzer';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import {
+  CargoPackage,
+  SemverChange,
+  SemverCheckResult,
+  SemverLevel,
+} from '../types/rust';
+
+type RustdocId = number | string; // Integers since format version 40
+
+// Subset of the rustdoc JSON output (`--output-format json`)
+interface RustdocCrate {
+  root: RustdocId;
+  index: Record<string, RustdocItem>;
+}
+
+interface RustdocItem {
+  name?: string | null;
+  visibility?: unknown; // 'public', 'default', 'crate' or { restricted }
+  attrs?: unknown[];
+  inner: Record<string, unknown>; // Single key naming the item kind
+}
+
+/**
+ * An item of a crate's public API, keyed by its path in the API map
+ */
+export interface PublicApiItem {
+  kind: string; // module, function, struct, variant, method, ...
+  signature: string; // Canonical JSON of the type information
+  parent?: string; // Path of the owning type or trait
+  // Adding it breaks users: trait items without a default and variants of
+  // exhaustive enums
+  required?: boolean;
+}
+
+// Keys holding the default of a trait item (`default` in older formats);
+// a function's has_body is false when it has none
+const DEFAULT_KEYS: Record<string, string[]> = {
+  function: ['has_body'],
+  assoc_type: ['type', 'default'],
+  assoc_const: ['value', 'default'],
+};
+
+const LEVELS: Array<SemverLevel | 'none'> = ['none', 'patch', 'minor', 'major'];
+
+// Identifiers, locations and docs differ between builds of the same API
+const IGNORED_KEYS = new Set([
+  'id',
+  'crate_id',
+  'span',
+  'docs',
+  'links',
+  'attrs',
+  'deprecation',
+]);
+
+/**
+ * Kind of a version bump in Cargo's terms, where the left-most non-zero
+ * component is the major version (0.1.0 -> 0.2.0 is a major bump,
+ * 0.1.0 -> 0.1.1 a minor one)
+ */
+export function classifyVersionBump(
+  baseVersion: string,
+  currentVersion: string
+): SemverLevel | 'none' {
+  const base = semver.parse(baseVersion);
+  const current = semver.parse(currentVersion);
+  if (!base || !current || semver.compare(currentVersion, baseVersion) <= 0) {
+    return 'none';
+  }
+
+  const components = (version: semver.SemVer) => [
+    version.major,
+    version.minor,
+    version.patch,
+  ];
+  const before = components(base);
+  const after = components(current);
+  const leading = before.findIndex(part => part !== 0);
+  const changed = after.findIndex((part, index) => part !== before[index]);
+  if (changed === -1) {
+    return 'patch'; // Pre-release or build metadata only
+  }
+
+  const shift = leading === -1 ? 2 : leading;
+  if (changed <= shift) return 'major';
+  return changed === shift + 1 ? 'minor' : 'patch';
+}
+
+export function isBumpSufficient(
+  actual: SemverLevel | 'none',
+  required: SemverLevel
+): boolean {
+  return (
+    required === 'patch' || LEVELS.indexOf(actual) >= LEVELS.indexOf(required)
+  );
+}
+
+/**
+ * Collect the failed lints of `cargo semver-checks` output:
+ *
+ *     --- failure function_missing: pub fn removed or renamed ---
+ *     ...
+ *     Failed in:
+ *       function demo::helper, previously in file src/lib.rs:5
+ *
+ *          Summary semver requires new major version: 1 major and ...
+ */
+export function parseSemverChecksOutput(output: string): {
+  requiredBump: SemverLevel;
+  changes: SemverChange[];
+} {
+  const summary = output.match(/semver requires new (major|minor) version/);
+  const requiredBump: SemverLevel = summary
+    ? (summary[1] as SemverLevel)
+    : 'patch';
+  const changes: SemverChange[] = [];
+  let lint: { id: string; title: string } | null = null;
+  let inFailedIn = false;
+
+  for (const line of output.split('\n')) {
+    const header = line.match(/^--- failure ([\w-]+): (.*?) ---\s*$/);
+    if (header) {
+      lint = { id: header[1], title: header[2] };
+      inFailedIn = false;
+    } else if (lint && /^Failed in:/.test(line)) {
+      inFailedIn = true;
+    } else if (lint && inFailedIn && /^\s+\S/.test(line)) {
+      // "  trait method demo::Loader::load in file src/lib.rs:3"
+      const item = line.match(
+        /^\s+(?:([a-z][a-z ]*?)\s+)?([A-Za-z_]\w*(?:::[A-Za-z_]\w*)+)/
+      );
+      changes.push({
+        kind: lintChangeKind(lint.id),
+        path: item ? item[2] : line.trim(),
+        itemKind: item?.[1],
+        level: requiredBump === 'patch' ? 'major' : requiredBump,
+        lint: lint.id,
+        detail: lint.title,
+      });
+    } else {
+      inFailedIn = false; // A blank or unindented line ends the list
+    }
+  }
+
+  return {
+    requiredBump:
+      changes.length > 0 && requiredBump === 'patch' ? 'major' : requiredBump,
+    changes,
+  };
+}
+
+function lintChangeKind(lint: string): SemverChange['kind'] {
+  if (/^trait_.*(added|required)|_required_.*added/.test(lint)) {
+    return 'trait-item-added';
+  }
+  return /missing|removed/.test(lint) ? 'removed' : 'signature-changed';
+}
+
+/**
+ * Flatten the public API in rustdoc JSON into paths (`demo::Config::load`)
+ * and canonical signatures, following `pub use` re-exports within the crate
+ */
+export function extractPublicApi(
+  doc: RustdocCrate
+): Map<string, PublicApiItem> {
+  const api = new Map<string, PublicApiItem>();
+  const lookup = (id: unknown): RustdocItem | undefined =>
+    id === null || id === undefined ? undefined : doc.index[String(id)];
+  const isPublic = (item: RustdocItem) => item.visibility === 'public';
+
+  const visitModule = (module: RustdocItem, prefix: string) => {
+    for (const id of idList(innerOf(module, 'module').items)) {
+      const child = lookup(id);
+      if (child && isPublic(child)) visitItem(child, prefix);
+    }
+  };
+
+  const addFields = (ids: unknown, owner: string) => {
+    for (const id of idList(ids)) {
+      const field = lookup(id);
+      if (field?.name && isPublic(field)) {
+        api.set(`${owner}::${field.name}`, {
+          kind: 'struct_field',
+          signature: canonicalize(field.inner.struct_field),
+          parent: owner,
+        });
+      }
+    }
+  };
+
+  const fieldType = (id: unknown) => {
+    const field = lookup(id);
+    return field ? normalize(field.inner.struct_field) : null;
+  };
+
+  // 'plain', { tuple: [types] } or { struct: { name: type } }
+  const variantShape = (variant: RustdocItem): unknown => {
+    const shape = innerOf(variant, 'variant').kind;
+    if (!isRecord(shape)) return shape;
+    if (isRecord(shape.struct)) {
+      return {
+        struct: Object.fromEntries(
+          idList(shape.struct.fields).map(id => [
+            lookup(id)?.name,
+            fieldType(id),
+          ])
+        ),
+      };
+    }
+    return { tuple: idList(shape.tuple, true).map(fieldType) };
+  };
+
+  const visitItem = (item: RustdocItem, prefix: string, alias?: string) => {
+    const [kind, value] = Object.entries(item.inner)[0] || [];
+    if (!kind) return;
+    const inner = isRecord(value) ? value : {};
+
+    if (kind === 'use') {
+      // Re-exports of other crates have no entry in the index
+      const target = lookup(inner.id);
+      if (!target) return;
+      if (inner.is_glob) {
+        if ('module' in target.inner) visitModule(target, prefix);
+      } else {
+        visitItem(target, prefix, String(inner.name));
+      }
+      return;
+    }
+
+    const name = alias || item.name;
+    const itemPath = `${prefix}::${name}`;
+    if (!name || api.has(itemPath)) return;
+
+    switch (kind) {
+      case 'module':
+        api.set(itemPath, { kind, signature: '' });
+        visitModule(item, itemPath);
+        return;
+      case 'function':
+        api.set(itemPath, {
+          kind,
+          signature: canonicalize(withoutKeys(inner, ['has_body'])),
+        });
+        return;
+      case 'struct': {
+        const shape = inner.kind; // 'unit', { plain } or { tuple }
+        if (isRecord(shape) && isRecord(shape.plain)) {
+          api.set(itemPath, {
+            kind,
+            signature: canonicalize({
+              generics: inner.generics,
+              plain: { hasPrivateFields: shape.plain.has_stripped_fields },
+            }),
+          });
+          addFields(shape.plain.fields, itemPath);
+        } else {
+          api.set(itemPath, {
+            kind,
+            signature: canonicalize({
+              generics: inner.generics,
+              kind: isRecord(shape)
+                ? { tuple: idList(shape.tuple, true).map(fieldType) }
+                : shape,
+            }),
+          });
+        }
+        break;
+      }
+      case 'union':
+        api.set(itemPath, {
+          kind,
+          signature: canonicalize({
+            generics: inner.generics,
+            hasPrivateFields: inner.has_stripped_fields,
+          }),
+        });
+        addFields(inner.fields, itemPath);
+        break;
+      case 'enum': {
+        const exhaustive = !JSON.stringify(item.attrs || []).includes(
+          'non_exhaustive'
+        );
+        api.set(itemPath, {
+          kind,
+          signature: canonicalize({ generics: inner.generics, exhaustive }),
+        });
+        for (const id of idList(inner.variants)) {
+          const variant = lookup(id);
+          if (!variant?.name) continue;
+          api.set(`${itemPath}::${variant.name}`, {
+            kind: 'variant',
+            signature: canonicalize(variantShape(variant)),
+            parent: itemPath,
+            required: exhaustive,
+          });
+        }
+        break;
+      }
+      case 'trait':
+        api.set(itemPath, {
+          kind,
+          signature: canonicalize(
+            withoutKeys(inner, ['items', 'implementations'])
+          ),
+        });
+        for (const id of idList(inner.items)) {
+          const traitItem = lookup(id);
+          const [itemKind, itemValue] =
+            Object.entries(traitItem?.inner || {})[0] || [];
+          if (!traitItem?.name || !itemKind || !isRecord(itemValue)) continue;
+          // The default body, type or value is not part of the contract
+          const defaultKeys = DEFAULT_KEYS[itemKind] || [];
+          api.set(`${itemPath}::${traitItem.name}`, {
+            kind: itemKind,
+            signature: canonicalize(withoutKeys(itemValue, defaultKeys)),
+            parent: itemPath,
+            required: !defaultKeys.some(
+              key =>
+                itemValue[key] !== null &&
+                itemValue[key] !== undefined &&
+                itemValue[key] !== false
+            ),
+          });
+        }
+        return;
+      default:
+        // Constants, statics and type aliases; macros only by name
+        api.set(itemPath, {
+          kind,
+          signature: kind.includes('macro') ? '' : canonicalize(inner),
+        });
+        return;
+    }
+
+    // Inherent methods of structs, unions and enums
+    for (const implId of idList(inner.impls)) {
+      const impl = innerOf(lookup(implId), 'impl');
+      if (impl.trait) continue;
+      for (const id of idList(impl.items)) {
+        const method = lookup(id);
+        if (!method?.name || !isPublic(method) || !method.inner.function) {
+          continue;
+        }
+        api.set(`${itemPath}::${method.name}`, {
+          kind: 'method',
+          signature: canonicalize(
+            withoutKeys(innerOf(method, 'function'), ['has_body'])
+          ),
+          parent: itemPath,
+        });
+      }
+    }
+  };
+
+  const root = lookup(doc.root);
+  if (root?.name) {
+    visitModule(root, root.name);
+  }
+  return api;
+}
+
+/**
+ * Compare two public APIs: removed items, changed signatures and new items
+ * users must now provide are breaking, other additions are minor
+ */
+export function diffPublicApi(
+  base: Map<string, PublicApiItem>,
+  current: Map<string, PublicApiItem>
+): SemverChange[] {
+  const changes: SemverChange[] = [];
+  // Children of removed or added items are reported with their parent
+  const reportedWithParent = (
+    item: PublicApiItem,
+    other: Map<string, PublicApiItem>
+  ) => item.parent !== undefined && !other.has(item.parent);
+
+  for (const [itemPath, before] of base) {
+    const after = current.get(itemPath);
+    if (!after) {
+      if (!reportedWithParent(before, current)) {
+        changes.push({
+          kind: 'removed',
+          path: itemPath,
+          itemKind: before.kind,
+          level: 'major',
+        });
+      }
+    } else if (
+      after.kind !== before.kind ||
+      after.signature !== before.signature
+    ) {
+      changes.push({
+        kind: 'signature-changed',
+        path: itemPath,
+        itemKind: after.kind,
+        level: 'major',
+      });
+    } else if (after.required && !before.required && after.kind !== 'variant') {
+      changes.push({
+        kind: 'signature-changed',
+        path: itemPath,
+        itemKind: after.kind,
+        level: 'major',
+        detail: 'no longer has a default',
+      });
+    }
+  }
+
+  for (const [itemPath, after] of current) {
+    if (base.has(itemPath) || reportedWithParent(after, base)) continue;
+    if (after.required) {
+      changes.push({
+        kind: after.kind === 'variant' ? 'added' : 'trait-item-added',
+        path: itemPath,
+        itemKind: after.kind,
+        level: 'major',
+        detail:
+          after.kind === 'variant'
+            ? 'new variant of an exhaustive enum'
+            : 'new required trait item',
+      });
+    } else {
+      changes.push({
+        kind: 'added',
+        path: itemPath,
+        itemKind: after.kind,
+        level: 'minor',
+      });
+    }
+  }
+
+  return changes;
+}
+
+/**
+ * Compares the public API of changed library crates against the base
+ * revision, checked out into a temporary git worktree. Uses
+ * cargo-semver-checks when installed and a diff of the rustdoc JSON of
+ * both revisions (nightly toolchain) otherwise.
+ */
+export class SemverChecker {
+  constructor(
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {}
+
+  async check(
+    projectPath: string,
+    changedFiles: string[],
+    baseBranch: string = 'main'
+  ): Promise<SemverCheckResult[]> {
+    const packages = await this.findChangedLibraries(changedFiles);
+    if (packages.length === 0) {
+      return [];
+    }
+
+    const gitAnalyzer = new GitDiffAnalyzer(projectPath);
+    const worktree = await gitAnalyzer.createWorktree(
+      await gitAnalyzer.getMergeBase(baseBranch)
+    );
+    const results: SemverCheckResult[] = [];
+    let semverChecksInstalled = true;
+
+    try {
+      for (const cargoPackage of packages) {
+        const baseManifestPath = path.join(
+          worktree,
+          path.relative(path.resolve(projectPath), cargoPackage.manifestPath)
+        );
+        const basePackage = await this.resolver.resolveForFile(
+          baseManifestPath
+        );
+        // New crates have no published API to break
+        if (!basePackage || basePackage.name !== cargoPackage.name) continue;
+
+        let result: SemverCheckResult | null = null;
+        if (semverChecksInstalled) {
+          result = await this.runSemverChecks(cargoPackage, basePackage);
+          semverChecksInstalled = result !== null;
+        }
+        results.push(
+          result || (await this.runRustdocDiff(cargoPackage, basePackage))
+        );
+      }
+    } finally {
+      await gitAnalyzer.removeWorktree(worktree);
+    }
+
+    return results;
+  }
+
+  /**
+   * Published library crates owning one of the changed files
+   */
+  private async findChangedLibraries(
+    changedFiles: string[]
+  ): Promise<CargoPackage[]> {
+    const packages = new Map<string, CargoPackage>();
+    for (const file of changedFiles) {
+      const cargoPackage = await this.resolver.resolveForFile(file);
+      if (!cargoPackage || packages.has(cargoPackage.manifestPath)) continue;
+
+      const manifest = await this.resolver.loadManifest(
+        cargoPackage.manifestPath
+      );
+      const packageTable = isTomlTable(manifest?.package)
+        ? manifest.package
+        : {};
+      const isLibrary =
+        isTomlTable(manifest?.lib) ||
+        (await fs.pathExists(path.join(cargoPackage.rootDir, 'src', 'lib.rs')));
+      if (isLibrary && packageTable.publish !== false) {
+        packages.set(cargoPackage.manifestPath, cargoPackage);
+      }
+    }
+    return [...packages.values()];
+  }
+
+  /**
+   * @returns null if cargo-semver-checks is not installed
+   */
+  private async runSemverChecks(
+    cargoPackage: CargoPackage,
+    basePackage: CargoPackage
+  ): Promise<SemverCheckResult | null> {
+    const { stdout, stderr, exitCode } =
+      await ToolExecutor.runCargoSemverChecks(
+        cargoPackage.manifestPath,
+        cargoPackage.name,
+        basePackage.workspaceRoot
+      );
+    if (stderr.includes('no such command')) {
+      return null;
+    }
+
+    const output = `${stdout}\n${stderr}`;
+    const { requiredBump, changes } = parseSemverChecksOutput(output);
+    return this.createResult(
+      cargoPackage,
+      basePackage,
+      'cargo-semver-checks',
+      requiredBump,
+      changes,
+      exitCode !== 0 && changes.length === 0
+        ? firstErrorLine(output, 'cargo semver-checks failed')
+        : undefined
+    );
+  }
+
+  private async runRustdocDiff(
+    cargoPackage: CargoPackage,
+    basePackage: CargoPackage
+  ): Promise<SemverCheckResult> {
+    const [current, base] = await Promise.all([
+      this.buildPublicApi(cargoPackage),
+      this.buildPublicApi(basePackage),
+    ]);
+    if (typeof current === 'string') {
+      return this.createResult(
+        cargoPackage,
+        basePackage,
+        'rustdoc-json',
+        'patch',
+        [],
+        current
+      );
+    }
+    if (typeof base === 'string') {
+      return this.createResult(
+        cargoPackage,
+        basePackage,
+        'rustdoc-json',
+        'patch',
+        [],
+        `${base} (base revision)`
+      );
+    }
+
+    const changes = diffPublicApi(base, current);
+    const requiredBump = changes.some(change => change.level === 'major')
+      ? 'major'
+      : changes.length > 0
+        ? 'minor'
+        : 'patch';
+    return this.createResult(
+      cargoPackage,
+      basePackage,
+      'rustdoc-json',
+      requiredBump,
+      changes
+    );
+  }
+
+  /**
+   * @returns The public API, or the error that prevented building it
+   */
+  private async buildPublicApi(
+    cargoPackage: CargoPackage
+  ): Promise<Map<string, PublicApiItem> | string> {
+    const targetDir = path.join(cargoPackage.workspaceRoot, 'target');
+    const { stderr, exitCode } = await ToolExecutor.runCargoRustdocJson(
+      cargoPackage.manifestPath,
+      cargoPackage.name,
+      targetDir
+    );
+    if (exitCode !== 0) {
+      return firstErrorLine(
+        stderr,
+        'rustdoc JSON could not be generated (requires a nightly toolchain)'
+      );
+    }
+
+    const manifest = await this.resolver.loadManifest(
+      cargoPackage.manifestPath
+    );
+    const libName =
+      isTomlTable(manifest?.lib) && typeof manifest.lib.name === 'string'
+        ? manifest.lib.name
+        : cargoPackage.name.replace(/-/g, '_');
+    const jsonPath = path.join(targetDir, 'doc', `${libName}.json`);
+    const doc = (await fs.pathExists(jsonPath))
+      ? safeJsonParse<RustdocCrate>(await fs.readFile(jsonPath, 'utf-8'))
+      : null;
+    return doc?.index ? extractPublicApi(doc) : `${jsonPath} is missing`;
+  }
+
+  private async createResult(
+    cargoPackage: CargoPackage,
+    basePackage: CargoPackage,
+    runner: SemverCheckResult['runner'],
+    requiredBump: SemverLevel,
+    changes: SemverChange[],
+    error?: string
+  ): Promise<SemverCheckResult> {
+    const [baseVersion, currentVersion] = await Promise.all([
+      this.packageVersion(basePackage),
+      this.packageVersion(cargoPackage),
+    ]);
+    const actualBump = classifyVersionBump(baseVersion, currentVersion);
+    const bumpTooSmall = !error && !isBumpSufficient(actualBump, requiredBump);
+
+    return {
+      packageName: cargoPackage.name,
+      manifestPath: cargoPackage.manifestPath,
+      runner,
+      baseVersion,
+      currentVersion,
+      requiredBump,
+      actualBump,
+      // Only breaking changes fail a review; additions are informational
+      violation: bumpTooSmall && requiredBump === 'major',
+      bumpTooSmall,
+      changes,
+      error,
+    };
+  }
+
+  /**
+   * Package version, resolving `version.workspace = true`
+   */
+  private async packageVersion(cargoPackage: CargoPackage): Promise<string> {
+    if (cargoPackage.version) {
+      return cargoPackage.version;
+    }
+    const workspace = await this.resolver.loadManifest(
+      cargoPackage.workspaceManifestPath
+    );
+    const inherited = workspacePackageTable(workspace).version;
+    // Cargo's default for manifests without a version
+    return typeof inherited === 'string' ? inherited : '0.0.0';
+  }
+}
+
+function workspacePackageTable(manifest: TomlTable | null): TomlTable {
+  const workspace = isTomlTable(manifest?.workspace) ? manifest.workspace : {};
+  return isTomlTable(workspace.package) ? workspace.package : {};
+}
+
+function firstErrorLine(output: string, fallback: string): string {
+  const line = output
+    .split('\n')
+    .find(candidate => /\berror\b/.test(candidate));
+  return line ? line.trim() : fallback;
+}
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null && !Array.isArray(value);
+}
+
+function innerOf(
+  item: RustdocItem | undefined,
+  kind: string
+): Record<string, unknown> {
+  const inner = item?.inner[kind];
+  return isRecord(inner) ? inner : {};
+}
+
+/**
+ * Item ids of a list; tuple fields keep null for private fields
+ */
+function idList(value: unknown, keepNull: boolean = false): unknown[] {
+  if (!Array.isArray(value)) return [];
+  return keepNull ? value : value.filter(id => id !== null);
+}
+
+function withoutKeys(
+  value: Record<string, unknown>,
+  keys: string[]
+): Record<string, unknown> {
+  return Object.fromEntries(
+    Object.entries(value).filter(([key]) => !keys.includes(key))
+  );
+}
+
+function normalize(value: unknown): unknown {
+  if (Array.isArray(value)) {
+    return value.map(normalize);
+  }
+  if (isRecord(value)) {
+    return Object.fromEntries(
+      Object.keys(value)
+        .filter(key => !IGNORED_KEYS.has(key))
+        .sort()
+        .map(key => [key, normalize(value[key])])
+    );
+  }
+  return value;
+}
+
+function canonicalize(value: unknown): string {
+  return JSON.stringify(normalize(value)) ?? '';
+}
@@ -0,0 +1,340 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import { CargoManifestReader } from './CargoManifestReader';
+import { isRegistryPackage, readCargoLock } from './CargoLockfile';
+import { maskRustSource, findUnsafeBlocks } from './RustSourceScanner';
+import { cargoHome, listRustSources, readRustSource } from './rustPaths';
+import {
+  UnsafeCounts,
+  UnsafeCrateInventory,
+  UnsafeFileInventory,
+  UnsafeInventory,
+} from '../types/rust';
+
+const FORBID_UNSAFE_ATTRIBUTE =
+  /#!\[\s*forbid\s*\(([^)\]]*\bunsafe_code\b[^)\]]*)\)\s*\]/;
+
+/**
+ * Count unsafe blocks, unsafe fns, unsafe impls and extern blocks in a
+ * Rust source file (comments and string literals are ignored)
+ */
+export function countUnsafe(content: string): UnsafeCounts {
+  const masked = maskRustSource(content);
+  const count = (regex: RegExp) => (masked.match(regex) || []).length;
+
+  return {
+    unsafeBlocks: findUnsafeBlocks(masked).length,
+    // unsafe fn, unsafe extern "C" fn
+    unsafeFns: count(/\bunsafe\s+(?:extern\s*(?:"[^"]*"\s*)?)?fn\b/g),
+    unsafeImpls: count(/\bunsafe\s+impl\b/g),
+    // extern "C" { ... } and (edition 2024) unsafe extern "C" { ... }
+    externBlocks: count(/\bextern\s*(?:"[^"]*"\s*)?\{/g),
+  };
+}
+
+export function totalUnsafe(counts: UnsafeCounts): number {
+  return (
+    counts.unsafeBlocks +
+    counts.unsafeFns +
+    counts.unsafeImpls +
+    counts.externBlocks
+  );
+}
+
+/**
+ * Whether a crate root declares `#![forbid(unsafe_code)]`
+ */
+export function forbidsUnsafeCode(content: string): boolean {
+  return FORBID_UNSAFE_ATTRIBUTE.test(maskRustSource(content));
+}
+
+/**
+ * Builds an inventory of unsafe code for the crates of a Cargo workspace
+ * and, optionally, for every registry dependency in Cargo.lock whose
+ * sources are unpacked in Cargo's registry cache or a `vendor/` directory
+ * (no network access, no compilation - unlike cargo-geiger).
+ */
+export class UnsafeInventoryScanner {
+  private manifestReader: CargoManifestReader;
+
+  constructor(
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver(),
+    private registrySrcDir: string = path.join(cargoHome(), 'registry', 'src')
+  ) {
+    this.manifestReader = new CargoManifestReader(resolver);
+  }
+
+  /**
+   * @param options.includeDependencies Also scan the registry dependencies
+   * in Cargo.lock (off by default: this reads every unpacked dependency)
+   * @returns null if the project is not a Cargo project
+   */
+  async scan(
+    projectPath: string,
+    options: { includeDependencies?: boolean } = {}
+  ): Promise<UnsafeInventory | null> {
+    const cargoPackage = await this.resolver.resolveForFile(
+      path.join(projectPath, 'Cargo.toml')
+    );
+    const workspaceManifestPath =
+      cargoPackage?.workspaceManifestPath ||
+      path.join(path.resolve(projectPath), 'Cargo.toml');
+    const workspaceManifest = await this.resolver.loadManifest(
+      workspaceManifestPath
+    );
+    if (!workspaceManifest) {
+      return null;
+    }
+
+    const workspaceRoot = path.dirname(workspaceManifestPath);
+    const inventory: UnsafeInventory = {
+      crates: await this.scanWorkspaceCrates(
+        workspaceManifestPath,
+        workspaceManifest
+      ),
+      unresolvedDependencies: [],
+    };
+
+    if (options.includeDependencies) {
+      await this.scanDependencies(workspaceRoot, inventory);
+    }
+
+    return inventory;
+  }
+
+  private async scanWorkspaceCrates(
+    workspaceManifestPath: string,
+    workspaceManifest: TomlTable
+  ): Promise<UnsafeCrateInventory[]> {
+    const workspaceRoot = path.dirname(workspaceManifestPath);
+    const members = await this.manifestReader.collectPackageManifests(
+      workspaceManifestPath,
+      workspaceManifest
+    );
+    const crates = members.map(([manifestPath, manifest]) =>
+      this.createCrate(manifestPath, manifest, 'workspace')
+    );
+
+    // Nested members own their files, so match the deepest crate first
+    const byDepth = [...crates].sort(
+      (a, b) => b.rootDir.length - a.rootDir.length
+    );
+    for (const source of await listRustSources(workspaceRoot, ['vendor/**'])) {
+      const owner = byDepth.find(crate =>
+        source.startsWith(crate.rootDir + path.sep)
+      );
+      if (owner) {
+        await this.scanFile(owner, source);
+      }
+    }
+
+    for (const [manifestPath, manifest] of members) {
+      const crate = crates.find(
+        candidate => candidate.rootDir === path.dirname(manifestPath)
+      );
+      if (crate) {
+        crate.forbidsUnsafe = await this.checkForbidsUnsafe(
+          crate.rootDir,
+          manifest,
+          workspaceManifest
+        );
+      }
+    }
+
+    return crates;
+  }
+
+  private async scanDependencies(
+    workspaceRoot: string,
+    inventory: UnsafeInventory
+  ): Promise<void> {
+    const packages = await readCargoLock(
+      path.join(workspaceRoot, 'Cargo.lock')
+    );
+    if (!packages) {
+      return;
+    }
+
+    const indexDirs = await fs.readdir(this.registrySrcDir).catch(() => []);
+
+    for (const pkg of packages.filter(isRegistryPackage)) {
+      const located = await this.locateDependency(
+        workspaceRoot,
+        indexDirs,
+        pkg.name,
+        pkg.version
+      );
+      if (!located) {
+        inventory.unresolvedDependencies.push(`${pkg.name} ${pkg.version}`);
+        continue;
+      }
+
+      const manifestPath = path.join(located.dir, 'Cargo.toml');
+      const manifest = (await this.resolver.loadManifest(manifestPath)) || {};
+      const crate = this.createCrate(manifestPath, manifest, located.source);
+      crate.name = pkg.name;
+      crate.version = pkg.version;
+
+      for (const source of await listRustSources(located.dir)) {
+        await this.scanFile(crate, source);
+      }
+      crate.forbidsUnsafe = await this.checkForbidsUnsafe(
+        crate.rootDir,
+        manifest
+      );
+      inventory.crates.push(crate);
+    }
+  }
+
+  private async locateDependency(
+    workspaceRoot: string,
+    indexDirs: string[],
+    name: string,
+    version: string
+  ): Promise<{ dir: string; source: 'registry' | 'vendor' } | null> {
+    // `cargo vendor` uses name-version only when several versions coexist
+    for (const vendored of [`${name}-${version}`, name]) {
+      const dir = path.join(workspaceRoot, 'vendor', vendored);
+      if (await fs.pathExists(path.join(dir, 'Cargo.toml'))) {
+        return { dir, source: 'vendor' };
+      }
+    }
+
+    for (const indexDir of indexDirs) {
+      const dir = path.join(
+        this.registrySrcDir,
+        indexDir,
+        `${name}-${version}`
+      );
+      if (await fs.pathExists(path.join(dir, 'Cargo.toml'))) {
+        return { dir, source: 'registry' };
+      }
+    }
+
+    return null;
+  }
+
+  private createCrate(
+    manifestPath: string,
+    manifest: TomlTable,
+    source: UnsafeCrateInventory['source']
+  ): UnsafeCrateInventory {
+    const pkg = isTomlTable(manifest.package) ? manifest.package : {};
+    return {
+      name:
+        typeof pkg.name === 'string'
+          ? pkg.name
+          : path.basename(path.dirname(manifestPath)),
+      version: typeof pkg.version === 'string' ? pkg.version : undefined,
+      source,
+      rootDir: path.dirname(manifestPath),
+      forbidsUnsafe: false,
+      filesScanned: 0,
+      files: [],
+      unsafeBlocks: 0,
+      unsafeFns: 0,
+      unsafeImpls: 0,
+      externBlocks: 0,
+    };
+  }
+
+  private async scanFile(
+    crate: UnsafeCrateInventory,
+    filePath: string
+  ): Promise<void> {
+    const content = await readRustSource(filePath);
+    if (content === null) {
+      return;
+    }
+
+    const counts = countUnsafe(content);
+    crate.filesScanned++;
+    if (totalUnsafe(counts) === 0) {
+      return;
+    }
+
+    const file: UnsafeFileInventory = {
+      file: path.relative(crate.rootDir, filePath),
+      ...counts,
+    };
+    crate.files.push(file);
+    crate.unsafeBlocks += counts.unsafeBlocks;
+    crate.unsafeFns += counts.unsafeFns;
+    crate.unsafeImpls += counts.unsafeImpls;
+    crate.externBlocks += counts.externBlocks;
+  }
+
+  /**
+   * A crate forbids unsafe code through `[lints.rust] unsafe_code =
+   * "forbid"` (possibly inherited from the workspace) or through
+   * `#![forbid(unsafe_code)]` in every target root
+   */
+  private async checkForbidsUnsafe(
+    rootDir: string,
+    manifest: TomlTable,
+    workspaceManifest?: TomlTable
+  ): Promise<boolean> {
+    const lints = isTomlTable(manifest.lints) ? manifest.lints : {};
+    const workspace =
+      workspaceManifest && isTomlTable(workspaceManifest.workspace)
+        ? workspaceManifest.workspace
+        : {};
+    const rustLints =
+      lints.workspace === true
+        ? isTomlTable(workspace.lints) && workspace.lints.rust
+        : lints.rust;
+    if (
+      isTomlTable(rustLints) &&
+      lintLevel(rustLints.unsafe_code) === 'forbid'
+    ) {
+      return true;
+    }
+
+    const roots = await this.findTargetRoots(rootDir, manifest);
+    if (roots.length === 0) {
+      return false;
+    }
+    for (const root of roots) {
+      const content = await fs.readFile(root, 'utf-8').catch(() => '');
+      if (!forbidsUnsafeCode(content)) {
+        return false;
+      }
+    }
+    return true;
+  }
+
+  private async findTargetRoots(
+    rootDir: string,
+    manifest: TomlTable
+  ): Promise<string[]> {
+    const candidates = ['src/lib.rs', 'src/main.rs'];
+    if (isTomlTable(manifest.lib) && typeof manifest.lib.path === 'string') {
+      candidates.push(manifest.lib.path);
+    }
+    if (Array.isArray(manifest.bin)) {
+      for (const bin of manifest.bin.filter(isTomlTable)) {
+        if (typeof bin.path === 'string') candidates.push(bin.path);
+      }
+    }
+
+    const roots: string[] = [];
+    for (const candidate of new Set(candidates)) {
+      const root = path.resolve(rootDir, candidate);
+      if (await fs.pathExists(root)) roots.push(root);
+    }
+    return roots;
+  }
+}
+
+/**
+ * Level of a `[lints]` entry: `"forbid"` or `{ level = "forbid", ... }`
+ */
+function lintLevel(value: unknown): string | undefined {
+  if (typeof value === 'string') return value;
+  if (isTomlTable(value) && typeof value.level === 'string') {
+    return value.level;
+  }
+  return undefined;
+}
@@ -0,0 +1,265 @@
+import * as path from 'path';
+import fs from 'fs-extra';
+import { ToolExecutor } from '../utils/toolExecutor';
+import { safeJsonParse } from '../utils/safeJsonParser';
+import { isTomlTable, TomlTable } from '../utils/tomlParser';
+import { CargoWorkspaceResolver } from './CargoWorkspaceResolver';
+import { CargoManifestReader } from './CargoManifestReader';
+import { maskRustSource } from './RustSourceScanner';
+import { listRustSources, readRustSource } from './rustPaths';
+import {
+  CargoDependency,
+  UnusedDependency,
+  UnusedDependencyReport,
+} from '../types/rust';
+
+// Subset of `cargo udeps --output json`
+interface UdepsReport {
+  unused_deps?: Record<
+    string, // Package id, e.g. "app 0.1.0 (path+file:///ws/app)"
+    {
+      manifest_path: string;
+      normal?: string[];
+      development?: string[];
+      build?: string[];
+    }
+  >;
+}
+
+/**
+ * Parse the text report of cargo-machete:
+ * `crate -- path/Cargo.toml:` followed by indented dependency names
+ */
+export function parseMacheteOutput(
+  output: string,
+  projectPath: string
+): UnusedDependency[] {
+  const dependencies: UnusedDependency[] = [];
+  let current: { packageName: string; manifestPath: string } | null = null;
+
+  for (const line of output.split('\n')) {
+    const header = line.match(/^(\S+) -- (.+Cargo\.toml):\s*$/);
+    if (header) {
+      current = {
+        packageName: header[1],
+        manifestPath: path.resolve(projectPath, header[2]),
+      };
+      continue;
+    }
+
+    const item = line.match(/^\s+(\S+)\s*$/);
+    if (current && item) {
+      dependencies.push({ ...current, dependency: item[1] });
+    } else {
+      current = null;
+    }
+  }
+
+  return dependencies;
+}
+
+/**
+ * Parse the JSON report of cargo-udeps
+ * @returns null if the output is not a udeps report
+ */
+export function parseUdepsOutput(json: string): UnusedDependency[] | null {
+  const report = safeJsonParse<UdepsReport>(json.trim());
+  if (!report || typeof report !== 'object') {
+    return null;
+  }
+
+  const dependencies: UnusedDependency[] = [];
+  for (const [packageId, unused] of Object.entries(report.unused_deps || {})) {
+    const base = {
+      packageName: packageId.split(' ')[0],
+      manifestPath: unused.manifest_path,
+    };
+    const kinds: Array<[string[] | undefined, CargoDependency['kind']]> = [
+      [unused.normal, 'normal'],
+      [unused.development, 'dev'],
+      [unused.build, 'build'],
+    ];
+    for (const [names, kind] of kinds) {
+      for (const dependency of names || []) {
+        dependencies.push({ ...base, dependency, kind });
+      }
+    }
+  }
+
+  return dependencies;
+}
+
+/**
+ * Whether masked Rust source refers to a crate: `name::`, `use name;`,
+ * `use name as ..` or `extern crate name`
+ */
+export function referencesCrate(masked: string, crateName: string): boolean {
+  const ident = crateName.replace(/-/g, '_');
+  return new RegExp(
+    `(?<![\\w])${ident}\\s*::|\\buse\\s+${ident}\\s*(?:;|as\\b)|\\bextern\\s+crate\\s+${ident}\\b`
+  ).test(masked);
+}
+
+/**
+ * Detects declared but unused dependencies of the crates of a Cargo
+ * project. cargo-machete (fast, heuristic) and, on request, cargo-udeps
+ * (exact, but a nightly build of the workspace) are preferred; without them
+ * the `use` paths of each crate's sources are compared with its manifest.
+ * Like cargo-machete, dependencies listed in
+ * `[package.metadata.cargo-machete] ignored` are skipped.
+ */
+export class UnusedDependencyDetector {
+  private manifestReader: CargoManifestReader;
+
+  constructor(
+    private resolver: CargoWorkspaceResolver = new CargoWorkspaceResolver()
+  ) {
+    this.manifestReader = new CargoManifestReader(resolver);
+  }
+
+  /**
+   * @param options.udeps Fall back to cargo-udeps before the import scan
+   * @returns null if the project is not a Cargo project
+   */
+  async detect(
+    projectPath: string,
+    options: { udeps?: boolean } = {}
+  ): Promise<UnusedDependencyReport | null> {
+    const manifestPath = path.join(path.resolve(projectPath), 'Cargo.toml');
+    if (!(await fs.pathExists(manifestPath))) {
+      return null;
+    }
+    const cargoPackage = await this.resolver.resolveForFile(manifestPath);
+    const workspaceManifestPath =
+      cargoPackage?.workspaceManifestPath || manifestPath;
+
+    return (
+      (await this.runMachete(path.dirname(workspaceManifestPath))) ||
+      (options.udeps ? await this.runUdeps(workspaceManifestPath) : null) ||
+      (await this.scanImports(workspaceManifestPath))
+    );
+  }
+
+  private async runMachete(
+    workspaceRoot: string
+  ): Promise<UnusedDependencyReport | null> {
+    try {
+      const { stdout, exitCode } =
+        await ToolExecutor.runCargoMachete(workspaceRoot);
+      // 0: nothing unused, 1: unused dependencies found
+      if (exitCode !== 0 && exitCode !== 1) {
+        return null;
+      }
+      return {
+        tool: 'cargo-machete',
+        dependencies: parseMacheteOutput(stdout, workspaceRoot),
+      };
+    } catch (error) {
+      console.debug(`cargo machete failed: ${error}`);
+      return null;
+    }
+  }
+
+  private async runUdeps(
+    manifestPath: string
+  ): Promise<UnusedDependencyReport | null> {
+    try {
+      // Missing plugin, missing nightly or a broken build: fall back
+      const { stdout, exitCode } =
+        await ToolExecutor.runCargoUdeps(manifestPath);
+      const dependencies =
+        exitCode === 0 || exitCode === 1 ? parseUdepsOutput(stdout) : null;
+      return dependencies ? { tool: 'cargo-udeps', dependencies } : null;
+    } catch (error) {
+      console.debug(`cargo udeps failed: ${error}`);
+      return null;
+    }
+  }
+
+  private async scanImports(
+    workspaceManifestPath: string
+  ): Promise<UnusedDependencyReport> {
+    const report: UnusedDependencyReport = {
+      tool: 'import-scan',
+      dependencies: [],
+    };
+    const workspaceManifest = await this.resolver.loadManifest(
+      workspaceManifestPath
+    );
+    if (!workspaceManifest) {
+      return { ...report, error: 'Cargo.toml could not be parsed' };
+    }
+
+    const workspace = isTomlTable(workspaceManifest.workspace)
+      ? workspaceManifest.workspace
+      : {};
+    const workspaceDependencies = isTomlTable(workspace.dependencies)
+      ? workspace.dependencies
+      : {};
+    const members = await this.manifestReader.collectPackageManifests(
+      workspaceManifestPath,
+      workspaceManifest
+    );
+
+    // Nested members own their files, so match the deepest crate first
+    const workspaceRoot = path.dirname(workspaceManifestPath);
+    const byDepth = [...members].sort(
+      ([a], [b]) => path.dirname(b).length - path.dirname(a).length
+    );
+    const sources = new Map<string, string[]>();
+    for (const file of await listRustSources(workspaceRoot, ['vendor/**'])) {
+      const owner = byDepth.find(([manifestPath]) =>
+        file.startsWith(path.dirname(manifestPath) + path.sep)
+      );
+      const content = owner ? await readRustSource(file) : null;
+      if (!owner || content === null) {
+        continue;
+      }
+      sources.set(owner[0], [
+        ...(sources.get(owner[0]) || []),
+        maskRustSource(content),
+      ]);
+    }
+
+    for (const [manifestPath, manifest] of members) {
+      const pkg = manifest.package as TomlTable;
+      const ignored = this.readIgnoredDependencies(pkg);
+      const masked = sources.get(manifestPath) || [];
+      const dependencies = this.manifestReader.collectDependencies(
+        manifest,
+        manifestPath,
+        workspaceDependencies
+      );
+
+      const reported = new Set<string>();
+      for (const dependency of dependencies) {
+        const key = dependency.alias || dependency.name;
+        if (
+          ignored.includes(key) ||
+          reported.has(`${dependency.kind}:${key}`) ||
+          masked.some(source => referencesCrate(source, key))
+        ) {
+          continue;
+        }
+        reported.add(`${dependency.kind}:${key}`);
+        report.dependencies.push({
+          packageName: String(pkg.name),
+          manifestPath,
+          dependency: key,
+          kind: dependency.kind,
+        });
+      }
+    }
+
+    return report;
+  }
+
+  private readIgnoredDependencies(pkg: TomlTable): string[] {
+    const metadata = isTomlTable(pkg.metadata) ? pkg.metadata : {};
+    const machete = metadata['cargo-machete'];
+    const ignored = isTomlTable(machete) ? machete.ignored : undefined;
+    return Array.isArray(ignored)
+      ? ignored.filter((name): name is string => typeof name === 'string')
+      : [];
+  }
+}
@@ -0,0 +1,75 @@
+import * as os from 'os';
+import * as path from 'path';
+import fs from 'fs-extra';
+import { glob } from 'glob';
+import { APP_CONFIG } from '../config/constants';
+
+// Build output and foreign trees never hold sources of the scanned crates
+const RUST_SOURCE_IGNORES = [
+  '**/target/**',
+  '**/.git/**',
+  '**/node_modules/**',
+];
+
+// Generated sources beyond this size are skipped
+const MAX_RUST_SOURCE_SIZE = 2 * 1024 * 1024;
+
+/**
+ * Expand a leading `~` to the user's home directory
+ */
+export function expandHome(filePath: string): string {
+  return filePath.replace(/^~(?=$|[\\/])/, os.homedir());
+}
+
+/**
+ * Cargo's home directory ($CARGO_HOME, defaulting to ~/.cargo)
+ */
+export function cargoHome(): string {
+  return path.resolve(
+    expandHome(process.env.CARGO_HOME || path.join('~', '.cargo'))
+  );
+}
+
+/**
+ * Advisory-db location: explicit option, environment, then the default
+ */
+export function resolveAdvisoryDbPath(configuredPath?: string): string {
+  const dbPath =
+    configuredPath ||
+    process.env[APP_CONFIG.RUST.ADVISORY_DB_ENV] ||
+    APP_CONFIG.RUST.ADVISORY_DB_PATH;
+  return path.resolve(expandHome(dbPath));
+}
+
+/**
+ * Absolute paths of the `.rs` files below `root`, sorted
+ * @param extraIgnores Further glob patterns relative to `root`
+ */
+export async function listRustSources(
+  root: string,
+  extraIgnores: string[] = []
+): Promise<string[]> {
+  const files = await glob('**/*.rs', {
+    cwd: root,
+    absolute: true,
+    nodir: true,
+    ignore: [...RUST_SOURCE_IGNORES, ...extraIgnores],
+  });
+  return files.sort();
+}
+
+/**
+ * Content of a Rust source file
+ * @returns null for oversized (generated) or unreadable files
+ */
+export async function readRustSource(filePath: string): Promise<string | null> {
+  try {
+    const stats = await fs.stat(filePath);
+    if (stats.size > MAX_RUST_SOURCE_SIZE) {
+      return null;
+    }
+    return await fs.readFile(filePath, 'utf-8');
+  } catch {
+    return null;
+  }
+}
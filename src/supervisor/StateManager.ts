@@ -193,6 +193,28 @@ export class StateManager extends EventEmitter {
     });
   }
 
+  updateTestCoverage(percentage: number | undefined): void {
+    this.stateLock.execute(async () => {
+      if (this.state.testCoverage !== percentage) {
+        this.state.testCoverage = percentage;
+        this.markDirty();
+        this.emit('coverage_changed', percentage);
+        this.updateHealthScore();
+      }
+    });
+  }
+
+  updateFailingTests(failed: number | undefined): void {
+    this.stateLock.execute(async () => {
+      if (this.state.failingTests !== failed) {
+        this.state.failingTests = failed;
+        this.markDirty();
+        this.emit('test_results_changed', failed);
+        this.updateHealthScore();
+      }
+    });
+  }
+
   addWatchedFile(filePath: string): void {
     if (!this.state.watchedFiles.has(filePath)) {
       this.state.watchedFiles.add(filePath);
@@ -225,10 +247,21 @@ export class StateManager extends EventEmitter {
   }
 
   private updateHealthScore(): void {
-    const toolCoverage = this.calculateToolCoverage();
-    const issueScore = this.calculateIssueScore();
+    const scores = [this.calculateToolCoverage(), this.calculateIssueScore()];
+
+    // Test coverage only counts once a coverage report has been read
+    if (this.state.testCoverage !== undefined) {
+      scores.push(this.state.testCoverage);
+    }
+
+    // Likewise for test results; every failing test costs 20 points
+    if (this.state.failingTests !== undefined) {
+      scores.push(Math.max(0, 100 - this.state.failingTests * 20));
+    }
 
-    this.state.healthScore = Math.round((toolCoverage + issueScore) / 2);
+    this.state.healthScore = Math.round(
+      scores.reduce((sum, score) => sum + score, 0) / scores.length
+    );
     this.emit('health_score_updated', this.state.healthScore);
   }
 
@@ -13,8 +13,13 @@ import {
   AuditConfig,
 } from '../auditor/ProductionReadinessAuditor';
 import { ToolsDatabaseManager } from '../database/ToolsDatabaseManager';
+import {
+  CoverageReader,
+  COVERAGE_REPORT_CANDIDATES,
+} from '../quality/CoverageReader';
 import { SecurityScanResult, SecurityFinding } from '../types/security';
 import { ProjectAnalysis } from '../types/index';
+import { RustTestRunner } from '../quality/RustTestRunner';
 import {
   SupervisorConfig,
   ProjectState,
@@ -40,6 +45,8 @@ export class WOARUSupervisor extends EventEmitter {
   private qualityRunner: QualityRunner;
   private productionAuditor: ProductionReadinessAuditor;
   private databaseManager: ToolsDatabaseManager;
+  private coverageReader: CoverageReader;
+  private testRunner: RustTestRunner;
 
   private config: SupervisorConfig;
   private isRunning = false;
@@ -69,6 +76,8 @@ export class WOARUSupervisor extends EventEmitter {
     this.qualityRunner = new QualityRunner(this.notificationManager);
     this.productionAuditor = new ProductionReadinessAuditor(this.projectPath);
     this.databaseManager = new ToolsDatabaseManager();
+    this.coverageReader = new CoverageReader(this.projectPath);
+    this.testRunner = new RustTestRunner(this.projectPath);
 
     this.setupEventListeners();
   }
@@ -177,6 +186,15 @@ export class WOARUSupervisor extends EventEmitter {
       this.stateManager.off('tool_detected', handleToolDetected)
     );
 
+    // Quality runner events (structured per-line issues, e.g. clippy JSON)
+    this.qualityRunner.onCodeIssues((file: string, issues: CodeIssue[]) => {
+      const relativeFile = path.relative(this.projectPath, path.resolve(file));
+      this.stateManager.updateCodeIssues(
+        relativeFile,
+        issues.map(issue => ({ ...issue, file: relativeFile }))
+      );
+    });
+
     const handleCriticalIssues = (issues: CodeIssue[]) => {
       try {
         this.notificationManager.notifyIssues(issues);
@@ -335,6 +353,7 @@ export class WOARUSupervisor extends EventEmitter {
 
       // Stop components
       this.fileWatcher.stop();
+      this.qualityRunner.cancelRustChecks();
       this.stateManager.stopAutoSave();
       this.databaseManager.stopBackgroundUpdates();
 
@@ -384,6 +403,9 @@ export class WOARUSupervisor extends EventEmitter {
       // Detect existing tools
       this.detectExistingTools(analysis);
 
+      await this.refreshTestCoverage();
+      await this.refreshTestResults();
+
       this.notificationManager.showSuccess(
         `Project analyzed: ${language} ${frameworks.length > 0 ? `(${frameworks.join(', ')})` : ''}`
       );
@@ -463,6 +485,13 @@ export class WOARUSupervisor extends EventEmitter {
         this.emit('file-changed', change.path);
       });
 
+      // Cargo manifests define which package owns each Rust file
+      if (
+        changes.some(change => path.basename(change.path) === 'Cargo.toml')
+      ) {
+        this.qualityRunner.invalidateRustWorkspaceCache();
+      }
+
       // Check for package definition changes for security scanning
       const packageFiles = [
         'package.json',
@@ -476,6 +505,18 @@ export class WOARUSupervisor extends EventEmitter {
         packageFiles.some(pkgFile => change.path.endsWith(pkgFile))
       );
 
+      // A fresh coverage report (cargo tarpaulin / llvm-cov) changes the score
+      const coverageReports = COVERAGE_REPORT_CANDIDATES.map(candidate =>
+        path.basename(candidate)
+      );
+      if (
+        changes.some(change =>
+          coverageReports.includes(path.basename(change.path))
+        )
+      ) {
+        await this.refreshTestCoverage();
+      }
+
       // Check for new recommendations based on changes
       const fileSpecificRecommendations: ToolRecommendation[] = [];
 
@@ -610,6 +651,10 @@ export class WOARUSupervisor extends EventEmitter {
         }
       }
 
+      // Reports under target/ are not watched, so re-read them periodically
+      await this.refreshTestCoverage();
+      await this.refreshTestResults();
+
       this.lastRecommendationCheck = new Date();
     } catch (error) {
       this.notificationManager.showError(
@@ -618,6 +663,28 @@ export class WOARUSupervisor extends EventEmitter {
     }
   }
 
+  private async refreshTestCoverage(): Promise<void> {
+    try {
+      const report = await this.coverageReader.read();
+      this.stateManager.updateTestCoverage(report?.percentage);
+    } catch (error) {
+      console.debug(`Coverage report could not be read: ${error}`);
+    }
+  }
+
+  /**
+   * Failing tests from the latest cargo-nextest JUnit report (written by
+   * `cargo nextest run` with a junit profile or by `woaru review`)
+   */
+  private async refreshTestResults(): Promise<void> {
+    try {
+      const results = await this.testRunner.readLatestJUnit();
+      this.stateManager.updateFailingTests(results?.failed);
+    } catch (error) {
+      console.debug(`Test report could not be read: ${error}`);
+    }
+  }
+
   private async autoSetupTools(
     recommendations: ToolRecommendation[]
   ): Promise<void> {
@@ -7,6 +7,8 @@ export interface ProjectState {
   codeIssues: Map<string, CodeIssue[]>;
   lastAnalysis: Date;
   healthScore: number;
+  testCoverage?: number; // Line coverage (%) of the latest coverage report
+  failingTests?: number; // Failed tests of the latest test report
   fileCount: number;
   watchedFiles: Set<string>;
 }
@@ -0,0 +1,34 @@
+export interface BenchmarkEstimate {
+  id: string; // Criterion full id, e.g. parse/small_input
+  meanNs: number;
+  medianNs?: number;
+  stdDevNs?: number;
+}
+
+export interface BenchmarkRun {
+  commit: string;
+  branch?: string;
+  measuredAt: string; // ISO time of the newest estimates.json
+  benchmarks: BenchmarkEstimate[];
+}
+
+export interface BenchmarkComparison {
+  id: string;
+  baselineNs: number;
+  currentNs: number;
+  changePercent: number; // Positive when the benchmark got slower
+}
+
+export type BenchmarkBaselineSource = 'base-branch' | 'previous-run';
+
+export interface BenchmarkReport {
+  current: BenchmarkRun;
+  baseline?: BenchmarkRun;
+  baselineSource?: BenchmarkBaselineSource;
+  thresholdPercent: number;
+  regressions: BenchmarkComparison[];
+  improvements: BenchmarkComparison[];
+  unchanged: number;
+  added: string[]; // Benchmarks without a baseline measurement
+  stale?: boolean; // Results predate HEAD and were not recorded for it
+}
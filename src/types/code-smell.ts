@@ -18,7 +18,10 @@ export type CodeSmellType =
   | 'nested-depth'
   | 'magic-number'
   | 'duplicate-code'
-  | 'dead-code';
+  | 'dead-code'
+  | 'unwrap-usage'
+  | 'panic-macro'
+  | 'unsafe-block';
 
 export interface ComplexityMetric {
   functionName: string;
@@ -0,0 +1,37 @@
+export type CoverageFormat = 'lcov' | 'cobertura' | 'tarpaulin';
+
+export interface FileCoverage {
+  file: string; // Absolute path
+  linesFound: number; // Instrumented lines
+  linesHit: number;
+  percentage: number;
+  uncoveredLines: number[];
+}
+
+export interface CoverageReport {
+  format: CoverageFormat;
+  reportPath: string;
+  generatedAt: Date; // mtime of the report file
+  files: FileCoverage[];
+  linesFound: number;
+  linesHit: number;
+  percentage: number;
+}
+
+/**
+ * Changed lines that are instrumented but never executed by the test suite
+ */
+export interface UncoveredChange {
+  file: string;
+  lines: number[];
+  fileCoverage: number;
+}
+
+/**
+ * Coverage as attached to a review report
+ */
+export interface ReviewCoverage {
+  report: CoverageReport;
+  changedFiles: FileCoverage[];
+  uncoveredChanges: UncoveredChange[];
+}
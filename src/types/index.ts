@@ -1,6 +1,15 @@
+import {
+  AsyncBlockingFinding,
+  CargoManifestSummary,
+  DuplicateCrate,
+  RustToolchainStatus,
+  UnusedDependencyReport,
+} from './rust';
+
 export interface ToolConfig {
   description: string;
   packages: string[];
+  registry?: 'npm' | 'pypi' | 'nuget' | 'crates'; // Source of stats, default npm
   configs?: Record<string, string[]>;
   configFiles?: string[];
   metadata?: {
@@ -8,6 +17,8 @@ export interface ToolConfig {
     lastChecked: string;
     npmDownloads?: number;
     githubStars?: number;
+    latestVersion?: string;
+    lastRelease?: string;
     alternatives?: string[];
     deprecated?: boolean;
     successor?: string;
@@ -57,6 +68,9 @@ export interface ProjectAnalysis {
   structure: string[];
   detectedLanguages?: string[];
   projectPath?: string;
+  cargo?: CargoManifestSummary; // Rust projects only
+  rustToolchain?: RustToolchainStatus; // Rust projects only
+  asyncBlocking?: AsyncBlockingFinding[]; // Rust projects using tokio
 }
 
 export interface SetupRecommendation {
@@ -71,6 +85,7 @@ export interface SetupRecommendation {
 
 export interface RefactorSuggestion {
   filename: string;
+  line?: number; // For findings tied to a source location
   suggestion: string;
   type: 'performance' | 'maintainability' | 'security' | 'best-practice';
 }
@@ -106,8 +121,37 @@ export interface AnalysisResult {
     health_score: number;
     recommendations?: string[];
   };
+  test_coverage?: {
+    format: string; // lcov, cobertura or tarpaulin
+    report: string;
+    percentage: number;
+    lines_found: number;
+    lines_hit: number;
+    least_covered_files: Array<{ file: string; percentage: number }>;
+  };
+  doc_coverage?: {
+    source: 'rustdoc' | 'source-scan';
+    percentage: number; // Public items with a doc comment
+    documented: number;
+    total: number;
+    crates: Array<{
+      name: string;
+      percentage: number;
+      documented: number;
+      total: number;
+      modules: Array<{
+        module: string;
+        file: string;
+        percentage: number;
+        documented: number;
+        total: number;
+      }>;
+    }>;
+  };
   detailed_security?: {
     dependency_vulnerabilities?: SecurityVulnerability[];
+    duplicate_dependencies?: DuplicateCrate[]; // Rust: from Cargo.lock
+    unused_dependencies?: UnusedDependencyReport; // Rust only
     infrastructure_security?: InfrastructureSecurityResult;
     configuration_audits?: ConfigurationAudit[];
   };
@@ -118,6 +162,7 @@ export interface SetupOptions {
   force?: boolean;
   skipBackup?: boolean;
   interactive?: boolean;
+  fix?: boolean; // Let tools rewrite code (cargo fmt, cargo clippy --fix)
 }
 
 export interface PackageJson {
@@ -0,0 +1,467 @@
+/**
+ * Rust-related types for WOARU Rust/Cargo Integration
+ */
+
+export type RustDiagnosticLevel =
+  | 'error'
+  | 'warning'
+  | 'note'
+  | 'help'
+  | 'ice';
+
+export interface RustSuggestion {
+  message: string;
+  replacement: string;
+  applicability:
+    | 'MachineApplicable'
+    | 'MaybeIncorrect'
+    | 'HasPlaceholders'
+    | 'Unspecified';
+  file: string;
+  line: number;
+  column: number;
+  endLine: number;
+  endColumn: number;
+}
+
+export interface RustDiagnostic {
+  file: string;
+  line: number;
+  column: number;
+  endLine: number;
+  endColumn: number;
+  level: RustDiagnosticLevel;
+  message: string;
+  lint?: string; // e.g. clippy::needless_return or E0308
+  suggestions: RustSuggestion[];
+  rendered?: string;
+  packageId?: string;
+}
+
+/**
+ * Subset of the `cargo --message-format=json` compiler-message payload
+ */
+export interface CargoCompilerSpan {
+  file_name: string;
+  line_start: number;
+  line_end: number;
+  column_start: number;
+  column_end: number;
+  is_primary: boolean;
+  label?: string | null;
+  suggested_replacement?: string | null;
+  suggestion_applicability?: RustSuggestion['applicability'] | null;
+}
+
+export interface CargoCompilerDiagnostic {
+  message: string;
+  code?: { code: string; explanation?: string | null } | null;
+  level: string;
+  spans: CargoCompilerSpan[];
+  children: CargoCompilerDiagnostic[];
+  rendered?: string | null;
+}
+
+export interface CargoCompilerMessage {
+  reason: string;
+  package_id?: string;
+  manifest_path?: string;
+  message?: CargoCompilerDiagnostic;
+}
+
+/**
+ * A Cargo package resolved from its manifest, with its owning workspace
+ */
+export interface CargoPackage {
+  name: string;
+  version?: string;
+  manifestPath: string;
+  rootDir: string;
+  workspaceRoot: string; // Equals rootDir for standalone packages
+  workspaceManifestPath: string;
+}
+
+export type CargoDependencyKind = 'normal' | 'dev' | 'build';
+
+/**
+ * A dependency declared in a Cargo manifest, with workspace inheritance
+ * (`foo = { workspace = true }`) already resolved
+ */
+export interface CargoDependency {
+  name: string; // Crate name, honouring `package = "..."` renames
+  alias?: string; // Manifest key when the crate is renamed
+  version?: string;
+  kind: CargoDependencyKind;
+  features: string[];
+  optional: boolean;
+  workspaceInherited: boolean;
+  target?: string; // e.g. cfg(unix) for [target.'cfg(unix)'.dependencies]
+  manifestPath: string;
+}
+
+/**
+ * Dependency-level view of a Cargo project or workspace
+ */
+export interface CargoManifestSummary {
+  manifestPath: string;
+  workspaceManifestPath: string;
+  packages: string[];
+  dependencies: CargoDependency[];
+  features: Record<string, Record<string, string[]>>; // [features] by package
+  frameworks: string[];
+  binaries: string[]; // Packages with a binary target
+}
+
+/**
+ * Subset of the RustSec advisory metadata emitted by `cargo audit --json`
+ */
+export interface RustSecAdvisory {
+  id: string; // e.g. RUSTSEC-2023-0001
+  package: string;
+  title: string;
+  description: string;
+  date?: string;
+  aliases?: string[]; // CVE / GHSA identifiers
+  cvss?: string | null; // CVSS v3 vector
+  informational?: string | null; // unmaintained, unsound, notice
+  categories?: string[];
+  url?: string | null;
+  references?: string[];
+}
+
+export interface CargoAuditPackage {
+  name: string;
+  version: string;
+  source?: string | null;
+}
+
+export interface CargoAuditVersions {
+  patched: string[];
+  unaffected: string[];
+}
+
+export interface CargoAuditVulnerability {
+  advisory: RustSecAdvisory;
+  versions: CargoAuditVersions;
+  package: CargoAuditPackage;
+}
+
+export interface CargoAuditWarning {
+  kind: string; // unmaintained, unsound, yanked
+  package: CargoAuditPackage;
+  advisory?: RustSecAdvisory | null;
+  versions?: CargoAuditVersions | null;
+}
+
+/**
+ * Top-level report of `cargo audit --json`
+ */
+export interface CargoAuditReport {
+  database?: { 'advisory-count'?: number; 'last-updated'?: string };
+  lockfile?: { 'dependency-count'?: number };
+  vulnerabilities: {
+    found: boolean;
+    count: number;
+    list: CargoAuditVulnerability[];
+  };
+  warnings?: Record<string, CargoAuditWarning[]>;
+}
+
+/**
+ * A `[[package]]` entry of Cargo.lock
+ */
+export interface CargoLockPackage {
+  name: string;
+  version: string;
+  source?: string; // Missing for workspace and path crates
+  checksum?: string;
+  dependencies: string[]; // "name" or "name version" when ambiguous
+}
+
+/**
+ * A crate locked in several semver-incompatible versions
+ */
+export interface DuplicateCrate {
+  name: string;
+  versions: Array<{
+    version: string;
+    // Shortest chains from a workspace crate, e.g. ["app@0.1.0", "h2@0.3.26"]
+    paths: string[][];
+  }>;
+}
+
+/**
+ * A declared dependency that no source file of its package refers to
+ */
+export interface UnusedDependency {
+  packageName: string;
+  manifestPath: string;
+  dependency: string; // Manifest key, i.e. the alias of renamed crates
+  kind?: CargoDependencyKind; // Unknown for cargo-machete
+}
+
+export interface UnusedDependencyReport {
+  tool: 'cargo-machete' | 'cargo-udeps' | 'import-scan';
+  dependencies: UnusedDependency[];
+  error?: string;
+}
+
+export type CargoDenyCheck = 'advisories' | 'bans' | 'licenses' | 'sources';
+
+/**
+ * One diagnostic of `cargo deny --format json check`
+ */
+export interface CargoDenyDiagnostic {
+  check: CargoDenyCheck;
+  code: string; // e.g. rejected, banned, duplicate, source-not-allowed
+  severity: 'error' | 'warning' | 'note' | 'help';
+  message: string;
+  crate?: string;
+  version?: string;
+  labels: string[];
+  notes: string[];
+}
+
+/**
+ * Outcome of running all cargo-deny checks for a project
+ */
+export interface CargoDenyResult {
+  configPath: string | null; // null when the project has no deny.toml
+  diagnostics: CargoDenyDiagnostic[];
+  error?: string;
+}
+
+/**
+ * Occurrences of `unsafe` in Rust source
+ */
+export interface UnsafeCounts {
+  unsafeBlocks: number;
+  unsafeFns: number;
+  unsafeImpls: number;
+  externBlocks: number;
+}
+
+export interface UnsafeFileInventory extends UnsafeCounts {
+  file: string; // Relative to the crate root
+}
+
+export interface UnsafeCrateInventory extends UnsafeCounts {
+  name: string;
+  version?: string;
+  source: 'workspace' | 'registry' | 'vendor';
+  rootDir: string;
+  forbidsUnsafe: boolean; // #![forbid(unsafe_code)] or [lints.rust]
+  filesScanned: number;
+  files: UnsafeFileInventory[]; // Only files containing unsafe code
+}
+
+export interface UnsafeInventory {
+  crates: UnsafeCrateInventory[];
+  unresolvedDependencies: string[]; // "name version" not found locally
+}
+
+export type BuildScriptRiskKind = 'network' | 'process' | 'env';
+
+/**
+ * A risky call found by statically scanning a build script
+ */
+export interface BuildScriptFinding {
+  kind: BuildScriptRiskKind;
+  file: string; // Absolute path of the scanned source
+  line: number;
+  detail: string; // e.g. "std::process::Command" or "env::var(\"HOME\")"
+}
+
+/**
+ * A package that runs code at compile time
+ */
+export interface CompileTimeCrate {
+  name: string;
+  version: string;
+  source: 'workspace' | 'registry' | 'git' | 'path';
+  manifestPath: string;
+  buildScript?: string; // Absolute path of build.rs (or `package.build`)
+  procMacro: boolean;
+  findings: BuildScriptFinding[];
+}
+
+export interface CompileTimeCodeReport {
+  crates: CompileTimeCrate[]; // Only packages with a build script or proc-macro
+  error?: string; // cargo metadata failed, e.g. dependencies not fetched
+}
+
+/**
+ * Declared MSRV and target kinds of a workspace package
+ */
+export interface RustCrateMsrv {
+  name: string;
+  manifestPath: string;
+  rustVersion?: string; // package.rust-version, workspace inheritance resolved
+  library: boolean;
+  binary: boolean;
+}
+
+/**
+ * rust-toolchain.toml (or the legacy rust-toolchain file)
+ */
+export interface RustToolchainFile {
+  path: string;
+  channel?: string; // e.g. 1.78.0, stable, nightly-2024-05-01
+  pinned: boolean; // A version or dated nightly rather than a moving channel
+}
+
+export interface MsrvCheckResult {
+  toolchain: string;
+  success: boolean;
+  errors: string[];
+}
+
+/**
+ * MSRV declarations of a workspace compared with the installed toolchains
+ */
+export interface RustToolchainStatus {
+  crates: RustCrateMsrv[];
+  msrv?: string; // Lowest declared rust-version
+  toolchainFile?: RustToolchainFile;
+  installedToolchains?: string[] | null; // Only when listed; null without rustup
+  msrvToolchain?: string; // Installed toolchain matching the MSRV
+  msrvCheck?: MsrvCheckResult; // cargo check on the MSRV toolchain
+}
+
+export type FeatureCheckMode = 'each-feature' | 'powerset';
+
+/**
+ * Feature flags of one `cargo check` run
+ */
+export interface FeatureCombination {
+  features: string[];
+  noDefaultFeatures: boolean;
+  allFeatures: boolean;
+}
+
+export interface FeatureCombinationFailure {
+  combination: FeatureCombination;
+  errors: string[];
+  line: number; // Line of the feature (or the [features] header) in Cargo.toml
+}
+
+/**
+ * Feature combinations of one package checked with cargo-hack or the
+ * internal powerset fallback
+ */
+export interface FeatureCheckResult {
+  packageName: string;
+  manifestPath: string;
+  runner: 'cargo-hack' | 'internal';
+  mode: FeatureCheckMode;
+  depth: number;
+  checked: number; // Combinations that were run
+  truncated: boolean; // Internal fallback stopped at its combination limit
+  failures: FeatureCombinationFailure[];
+}
+
+export type SemverLevel = 'major' | 'minor' | 'patch';
+
+export type SemverChangeKind =
+  | 'removed' // Public item removed or renamed
+  | 'signature-changed'
+  | 'trait-item-added' // New required item in an existing trait
+  | 'added';
+
+export interface SemverChange {
+  kind: SemverChangeKind;
+  path: string; // e.g. demo::Config::load
+  itemKind?: string; // function, struct, trait, ...
+  level: SemverLevel; // Version bump the change requires
+  lint?: string; // cargo-semver-checks lint id
+  detail?: string;
+}
+
+/**
+ * Public API of one library crate compared against the base revision
+ */
+export interface SemverCheckResult {
+  packageName: string;
+  manifestPath: string;
+  runner: 'cargo-semver-checks' | 'rustdoc-json';
+  baseVersion: string;
+  currentVersion: string;
+  requiredBump: SemverLevel;
+  actualBump: SemverLevel | 'none'; // In Cargo terms: 0.1 -> 0.2 is major
+  violation: boolean; // Breaking changes without a major version bump
+  bumpTooSmall: boolean; // Also true for additions without a minor bump
+  changes: SemverChange[];
+  error?: string; // Neither runner produced a comparison
+}
+
+export type AsyncBlockingKind =
+  | 'thread-sleep'
+  | 'std-fs'
+  | 'reqwest-blocking'
+  | 'mutex-across-await';
+
+/**
+ * A blocking call or lock guard inside async code
+ */
+export interface AsyncBlockingFinding {
+  kind: AsyncBlockingKind;
+  file: string; // Relative to the project root
+  line: number;
+  context: string; // `async fn name` or `async block`
+  call: string; // e.g. "std::thread::sleep" or "std::fs::read_to_string"
+  alternative: string;
+}
+
+/**
+ * Public items with a doc comment, of a crate or module
+ */
+export interface DocCoverageCount {
+  documented: number;
+  total: number;
+}
+
+export interface ModuleDocCoverage extends DocCoverageCount {
+  module: string; // e.g. `crate` or `net::http`
+  file: string; // Absolute path of the module's source file
+}
+
+export interface CrateDocCoverage extends DocCoverageCount {
+  name: string;
+  manifestPath: string;
+  modules: ModuleDocCoverage[];
+}
+
+/**
+ * Documentation coverage of the library crates of a workspace
+ */
+export interface RustDocCoverageReport extends DocCoverageCount {
+  source: 'rustdoc' | 'source-scan';
+  crates: CrateDocCoverage[];
+}
+
+/**
+ * A cargo-fuzz target below a `fuzz/` directory
+ */
+export interface FuzzTarget {
+  name: string;
+  file: string; // Absolute path of the target's source
+  manifestPath: string; // The fuzz crate's Cargo.toml
+}
+
+/**
+ * A public library function taking untrusted bytes or text
+ */
+export interface FuzzCandidate {
+  crate: string;
+  name: string;
+  file: string; // Absolute path
+  line: number;
+  input: '&[u8]' | '&str';
+  fuzzed: boolean; // Called from at least one fuzz target
+}
+
+export interface FuzzReadinessReport {
+  fuzzTargets: FuzzTarget[];
+  propertyTesting: Array<{ crate: string; frameworks: string[] }>;
+  candidates: FuzzCandidate[];
+}
@@ -9,6 +9,7 @@ export interface SecurityFinding {
     | 'trufflehog'
     | 'trivy'
     | 'semgrep'
+    | 'cargo-audit'
     | 'woaru-security';
   type: 'vulnerability' | 'secret' | 'misconfiguration';
   severity: 'critical' | 'high' | 'medium' | 'low' | 'info';
@@ -20,6 +21,7 @@ export interface SecurityFinding {
   package?: string;
   version?: string;
   fixedIn?: string;
+  advisoryId?: string; // e.g. RUSTSEC-2023-0001
   cve?: string;
   cwe?: string[];
   exploitMaturity?: string;
@@ -94,4 +96,6 @@ export interface SecurityCheckOptions {
   severityThreshold?: 'low' | 'medium' | 'high' | 'critical';
   timeout?: number;
   quiet?: boolean;
+  projectPath?: string; // Defaults to the current working directory
+  advisoryDbPath?: string; // Local RustSec advisory-db checkout for cargo-audit
 }
@@ -0,0 +1,25 @@
+export type TestRunner = 'nextest' | 'libtest-json' | 'libtest';
+
+export type TestOutcome = 'passed' | 'failed' | 'ignored';
+
+export interface TestCaseResult {
+  name: string; // e.g. tests::parses_empty_input
+  suite?: string; // Test binary / JUnit testsuite, if reported
+  outcome: TestOutcome;
+  durationMs?: number;
+  message?: string; // Failure message or captured output (truncated)
+}
+
+export interface TestRunResult {
+  runner: TestRunner;
+  success: boolean;
+  passed: number;
+  failed: number;
+  ignored: number;
+  durationMs?: number;
+  failedTests: TestCaseResult[];
+  ignoredTests: TestCaseResult[];
+  slowTests: TestCaseResult[];
+  slowThresholdMs: number;
+  error?: string; // Build failure or runner error without test results
+}
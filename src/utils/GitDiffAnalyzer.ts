@@ -1,4 +1,5 @@
 import { spawn } from 'child_process';
+import * as os from 'os';
 import * as path from 'path';
 import fs from 'fs-extra';
 
@@ -73,6 +74,54 @@ export class GitDiffAnalyzer {
     });
   }
 
+  /**
+   * Added or modified line numbers (new side of the diff) per changed file
+   */
+  async getChangedLinesSince(
+    baseBranch: string = 'main'
+  ): Promise<Map<string, number[]>> {
+    const diff = await this.runGit(
+      ['diff', '--unified=0', '--no-color', `${baseBranch}...HEAD`],
+      'Git command failed'
+    );
+    return this.parseChangedLines(diff, await this.getRepositoryRoot());
+  }
+
+  /**
+   * @param repositoryRoot Directory the diff paths are relative to; git
+   * always reports them from the top level, even in a subdirectory
+   */
+  parseChangedLines(
+    diff: string,
+    repositoryRoot: string = this.projectPath
+  ): Map<string, number[]> {
+    const changedLines = new Map<string, number[]>();
+    let currentLines: number[] | null = null;
+
+    for (const line of diff.split('\n')) {
+      if (line.startsWith('+++ ')) {
+        const target = line.slice(4).trim();
+        currentLines = null;
+        if (target !== '/dev/null') {
+          const file = path.resolve(repositoryRoot, target.slice(2)); // b/
+          currentLines = changedLines.get(file) || [];
+          changedLines.set(file, currentLines);
+        }
+      } else if (line.startsWith('@@') && currentLines) {
+        // @@ -a,b +c,d @@ - d defaults to 1, d=0 means a pure deletion
+        const match = line.match(/^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@/);
+        if (!match) continue;
+        const start = Number(match[1]);
+        const count = match[2] === undefined ? 1 : Number(match[2]);
+        for (let i = 0; i < count; i++) {
+          currentLines.push(start + i);
+        }
+      }
+    }
+
+    return changedLines;
+  }
+
   async getCurrentBranch(): Promise<string> {
     return new Promise((resolve, reject) => {
       const gitProcess = spawn('git', ['branch', '--show-current'], {
@@ -140,6 +189,116 @@ export class GitDiffAnalyzer {
     });
   }
 
+  /**
+   * Top-level directory of the working tree
+   */
+  async getRepositoryRoot(): Promise<string> {
+    const stdout = await this.runGit(
+      ['rev-parse', '--show-toplevel'],
+      'Failed to resolve repository root'
+    );
+    return path.resolve(stdout.trim());
+  }
+
+  /**
+   * Full hash of the checked out commit
+   */
+  async getHeadCommit(): Promise<string> {
+    const stdout = await this.runGit(
+      ['rev-parse', 'HEAD'],
+      'Failed to resolve HEAD'
+    );
+    return stdout.trim();
+  }
+
+  /**
+   * Committer date of the checked out commit
+   */
+  async getHeadCommitTime(): Promise<Date> {
+    const stdout = await this.runGit(
+      ['log', '-1', '--format=%ct', 'HEAD'],
+      'Failed to read HEAD commit time'
+    );
+    return new Date(Number(stdout.trim()) * 1000);
+  }
+
+  /**
+   * Commit the `baseBranch...HEAD` diff is taken against
+   */
+  async getMergeBase(baseBranch: string = 'main'): Promise<string> {
+    const stdout = await this.runGit(
+      ['merge-base', baseBranch, 'HEAD'],
+      'Failed to find merge base'
+    );
+    return stdout.trim();
+  }
+
+  /**
+   * Check out a revision into a temporary detached worktree, leaving the
+   * working copy untouched
+   * @returns The worktree directory, to be passed to removeWorktree()
+   */
+  async createWorktree(revision: string): Promise<string> {
+    const worktreePath = await fs.mkdtemp(
+      path.join(os.tmpdir(), 'woaru-worktree-')
+    );
+    try {
+      await this.runGit(
+        ['worktree', 'add', '--detach', worktreePath, revision],
+        'Failed to create worktree'
+      );
+    } catch (error) {
+      await fs.remove(worktreePath);
+      throw error;
+    }
+    return worktreePath;
+  }
+
+  async removeWorktree(worktreePath: string): Promise<void> {
+    try {
+      await this.runGit(
+        ['worktree', 'remove', '--force', worktreePath],
+        'Failed to remove worktree'
+      );
+    } finally {
+      // Build output (target/) can outlive a failed `worktree remove`
+      await fs.remove(worktreePath);
+    }
+  }
+
+  private runGit(args: string[], errorMessage: string): Promise<string> {
+    return new Promise((resolve, reject) => {
+      const gitProcess = spawn('git', args, {
+        cwd: this.projectPath,
+        stdio: 'pipe',
+      });
+
+      let stdout = '';
+      let stderr = '';
+
+      gitProcess.stdout.on('data', (data: Buffer) => {
+        stdout += data.toString();
+      });
+
+      gitProcess.stderr.on('data', (data: Buffer) => {
+        stderr += data.toString();
+      });
+
+      gitProcess.on('close', (code: number) => {
+        if (code !== 0) {
+          reject(new Error(`${errorMessage}: ${stderr}`));
+          return;
+        }
+
+        resolve(stdout);
+      });
+
+      gitProcess.on('error', error => {
+        reject(new Error(`Failed to execute git command: ${error.message}`));
+      });
+    });
+  }
+
   filterFilesByExtension(files: string[], extensions: string[]): string[] {
     return files.filter(file => {
       const ext = path.extname(file).toLowerCase();
@@ -38,6 +38,7 @@ export async function safeExecAsync(
     cwd?: string;
     timeout?: number;
     env?: NodeJS.ProcessEnv;
+    signal?: AbortSignal; // Kills the process and rejects with an AbortError
   } = {}
 ): Promise<ExecResult> {
   return new Promise((resolve, reject) => {
@@ -137,6 +138,7 @@ export const ALLOWED_COMMANDS = [
   'mvn',
   'gradle',
   'rustc',
+  'rustup',
   'cargo',
   'clippy',
   'snyk',
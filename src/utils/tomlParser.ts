@@ -0,0 +1,377 @@
+/**
+ * Minimal TOML Parser
+ * Covers the TOML subset used by Cargo manifests, lockfiles and tool configs:
+ * tables, arrays of tables, dotted/quoted keys, strings, numbers, booleans,
+ * arrays and inline tables. Date-times are kept as raw strings.
+ */
+
+export type TomlValue = string | number | boolean | TomlValue[] | TomlTable;
+
+export interface TomlTable {
+  [key: string]: TomlValue;
+}
+
+export class TomlParseError extends Error {
+  constructor(
+    message: string,
+    public readonly line: number
+  ) {
+    super(`${message} (line ${line})`);
+    this.name = 'TomlParseError';
+  }
+}
+
+/**
+ * Parse a TOML document into a plain object
+ * @throws TomlParseError on malformed input
+ */
+export function parseToml(content: string): TomlTable {
+  return new TomlParser(content).parse();
+}
+
+/**
+ * Type guard for TOML tables (plain objects, not arrays)
+ */
+export function isTomlTable(value: unknown): value is TomlTable {
+  return typeof value === 'object' && value !== null && !Array.isArray(value);
+}
+
+/**
+ * Tables have no prototype, so keys such as `__proto__` or `constructor`
+ * from untrusted manifests are ordinary entries
+ */
+function createTable(): TomlTable {
+  return Object.create(null) as TomlTable;
+}
+
+const BARE_KEY = /[A-Za-z0-9_-]+/y;
+const SCALAR_TOKEN = /[^\s,\]}#]+/y;
+const NUMBER = /^[+-]?(?:\d[\d_]*)(?:\.\d[\d_]*)?(?:[eE][+-]?\d[\d_]*)?$/;
+
+class TomlParser {
+  private pos = 0;
+  private readonly root: TomlTable = createTable();
+  private current: TomlTable;
+
+  constructor(private readonly src: string) {
+    this.current = this.root;
+  }
+
+  parse(): TomlTable {
+    for (;;) {
+      this.skipBlank();
+      if (this.pos >= this.src.length) break;
+
+      if (this.peek() === '[') {
+        this.parseTableHeader();
+      } else {
+        this.parseKeyValue(this.current);
+      }
+      this.expectLineEnd();
+    }
+    return this.root;
+  }
+
+  private parseTableHeader(): void {
+    const isArray = this.src.startsWith('[[', this.pos);
+    this.pos += isArray ? 2 : 1;
+    const keys = this.parseKey();
+    this.skipInline();
+    this.expect(isArray ? ']]' : ']');
+
+    if (!isArray) {
+      this.current = this.walk(this.root, keys);
+      return;
+    }
+
+    const parent = this.walk(this.root, keys.slice(0, -1));
+    const last = keys[keys.length - 1];
+    const existing = parent[last] ?? [];
+    if (!Array.isArray(existing)) {
+      throw this.error(`Key "${keys.join('.')}" is not an array of tables`);
+    }
+    const table = createTable();
+    existing.push(table);
+    parent[last] = existing;
+    this.current = table;
+  }
+
+  private parseKeyValue(target: TomlTable): void {
+    const keys = this.parseKey();
+    this.skipInline();
+    this.expect('=');
+    this.skipInline();
+    const value = this.parseValue();
+    const parent = this.walk(target, keys.slice(0, -1));
+    parent[keys[keys.length - 1]] = value;
+  }
+
+  private parseKey(): string[] {
+    const keys: string[] = [];
+    for (;;) {
+      this.skipInline();
+      const ch = this.peek();
+      if (ch === '"') {
+        keys.push(this.parseBasicString());
+      } else if (ch === "'") {
+        keys.push(this.parseLiteralString());
+      } else {
+        BARE_KEY.lastIndex = this.pos;
+        const match = BARE_KEY.exec(this.src);
+        if (!match) throw this.error('Expected key');
+        keys.push(match[0]);
+        this.pos += match[0].length;
+      }
+      this.skipInline();
+      if (this.peek() !== '.') return keys;
+      this.pos++;
+    }
+  }
+
+  private parseValue(): TomlValue {
+    const ch = this.peek();
+    if (this.src.startsWith('"""', this.pos)) return this.parseMultilineBasic();
+    if (this.src.startsWith("'''", this.pos))
+      return this.parseMultilineLiteral();
+    if (ch === '"') return this.parseBasicString();
+    if (ch === "'") return this.parseLiteralString();
+    if (ch === '[') return this.parseArray();
+    if (ch === '{') return this.parseInlineTable();
+    return this.parseScalar();
+  }
+
+  private parseScalar(): TomlValue {
+    SCALAR_TOKEN.lastIndex = this.pos;
+    const match = SCALAR_TOKEN.exec(this.src);
+    if (!match) throw this.error('Expected value');
+    const token = match[0];
+    this.pos += token.length;
+
+    if (token === 'true') return true;
+    if (token === 'false') return false;
+    if (NUMBER.test(token)) return Number(token.replace(/_/g, ''));
+    if (/^0x[0-9a-fA-F_]+$/.test(token)) {
+      return parseInt(token.slice(2).replace(/_/g, ''), 16);
+    }
+    if (/^[+-]?inf$/.test(token)) {
+      return token.startsWith('-') ? -Infinity : Infinity;
+    }
+    if (/^[+-]?nan$/.test(token)) return NaN;
+    if (/^\d{4}-\d{2}-\d{2}/.test(token) || /^\d{2}:\d{2}/.test(token)) {
+      return token; // Date-times are kept verbatim
+    }
+    throw this.error(`Invalid value "${token}"`);
+  }
+
+  private parseArray(): TomlValue[] {
+    this.pos++; // [
+    const items: TomlValue[] = [];
+    for (;;) {
+      this.skipBlank();
+      if (this.peek() === ']') break;
+      items.push(this.parseValue());
+      this.skipBlank();
+      if (this.peek() === ',') {
+        this.pos++;
+        continue;
+      }
+      if (this.peek() !== ']') throw this.error('Expected "," or "]"');
+      break;
+    }
+    this.pos++; // ]
+    return items;
+  }
+
+  private parseInlineTable(): TomlTable {
+    this.pos++; // {
+    const table = createTable();
+    this.skipInline();
+    if (this.peek() === '}') {
+      this.pos++;
+      return table;
+    }
+    for (;;) {
+      this.parseKeyValue(table);
+      this.skipInline();
+      if (this.peek() === ',') {
+        this.pos++;
+        continue;
+      }
+      this.expect('}');
+      return table;
+    }
+  }
+
+  private parseBasicString(): string {
+    this.pos++; // "
+    let result = '';
+    for (;;) {
+      const ch = this.src[this.pos];
+      if (ch === undefined || ch === '\n') {
+        throw this.error('Unterminated string');
+      }
+      this.pos++;
+      if (ch === '"') return result;
+      result += ch === '\\' ? this.parseEscape() : ch;
+    }
+  }
+
+  private parseMultilineBasic(): string {
+    this.pos += 3;
+    this.skipLeadingNewline();
+    let result = '';
+    for (;;) {
+      if (this.pos >= this.src.length) {
+        throw this.error('Unterminated multi-line string');
+      }
+      if (this.src.startsWith('"""', this.pos)) {
+        this.pos += 3;
+        return result;
+      }
+      const ch = this.src[this.pos++];
+      if (ch !== '\\') {
+        result += ch;
+        continue;
+      }
+      // Line-ending backslash trims all following whitespace
+      if (/^[ \t]*\r?\n/.test(this.src.slice(this.pos, this.pos + 64))) {
+        while (/\s/.test(this.src[this.pos] ?? '')) this.pos++;
+        continue;
+      }
+      result += this.parseEscape();
+    }
+  }
+
+  private parseLiteralString(): string {
+    this.pos++; // '
+    const end = this.src.indexOf("'", this.pos);
+    const newline = this.src.indexOf('\n', this.pos);
+    if (end === -1 || (newline !== -1 && newline < end)) {
+      throw this.error('Unterminated literal string');
+    }
+    const result = this.src.slice(this.pos, end);
+    this.pos = end + 1;
+    return result;
+  }
+
+  private parseMultilineLiteral(): string {
+    this.pos += 3;
+    this.skipLeadingNewline();
+    const end = this.src.indexOf("'''", this.pos);
+    if (end === -1) throw this.error('Unterminated multi-line literal string');
+    const result = this.src.slice(this.pos, end);
+    this.pos = end + 3;
+    return result;
+  }
+
+  private parseEscape(): string {
+    const ch = this.src[this.pos++];
+    switch (ch) {
+      case 'b':
+        return '\b';
+      case 't':
+        return '\t';
+      case 'n':
+        return '\n';
+      case 'f':
+        return '\f';
+      case 'r':
+        return '\r';
+      case '"':
+        return '"';
+      case '\\':
+        return '\\';
+      case 'u':
+      case 'U': {
+        const length = ch === 'u' ? 4 : 8;
+        const hex = this.src.slice(this.pos, this.pos + length);
+        if (!/^[0-9a-fA-F]+$/.test(hex) || hex.length !== length) {
+          throw this.error('Invalid unicode escape');
+        }
+        this.pos += length;
+        return String.fromCodePoint(parseInt(hex, 16));
+      }
+      default:
+        throw this.error(`Invalid escape "\\${ch}"`);
+    }
+  }
+
+  /**
+   * Descend into (and create) nested tables; arrays of tables resolve to
+   * their most recently defined element, as in TOML
+   */
+  private walk(table: TomlTable, keys: string[]): TomlTable {
+    let node = table;
+    for (const key of keys) {
+      let next = node[key];
+      if (next === undefined) {
+        next = createTable();
+        node[key] = next;
+      }
+      if (Array.isArray(next)) {
+        next = next[next.length - 1];
+      }
+      if (!isTomlTable(next)) {
+        throw this.error(`Key "${key}" is not a table`);
+      }
+      node = next;
+    }
+    return node;
+  }
+
+  private skipLeadingNewline(): void {
+    if (this.src.startsWith('\r\n', this.pos)) this.pos += 2;
+    else if (this.src[this.pos] === '\n') this.pos++;
+  }
+
+  private skipInline(): void {
+    while (this.peek() === ' ' || this.peek() === '\t') this.pos++;
+  }
+
+  /** Skip whitespace, newlines and comments */
+  private skipBlank(): void {
+    for (;;) {
+      const ch = this.peek();
+      if (ch === ' ' || ch === '\t' || ch === '\r' || ch === '\n') {
+        this.pos++;
+      } else if (ch === '#') {
+        this.skipComment();
+      } else {
+        return;
+      }
+    }
+  }
+
+  private skipComment(): void {
+    const newline = this.src.indexOf('\n', this.pos);
+    this.pos = newline === -1 ? this.src.length : newline;
+  }
+
+  private expectLineEnd(): void {
+    this.skipInline();
+    if (this.peek() === '#') this.skipComment();
+    if (this.src.startsWith('\r\n', this.pos)) {
+      this.pos += 2;
+    } else if (this.peek() === '\n') {
+      this.pos++;
+    } else if (this.pos < this.src.length) {
+      throw this.error('Expected end of line');
+    }
+  }
+
+  private expect(token: string): void {
+    if (!this.src.startsWith(token, this.pos)) {
+      throw this.error(`Expected "${token}"`);
+    }
+    this.pos += token.length;
+  }
+
+  private peek(): string {
+    return this.src[this.pos] ?? '';
+  }
+
+  private error(message: string): TomlParseError {
+    const line = this.src.slice(0, this.pos).split('\n').length;
+    return new TomlParseError(message, line);
+  }
+}
@@ -15,6 +15,7 @@ export interface ToolExecutionOptions {
   timeout?: number;
   cwd?: string;
   env?: NodeJS.ProcessEnv;
+  signal?: AbortSignal;
 }
 
 export class ToolExecutor {
@@ -111,6 +112,455 @@ export class ToolExecutor {
     });
   }
 
+  /**
+   * Run Cargo Clippy with machine-readable JSON diagnostics
+   */
+  static async runCargoClippy(
+    manifestPath: string,
+    extraArgs: string[] = [],
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    const sanitizedPath = sanitizeFilePath(manifestPath);
+    return safeExecAsync(
+      'cargo',
+      [
+        'clippy',
+        '--message-format=json',
+        '--manifest-path',
+        sanitizedPath,
+        ...extraArgs,
+      ],
+      {
+        timeout: 120000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Read workspace members and their declared dependencies without
+   * resolving (or downloading) the dependency graph
+   */
+  static async runCargoMetadata(
+    manifestPath: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'metadata',
+        '--format-version',
+        '1',
+        '--no-deps',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+      ],
+      {
+        timeout: 60000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Read the resolved dependency graph from Cargo.lock and the locally
+   * fetched sources; never touches the network or rewrites the lockfile
+   */
+  static async runCargoMetadataWithDependencies(
+    manifestPath: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'metadata',
+        '--format-version',
+        '1',
+        '--locked',
+        '--offline',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+      ],
+      {
+        timeout: 120000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Run cargo-audit against a local advisory database without fetching it
+   */
+  static async runCargoAudit(
+    lockfilePath: string,
+    advisoryDbPath: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'audit',
+        '--json',
+        '--no-fetch',
+        '--stale',
+        '--db',
+        sanitizeFilePath(advisoryDbPath),
+        '--file',
+        sanitizeFilePath(lockfilePath),
+      ],
+      {
+        timeout: 60000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Run a single cargo-deny check with JSON diagnostics (written to stderr)
+   */
+  static async runCargoDeny(
+    manifestPath: string,
+    check: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'deny',
+        '--format',
+        'json',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+        'check',
+        '--disable-fetch',
+        check,
+      ],
+      {
+        timeout: 120000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Find unused dependencies of all crates below a directory with
+   * cargo-machete (text output, exit code 1 when some were found)
+   */
+  static async runCargoMachete(
+    projectPath: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      ['machete', sanitizeFilePath(projectPath)],
+      {
+        timeout: 60000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Find unused dependencies with cargo-udeps, which compiles all targets
+   * on the nightly toolchain
+   */
+  static async runCargoUdeps(
+    manifestPath: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        '+nightly',
+        'udeps',
+        '--workspace',
+        '--all-targets',
+        '--output',
+        'json',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+      ],
+      {
+        timeout: 600000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * List the toolchains installed through rustup
+   */
+  static async runRustupToolchainList(
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync('rustup', ['toolchain', 'list'], {
+      timeout: 15000,
+      ...options,
+    });
+  }
+
+  /**
+   * Run `cargo check` for the whole workspace on a specific toolchain
+   */
+  static async runCargoCheck(
+    manifestPath: string,
+    toolchain: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        `+${toolchain}`,
+        'check',
+        '--workspace',
+        '--message-format=json',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+      ],
+      {
+        timeout: 600000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Run the workspace tests with cargo-nextest, layering the given tool
+   * config file over the repository's nextest config; the profile decides
+   * where the JUnit report is written
+   */
+  static async runCargoNextest(
+    manifestPath: string,
+    configFile: string,
+    profile: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'nextest',
+        'run',
+        '--workspace',
+        '--no-fail-fast',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+        '--tool-config-file',
+        `woaru:${sanitizeFilePath(configFile)}`,
+        '--profile',
+        profile,
+      ],
+      {
+        timeout: 900000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Run the workspace benchmarks with cargo bench (Criterion writes its
+   * estimates to target/criterion)
+   */
+  static async runCargoBench(
+    manifestPath: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'bench',
+        '--workspace',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+      ],
+      {
+        timeout: 1800000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Run the workspace tests with cargo test, passing `libtestArgs` to the
+   * test binaries (after `--`)
+   */
+  static async runCargoTest(
+    manifestPath: string,
+    libtestArgs: string[] = [],
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'test',
+        '--workspace',
+        '--no-fail-fast',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+        ...(libtestArgs.length > 0 ? ['--', ...libtestArgs] : []),
+      ],
+      {
+        timeout: 900000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Run `cargo hack check` over the feature combinations of one package,
+   * continuing after failures
+   */
+  static async runCargoHackCheck(
+    manifestPath: string,
+    packageName: string,
+    hackArgs: string[],
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'hack',
+        'check',
+        ...hackArgs,
+        '--keep-going',
+        '--message-format=short',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+        '--package',
+        packageName,
+      ],
+      {
+        timeout: 1800000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Run `cargo check` for one package with the given feature flags
+   */
+  static async runCargoFeatureCheck(
+    manifestPath: string,
+    packageName: string,
+    featureArgs: string[],
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'check',
+        '--message-format=short',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+        '--package',
+        packageName,
+        ...featureArgs,
+      ],
+      {
+        timeout: 600000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Run cargo-semver-checks for one package against a checkout of the
+   * baseline revision
+   */
+  static async runCargoSemverChecks(
+    manifestPath: string,
+    packageName: string,
+    baselineRoot: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        'semver-checks',
+        'check-release',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+        '--package',
+        packageName,
+        '--baseline-root',
+        sanitizeFilePath(baselineRoot),
+        '--color',
+        'never',
+      ],
+      {
+        timeout: 1800000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Print the documentation coverage of a package's library target as JSON
+   * (`--show-coverage`, nightly only)
+   */
+  static async runCargoRustdocCoverage(
+    manifestPath: string,
+    packageName: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        '+nightly',
+        'rustdoc',
+        '--lib',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+        '--package',
+        packageName,
+        '--',
+        '-Z',
+        'unstable-options',
+        '--show-coverage',
+        '--output-format',
+        'json',
+      ],
+      {
+        timeout: 600000,
+        ...options,
+      }
+    );
+  }
+
+  /**
+   * Write the rustdoc JSON of a package's library target (nightly only) to
+   * `<targetDir>/doc/<crate_name>.json`
+   */
+  static async runCargoRustdocJson(
+    manifestPath: string,
+    packageName: string,
+    targetDir: string,
+    options: ToolExecutionOptions = {}
+  ): Promise<ExecResult> {
+    return safeExecAsync(
+      'cargo',
+      [
+        '+nightly',
+        'rustdoc',
+        '--lib',
+        '--manifest-path',
+        sanitizeFilePath(manifestPath),
+        '--package',
+        packageName,
+        '--target-dir',
+        sanitizeFilePath(targetDir),
+        '--',
+        '-Z',
+        'unstable-options',
+        '--output-format',
+        'json',
+      ],
+      {
+        timeout: 900000,
+        ...options,
+      }
+    );
+  }
+
   /**
    * Run .NET format on a file
    */
@@ -1,4 +1,5 @@
 import { CodeAnalyzer } from '../src/analyzer/CodeAnalyzer';
+import { UnsafeInventoryScanner } from '../src/rust/UnsafeInventoryScanner';
 import * as fs from 'fs-extra';
 import * as path from 'path';
 import { tmpdir } from 'os';
@@ -110,6 +111,28 @@ public class TestClass {
     });
   });
 
+  describe('Rust analysis', () => {
+    it('should report a shared inventory including dependencies', async () => {
+      const scan = jest.spyOn(UnsafeInventoryScanner.prototype, 'scan');
+      const counts = { unsafeBlocks: 0, unsafeFns: 0, unsafeImpls: 0, externBlocks: 0 };
+
+      const insights = await analyzer.analyzeCodebase(testDir, 'Rust', {
+        unsafeInventory: {
+          crates: [
+            { ...counts, name: 'app', source: 'workspace', rootDir: testDir, forbidsUnsafe: true, filesScanned: 1, files: [] },
+            { ...counts, unsafeBlocks: 3, name: 'libc', version: '0.2.150', source: 'registry', rootDir: '/registry/libc', forbidsUnsafe: false, filesScanned: 4, files: [] },
+          ],
+          unresolvedDependencies: [],
+        },
+      });
+
+      expect(scan).not.toHaveBeenCalled();
+      expect(insights.get('cargo-geiger')?.severity).toBe('low');
+      expect(insights.get('cargo-geiger')?.evidence[0]).toContain('libc 0.2.150 (registry)');
+      scan.mockRestore();
+    });
+  });
+
   describe('edge cases', () => {
     it('should handle empty directories', async () => {
       const insights = await analyzer.analyzeCodebase(testDir, 'JavaScript');
@@ -0,0 +1,79 @@
+/**
+ * Unit Tests for AsyncBlockingDetector
+ * Testing blocking calls and std lock guards held across .await in async
+ * functions and blocks
+ */
+
+import { findAsyncBlocking } from '../../src/rust/AsyncBlockingDetector';
+
+const HANDLERS = `use std::fs;
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+
+pub async fn load(state: Arc<Mutex<Vec<u8>>>) -> Vec<u8> {
+    sleep(Duration::from_millis(10));
+    let config = fs::read_to_string("config.toml").unwrap();
+    let body = reqwest::blocking::get("https://example.com").unwrap();
+    let guard = state.lock().unwrap();
+    fetch().await;
+    guard.clone()
+}
+
+pub async fn scoped(state: Arc<Mutex<Vec<u8>>>) {
+    {
+        let guard = state.lock().expect("poisoned");
+        guard.len();
+    }
+    let other = state.lock().unwrap();
+    drop(other);
+    fetch().await;
+    let data = tokio::fs::read("x").await.unwrap();
+    let parsed = tokio::task::spawn_blocking(move || {
+        std::fs::read_to_string("big.json")
+    })
+    .await;
+}
+
+pub fn sync_helper() {
+    std::thread::sleep(Duration::from_secs(1));
+}
+
+pub fn spawn() {
+    tokio::spawn(async move {
+        // std::fs::read("ignored") in a comment
+        std::fs::write("out", "data").unwrap();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn slow() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+`;
+
+describe('findAsyncBlocking', () => {
+  it('should report blocking calls and guards held across .await', () => {
+    const findings = findAsyncBlocking(HANDLERS, 'src/handlers.rs');
+
+    expect(findings.map(f => [f.line, f.kind, f.call, f.context])).toEqual([
+      [6, 'thread-sleep', 'std::thread::sleep', 'async fn load'],
+      [7, 'std-fs', 'std::fs::read_to_string', 'async fn load'],
+      [8, 'reqwest-blocking', 'reqwest::blocking::get', 'async fn load'],
+      [9, 'mutex-across-await', 'std::sync::Mutex::lock', 'async fn load'],
+      [36, 'std-fs', 'std::fs::write', 'async block'],
+    ]);
+    expect(findings[0]).toMatchObject({
+      file: 'src/handlers.rs',
+      alternative: 'use tokio::time::sleep(duration).await',
+    });
+  });
+
+  it('should ignore files without async code', () => {
+    expect(
+      findAsyncBlocking('fn main() { std::fs::read("x").unwrap(); }', 'a.rs')
+    ).toEqual([]);
+  });
+});
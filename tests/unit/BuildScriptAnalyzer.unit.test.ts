@@ -0,0 +1,245 @@
+/**
+ * Unit Tests for BuildScriptAnalyzer
+ * Testing the detection of build scripts and proc-macros from cargo metadata
+ * and the static scan for network, process and environment access
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  BuildScriptAnalyzer,
+  findCompileTimeCrates,
+  scanBuildScript,
+} from '../../src/rust/BuildScriptAnalyzer';
+import { ToolExecutor } from '../../src/utils/toolExecutor';
+
+const BUILD_RS = `use std::env;
+use std::process::Command;
+
+// std::net::TcpStream::connect in a comment is ignored
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let feature = env::var(format!("CARGO_FEATURE_{}", "STD"));
+    let token = std::env::var("GITHUB_TOKEN").ok();
+    let home = env::var_os(name);
+    let body = reqwest::blocking::get("https://example.com/lib.tar.gz");
+    let rustc = Command::new(env::var("RUSTC").unwrap()).output();
+    println!("cargo:warning=env::var(\\"SECRET\\") in a string");
+}
+`;
+
+describe('scanBuildScript', () => {
+  it('should flag network, processes and non-Cargo environment reads', () => {
+    const findings = scanBuildScript(BUILD_RS, 'build.rs');
+
+    expect(findings.map(f => [f.kind, f.line, f.detail])).toEqual([
+      ['process', 2, 'std::process::Command'],
+      ['env', 8, 'env::var("GITHUB_TOKEN")'],
+      ['env', 9, 'env::var_os(<dynamisch>)'],
+      ['network', 10, 'reqwest::blocking'],
+      ['process', 11, 'Command::new'],
+    ]);
+  });
+
+  it('should accept metadata of links dependencies', () => {
+    expect(
+      scanBuildScript('let inc = env::var("DEP_OPENSSL_INCLUDE");', 'build.rs')
+    ).toEqual([]);
+  });
+});
+
+describe('findCompileTimeCrates', () => {
+  it('should list packages with build scripts or proc-macro targets', () => {
+    const lib = (src: string) => ({ kind: ['lib'], src_path: src });
+    const metadata = JSON.stringify({
+      packages: [
+        {
+          id: 'app 0.1.0 (path+file:///ws/app)',
+          name: 'app',
+          version: '0.1.0',
+          source: null,
+          manifest_path: '/ws/app/Cargo.toml',
+          targets: [
+            lib('/ws/app/src/lib.rs'),
+            { kind: ['custom-build'], src_path: '/ws/app/build.rs' },
+          ],
+        },
+        {
+          id: 'serde_derive 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)',
+          name: 'serde_derive',
+          version: '1.0.0',
+          source: 'registry+https://github.com/rust-lang/crates.io-index',
+          manifest_path: '/reg/serde_derive-1.0.0/Cargo.toml',
+          targets: [{ kind: ['proc-macro'], src_path: '/reg/lib.rs' }],
+        },
+        {
+          id: 'itoa 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)',
+          name: 'itoa',
+          version: '1.0.0',
+          source: 'registry+https://github.com/rust-lang/crates.io-index',
+          manifest_path: '/reg/itoa-1.0.0/Cargo.toml',
+          targets: [lib('/reg/itoa-1.0.0/src/lib.rs')],
+        },
+        {
+          id: 'sys 0.2.0 (git+https://example.com/sys#abc)',
+          name: 'sys',
+          version: '0.2.0',
+          source: 'git+https://example.com/sys#abc',
+          manifest_path: '/git/sys/Cargo.toml',
+          targets: [{ kind: ['custom-build'], src_path: '/git/sys/build.rs' }],
+        },
+      ],
+      workspace_members: ['app 0.1.0 (path+file:///ws/app)'],
+    });
+
+    const crates = findCompileTimeCrates(metadata)!;
+
+    expect(
+      crates.map(c => [c.name, c.source, c.buildScript, c.procMacro])
+    ).toEqual([
+      ['app', 'workspace', '/ws/app/build.rs', false],
+      ['serde_derive', 'registry', undefined, true],
+      ['sys', 'git', '/git/sys/build.rs', false],
+    ]);
+    expect(findCompileTimeCrates('not json')).toBeNull();
+  });
+});
+
+describe('BuildScriptAnalyzer', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-build-rs-'));
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should scan build scripts including modules of build/ dirs', async () => {
+    const depDir = path.join(tempDir, 'vendor', 'openssl-sys');
+    await fs.writeFile(path.join(tempDir, 'Cargo.toml'), '[package]\n');
+    await fs.writeFile(
+      path.join(tempDir, 'build.rs'),
+      'fn main() { println!("cargo:rerun-if-changed=build.rs"); }\n'
+    );
+    await fs.outputFile(path.join(depDir, 'Cargo.toml'), '[package]\n');
+    await fs.outputFile(
+      path.join(depDir, 'build', 'main.rs'),
+      'mod find;\nfn main() { find::run(); }\n'
+    );
+    await fs.outputFile(
+      path.join(depDir, 'build', 'find.rs'),
+      'pub fn run() {\n    std::process::Command::new("pkg-config");\n}\n'
+    );
+    const target = (name: string, src: string) => ({
+      id: name,
+      name,
+      version: '1.0.0',
+      source: name === 'app' ? null : 'registry+https://example.com/index',
+      manifest_path: path.join(path.dirname(src), 'Cargo.toml'),
+      targets: [{ kind: ['custom-build'], src_path: src }],
+    });
+    const metadata = {
+      packages: [
+        target('app', path.join(tempDir, 'build.rs')),
+        {
+          ...target('openssl-sys', path.join(depDir, 'build', 'main.rs')),
+          manifest_path: path.join(depDir, 'Cargo.toml'),
+        },
+      ],
+      workspace_members: ['app'],
+    };
+    const cargo = jest
+      .spyOn(ToolExecutor, 'runCargoMetadataWithDependencies')
+      .mockResolvedValue({
+        stdout: JSON.stringify(metadata),
+        stderr: '',
+        exitCode: 0,
+      });
+
+    const report = await new BuildScriptAnalyzer().analyze(tempDir);
+
+    expect(cargo).toHaveBeenCalledWith(path.join(tempDir, 'Cargo.toml'));
+    expect(report!.error).toBeUndefined();
+    expect(report!.crates.map(c => [c.name, c.findings.length])).toEqual([
+      ['app', 0],
+      ['openssl-sys', 1],
+    ]);
+    expect(report!.crates[1].findings[0]).toMatchObject({
+      kind: 'process',
+      file: path.join(depDir, 'build', 'find.rs'),
+      line: 2,
+    });
+  });
+
+  it('should scan only the modules a build script in src/ declares', async () => {
+    await fs.writeFile(path.join(tempDir, 'Cargo.toml'), '[package]\n');
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'build.rs'),
+      '#[path = "codegen/gen.rs"]\nmod gen;\nfn main() { gen::run(); }\n'
+    );
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'codegen', 'gen.rs'),
+      'pub fn run() {\n    std::process::Command::new("protoc");\n}\n'
+    );
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'lib.rs'),
+      'pub fn spawn() { std::process::Command::new("sh"); }\n'
+    );
+    jest
+      .spyOn(ToolExecutor, 'runCargoMetadataWithDependencies')
+      .mockResolvedValue({
+        stdout: JSON.stringify({
+          packages: [
+            {
+              id: 'app',
+              name: 'app',
+              version: '1.0.0',
+              source: null,
+              manifest_path: path.join(tempDir, 'Cargo.toml'),
+              targets: [
+                {
+                  kind: ['custom-build'],
+                  src_path: path.join(tempDir, 'src', 'build.rs'),
+                },
+              ],
+            },
+          ],
+          workspace_members: ['app'],
+        }),
+        stderr: '',
+        exitCode: 0,
+      });
+
+    const report = await new BuildScriptAnalyzer().analyze(tempDir);
+
+    expect(report!.crates[0].findings).toEqual([
+      expect.objectContaining({
+        kind: 'process',
+        file: path.join(tempDir, 'src', 'codegen', 'gen.rs'),
+        line: 2,
+      }),
+    ]);
+  });
+
+  it('should report cargo metadata failures and skip non-Cargo projects', async () => {
+    expect(await new BuildScriptAnalyzer().analyze(tempDir)).toBeNull();
+
+    await fs.writeFile(path.join(tempDir, 'Cargo.toml'), '[package]\n');
+    jest
+      .spyOn(ToolExecutor, 'runCargoMetadataWithDependencies')
+      .mockResolvedValue({
+        stdout: '',
+        stderr: 'error: the lock file needs to be updated',
+        exitCode: 101,
+      });
+
+    expect(await new BuildScriptAnalyzer().analyze(tempDir)).toEqual({
+      crates: [],
+      error: 'error: the lock file needs to be updated',
+    });
+  });
+});
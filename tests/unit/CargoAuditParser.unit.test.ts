@@ -0,0 +1,95 @@
+/**
+ * Unit Tests for CargoAuditParser
+ * Testing the mapping of cargo-audit JSON reports onto security findings
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { CargoAuditParser } from '../../src/rust/CargoAuditParser';
+
+const report = fs.readFileSync(
+  path.join(__dirname, '../fixtures/cargo-audit-report.json'),
+  'utf-8'
+);
+
+describe('CargoAuditParser', () => {
+  it('should map RustSec vulnerabilities with advisory, crate and fix', () => {
+    const result = CargoAuditParser.parse(report);
+
+    expect(result?.tool).toBe('cargo-audit');
+    expect(result?.findings[0]).toMatchObject({
+      tool: 'cargo-audit',
+      severity: 'medium',
+      advisoryId: 'RUSTSEC-2023-0071',
+      package: 'rsa',
+      version: '0.9.6',
+      cve: 'CVE-2023-49092',
+      fixedIn: undefined,
+    });
+    expect(result?.findings[0].references?.[0]).toBe(
+      'https://rustsec.org/advisories/RUSTSEC-2023-0071'
+    );
+    expect(result?.findings[1]).toMatchObject({
+      severity: 'high', // No CVSS vector
+      advisoryId: 'RUSTSEC-2024-0003',
+      fixedIn: '^0.3.24, >=0.4.2',
+      cve: undefined,
+    });
+    expect(result?.findings[1].recommendation).toContain(
+      'cargo update -p h2'
+    );
+  });
+
+  it('should include unmaintained and yanked warnings in the summary', () => {
+    const result = CargoAuditParser.parse(report);
+
+    expect(result?.findings.slice(2)).toEqual([
+      expect.objectContaining({
+        advisoryId: 'RUSTSEC-2021-0139',
+        package: 'ansi_term',
+        severity: 'low',
+      }),
+      expect.objectContaining({
+        package: 'spin',
+        version: '0.9.3',
+        severity: 'low',
+        title: 'spin 0.9.3 is yanked',
+      }),
+    ]);
+    expect(result?.summary).toEqual({
+      total: 4,
+      critical: 0,
+      high: 1,
+      medium: 1,
+      low: 2,
+      info: 0,
+    });
+  });
+
+  it('should compute CVSS v3 base scores and severities', () => {
+    expect(
+      CargoAuditParser.cvssBaseScore(
+        'CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H'
+      )
+    ).toBe(9.8);
+    expect(
+      CargoAuditParser.cvssBaseScore(
+        'CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:L/I:L/A:N'
+      )
+    ).toBe(6.4);
+    expect(
+      CargoAuditParser.severityFromCvss(
+        'CVSS:3.0/AV:N/AC:H/PR:N/UI:N/S:U/C:H/I:N/A:N'
+      )
+    ).toBe('medium');
+    expect(
+      CargoAuditParser.severityFromCvss('CVSS:4.0/AV:N/AC:L/AT:N/PR:N')
+    ).toBeNull();
+  });
+
+  it('should return null for output that is not a report', () => {
+    expect(CargoAuditParser.parse('error: no such command: `audit`')).toBe(
+      null
+    );
+  });
+});
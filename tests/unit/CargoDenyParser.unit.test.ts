@@ -0,0 +1,83 @@
+/**
+ * Unit Tests for CargoDenyParser
+ * Testing the JSON-lines diagnostics emitted by `cargo deny --format json`
+ */
+
+import { CargoDenyParser } from '../../src/rust/CargoDenyParser';
+
+const licensesOutput = [
+  JSON.stringify({
+    type: 'diagnostic',
+    fields: {
+      code: 'rejected',
+      severity: 'error',
+      message: 'failed to satisfy license requirements',
+      labels: [
+        {
+          message: 'license expression retrieved via Cargo.toml `license`',
+          span: 'GPL-3.0-only',
+          line: 1,
+          column: 1,
+        },
+      ],
+      notes: ['GPL-3.0-only - GNU General Public License v3.0 only'],
+      graphs: [{ Krate: { name: 'readline-gpl', version: '0.2.1' } }],
+    },
+  }),
+  JSON.stringify({
+    type: 'diagnostic',
+    fields: {
+      code: 'accepted',
+      severity: 'help',
+      message: 'license requirements satisfied',
+      graphs: [{ Krate: { name: 'serde', version: '1.0.197' } }],
+    },
+  }),
+  JSON.stringify({
+    type: 'summary',
+    fields: { licenses: { errors: 1, warnings: 0, notes: 0, helps: 1 } },
+  }),
+].join('\n');
+
+describe('CargoDenyParser', () => {
+  it('should parse diagnostics with crate, labels and notes', () => {
+    const diagnostics = CargoDenyParser.parse(licensesOutput, 'licenses');
+
+    expect(diagnostics).toHaveLength(2);
+    expect(diagnostics[0]).toEqual({
+      check: 'licenses',
+      code: 'rejected',
+      severity: 'error',
+      message: 'failed to satisfy license requirements',
+      crate: 'readline-gpl',
+      version: '0.2.1',
+      labels: [
+        'license expression retrieved via Cargo.toml `license`: GPL-3.0-only',
+      ],
+      notes: ['GPL-3.0-only - GNU General Public License v3.0 only'],
+    });
+    expect(diagnostics[1]).toMatchObject({
+      code: 'accepted',
+      severity: 'help',
+    });
+  });
+
+  it('should ignore plain text and collect error logs', () => {
+    const output = [
+      '   Compiling nothing',
+      JSON.stringify({
+        type: 'log',
+        fields: {
+          level: 'ERROR',
+          message: 'failed to deserialize config from deny.toml',
+        },
+      }),
+      JSON.stringify({ type: 'log', fields: { level: 'WARN', message: 'x' } }),
+    ].join('\n');
+
+    expect(CargoDenyParser.parse(output, 'bans')).toEqual([]);
+    expect(CargoDenyParser.parseErrors(output)).toEqual([
+      'failed to deserialize config from deny.toml',
+    ]);
+  });
+});
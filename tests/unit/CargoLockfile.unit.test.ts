@@ -0,0 +1,94 @@
+/**
+ * Unit Tests for CargoLockfile
+ * Testing semver-incompatible duplicate crates and the dependency chains
+ * pulling them in
+ */
+
+import {
+  findDuplicateVersions,
+  parseCargoLock,
+  semverCompatibilityKey,
+} from '../../src/rust/CargoLockfile';
+
+const REGISTRY = 'registry+https://github.com/rust-lang/crates.io-index';
+
+// Version 4 lockfile: references carry a version only where ambiguous
+const CARGO_LOCK = `# This file is automatically @generated by Cargo.
+version = 4
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "hyper",
+ "rand 0.8.5",
+ "reqwest",
+]
+
+[[package]]
+name = "hyper"
+version = "1.4.1"
+source = "${REGISTRY}"
+dependencies = [
+ "itoa",
+]
+
+[[package]]
+name = "itoa"
+version = "1.0.11"
+source = "${REGISTRY}"
+
+[[package]]
+name = "rand"
+version = "0.7.3"
+source = "${REGISTRY}"
+
+[[package]]
+name = "rand"
+version = "0.8.5"
+source = "${REGISTRY}"
+
+[[package]]
+name = "reqwest"
+version = "0.12.5"
+source = "${REGISTRY}"
+dependencies = [
+ "hyper",
+ "rand 0.7.3 (${REGISTRY})",
+]
+`;
+
+describe('CargoLockfile', () => {
+  it('should derive the Cargo compatibility key of a version', () => {
+    expect(semverCompatibilityKey('1.4.1')).toBe('1');
+    expect(semverCompatibilityKey('0.8.5')).toBe('0.8');
+    expect(semverCompatibilityKey('0.0.3-alpha.1')).toBe('0.0.3');
+  });
+
+  it('should report incompatible versions with their dependency paths', () => {
+    const duplicates = findDuplicateVersions(parseCargoLock(CARGO_LOCK));
+
+    expect(duplicates).toEqual([
+      {
+        name: 'rand',
+        versions: [
+          {
+            version: '0.7.3',
+            paths: [['app@0.1.0', 'reqwest@0.12.5', 'rand@0.7.3']],
+          },
+          { version: '0.8.5', paths: [['app@0.1.0', 'rand@0.8.5']] },
+        ],
+      },
+    ]);
+  });
+
+  it('should not report compatible versions from different sources', () => {
+    const packages = parseCargoLock(CARGO_LOCK).map(pkg =>
+      pkg.name === 'rand' && pkg.version === '0.7.3'
+        ? { ...pkg, version: '0.8.4', source: 'git+https://example.com/rand' }
+        : pkg
+    );
+
+    expect(findDuplicateVersions(packages)).toEqual([]);
+  });
+});
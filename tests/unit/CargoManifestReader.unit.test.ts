@@ -0,0 +1,218 @@
+/**
+ * Unit Tests for CargoManifestReader
+ * Testing dependency extraction, workspace inheritance and framework detection
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  CargoManifestReader,
+  detectRustFrameworks,
+  determineRustProjectType,
+} from '../../src/rust/CargoManifestReader';
+
+async function writeFiles(
+  root: string,
+  files: Record<string, string>
+): Promise<void> {
+  for (const [relative, content] of Object.entries(files)) {
+    const target = path.join(root, relative);
+    await fs.ensureDir(path.dirname(target));
+    await fs.writeFile(target, content);
+  }
+}
+
+describe('CargoManifestReader', () => {
+  let tempDir: string;
+  let reader: CargoManifestReader;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-manifest-'));
+    reader = new CargoManifestReader();
+  });
+
+  afterEach(async () => {
+    await fs.remove(tempDir);
+  });
+
+  it('should read all dependency kinds of a standalone package', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml': `
+[package]
+name = "api"
+version = "0.1.0"
+
+[dependencies]
+axum = "0.7"
+tokio = { version = "1", features = ["full"] }
+db = { package = "sqlx", version = "0.7", optional = true }
+
+[dev-dependencies]
+proptest = "1"
+
+[build-dependencies]
+cc = "1"
+
+[target.'cfg(unix)'.dependencies]
+nix = "0.27"
+
+[features]
+default = ["postgres"]
+postgres = ["dep:db", "db/postgres"]
+`,
+    });
+
+    const summary = await reader.readProject(tempDir);
+
+    expect(summary?.packages).toEqual(['api']);
+    expect(
+      summary?.dependencies.map(dep => [dep.name, dep.kind, dep.target])
+    ).toEqual([
+      ['axum', 'normal', undefined],
+      ['tokio', 'normal', undefined],
+      ['sqlx', 'normal', undefined],
+      ['proptest', 'dev', undefined],
+      ['cc', 'build', undefined],
+      ['nix', 'normal', 'cfg(unix)'],
+    ]);
+    expect(summary?.dependencies[1].features).toEqual(['full']);
+    expect(summary?.dependencies[2]).toMatchObject({
+      alias: 'db',
+      optional: true,
+    });
+    expect(summary?.features.api.postgres).toEqual(['dep:db', 'db/postgres']);
+    expect(summary?.frameworks).toEqual(['axum', 'tokio', 'sqlx']);
+  });
+
+  it('should collect members of a workspace and resolve inherited dependencies', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml': `
+[workspace]
+members = ["crates/*"]
+exclude = ["crates/legacy"]
+
+[workspace.dependencies]
+tokio = { version = "1.35", features = ["rt"] }
+serde = "1.0"
+`,
+      'crates/server/Cargo.toml': `
+[package]
+name = "server"
+
+[dependencies]
+tokio = { workspace = true, features = ["macros"] }
+serde.workspace = true
+
+[features]
+default = ["tls"]
+tls = []
+`,
+      'crates/client/Cargo.toml': `
+[package]
+name = "client"
+
+[features]
+default = []
+`,
+      'crates/legacy/Cargo.toml': `
+[package]
+name = "legacy"
+
+[dependencies]
+rocket = "0.5"
+`,
+    });
+
+    const summary = await reader.readProject(tempDir);
+
+    expect(summary?.packages).toEqual(['client', 'server']);
+    expect(summary?.features).toEqual({
+      client: { default: [] },
+      server: { default: ['tls'], tls: [] },
+    });
+    expect(summary?.dependencies).toEqual([
+      expect.objectContaining({
+        name: 'tokio',
+        version: '1.35',
+        features: ['rt', 'macros'],
+        workspaceInherited: true,
+      }),
+      expect.objectContaining({
+        name: 'serde',
+        version: '1.0',
+        workspaceInherited: true,
+      }),
+    ]);
+    expect(summary?.frameworks).toEqual(['tokio', 'serde']);
+  });
+
+  it('should resolve inherited dependencies when reading a member crate', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml':
+        '[workspace]\nmembers = ["app"]\n\n[workspace.dependencies]\nbevy = "0.12"\n',
+      'app/Cargo.toml':
+        '[package]\nname = "app"\n\n[dependencies]\nbevy = { workspace = true }\n',
+    });
+
+    const summary = await reader.readProject(path.join(tempDir, 'app'));
+
+    expect(summary?.workspaceManifestPath).toBe(
+      path.join(tempDir, 'Cargo.toml')
+    );
+    expect(summary?.dependencies[0]).toMatchObject({
+      name: 'bevy',
+      version: '0.12',
+    });
+    expect(summary?.frameworks).toEqual(['bevy']);
+  });
+
+  it('should classify binaries by framework and libraries', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml': '[workspace]\nmembers = ["api", "cli", "core"]\n',
+      'api/Cargo.toml':
+        '[package]\nname = "api"\n\n[dependencies]\naxum = "0.7"\n',
+      'api/src/main.rs': 'fn main() {}\n',
+      'cli/Cargo.toml':
+        '[package]\nname = "cli"\n\n[dependencies]\nclap = "4"\n\n[[bin]]\nname = "tool"\npath = "tool.rs"\n',
+      'core/Cargo.toml':
+        '[package]\nname = "core"\n\n[dependencies]\nserde = "1"\ntokio = "1"\n',
+      'core/src/lib.rs': 'pub fn run() {}\n',
+    });
+
+    const project = async (member: string) => {
+      const summary = await reader.readProject(path.join(tempDir, member));
+      return summary && determineRustProjectType(summary);
+    };
+
+    expect(await project('api')).toBe('backend');
+    expect(await project('cli')).toBe('cli');
+    expect(await project('core')).toBe('library');
+    const workspace = await reader.readProject(tempDir);
+    expect(workspace?.binaries).toEqual(['api', 'cli']);
+    expect(workspace && determineRustProjectType(workspace)).toBe('backend');
+  });
+
+  it('should return null without a Cargo.toml', async () => {
+    expect(await reader.readProject(tempDir)).toBeNull();
+  });
+
+  it('should only derive frameworks from normal dependencies', () => {
+    const base = {
+      features: [],
+      optional: false,
+      workspaceInherited: false,
+      manifestPath: 'Cargo.toml',
+    };
+
+    expect(
+      detectRustFrameworks([
+        { ...base, name: 'actix-web', kind: 'normal' },
+        { ...base, name: 'tokio', kind: 'dev' },
+        { ...base, name: 'anyhow', kind: 'normal' },
+        { ...base, name: 'constructor', kind: 'normal' },
+        { ...base, name: 'toString', kind: 'normal' },
+      ])
+    ).toEqual(['actix']);
+  });
+});
@@ -0,0 +1,150 @@
+/**
+ * Unit Tests for CargoWorkspaceResolver
+ * Testing file → package resolution across standalone crates and workspaces
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import { CargoWorkspaceResolver } from '../../src/rust/CargoWorkspaceResolver';
+
+async function writeFiles(
+  root: string,
+  files: Record<string, string>
+): Promise<void> {
+  for (const [relative, content] of Object.entries(files)) {
+    const target = path.join(root, relative);
+    await fs.ensureDir(path.dirname(target));
+    await fs.writeFile(target, content);
+  }
+}
+
+describe('CargoWorkspaceResolver', () => {
+  let tempDir: string;
+  let resolver: CargoWorkspaceResolver;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-cargo-'));
+    resolver = new CargoWorkspaceResolver();
+  });
+
+  afterEach(async () => {
+    await fs.remove(tempDir);
+  });
+
+  it('should resolve files under src/ to a standalone package', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml': '[package]\nname = "solo"\nversion = "0.2.0"\n',
+      'src/lib.rs': '',
+      'src/nested/mod.rs': '',
+    });
+
+    const pkg = await resolver.resolveForFile(
+      path.join(tempDir, 'src/nested/mod.rs')
+    );
+
+    expect(pkg).toEqual({
+      name: 'solo',
+      version: '0.2.0',
+      manifestPath: path.join(tempDir, 'Cargo.toml'),
+      rootDir: tempDir,
+      workspaceRoot: tempDir,
+      workspaceManifestPath: path.join(tempDir, 'Cargo.toml'),
+    });
+  });
+
+  it('should resolve workspace members through a virtual manifest', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml':
+        '[workspace]\nmembers = ["crates/*"]\nexclude = ["crates/scratch"]\n',
+      'crates/core/Cargo.toml':
+        '[package]\nname = "core"\nversion.workspace = true\n',
+      'crates/core/src/lib.rs': '',
+      'crates/scratch/Cargo.toml': '[package]\nname = "scratch"\n',
+      'crates/scratch/src/main.rs': '',
+    });
+
+    const member = await resolver.resolveForFile(
+      path.join(tempDir, 'crates/core/src/lib.rs')
+    );
+    expect(member).toMatchObject({
+      name: 'core',
+      version: undefined,
+      rootDir: path.join(tempDir, 'crates/core'),
+      workspaceRoot: tempDir,
+      workspaceManifestPath: path.join(tempDir, 'Cargo.toml'),
+    });
+
+    const excluded = await resolver.resolveForFile(
+      path.join(tempDir, 'crates/scratch/src/main.rs')
+    );
+    expect(excluded).toMatchObject({
+      name: 'scratch',
+      workspaceRoot: path.join(tempDir, 'crates/scratch'),
+    });
+  });
+
+  it('should treat a root package with [workspace] as the workspace root', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml':
+        '[package]\nname = "app"\n\n[workspace]\nmembers = ["tools/gen"]\n',
+      'src/main.rs': '',
+      'tools/gen/Cargo.toml': '[package]\nname = "gen"\n',
+      'tools/gen/src/main.rs': '',
+    });
+
+    const root = await resolver.resolveForFile(
+      path.join(tempDir, 'src/main.rs')
+    );
+    const member = await resolver.resolveForFile(
+      path.join(tempDir, 'tools/gen/src/main.rs')
+    );
+
+    expect(root?.workspaceRoot).toBe(tempDir);
+    expect(member).toMatchObject({ name: 'gen', workspaceRoot: tempDir });
+  });
+
+  it('should return null for files outside any package', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml': '[workspace]\nmembers = []\n',
+      'scripts/tool.rs': '',
+    });
+
+    expect(
+      await resolver.resolveForFile(path.join(tempDir, 'scripts/tool.rs'))
+    ).toBeNull();
+  });
+
+  it('should cache mappings until invalidated', async () => {
+    await writeFiles(tempDir, {
+      'Cargo.toml': '[package]\nname = "before"\n',
+      'src/lib.rs': '',
+    });
+    const file = path.join(tempDir, 'src/lib.rs');
+
+    expect((await resolver.resolveForFile(file))?.name).toBe('before');
+
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "after"\n'
+    );
+    expect((await resolver.resolveForFile(file))?.name).toBe('before');
+
+    resolver.invalidate();
+    expect((await resolver.resolveForFile(file))?.name).toBe('after');
+  });
+
+  it('should match members and exclude globs', () => {
+    const workspace = {
+      members: ['crates/*', 'tools/**'],
+      exclude: ['crates/experimental'],
+    };
+
+    expect(resolver.isWorkspaceMember(workspace, 'crates/core')).toBe(true);
+    expect(resolver.isWorkspaceMember(workspace, 'tools/a/b')).toBe(true);
+    expect(resolver.isWorkspaceMember(workspace, 'crates/experimental')).toBe(
+      false
+    );
+    expect(resolver.isWorkspaceMember(workspace, 'examples/demo')).toBe(false);
+  });
+});
@@ -0,0 +1,119 @@
+/**
+ * Unit Tests for ClippyAction and CargoManifestEditor
+ * Testing [lints] insertion into Cargo.toml and journal-based rollback
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import { ClippyAction } from '../../src/actions/ClippyAction';
+import {
+  appendTomlTables,
+  renderLintTables,
+  supportsLintsTable,
+} from '../../src/rust/CargoManifestEditor';
+import { parseToml } from '../../src/utils/tomlParser';
+
+const PACKAGE_MANIFEST = `# Keep this comment
+[package]
+name = "demo"   # aligned comment
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+serde = "1"
+`;
+
+describe('CargoManifestEditor', () => {
+  it('should append lint tables without touching existing text', () => {
+    const updated = appendTomlTables(
+      PACKAGE_MANIFEST,
+      renderLintTables('package')
+    );
+
+    expect(updated.startsWith(PACKAGE_MANIFEST)).toBe(true);
+    expect(updated).toContain('\n\n[lints.rust]\nunsafe_code = "warn"\n');
+    expect(parseToml(updated).lints).toMatchObject({
+      clippy: { all: { level: 'warn', priority: -1 } },
+    });
+  });
+
+  it('should keep CRLF line endings', () => {
+    const updated = appendTomlTables(
+      '[package]\r\nname = "demo"',
+      '[lints]\nworkspace = true'
+    );
+
+    expect(updated).toBe(
+      '[package]\r\nname = "demo"\r\n\r\n[lints]\r\nworkspace = true\r\n'
+    );
+  });
+
+  it('should reject rust-version below 1.74', () => {
+    expect(supportsLintsTable(parseToml(PACKAGE_MANIFEST))).toBe(true);
+    expect(supportsLintsTable({ package: { 'rust-version': '1.70' } })).toBe(
+      false
+    );
+  });
+});
+
+describe('ClippyAction', () => {
+  let tempDir: string;
+  let action: ClippyAction;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-clippy-'));
+    action = new ClippyAction();
+    jest.spyOn(console, 'log').mockImplementation(() => {});
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should let workspace members inherit workspace lints', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[workspace]\nmembers = ["crates/*"]\n'
+    );
+    await fs.ensureDir(path.join(tempDir, 'crates', 'core'));
+    await fs.writeFile(
+      path.join(tempDir, 'crates', 'core', 'Cargo.toml'),
+      '[package]\nname = "core"\nversion = "0.1.0"\n'
+    );
+
+    expect(await action.execute(tempDir, {})).toBe(true);
+
+    const root = await fs.readFile(path.join(tempDir, 'Cargo.toml'), 'utf-8');
+    const member = await fs.readFile(
+      path.join(tempDir, 'crates', 'core', 'Cargo.toml'),
+      'utf-8'
+    );
+    expect(root).toContain('[workspace.lints.clippy]');
+    expect(member).toContain('[lints]\nworkspace = true');
+  });
+
+  it('should restore Cargo.toml and remove clippy.toml on rollback', async () => {
+    const manifestPath = path.join(tempDir, 'Cargo.toml');
+    await fs.writeFile(manifestPath, PACKAGE_MANIFEST);
+
+    expect(await action.canExecute(tempDir)).toBe(true);
+    expect(await action.execute(tempDir, {})).toBe(true);
+    expect(await fs.pathExists(path.join(tempDir, 'clippy.toml'))).toBe(true);
+    expect(await action.canExecute(tempDir)).toBe(false);
+    // Backups live in the journal directory, not next to the manifest
+    expect(await fs.readdir(tempDir)).toEqual(
+      expect.arrayContaining(['.woaru', 'Cargo.toml', 'clippy.toml'])
+    );
+    expect(await fs.readdir(tempDir)).toHaveLength(3);
+
+    expect(await action.rollback(tempDir)).toBe(true);
+    expect(await fs.readFile(manifestPath, 'utf-8')).toBe(PACKAGE_MANIFEST);
+    expect(await fs.pathExists(path.join(tempDir, 'clippy.toml'))).toBe(false);
+    expect(
+      await fs.pathExists(path.join(tempDir, '.woaru', 'backups', 'clippy'))
+    ).toBe(false);
+    expect(await action.rollback(tempDir)).toBe(false);
+  });
+});
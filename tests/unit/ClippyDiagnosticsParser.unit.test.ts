@@ -0,0 +1,174 @@
+/**
+ * Unit Tests for ClippyDiagnosticsParser
+ * Testing conversion of cargo --message-format=json output into per-line findings
+ */
+
+import * as path from 'path';
+import { ClippyDiagnosticsParser } from '../../src/rust/ClippyDiagnosticsParser';
+
+const root = path.resolve('/work/crate');
+
+function compilerMessage(message: Record<string, unknown>): string {
+  return JSON.stringify({
+    reason: 'compiler-message',
+    package_id: 'demo 0.1.0 (path+file:///work/crate)',
+    message,
+  });
+}
+
+const needlessReturn = compilerMessage({
+  message: 'unneeded `return` statement',
+  code: { code: 'clippy::needless_return', explanation: null },
+  level: 'warning',
+  spans: [
+    {
+      file_name: 'src/lib.rs',
+      line_start: 3,
+      line_end: 3,
+      column_start: 5,
+      column_end: 14,
+      is_primary: true,
+      suggested_replacement: null,
+    },
+  ],
+  children: [
+    {
+      message: 'remove `return`',
+      code: null,
+      level: 'help',
+      spans: [
+        {
+          file_name: 'src/lib.rs',
+          line_start: 3,
+          line_end: 3,
+          column_start: 5,
+          column_end: 14,
+          is_primary: true,
+          suggested_replacement: 'x',
+          suggestion_applicability: 'MachineApplicable',
+        },
+      ],
+      children: [],
+      rendered: null,
+    },
+  ],
+  rendered: 'warning: unneeded `return` statement\n --> src/lib.rs:3:5\n',
+});
+
+const typeError = compilerMessage({
+  message: 'mismatched types',
+  code: { code: 'E0308', explanation: '...' },
+  level: 'error',
+  spans: [
+    {
+      file_name: 'src/main.rs',
+      line_start: 10,
+      line_end: 10,
+      column_start: 9,
+      column_end: 12,
+      is_primary: true,
+    },
+  ],
+  children: [],
+  rendered: 'error[E0308]: mismatched types',
+});
+
+const abortSummary = compilerMessage({
+  message: 'aborting due to 1 previous error',
+  code: null,
+  level: 'error',
+  spans: [],
+  children: [],
+  rendered: 'error: aborting due to 1 previous error',
+});
+
+describe('ClippyDiagnosticsParser', () => {
+  it('should turn each compiler message into its own diagnostic', () => {
+    const output = [
+      '{"reason":"compiler-artifact","package_id":"dep 1.0.0"}',
+      needlessReturn,
+      typeError,
+      abortSummary,
+      '{"reason":"build-finished","success":false}',
+    ].join('\n');
+
+    const diagnostics = ClippyDiagnosticsParser.parse(output, root);
+
+    expect(diagnostics).toHaveLength(2);
+    expect(diagnostics[0]).toMatchObject({
+      file: path.join(root, 'src/lib.rs'),
+      line: 3,
+      column: 5,
+      endColumn: 14,
+      level: 'warning',
+      lint: 'clippy::needless_return',
+    });
+    expect(diagnostics[0].suggestions).toEqual([
+      expect.objectContaining({
+        message: 'remove `return`',
+        replacement: 'x',
+        applicability: 'MachineApplicable',
+      }),
+    ]);
+    expect(diagnostics[1]).toMatchObject({ level: 'error', lint: 'E0308' });
+  });
+
+  it('should deduplicate messages repeated for several targets', () => {
+    const output = [needlessReturn, needlessReturn].join('\n');
+    expect(ClippyDiagnosticsParser.parse(output, root)).toHaveLength(1);
+  });
+
+  it('should ignore non-JSON lines', () => {
+    const output = ['    Checking demo v0.1.0', needlessReturn].join('\n');
+    expect(ClippyDiagnosticsParser.parse(output, root)).toHaveLength(1);
+  });
+
+  it('should filter diagnostics by file', () => {
+    const diagnostics = ClippyDiagnosticsParser.parse(
+      [needlessReturn, typeError].join('\n'),
+      root
+    );
+    const filtered = ClippyDiagnosticsParser.filterByFile(
+      diagnostics,
+      path.join(root, 'src/main.rs')
+    );
+
+    expect(filtered).toHaveLength(1);
+    expect(filtered[0].lint).toBe('E0308');
+  });
+
+  it('should map diagnostics to CodeIssues with severity and auto-fix flag', () => {
+    const diagnostics = ClippyDiagnosticsParser.parse(
+      [needlessReturn, typeError].join('\n'),
+      root
+    );
+    const issues = ClippyDiagnosticsParser.toCodeIssues(
+      diagnostics,
+      'src/lib.rs'
+    );
+
+    expect(issues[0]).toMatchObject({
+      type: 'clippy::needless_return',
+      severity: 'medium',
+      line: 3,
+      tool: 'clippy',
+      autoFixable: true,
+    });
+    expect(issues[1]).toMatchObject({
+      type: 'E0308',
+      severity: 'critical',
+      autoFixable: false,
+    });
+  });
+
+  it('should format issues and fixes like other linters', () => {
+    const diagnostics = ClippyDiagnosticsParser.parse(needlessReturn, root);
+
+    expect(ClippyDiagnosticsParser.formatIssue(diagnostics[0])).toBe(
+      'Line 3:5 - WARNING: unneeded `return` statement (Lint: clippy::needless_return)'
+    );
+    expect(ClippyDiagnosticsParser.formatFixes(diagnostics)).toEqual([
+      'Line 3:5 - remove `return`: `x`',
+    ]);
+  });
+});
@@ -0,0 +1,179 @@
+/**
+ * Unit Tests for CoverageReader
+ * Testing lcov, Cobertura and tarpaulin parsing and changed-line coverage
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  CoverageParser,
+  CoverageReader,
+  formatLineRanges,
+} from '../../src/quality/CoverageReader';
+import { GitDiffAnalyzer } from '../../src/utils/GitDiffAnalyzer';
+
+const ROOT = path.resolve('/project');
+
+describe('CoverageParser', () => {
+  it('should parse lcov from cargo llvm-cov', () => {
+    const lcov = [
+      'SF:/project/src/lib.rs',
+      'FN:1,demo::add',
+      'DA:1,3',
+      'DA:2,3',
+      'DA:5,0',
+      'LF:3',
+      'LH:2',
+      'end_of_record',
+      'SF:src/main.rs',
+      'DA:1,0',
+      'end_of_record',
+    ].join('\n');
+
+    const files = CoverageParser.parse('lcov', lcov, ROOT);
+
+    expect(files).toEqual([
+      {
+        file: path.join(ROOT, 'src/lib.rs'),
+        linesFound: 3,
+        linesHit: 2,
+        percentage: 66.7,
+        uncoveredLines: [5],
+      },
+      {
+        file: path.join(ROOT, 'src/main.rs'),
+        linesFound: 1,
+        linesHit: 0,
+        percentage: 0,
+        uncoveredLines: [1],
+      },
+    ]);
+  });
+
+  it('should parse Cobertura XML relative to its source root', () => {
+    const xml = `<?xml version="1.0"?>
+<coverage line-rate="0.5">
+  <sources><source>/project</source></sources>
+  <packages><package name="demo"><classes>
+    <class name="lib" filename="src/lib.rs" line-rate="0.5">
+      <lines>
+        <line number="3" hits="2"/>
+        <line number="4" hits="0"/>
+      </lines>
+    </class>
+  </classes></package></packages>
+</coverage>`;
+
+    expect(CoverageParser.detectFormat('cobertura.xml', xml)).toBe(
+      'cobertura'
+    );
+    expect(CoverageParser.parse('cobertura', xml, '/elsewhere')).toEqual([
+      {
+        file: path.join(ROOT, 'src/lib.rs'),
+        linesFound: 2,
+        linesHit: 1,
+        percentage: 50,
+        uncoveredLines: [4],
+      },
+    ]);
+  });
+
+  it('should parse tarpaulin JSON path components and line stats', () => {
+    const json = JSON.stringify({
+      files: [
+        {
+          path: ['/', 'project', 'src', 'lib.rs'],
+          content: 'fn main() {}',
+          traces: [
+            { line: 1, address: [1], length: 1, stats: { Line: 1 } },
+            { line: 2, address: [2], length: 1, stats: { Line: 0 } },
+          ],
+          covered: 1,
+          coverable: 2,
+        },
+      ],
+    });
+
+    expect(CoverageParser.detectFormat('report.json', json)).toBe('tarpaulin');
+    expect(CoverageParser.parse('tarpaulin', json, ROOT)[0]).toMatchObject({
+      file: path.join(ROOT, 'src/lib.rs'),
+      linesHit: 1,
+      uncoveredLines: [2],
+    });
+  });
+});
+
+describe('CoverageReader', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-coverage-'));
+  });
+
+  afterEach(async () => {
+    await fs.remove(tempDir);
+  });
+
+  it('should find the report and flag uncovered changed lines', async () => {
+    await fs.ensureDir(path.join(tempDir, 'target', 'llvm-cov'));
+    await fs.writeFile(
+      path.join(tempDir, 'target', 'llvm-cov', 'lcov.info'),
+      'SF:src/lib.rs\nDA:1,1\nDA:2,0\nDA:3,0\nDA:7,0\nend_of_record\n'
+    );
+
+    const report = await new CoverageReader(tempDir).read();
+    expect(report).toMatchObject({ format: 'lcov', percentage: 25 });
+
+    const libRs = path.join(tempDir, 'src', 'lib.rs');
+    const coverage = CoverageReader.forChanges(
+      report!,
+      new Map([[libRs, [1, 2, 3, 4]]])
+    );
+    expect(coverage.uncoveredChanges).toEqual([
+      { file: libRs, lines: [2, 3], fileCoverage: 25 },
+    ]);
+  });
+
+  it('should return null without a report', async () => {
+    expect(await new CoverageReader(tempDir).read()).toBeNull();
+  });
+});
+
+describe('changed lines', () => {
+  it('should collect added lines from a zero-context diff', () => {
+    const diff = [
+      'diff --git a/src/lib.rs b/src/lib.rs',
+      '--- a/src/lib.rs',
+      '+++ b/src/lib.rs',
+      '@@ -2,0 +3,2 @@ fn add()',
+      '+    let x = 1;',
+      '+    x',
+      '@@ -10 +12 @@',
+      '-old',
+      '+new',
+      '@@ -20,2 +22,0 @@',
+      'diff --git a/gone.rs b/gone.rs',
+      '+++ /dev/null',
+    ].join('\n');
+
+    const lines = new GitDiffAnalyzer(ROOT).parseChangedLines(diff);
+
+    expect(lines).toEqual(
+      new Map([[path.join(ROOT, 'src/lib.rs'), [3, 4, 12]]])
+    );
+    expect(formatLineRanges([3, 4, 12])).toBe('3-4, 12');
+  });
+
+  it('should resolve diff paths against the repository root', () => {
+    const diff = ['+++ b/crates/core/src/lib.rs', '@@ -1 +1 @@'].join('\n');
+
+    const lines = new GitDiffAnalyzer(
+      path.join(ROOT, 'crates/core')
+    ).parseChangedLines(diff, ROOT);
+
+    expect(lines).toEqual(
+      new Map([[path.join(ROOT, 'crates/core/src/lib.rs'), [1]]])
+    );
+  });
+});
@@ -0,0 +1,171 @@
+/**
+ * Unit Tests for CratesIoStatsSource
+ * Testing offline crate stats from a sparse-index mirror and recorded API
+ * responses, and their use in ToolsUpdater
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  CratesIoStatsSource,
+  sparseIndexPath,
+} from '../../src/database/CratesIoStatsSource';
+import { ToolsUpdater } from '../../src/database/ToolsUpdater';
+import { ToolsDatabase } from '../../src/types';
+
+const indexLine = (vers: string, yanked: boolean, pubtime?: string) =>
+  JSON.stringify({
+    name: 'cargo-deny',
+    vers,
+    deps: [],
+    cksum: '00',
+    features: {},
+    yanked,
+    ...(pubtime ? { pubtime } : {}),
+  });
+
+const CARGO_DENY_RESPONSE = {
+  crate: {
+    name: 'cargo-deny',
+    downloads: 4200000,
+    recent_downloads: 350000,
+    updated_at: '2025-01-20T10:00:00.000000Z',
+    max_stable_version: '0.16.4',
+    max_version: '0.16.4',
+    repository: 'https://github.com/EmbarkStudios/cargo-deny',
+  },
+  versions: [
+    {
+      num: '0.16.4',
+      yanked: false,
+      created_at: '2025-01-18T09:00:00.000000Z',
+    },
+  ],
+};
+
+describe('CratesIoStatsSource', () => {
+  let tempDir: string;
+  let indexDir: string;
+  let fixtureDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-crates-'));
+    indexDir = path.join(tempDir, 'index');
+    fixtureDir = path.join(tempDir, 'fixtures');
+
+    const denyIndex = path.join(indexDir, sparseIndexPath('cargo-deny'));
+    await fs.ensureDir(path.dirname(denyIndex));
+    await fs.writeFile(
+      denyIndex,
+      [
+        indexLine('0.16.3', false, '2024-12-01T08:00:00Z'),
+        indexLine('0.16.4', false, '2025-01-18T09:00:00Z'),
+        indexLine('0.17.0-rc.1', false),
+        indexLine('0.17.0', true),
+      ].join('\n') + '\n'
+    );
+
+    const yankedIndex = path.join(indexDir, sparseIndexPath('gone'));
+    await fs.ensureDir(path.dirname(yankedIndex));
+    await fs.writeFile(yankedIndex, indexLine('1.0.0', true) + '\n');
+
+    await fs.ensureDir(fixtureDir);
+    await fs.writeJson(
+      path.join(fixtureDir, 'cargo-deny.json'),
+      CARGO_DENY_RESPONSE
+    );
+  });
+
+  afterEach(async () => {
+    await fs.remove(tempDir);
+  });
+
+  it('should map crate names to sparse-index paths', () => {
+    expect(sparseIndexPath('a')).toBe(path.join('1', 'a'));
+    expect(sparseIndexPath('cc')).toBe(path.join('2', 'cc'));
+    expect(sparseIndexPath('Syn')).toBe(path.join('3', 's', 'syn'));
+    expect(sparseIndexPath('cargo-deny')).toBe(
+      path.join('ca', 'rg', 'cargo-deny')
+    );
+  });
+
+  it('should read the newest stable release from a sparse index', async () => {
+    const source = new CratesIoStatsSource({ indexDir });
+
+    expect(await source.getStats('cargo-deny')).toEqual({
+      name: 'cargo-deny',
+      downloads: 0,
+      version: '0.16.4',
+      lastRelease: '2025-01-18T09:00:00Z',
+      yanked: false,
+    });
+    expect(await source.getStats('gone')).toEqual(
+      expect.objectContaining({ yanked: true, version: undefined })
+    );
+    expect(await source.getStats('missing')).toBeNull();
+  });
+
+  it('should combine recorded downloads with the index', async () => {
+    const source = new CratesIoStatsSource({ indexDir, fixtureDir });
+
+    expect(await source.getStats('cargo-deny')).toEqual({
+      name: 'cargo-deny',
+      version: '0.16.4',
+      downloads: 350000,
+      totalDownloads: 4200000,
+      lastRelease: '2025-01-18T09:00:00Z',
+      repository: 'https://github.com/EmbarkStudios/cargo-deny',
+      yanked: false,
+    });
+  });
+});
+
+describe('ToolsUpdater with crates.io stats', () => {
+  let fixtureDir: string;
+
+  beforeEach(async () => {
+    fixtureDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-crates-'));
+    await fs.writeJson(
+      path.join(fixtureDir, 'cargo-deny.json'),
+      CARGO_DENY_RESPONSE
+    );
+  });
+
+  afterEach(async () => {
+    await fs.remove(fixtureDir);
+  });
+
+  it('should update crates registry tools from recorded responses', async () => {
+    const database: ToolsDatabase = {
+      version: '1.0.0',
+      lastUpdated: '2024-12-24',
+      categories: {
+        linting: {},
+        security: {
+          'cargo-deny': {
+            description: 'Lint Rust dependencies',
+            packages: ['cargo-deny'],
+            registry: 'crates',
+          },
+        },
+      },
+      frameworks: {},
+    };
+
+    const updated = await new ToolsUpdater({
+      fixtureDir,
+    }).generateUpdatedDatabase(database);
+
+    expect(updated.categories.security['cargo-deny'].metadata).toEqual(
+      expect.objectContaining({
+        popularity: 350000,
+        githubStars: 0,
+        latestVersion: '0.16.4',
+        lastRelease: '2025-01-18T09:00:00.000000Z',
+        deprecated: false,
+        alternatives: [],
+      })
+    );
+  });
+});
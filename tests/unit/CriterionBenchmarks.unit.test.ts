@@ -0,0 +1,192 @@
+/**
+ * Unit Tests for CriterionBenchmarks
+ * Testing Criterion estimate parsing, the per-commit history and the
+ * comparison with the base branch or the previous run
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  BenchmarkHistory,
+  compareBenchmarkRuns,
+  CriterionBenchmarkTracker,
+  parseCriterionEstimates,
+} from '../../src/quality/CriterionBenchmarks';
+import { BenchmarkRun } from '../../src/types/benchmarks';
+import { ToolExecutor } from '../../src/utils/toolExecutor';
+
+const BASE_COMMIT = 'a'.repeat(40);
+const HEAD_COMMIT = 'b'.repeat(40);
+
+function estimates(meanNs: number): string {
+  return JSON.stringify({
+    mean: {
+      confidence_interval: { lower_bound: meanNs * 0.9, upper_bound: meanNs },
+      point_estimate: meanNs,
+      standard_error: 1.5,
+    },
+    median: { point_estimate: meanNs - 1 },
+    std_dev: { point_estimate: 3.2 },
+    slope: null,
+  });
+}
+
+function run(commit: string, means: Record<string, number>): BenchmarkRun {
+  return {
+    commit,
+    measuredAt: '2026-01-01T00:00:00.000Z',
+    benchmarks: Object.entries(means).map(([id, meanNs]) => ({ id, meanNs })),
+  };
+}
+
+describe('CriterionBenchmarks', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-bench-'));
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should parse the mean, median and standard deviation', () => {
+    expect(parseCriterionEstimates('parse/small', estimates(120))).toEqual({
+      id: 'parse/small',
+      meanNs: 120,
+      medianNs: 119,
+      stdDevNs: 3.2,
+    });
+    expect(parseCriterionEstimates('x', '{"slope": null}')).toBe(null);
+    expect(parseCriterionEstimates('x', 'not json')).toBe(null);
+  });
+
+  it('should classify changes beyond the threshold', () => {
+    const result = compareBenchmarkRuns(
+      run(HEAD_COMMIT, { parse: 130, encode: 80, hash: 102, fresh: 5 }),
+      run(BASE_COMMIT, { parse: 100, encode: 100, hash: 100 }),
+      5
+    );
+
+    expect(result.regressions).toEqual([
+      { id: 'parse', baselineNs: 100, currentNs: 130, changePercent: 30 },
+    ]);
+    expect(result.improvements.map(c => [c.id, c.changePercent])).toEqual([
+      ['encode', -20],
+    ]);
+    expect(result.unchanged).toBe(1);
+    expect(result.added).toEqual(['fresh']);
+  });
+
+  it('should compare with the base branch and record the run', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "demo"\nversion = "0.1.0"\n'
+    );
+    const benchDir = path.join(tempDir, 'target', 'criterion', 'parse');
+    const newDir = path.join(benchDir, 'new');
+    await fs.outputFile(path.join(newDir, 'estimates.json'), estimates(150));
+    await fs.outputFile(
+      path.join(newDir, 'benchmark.json'),
+      JSON.stringify({ full_id: 'parse/1 KiB', directory_name: 'parse' })
+    );
+    await fs.outputFile(
+      path.join(benchDir, 'base', 'estimates.json'),
+      estimates(1)
+    );
+    await new BenchmarkHistory(tempDir).save(
+      run(BASE_COMMIT, { 'parse/1 KiB': 100 })
+    );
+
+    const report = await new CriterionBenchmarkTracker(tempDir).track({
+      commit: HEAD_COMMIT,
+      commitTime: new Date(Date.now() - 60_000),
+      baseCommit: BASE_COMMIT,
+      thresholdPercent: 10,
+    });
+
+    expect(report).toMatchObject({
+      baselineSource: 'base-branch',
+      thresholdPercent: 10,
+      regressions: [{ id: 'parse/1 KiB', changePercent: 50 }],
+    });
+    const saved = await new BenchmarkHistory(tempDir).load(HEAD_COMMIT);
+    expect(saved?.benchmarks).toEqual([
+      { id: 'parse/1 KiB', meanNs: 150, medianNs: 149, stdDevNs: 3.2 },
+    ]);
+
+    // Without a new cargo bench run the stored results are not a baseline
+    const rerun = await new CriterionBenchmarkTracker(tempDir).track({
+      commit: HEAD_COMMIT,
+    });
+    expect(rerun?.baselineSource).toBe('previous-run');
+    expect(rerun?.baseline?.commit).toBe(BASE_COMMIT);
+  });
+
+  it('should not record results older than the commit', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "demo"\nversion = "0.1.0"\n'
+    );
+    const criterionDir = path.join(tempDir, 'target', 'criterion');
+    await fs.outputFile(
+      path.join(criterionDir, 'parse', 'new', 'estimates.json'),
+      estimates(150)
+    );
+
+    const report = await new CriterionBenchmarkTracker(tempDir).track({
+      commit: HEAD_COMMIT,
+      commitTime: new Date(Date.now() + 60_000),
+    });
+
+    expect(report?.stale).toBe(true);
+    await expect(new BenchmarkHistory(tempDir).load(HEAD_COMMIT)).resolves.toBe(
+      null
+    );
+  });
+
+  it('should only read the results of the cargo bench run', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "demo"\nversion = "0.1.0"\n'
+    );
+    const criterionDir = path.join(tempDir, 'target', 'criterion');
+    const oldFile = path.join(criterionDir, 'old', 'new', 'estimates.json');
+    await fs.outputFile(oldFile, estimates(100));
+    const lastWeek = new Date(Date.now() - 7 * 24 * 3600 * 1000);
+    await fs.utimes(oldFile, lastWeek, lastWeek);
+    jest.spyOn(ToolExecutor, 'runCargoBench').mockImplementation(async () => {
+      await fs.outputFile(
+        path.join(criterionDir, 'parse', 'new', 'estimates.json'),
+        estimates(150)
+      );
+      return { stdout: '', stderr: '', exitCode: 0 };
+    });
+
+    const report = await new CriterionBenchmarkTracker(tempDir).track({
+      commit: HEAD_COMMIT,
+      runBenchmarks: true,
+    });
+
+    expect(report?.stale).toBeUndefined();
+    expect(report?.current.benchmarks.map(b => b.id)).toEqual(['parse']);
+    expect(Date.parse(report!.current.measuredAt)).toBeGreaterThan(
+      lastWeek.getTime()
+    );
+    const saved = await new BenchmarkHistory(tempDir).load(HEAD_COMMIT);
+    expect(saved?.benchmarks.map(b => b.id)).toEqual(['parse']);
+  });
+
+  it('should return null without Criterion results', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "demo"\nversion = "0.1.0"\n'
+    );
+
+    await expect(
+      new CriterionBenchmarkTracker(tempDir).track({ commit: HEAD_COMMIT })
+    ).resolves.toBe(null);
+  });
+});
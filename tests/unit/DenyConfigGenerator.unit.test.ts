@@ -0,0 +1,125 @@
+/**
+ * Unit Tests for DenyConfigGenerator
+ * Testing deny.toml generation from the licenses of locked crates
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import { parseToml } from '../../src/utils/tomlParser';
+import {
+  DenyConfigGenerator,
+  extractLicenseIds,
+  renderDenyToml,
+} from '../../src/rust/DenyConfigGenerator';
+
+const CRATES_IO = 'registry+https://github.com/rust-lang/crates.io-index';
+
+describe('DenyConfigGenerator', () => {
+  let tempDir: string;
+  let registrySrc: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-deny-'));
+    registrySrc = path.join(tempDir, 'registry', 'src');
+  });
+
+  afterEach(async () => {
+    await fs.remove(tempDir);
+  });
+
+  async function cacheCrate(
+    name: string,
+    version: string,
+    license: string
+  ) {
+    const dir = path.join(registrySrc, 'index.crates.io-6f17d22bba15001f');
+    await fs.ensureDir(path.join(dir, `${name}-${version}`));
+    await fs.writeFile(
+      path.join(dir, `${name}-${version}`, 'Cargo.toml'),
+      `[package]\nname = "${name}"\nversion = "${version}"\nlicense = "${license}"\n`
+    );
+  }
+
+  it('should collect licenses of registry crates from the local cache', async () => {
+    await cacheCrate('serde', '1.0.197', 'MIT OR Apache-2.0');
+    await cacheCrate('ring', '0.17.8', 'MIT AND ISC AND OpenSSL');
+    const lockfile = path.join(tempDir, 'Cargo.lock');
+    await fs.writeFile(
+      lockfile,
+      [
+        'version = 4',
+        '',
+        '[[package]]',
+        'name = "app"',
+        'version = "0.1.0"',
+        'dependencies = ["ring", "serde"]',
+        '',
+        '[[package]]',
+        'name = "ring"',
+        'version = "0.17.8"',
+        `source = "${CRATES_IO}"`,
+        '',
+        '[[package]]',
+        'name = "serde"',
+        'version = "1.0.197"',
+        `source = "${CRATES_IO}"`,
+        '',
+        '[[package]]',
+        'name = "uncached"',
+        'version = "2.0.0"',
+        `source = "${CRATES_IO}"`,
+      ].join('\n')
+    );
+
+    const result = await new DenyConfigGenerator(registrySrc).collectLicenses(
+      lockfile
+    );
+
+    expect([...result.licenses.keys()].sort()).toEqual([
+      'Apache-2.0',
+      'ISC',
+      'MIT',
+      'OpenSSL',
+    ]);
+    expect(result.licenses.get('MIT')).toEqual([
+      'ring 0.17.8',
+      'serde 1.0.197',
+    ]);
+    expect(result.unknown).toEqual(['uncached 2.0.0']);
+  });
+
+  it('should extract ids from SPDX and legacy license expressions', () => {
+    expect(extractLicenseIds('(MIT OR Apache-2.0) AND Unicode-3.0')).toEqual([
+      'MIT',
+      'Apache-2.0',
+      'Unicode-3.0',
+    ]);
+    expect(extractLicenseIds('Apache-2.0 WITH LLVM-exception')).toEqual([
+      'Apache-2.0',
+    ]);
+    expect(extractLicenseIds('MIT/Apache-2.0')).toEqual(['MIT', 'Apache-2.0']);
+  });
+
+  it('should render a parseable deny.toml allowing the used licenses', () => {
+    const content = renderDenyToml({
+      licenses: new Map([
+        ['MIT', ['serde 1.0.197', 'ring 0.17.8']],
+        ['Apache-2.0', ['serde 1.0.197']],
+      ]),
+      unknown: ['uncached 2.0.0'],
+    });
+    const config = parseToml(content);
+
+    expect(config.licenses).toMatchObject({
+      version: 2,
+      allow: ['Apache-2.0', 'MIT'],
+      private: { ignore: true },
+    });
+    expect(content).toContain('"MIT", # 2 crates');
+    expect(content).toContain('# - uncached 2.0.0');
+    expect(config.sources).toMatchObject({ 'unknown-git': 'deny' });
+    // cargo-deny manages the location of its advisory database itself
+    expect(config.advisories).toEqual({ version: 2, yanked: 'warn' });
+  });
+});
@@ -0,0 +1,184 @@
+/**
+ * Unit Tests for FeatureCombinationChecker
+ * Testing combination enumeration, cargo-hack output parsing and the
+ * internal cargo check fallback
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  describeCombination,
+  enumerateFeatureCombinations,
+  FeatureCombinationChecker,
+  findFeatureLines,
+  parseCargoHackOutput,
+} from '../../src/rust/FeatureCombinationChecker';
+import { ToolExecutor } from '../../src/utils/toolExecutor';
+
+const MANIFEST = `[package]
+name = "demo"
+version = "0.1.0"
+
+[features]
+default = ["json"]
+json = ["dep:serde_json"]
+"tls" = []
+cli = ["json"]
+
+[dependencies]
+serde_json = { version = "1", optional = true }
+`;
+
+const CARGO_HACK_OUTPUT = `info: running \`cargo check --no-default-features --manifest-path Cargo.toml --package demo\` on demo (1/5)
+    Finished \`dev\` profile [unoptimized + debuginfo] target(s) in 0.10s
+info: running \`cargo check --no-default-features --features json --manifest-path Cargo.toml --package demo\` on demo (2/5)
+    Finished \`dev\` profile [unoptimized + debuginfo] target(s) in 0.20s
+info: running \`cargo check --no-default-features --features tls --manifest-path Cargo.toml --package demo\` on demo (3/5)
+src/net.rs:4:5: error[E0433]: failed to resolve: use of undeclared crate or module \`serde_json\`
+error: could not compile \`demo\` (lib) due to 1 previous error
+info: running \`cargo check --no-default-features --features cli --manifest-path Cargo.toml --package demo\` on demo (4/5)
+info: running \`cargo check --all-features --manifest-path Cargo.toml --package demo\` on demo (5/5)
+error: failed to run \`cargo check --no-default-features --features tls --manifest-path Cargo.toml --package demo\`
+`;
+
+describe('enumerateFeatureCombinations', () => {
+  it('should check each feature on its own', () => {
+    expect(
+      enumerateFeatureCombinations(['a', 'b'], 'each-feature').map(
+        describeCombination
+      )
+    ).toEqual([
+      '--no-default-features',
+      '--no-default-features --features a',
+      '--no-default-features --features b',
+      '--all-features',
+    ]);
+  });
+
+  it('should build the powerset up to the configured depth', () => {
+    const combinations = enumerateFeatureCombinations(
+      ['a', 'b', 'c'],
+      'powerset',
+      2
+    );
+
+    expect(combinations.map(c => c.features.join('+'))).toEqual([
+      '',
+      'a',
+      'b',
+      'c',
+      'a+b',
+      'a+c',
+      'b+c',
+      '',
+    ]);
+    expect(combinations[combinations.length - 1].allFeatures).toBe(true);
+    expect(
+      enumerateFeatureCombinations(['a', 'b'], 'powerset', 2)
+    ).toHaveLength(4);
+  });
+});
+
+describe('cargo-hack output and manifest lines', () => {
+  it('should attribute errors to the combination that produced them', () => {
+    const { checked, failures } = parseCargoHackOutput(CARGO_HACK_OUTPUT);
+
+    expect(checked).toBe(5);
+    expect(failures).toEqual([
+      {
+        combination: {
+          features: ['tls'],
+          noDefaultFeatures: true,
+          allFeatures: false,
+        },
+        errors: [
+          'src/net.rs:4:5: error[E0433]: failed to resolve: use of undeclared crate or module `serde_json`',
+        ],
+      },
+    ]);
+  });
+
+  it('should find the [features] header and each feature line', () => {
+    const lines = findFeatureLines(MANIFEST);
+
+    expect(lines.get('')).toBe(5);
+    expect(lines.get('json')).toBe(7);
+    expect(lines.get('tls')).toBe(8);
+    expect(lines.has('serde_json')).toBe(false);
+  });
+});
+
+describe('FeatureCombinationChecker', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-features-'));
+    await fs.writeFile(path.join(tempDir, 'Cargo.toml'), MANIFEST);
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should map cargo-hack failures to feature lines', async () => {
+    jest.spyOn(ToolExecutor, 'runCargoHackCheck').mockResolvedValue({
+      stdout: '',
+      stderr: CARGO_HACK_OUTPUT,
+      exitCode: 1,
+    });
+
+    const [result] = (await new FeatureCombinationChecker().check(tempDir))!;
+
+    expect(result).toEqual(
+      expect.objectContaining({
+        packageName: 'demo',
+        runner: 'cargo-hack',
+        checked: 5,
+        manifestPath: path.join(tempDir, 'Cargo.toml'),
+      })
+    );
+    expect(result.failures.map(failure => failure.line)).toEqual([8]);
+  });
+
+  it('should fall back to cargo check when cargo-hack is missing', async () => {
+    jest.spyOn(ToolExecutor, 'runCargoHackCheck').mockResolvedValue({
+      stdout: '',
+      stderr: 'error: no such command: `hack`',
+      exitCode: 101,
+    });
+    const featureCheck = jest
+      .spyOn(ToolExecutor, 'runCargoFeatureCheck')
+      .mockImplementation(async (_manifest, _pkg, args) =>
+        args.includes('--all-features')
+          ? {
+              stdout: '',
+              stderr: 'src/lib.rs:1:1: error: conflicting features\n',
+              exitCode: 101,
+            }
+          : { stdout: '', stderr: '', exitCode: 0 }
+      );
+
+    const [result] = (await new FeatureCombinationChecker().check(tempDir, {
+      mode: 'powerset',
+      depth: 2,
+    }))!;
+
+    expect(result.runner).toBe('internal');
+    expect(featureCheck).toHaveBeenCalledTimes(8);
+    expect(result.checked).toBe(8);
+    expect(result.failures).toEqual([
+      expect.objectContaining({
+        errors: ['src/lib.rs:1:1: error: conflicting features'],
+        line: 5,
+      }),
+    ]);
+  });
+
+  it('should return null outside of a Cargo project', async () => {
+    await fs.remove(path.join(tempDir, 'Cargo.toml'));
+
+    expect(await new FeatureCombinationChecker().check(tempDir)).toBeNull();
+  });
+});
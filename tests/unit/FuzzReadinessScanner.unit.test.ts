@@ -0,0 +1,109 @@
+/**
+ * Unit Tests for FuzzReadinessScanner
+ * Testing the detection of cargo-fuzz targets, property-testing crates and
+ * public functions taking untrusted byte or string input
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  findFuzzCandidates,
+  functionParameters,
+  FuzzReadinessScanner,
+} from '../../src/rust/FuzzReadinessScanner';
+
+const LIB = `/// Parses a frame
+pub fn parse_frame<'a>(input: &'a [u8]) -> Option<&'a [u8]> {
+    input.get(1..)
+}
+
+pub fn decode_header(raw: &str, strict: bool) -> bool {
+    strict && raw.is_empty()
+}
+
+pub fn fill(buffer: &mut [u8]) {
+    buffer.fill(0);
+}
+
+pub fn apply<F: Fn(&str)>(f: F) {
+    f("x")
+}
+
+fn private_parse(input: &[u8]) -> usize {
+    input.len()
+}
+
+pub unsafe fn from_raw(ptr: &[u8]) -> u8 {
+    ptr[0]
+}
+
+#[cfg(test)]
+mod tests {
+    pub fn helper(input: &str) {}
+}
+`;
+
+describe('FuzzReadinessScanner', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-fuzz-'));
+  });
+
+  afterEach(async () => {
+    await fs.remove(tempDir);
+  });
+
+  it('should find public functions taking &[u8] or &str', () => {
+    expect(functionParameters('pub fn apply<F: Fn(&str)>(f: F)')).toBe('f: F');
+
+    const candidates = findFuzzCandidates(LIB, 'src/lib.rs', 'demo');
+
+    expect(candidates.map(c => [c.name, c.input, c.line])).toEqual([
+      ['parse_frame', '&[u8]', 2],
+      ['decode_header', '&str', 6],
+    ]);
+  });
+
+  it('should match candidates against fuzz targets', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "demo"\nversion = "0.1.0"\n\n[dev-dependencies]\nproptest = "1"\n'
+    );
+    await fs.outputFile(path.join(tempDir, 'src', 'lib.rs'), LIB);
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'main.rs'),
+      'pub fn run(args: &str) {}\nfn main() {}\n'
+    );
+    await fs.outputFile(
+      path.join(tempDir, 'fuzz', 'Cargo.toml'),
+      '[package]\nname = "demo-fuzz"\nversion = "0.0.0"\n\n[package.metadata]\ncargo-fuzz = true\n\n[[bin]]\nname = "frame"\npath = "fuzz_targets/frame.rs"\n'
+    );
+    await fs.outputFile(
+      path.join(tempDir, 'fuzz', 'fuzz_targets', 'frame.rs'),
+      '#![no_main]\n// decode_header(s) is next\nfuzz_target!(|data: &[u8]| { let _ = demo::parse_frame(data); });\n'
+    );
+
+    const report = await new FuzzReadinessScanner().scan(tempDir);
+
+    expect(report!.fuzzTargets).toEqual([
+      {
+        name: 'frame',
+        file: path.join(tempDir, 'fuzz', 'fuzz_targets', 'frame.rs'),
+        manifestPath: path.join(tempDir, 'fuzz', 'Cargo.toml'),
+      },
+    ]);
+    expect(report!.propertyTesting).toEqual([
+      { crate: 'demo', frameworks: ['proptest'] },
+    ]);
+    expect(report!.candidates.map(c => [c.name, c.fuzzed])).toEqual([
+      ['parse_frame', true],
+      ['decode_header', false],
+    ]);
+  });
+
+  it('should return null outside of Cargo projects', async () => {
+    await expect(new FuzzReadinessScanner().scan(tempDir)).resolves.toBe(null);
+  });
+});
@@ -0,0 +1,252 @@
+/**
+ * Unit Tests for ProductionReadinessAuditor
+ * Testing the Rust error monitoring, testing, environment and release
+ * profile audits on small Cargo projects
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  ProductionAudit,
+  ProductionReadinessAuditor,
+} from '../../src/auditor/ProductionReadinessAuditor';
+import { QualityRunner } from '../../src/quality/QualityRunner';
+import { BuildScriptAnalyzer } from '../../src/rust/BuildScriptAnalyzer';
+import {
+  CargoManifestReader,
+  determineRustProjectType,
+} from '../../src/rust/CargoManifestReader';
+
+const RUST_CHECKS = [
+  'rust-logging-facade',
+  'rust-error-tracking',
+  'rust-log-backend',
+  'rust-tests',
+  'rust-integration-tests',
+  'rust-unit-tests',
+  'rust-dotenv-unmaintained',
+  'env-config',
+  'rust-env-loading',
+  'rust-release-profile',
+  'compile-time-code',
+];
+
+const UNIT_TEST =
+  '#[cfg(test)]\nmod tests {\n    #[test]\n    fn works() {}\n}\n';
+
+function manifest(dependencies: string[], extra = ''): string {
+  return `[package]\nname = "demo"\nversion = "0.1.0"\n\n[dependencies]\n${dependencies.join('\n')}\n${extra}`;
+}
+
+describe('ProductionReadinessAuditor', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-auditor-'));
+    // External scanners are covered by their own tests
+    jest.spyOn(QualityRunner.prototype, 'runSnykChecks').mockResolvedValue([]);
+    jest
+      .spyOn(QualityRunner.prototype, 'runCargoAuditCheck')
+      .mockResolvedValue(null);
+    jest
+      .spyOn(QualityRunner.prototype, 'runCargoDenyChecks')
+      .mockResolvedValue(null);
+    jest
+      .spyOn(BuildScriptAnalyzer.prototype, 'analyze')
+      .mockResolvedValue(null);
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  async function audit(
+    files: Record<string, string>
+  ): Promise<Record<string, ProductionAudit>> {
+    for (const [relative, content] of Object.entries(files)) {
+      await fs.outputFile(path.join(tempDir, relative), content);
+    }
+    const cargo = await new CargoManifestReader().readProject(tempDir);
+    const auditor = new ProductionReadinessAuditor(tempDir);
+    auditor.useUnsafeInventory(null);
+
+    const audits = await auditor.auditProject({
+      language: 'Rust',
+      frameworks: cargo!.frameworks,
+      projectType: determineRustProjectType(cargo!),
+    });
+    return Object.fromEntries(
+      audits
+        .filter(audit => RUST_CHECKS.includes(audit.check))
+        .map(audit => [audit.check, audit])
+    );
+  }
+
+  it('should only ask libraries for a logging facade', async () => {
+    const audits = await audit({
+      'Cargo.toml': manifest(['serde = "1"']),
+      'src/lib.rs': `pub fn run() {}\n${UNIT_TEST}`,
+      'tests/api.rs': '#[test]\nfn api() {}\n',
+      '.env': 'PORT=1\n',
+      '.env.example': 'PORT=\n',
+    });
+
+    expect(Object.keys(audits)).toEqual(['rust-logging-facade']);
+    expect(audits['rust-logging-facade'].priority).toBe('low');
+  });
+
+  it('should require error tracking and logging for backends', async () => {
+    const audits = await audit({
+      'Cargo.toml': manifest(['axum = "0.7"', 'tokio = "1"']),
+      'src/main.rs': 'fn main() {}\n',
+    });
+
+    expect(audits['rust-error-tracking']).toMatchObject({
+      status: 'missing',
+      priority: 'critical',
+    });
+    expect(audits['rust-log-backend']).toBeUndefined();
+  });
+
+  it('should ask for sentry and a subscriber next to tracing', async () => {
+    const withTracing = await audit({
+      'Cargo.toml': manifest(['clap = "4"', 'tracing = "0.1"']),
+      'src/main.rs': 'fn main() {}\n',
+    });
+
+    expect(withTracing['rust-error-tracking']).toMatchObject({
+      status: 'partial',
+      priority: 'high',
+    });
+    expect(withTracing['rust-log-backend'].packages).toEqual([
+      'tracing-subscriber',
+    ]);
+
+    const complete = await audit({
+      'Cargo.toml': manifest([
+        'clap = "4"',
+        'tracing = "0.1"',
+        'tracing-subscriber = "0.3"',
+        'sentry = "0.34"',
+      ]),
+    });
+    expect(complete['rust-error-tracking']).toBeUndefined();
+    expect(complete['rust-log-backend']).toBeUndefined();
+  });
+
+  it('should check how binaries load .env files', async () => {
+    const unloaded = await audit({
+      'Cargo.toml': manifest(['clap = "4"']),
+      'src/main.rs': 'fn main() {}\n',
+      '.env.example': 'PORT=\n',
+    });
+    expect(unloaded['rust-env-loading'].packages).toEqual([
+      'dotenvy',
+      'config',
+    ]);
+
+    await fs.remove(path.join(tempDir, '.env.example'));
+    const unmaintained = await audit({
+      'Cargo.toml': manifest(['clap = "4"', 'dotenv = "0.15"']),
+    });
+    expect(unmaintained['rust-dotenv-unmaintained'].packages).toEqual([
+      'dotenvy',
+    ]);
+    expect(unmaintained['env-config']).toBeDefined();
+    expect(unmaintained['rust-env-loading']).toBeUndefined();
+  });
+
+  it('should check [profile.release] of binaries', async () => {
+    const missing = await audit({
+      'Cargo.toml': manifest(['clap = "4"']),
+      'src/main.rs': 'fn main() {}\n',
+    });
+    expect(missing['rust-release-profile']).toMatchObject({
+      status: 'missing',
+      priority: 'medium',
+      category: 'build-performance',
+      files: ['Cargo.toml'],
+    });
+
+    const tokioAbort = await audit({
+      'Cargo.toml': manifest(
+        ['axum = "0.7"', 'tokio = "1"'],
+        '\n[profile.release]\nlto = "thin"\ncodegen-units = 1\npanic = "abort"\n'
+      ),
+    });
+    expect(tokioAbort['rust-release-profile']).toMatchObject({
+      status: 'partial',
+      priority: 'low',
+    });
+    expect(tokioAbort['rust-release-profile'].recommendation).toContain(
+      'tokio-Task'
+    );
+
+    const tuned = await audit({
+      'Cargo.toml': manifest(
+        ['clap = "4"'],
+        '\n[profile.release]\nlto = "fat"\ncodegen-units = 1\npanic = "abort"\ndebug = "line-tables-only"\n'
+      ),
+    });
+    expect(tuned['rust-release-profile']).toBeUndefined();
+  });
+
+  it('should list every dependency running code at compile time', async () => {
+    const crates = Array.from({ length: 15 }, (_, i) => ({
+      name: `macro-${i}`,
+      version: '1.0.0',
+      source: 'registry' as const,
+      manifestPath: `/registry/macro-${i}/Cargo.toml`,
+      buildScript: i === 0 ? '/registry/macro-0/build.rs' : undefined,
+      procMacro: i > 0,
+      findings: [],
+    }));
+    jest
+      .spyOn(BuildScriptAnalyzer.prototype, 'analyze')
+      .mockResolvedValue({ crates });
+
+    const audits = await audit({
+      'Cargo.toml': manifest(['serde = "1"']),
+      'src/lib.rs': 'pub fn run() {}\n',
+    });
+
+    expect(audits['compile-time-code'].message).toContain(
+      '15 Abhängigkeit(en)'
+    );
+    expect(audits['compile-time-code'].packages).toHaveLength(15);
+    expect(audits['compile-time-code'].packages?.[0]).toBe(
+      'macro-0@1.0.0 (build.rs)'
+    );
+  });
+
+  it('should tell unit tests from integration tests', async () => {
+    const none = await audit({
+      'Cargo.toml': manifest([]),
+      'src/lib.rs': 'pub fn run() {}\n',
+    });
+    expect(none['rust-tests']).toMatchObject({
+      status: 'missing',
+      priority: 'critical',
+    });
+
+    const unitOnly = await audit({
+      'src/lib.rs': `pub fn run() {}\n${UNIT_TEST}`,
+      'src/tests/helpers.rs': 'pub fn helper() {}\n',
+    });
+    expect(unitOnly['rust-integration-tests'].message).toContain(
+      '1 Datei(en)'
+    );
+
+    await fs.writeFile(
+      path.join(tempDir, 'src', 'lib.rs'),
+      'pub fn run() {}\n'
+    );
+    const integrationOnly = await audit({
+      'tests/api.rs': '#[test]\nfn api() {}\n',
+    });
+    expect(Object.keys(integrationOnly)).toContain('rust-unit-tests');
+    expect(integrationOnly['rust-tests']).toBeUndefined();
+  });
+});
@@ -0,0 +1,36 @@
+/**
+ * Unit Tests for PromptManager
+ * Testing the selection of language-specific prompt variants
+ */
+
+import { PromptManager } from '../../src/ai/PromptManager';
+
+describe('PromptManager', () => {
+  const promptManager = PromptManager.getInstance();
+
+  it('should load the Rust variant of the default review prompt', async () => {
+    const template = await promptManager.loadLanguagePrompt(
+      'woaru-test-provider',
+      'default_review',
+      'Rust'
+    );
+
+    expect(template?.name).toBe('Rust Code Review');
+    expect(template?.parameters.focus_areas).toEqual(
+      expect.arrayContaining(['ownership', 'unsafe_soundness', 'async_pitfalls'])
+    );
+    expect(template?.user_prompt).toContain('{code_content}');
+  });
+
+  it('should return undefined for languages without a variant', async () => {
+    for (const language of ['typescript', 'unknown', '../default_review']) {
+      await expect(
+        promptManager.loadLanguagePrompt(
+          'woaru-test-provider',
+          'default_review',
+          language
+        )
+      ).resolves.toBeUndefined();
+    }
+  });
+});
@@ -0,0 +1,146 @@
+/**
+ * Unit Tests for RustCheckScheduler
+ * Testing reverse dependencies from cargo metadata and the coalescing and
+ * cancellation of watch-mode clippy runs
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import { CargoDependencyGraph } from '../../src/rust/CargoDependencyGraph';
+import {
+  RustCheckRun,
+  RustCheckScheduler,
+} from '../../src/rust/RustCheckScheduler';
+import { ToolExecutor } from '../../src/utils/toolExecutor';
+
+const pkg = (
+  name: string,
+  dependencies: Array<{ name: string; kind: 'dev' | 'build' | null }> = []
+) => ({
+  name,
+  dependencies: dependencies.map(dependency => ({
+    ...dependency,
+    path: `/ws/${dependency.name}`,
+  })),
+});
+
+const METADATA = JSON.stringify({
+  packages: [
+    pkg('core'),
+    pkg('app', [{ name: 'core', kind: null }]),
+    pkg('cli', [{ name: 'app', kind: null }]),
+    pkg('bench', [{ name: 'core', kind: 'dev' }]),
+    {
+      name: 'tool',
+      dependencies: [{ name: 'serde', kind: null, req: '^1' }],
+    },
+  ],
+  workspace_members: [],
+});
+
+describe('CargoDependencyGraph', () => {
+  it('should find transitive dependents, ignoring dev-dependencies', () => {
+    const graph = CargoDependencyGraph.fromMetadata(METADATA)!;
+
+    expect(graph.reverseDependencies('core')).toEqual(['app', 'cli']);
+    expect(graph.reverseDependencies('tool')).toEqual([]);
+    expect(graph.withReverseDependencies(['app', 'tool'])).toEqual([
+      'app',
+      'tool',
+      'cli',
+    ]);
+    expect(CargoDependencyGraph.fromMetadata('not json')).toBeNull();
+  });
+});
+
+describe('RustCheckScheduler', () => {
+  let tempDir: string;
+
+  const crateFile = (name: string) =>
+    path.join(tempDir, name, 'src', 'lib.rs');
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-watch-'));
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[workspace]\nmembers = ["core", "app", "cli", "bench", "tool"]\n'
+    );
+    for (const name of ['core', 'app', 'cli', 'bench', 'tool']) {
+      await fs.outputFile(
+        path.join(tempDir, name, 'Cargo.toml'),
+        `[package]\nname = "${name}"\nversion = "0.1.0"\n`
+      );
+      await fs.outputFile(crateFile(name), '');
+    }
+    jest.spyOn(ToolExecutor, 'runCargoMetadata').mockResolvedValue({
+      stdout: METADATA,
+      stderr: '',
+      exitCode: 0,
+    });
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should cancel stale runs and also check dependent crates', async () => {
+    const signals: AbortSignal[] = [];
+    const clippy = jest
+      .spyOn(ToolExecutor, 'runCargoClippy')
+      .mockImplementation(async (_manifest, _args, options = {}) => {
+        signals.push(options.signal!);
+        if (signals.length === 1) {
+          // Still running when the next save arrives
+          return new Promise((_resolve, reject) =>
+            options.signal!.addEventListener('abort', () =>
+              reject(new Error('The operation was aborted'))
+            )
+          );
+        }
+        return { stdout: '', stderr: '', exitCode: 0 };
+      });
+
+    let finish: (run: RustCheckRun) => void = () => undefined;
+    const finished = new Promise<RustCheckRun>(resolve => (finish = resolve));
+    const runs: RustCheckRun[] = [];
+    const scheduler = new RustCheckScheduler(
+      run => {
+        runs.push(run);
+        finish(run);
+      },
+      undefined,
+      5
+    );
+
+    expect(await scheduler.schedule(crateFile('core'))).toBe(true);
+    while (signals.length === 0) {
+      await new Promise(resolve => setTimeout(resolve, 5));
+    }
+    await scheduler.schedule(crateFile('tool'));
+    await scheduler.schedule(crateFile('tool'));
+    const run = await finished;
+
+    expect(signals[0].aborted).toBe(true);
+    expect(clippy).toHaveBeenCalledTimes(2);
+    expect(clippy.mock.calls[1][1]).toEqual([
+      '-p',
+      'tool',
+      '-p',
+      'core',
+      '-p',
+      'app',
+      '-p',
+      'cli',
+    ]);
+    expect(runs).toHaveLength(1);
+    expect(run.changedPackages).toEqual(['tool', 'core']);
+    expect(run.changedFiles.sort()).toEqual(
+      [crateFile('core'), crateFile('tool')].sort()
+    );
+    expect(await scheduler.schedule(path.join(os.tmpdir(), 'x.rs'))).toBe(
+      false
+    );
+  });
+});
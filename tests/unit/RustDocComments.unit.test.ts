@@ -0,0 +1,220 @@
+/**
+ * Unit Tests for RustDocComments
+ * Testing public item extraction and rustdoc comment rendering
+ */
+
+import {
+  extractRustDocItems,
+  hasDocCommentAbove,
+  renderRustDocComment,
+} from '../../src/rust/RustDocComments';
+
+const SOURCE = `use std::fmt;
+
+/// Already documented
+#[derive(Debug, Clone)]
+pub struct Config<'a, T: Clone> {
+    name: &'a str,
+    value: T,
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Mode {
+    Fast,
+    Slow,
+}
+
+#[must_use]
+pub async fn load<P>(
+    path: P,
+) -> Result<String, std::io::Error>
+where
+    P: AsRef<std::path::Path>,
+{
+    let text = std::fs::read_to_string(path)?;
+    assert!(!text.is_empty(), "{}", "pub fn hidden() {}");
+    Ok(text)
+}
+
+pub(crate) fn internal() {}
+
+impl<T: Clone> fmt::Display for Config<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Mode {
+    pub fn parse(value: &str) -> Self {
+        value.parse::<u8>().map(|_| Mode::Fast).unwrap()
+    }
+}
+
+pub trait Loader: Send {}
+
+#[cfg(test)]
+mod tests {
+    pub fn helper() {}
+}
+`;
+
+describe('extractRustDocItems', () => {
+  it('should find public items and impl blocks outside of tests', () => {
+    const items = extractRustDocItems(SOURCE);
+
+    expect(items.map(item => [item.kind, item.name])).toEqual([
+      ['struct', 'Config'],
+      ['enum', 'Mode'],
+      ['fn', 'load'],
+      ['impl', "impl fmt::Display for Config<'_, T>"],
+      ['impl', 'impl Mode'],
+      ['fn', 'parse'],
+      ['trait', 'Loader'],
+    ]);
+  });
+
+  it('should start items at their attributes and read existing docs', () => {
+    const [config, mode, load] = extractRustDocItems(SOURCE);
+
+    expect(config).toEqual(
+      expect.objectContaining({
+        startLine: 3,
+        line: 5,
+        existingDoc: 'Already documented',
+      })
+    );
+    expect(mode).toEqual(
+      expect.objectContaining({ startLine: 10, line: 12, endLine: 15 })
+    );
+    expect(mode.existingDoc).toBeUndefined();
+    expect(load).toEqual(
+      expect.objectContaining({
+        startLine: 17,
+        signature:
+          'pub async fn load<P>( path: P, ) -> Result<String, std::io::Error> where P: AsRef<std::path::Path>,',
+        panics: ['assert!'],
+      })
+    );
+  });
+
+  it('should not end declarations at semicolons in array types', () => {
+    const items = extractRustDocItems(
+      [
+        'pub struct Key([u8; 32]);',
+        '',
+        'impl Key {',
+        '    pub fn digest(&self) -> [u8; 32] {',
+        '        self.0.first().unwrap();',
+        '        self.0',
+        '    }',
+        '}',
+      ].join('\n')
+    );
+
+    expect(
+      items.map(item => [item.kind, item.name, item.line, item.endLine])
+    ).toEqual([
+      ['struct', 'Key', 1, 1],
+      ['impl', 'impl Key', 3, 8],
+      ['fn', 'digest', 4, 7],
+    ]);
+    expect(items[0].signature).toBe('pub struct Key([u8; 32])');
+    expect(items[2].signature).toBe('pub fn digest(&self) -> [u8; 32]');
+    expect(items[2].panics).toEqual(['unwrap()']);
+  });
+
+  it('should detect docs directly above an insertion point', () => {
+    const lines = SOURCE.split('\n');
+
+    expect(hasDocCommentAbove(lines, 3)).toBe(true);
+    expect(hasDocCommentAbove(lines, 9)).toBe(false);
+  });
+});
+
+describe('renderRustDocComment', () => {
+  const items = extractRustDocItems(SOURCE);
+  const load = items.find(item => item.name === 'load')!;
+  const parse = items.find(item => item.name === 'parse')!;
+
+  it('should convert JSDoc output and add Panics', () => {
+    const generated = [
+      '```',
+      '/**',
+      ' * Loads a configuration file.',
+      ' *',
+      ' * @param {P} path - File to read',
+      ' * @throws {io::Error} When the file cannot be read',
+      ' */',
+      '```',
+    ].join('\n');
+
+    expect(renderRustDocComment(generated, load, 'pro').split('\n')).toEqual([
+      '/// Loads a configuration file.',
+      '///',
+      '/// # Arguments',
+      '///',
+      '/// * `path` - File to read',
+      '///',
+      '/// # Errors',
+      '///',
+      '/// When the file cannot be read',
+      '///',
+      '/// # Panics',
+      '///',
+      '/// May panic: the implementation uses `assert!`.',
+    ]);
+  });
+
+  it('should not invent Errors or Examples sections', () => {
+    expect(
+      renderRustDocComment('Loads a configuration file.', load, 'pro').split(
+        '\n'
+      )
+    ).toEqual([
+      '/// Loads a configuration file.',
+      '///',
+      '/// # Panics',
+      '///',
+      '/// May panic: the implementation uses `assert!`.',
+    ]);
+  });
+
+  it('should keep rustdoc sections and indent methods', () => {
+    const generated = [
+      '/// Parses a mode.',
+      '///',
+      '/// # Examples',
+      '///',
+      '/// ```',
+      '/// # use demo::Mode;',
+      '/// let mode = Mode::parse("1");',
+      '/// ```',
+    ].join('\n');
+
+    expect(renderRustDocComment(generated, parse, 'pro').split('\n')).toEqual([
+      '    /// Parses a mode.',
+      '    ///',
+      '    /// # Panics',
+      '    ///',
+      '    /// May panic: the implementation uses `unwrap()`.',
+      '    ///',
+      '    /// # Examples',
+      '    ///',
+      '    /// ```',
+      '    /// # use demo::Mode;',
+      '    /// let mode = Mode::parse("1");',
+      '    /// ```',
+    ]);
+  });
+
+  it('should render human-friendly docs as a single line', () => {
+    expect(
+      renderRustDocComment(
+        '// Explain-for-humans: Reads the settings file.',
+        load,
+        'nopro'
+      )
+    ).toBe('/// Explain-for-humans: Reads the settings file.');
+  });
+});
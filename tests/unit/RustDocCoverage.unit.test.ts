@@ -0,0 +1,153 @@
+/**
+ * Unit Tests for RustDocCoverage
+ * Testing rustdoc --show-coverage parsing and the source scan fallback per
+ * crate and module
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  countDocumentedItems,
+  docCoveragePercent,
+  filesMissingDocs,
+  moduleNameForFile,
+  parseRustdocCoverage,
+  RustDocCoverageAnalyzer,
+} from '../../src/rust/RustDocCoverage';
+import { ToolExecutor } from '../../src/utils/toolExecutor';
+
+const NET = `use std::io;
+
+/// Connects to a peer
+pub fn connect() -> io::Result<()> {
+    Ok(())
+}
+
+pub struct Peer;
+
+pub(crate) fn internal() {}
+
+impl Peer {
+    pub fn address(&self) -> &str {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub fn helper() {}
+}
+`;
+
+describe('RustDocCoverage', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-doccov-'));
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should count public items and name modules by file', () => {
+    expect(countDocumentedItems(NET)).toEqual({ documented: 1, total: 3 });
+    expect(moduleNameForFile('/ws/src/lib.rs', '/ws/src')).toBe('crate');
+    expect(moduleNameForFile('/ws/src/net/http/mod.rs', '/ws/src')).toBe(
+      'net::http'
+    );
+    expect(docCoveragePercent({ documented: 1, total: 3 })).toBe(33.3);
+    expect(docCoveragePercent({ documented: 0, total: 0 })).toBe(100);
+  });
+
+  it('should parse the JSON coverage table of rustdoc', () => {
+    const output = JSON.stringify({
+      'core/src/lib.rs': {
+        total: 4,
+        with_docs: 4,
+        total_examples: 2,
+        with_examples: 1,
+      },
+      'core/src/net.rs': { total: 5, with_docs: 2 },
+    });
+
+    expect(parseRustdocCoverage(output, '/ws', '/ws/core/src')).toEqual([
+      {
+        module: 'crate',
+        file: path.resolve('/ws/core/src/lib.rs'),
+        documented: 4,
+        total: 4,
+      },
+      {
+        module: 'net',
+        file: path.resolve('/ws/core/src/net.rs'),
+        documented: 2,
+        total: 5,
+      },
+    ]);
+    expect(parseRustdocCoverage('error: no such toolchain', '/ws', '/ws')).toBe(
+      null
+    );
+  });
+
+  it('should scan library sources without a nightly toolchain', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "demo"\nversion = "0.1.0"\n'
+    );
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'lib.rs'),
+      '//! Demo crate\n\n/// Networking\npub mod net;\n\n/// Entry point\npub fn run() {}\n'
+    );
+    await fs.outputFile(path.join(tempDir, 'src', 'net.rs'), NET);
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'main.rs'),
+      'pub fn cli() {}\nfn main() {}\n'
+    );
+    jest.spyOn(ToolExecutor, 'runCargoRustdocCoverage').mockResolvedValue({
+      stdout: '',
+      stderr: "error: toolchain 'nightly' is not installed",
+      exitCode: 1,
+    });
+
+    const report = await new RustDocCoverageAnalyzer().analyze(tempDir, {
+      rustdoc: true,
+    });
+
+    expect(report).toMatchObject({
+      source: 'source-scan',
+      documented: 2,
+      total: 4,
+    });
+    expect(report!.crates[0].modules.map(m => [m.module, m.total])).toEqual([
+      ['crate', 1],
+      ['net', 3],
+    ]);
+    expect(filesMissingDocs(report!)).toEqual([
+      path.join(tempDir, 'src', 'net.rs'),
+    ]);
+  });
+
+  it('should not run rustdoc unless requested', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "demo"\nversion = "0.1.0"\n'
+    );
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'lib.rs'),
+      '/// Entry point\npub fn run() {}\n'
+    );
+    const rustdoc = jest.spyOn(ToolExecutor, 'runCargoRustdocCoverage');
+
+    const report = await new RustDocCoverageAnalyzer().analyze(tempDir);
+
+    expect(rustdoc).not.toHaveBeenCalled();
+    expect(report).toMatchObject({
+      source: 'source-scan',
+      documented: 1,
+      total: 1,
+    });
+  });
+});
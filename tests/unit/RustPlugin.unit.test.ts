@@ -0,0 +1,207 @@
+/**
+ * Unit Tests for RustPlugin
+ * Testing recommendations derived from Cargo.toml dependencies and the
+ * MSRV / toolchain status
+ */
+
+import { RustPlugin } from '../../src/plugins/RustPlugin';
+import { ProjectAnalysis } from '../../src/types';
+import { CargoDependency } from '../../src/types/rust';
+
+function dependency(
+  name: string,
+  overrides: Partial<CargoDependency> = {}
+): CargoDependency {
+  return {
+    name,
+    kind: 'normal',
+    features: [],
+    optional: false,
+    workspaceInherited: false,
+    manifestPath: 'Cargo.toml',
+    ...overrides,
+  };
+}
+
+function rustAnalysis(dependencies: CargoDependency[]): ProjectAnalysis {
+  return {
+    language: 'Rust',
+    framework: [],
+    packageManager: 'cargo',
+    dependencies: dependencies
+      .filter(dep => dep.kind === 'normal')
+      .map(dep => dep.name),
+    devDependencies: [],
+    scripts: {},
+    configFiles: ['Cargo.toml'],
+    structure: [],
+    cargo: {
+      manifestPath: 'Cargo.toml',
+      workspaceManifestPath: 'Cargo.toml',
+      packages: ['app'],
+      dependencies,
+      features: {},
+      frameworks: [],
+      binaries: ['app'],
+    },
+  };
+}
+
+describe('RustPlugin', () => {
+  const plugin = new RustPlugin();
+
+  it('should recommend tokio-console and sqlx prepare for detected crates', () => {
+    const recommendations = plugin.getRecommendations(
+      rustAnalysis([
+        dependency('tokio', { version: '1', features: ['full'] }),
+        dependency('sqlx', { workspaceInherited: true }),
+      ])
+    );
+    const tools = recommendations.map(rec => rec.tool);
+
+    expect(tools).toContain('tokio-console');
+    expect(tools).toContain('sqlx-cli');
+    expect(
+      recommendations.find(rec => rec.tool === 'tokio-console')?.evidence
+    ).toBe('Cargo.toml: tokio 1 (features: full)');
+    expect(
+      recommendations.find(rec => rec.tool === 'sqlx-cli')?.reason
+    ).toContain('cargo sqlx prepare');
+  });
+
+  it('should skip framework recommendations for dev-only or configured crates', () => {
+    const tools = plugin
+      .getRecommendations(
+        rustAnalysis([
+          dependency('tokio', { kind: 'dev' }),
+          dependency('axum'),
+          dependency('tracing'),
+        ])
+      )
+      .map(rec => rec.tool);
+
+    expect(tools).not.toContain('tokio-console');
+    expect(tools).not.toContain('tracing');
+  });
+
+  it('should turn async blocking findings into located suggestions', () => {
+    const suggestions = plugin.getRefactorSuggestions({
+      ...rustAnalysis([dependency('tokio')]),
+      framework: ['tokio'],
+      structure: ['src/main.rs'],
+      asyncBlocking: [
+        {
+          kind: 'mutex-across-await',
+          file: 'src/state.rs',
+          line: 12,
+          context: 'async fn update',
+          call: 'std::sync::Mutex::lock',
+          alternative: 'use tokio::sync::Mutex',
+        },
+      ],
+    });
+
+    expect(suggestions.filter(s => s.type === 'performance')).toEqual([
+      {
+        filename: 'src/state.rs',
+        line: 12,
+        suggestion:
+          'std::sync::Mutex::lock guard is held across .await in async fn update: use tokio::sync::Mutex',
+        type: 'performance',
+      },
+    ]);
+  });
+
+  it('should handle projects detected only through Cargo metadata', () => {
+    expect(
+      plugin.canHandle({
+        ...rustAnalysis([]),
+        language: 'Unknown',
+        configFiles: [],
+      })
+    ).toBe(true);
+  });
+
+  it('should flag libraries without MSRV and unpinned binary toolchains', () => {
+    const recommendations = plugin.getRecommendations({
+      ...rustAnalysis([]),
+      rustToolchain: {
+        crates: [
+          {
+            name: 'core',
+            manifestPath: 'core/Cargo.toml',
+            library: true,
+            binary: false,
+          },
+          {
+            name: 'cli',
+            manifestPath: 'cli/Cargo.toml',
+            rustVersion: '1.70',
+            library: false,
+            binary: true,
+          },
+        ],
+        msrv: '1.70',
+        toolchainFile: {
+          path: 'rust-toolchain.toml',
+          channel: 'stable',
+          pinned: false,
+        },
+        installedToolchains: ['stable-x86_64-unknown-linux-gnu'],
+      },
+    });
+    const byTool = (tool: string) =>
+      recommendations.find(rec => rec.tool === tool);
+
+    expect(byTool('cargo-msrv')?.evidence).toBe(
+      'Library crates without rust-version: core'
+    );
+    expect(byTool('rust-toolchain')?.evidence).toContain('"stable"');
+    expect(byTool('msrv-toolchain')?.reason).toContain(
+      'rustup toolchain install 1.70'
+    );
+  });
+
+  it('should report a failed MSRV build and an outdated toolchain pin', () => {
+    const recommendations = plugin.getRecommendations({
+      ...rustAnalysis([]),
+      rustToolchain: {
+        crates: [
+          {
+            name: 'app',
+            manifestPath: 'Cargo.toml',
+            rustVersion: '1.75',
+            library: true,
+            binary: true,
+          },
+        ],
+        msrv: '1.75',
+        toolchainFile: {
+          path: 'rust-toolchain.toml',
+          channel: '1.72.1',
+          pinned: true,
+        },
+        installedToolchains: ['1.75.0-x86_64-unknown-linux-gnu'],
+        msrvToolchain: '1.75.0-x86_64-unknown-linux-gnu',
+        msrvCheck: {
+          toolchain: '1.75.0-x86_64-unknown-linux-gnu',
+          success: false,
+          errors: [
+            'src/lib.rs: Line 3:5 - ERROR: use of unstable library feature',
+          ],
+        },
+      },
+    });
+    const high = recommendations
+      .filter(rec => rec.priority === 'high')
+      .map(rec => rec.tool);
+
+    expect(high).toEqual(
+      expect.arrayContaining(['rust-toolchain', 'msrv-check'])
+    );
+    expect(recommendations.map(rec => rec.tool)).not.toContain('cargo-msrv');
+    expect(recommendations.map(rec => rec.tool)).not.toContain(
+      'msrv-toolchain'
+    );
+  });
+});
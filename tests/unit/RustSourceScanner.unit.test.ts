@@ -0,0 +1,116 @@
+/**
+ * Unit Tests for RustSourceScanner
+ * Testing comment/literal masking and item extraction on tricky Rust syntax
+ */
+
+import {
+  maskRustSource,
+  extractRustFunctions,
+  findTestRegions,
+  findUnsafeBlocks,
+  isRustLibrarySource,
+} from '../../src/rust/RustSourceScanner';
+
+describe('RustSourceScanner', () => {
+  it('should mask comments and literals while preserving offsets', () => {
+    const source = [
+      '// fn commented() {}',
+      'let s = r#"unwrap() { "#;',
+      "let c = '{'; let q = '\\''; fn f<'a>(x: &'a str) {}",
+      '/* outer /* nested { */ still comment */ let b = b"}";',
+    ].join('\n');
+
+    const masked = maskRustSource(source);
+
+    expect(masked).toHaveLength(source.length);
+    expect(masked.split('\n')).toHaveLength(4);
+    expect(masked).not.toContain('commented');
+    expect(masked).not.toContain('unwrap');
+    expect(masked).not.toContain('nested');
+    expect(masked).toContain("fn f<'a>(x: &'a str) {}");
+    expect(masked.match(/\{/g)).toHaveLength(1);
+    expect(masked.match(/\}/g)).toHaveLength(1);
+  });
+
+  it('should extract functions with impl/trait owners and parameters', () => {
+    const masked = maskRustSource(
+      [
+        'trait Greet {',
+        '    fn hi(&self);',
+        '    fn bye(&self) -> u8 { 1 }',
+        '}',
+        'impl<T: Clone> fmt::Display for Wrapper<T> where T: Debug {',
+        "    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {",
+        '        Ok(())',
+        '    }',
+        '}',
+        'pub async fn run(a: HashMap<K, V>, (x, y): (u8, u8)) -> impl Fn() {',
+        '    || {}',
+        '}',
+      ].join('\n')
+    );
+
+    const functions = extractRustFunctions(masked);
+
+    expect(functions.map(f => [f.name, f.line, f.parameters])).toEqual([
+      ['Greet::bye', 3, []],
+      ['Wrapper::fmt', 6, ['f']],
+      ['run', 10, ['a', '(x, y)']],
+    ]);
+  });
+
+  it('should not end a signature at semicolons in array types', () => {
+    const masked = maskRustSource(
+      [
+        'pub async fn digest(data: &[u8; 4]) -> [u8; 32] {',
+        '    [0; 32]',
+        '}',
+        'fn table() -> Vec<[u16; 2]> where [u16; 2]: Copy {',
+        '    Vec::new()',
+        '}',
+        'fn declared() -> [u8; 2];',
+      ].join('\n')
+    );
+
+    const functions = extractRustFunctions(masked);
+
+    expect(functions.map(f => [f.name, f.line, f.parameters])).toEqual([
+      ['digest', 1, ['data']],
+      ['table', 4, []],
+    ]);
+  });
+
+  it('should find test regions and unsafe blocks', () => {
+    const masked = maskRustSource(
+      [
+        '#[cfg(not(test))]',
+        'fn prod() { unsafe { ptr.read() } }',
+        '#[cfg(test)]',
+        'mod tests {',
+        '    #[tokio::test(flavor = "multi_thread")]',
+        '    async fn works() {}',
+        '}',
+        'unsafe fn raw() {}',
+      ].join('\n')
+    );
+
+    const regions = findTestRegions(masked);
+    const testModuleStart = masked.indexOf('#[cfg(test)]');
+
+    expect(regions.some(r => r.start === testModuleStart)).toBe(true);
+    expect(regions.some(r => r.start < masked.indexOf('fn prod'))).toBe(
+      false
+    );
+    expect(findUnsafeBlocks(masked)).toHaveLength(1);
+  });
+
+  it('should classify library sources by target directory', () => {
+    expect(isRustLibrarySource('/work/tests/app/src/lib.rs')).toBe(true);
+    expect(isRustLibrarySource('/work/app/src/parser/mod.rs')).toBe(true);
+    expect(isRustLibrarySource('/work/app/src/main.rs')).toBe(false);
+    expect(isRustLibrarySource('/work/app/src/bin/tool.rs')).toBe(false);
+    expect(isRustLibrarySource('/work/app/tests/it.rs')).toBe(false);
+    expect(isRustLibrarySource('/work/app/examples/demo.rs')).toBe(false);
+    expect(isRustLibrarySource('/work/app/build.rs')).toBe(false);
+  });
+});
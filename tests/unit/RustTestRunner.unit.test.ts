@@ -0,0 +1,166 @@
+/**
+ * Unit Tests for RustTestRunner
+ * Testing nextest JUnit, libtest JSON and plain libtest output parsing
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  RustTestParser,
+  RustTestRunner,
+} from '../../src/quality/RustTestRunner';
+
+const JUNIT_REPORT = `<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="nextest-run" tests="4" failures="1" errors="0">
+  <testsuite name="calc" tests="4" disabled="1" errors="0" failures="1">
+    <testcase name="tests::add" classname="calc" time="0.012"/>
+    <testcase name="tests::div" classname="calc" time="1.500">
+      <failure message="test failed" type="test failure">thread &apos;tests::div&apos; panicked at src/lib.rs:12:9:
+attempt to divide by zero</failure>
+    </testcase>
+    <testcase name="tests::flaky" classname="calc" time="2.250">
+      <flakyFailure message="test failed" type="test failure"/>
+    </testcase>
+    <testcase name="tests::network" classname="calc" time="0.000">
+      <skipped/>
+    </testcase>
+  </testsuite>
+</testsuites>
+`;
+
+const LIBTEST_OUTPUT = `
+running 3 tests
+test tests::add ... ok
+test tests::div ... FAILED
+test tests::slow ... ignored, needs a database
+
+failures:
+
+---- tests::div stdout ----
+thread 'tests::div' panicked at src/lib.rs:12:9:
+attempt to divide by zero
+
+failures:
+    tests::div
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured
+`;
+
+describe('RustTestParser', () => {
+  it('should parse failures, skips and flaky retries from JUnit', () => {
+    const tests = RustTestParser.parseJUnit(JUNIT_REPORT);
+
+    expect(tests.map(test => [test.name, test.outcome])).toEqual([
+      ['tests::add', 'passed'],
+      ['tests::div', 'failed'],
+      ['tests::flaky', 'passed'],
+      ['tests::network', 'ignored'],
+    ]);
+    expect(tests[1]).toEqual(
+      expect.objectContaining({ suite: 'calc', durationMs: 1500 })
+    );
+    expect(tests[1].message).toContain("thread 'tests::div' panicked");
+  });
+
+  it('should parse libtest JSON events', () => {
+    const output = [
+      '{ "type": "suite", "event": "started", "test_count": 3 }',
+      '{ "type": "test", "event": "started", "name": "tests::add" }',
+      '{ "type": "test", "name": "tests::add", "event": "ok", "exec_time": 0.004 }',
+      '{ "type": "test", "name": "tests::div", "event": "failed", "exec_time": 0.1, "stdout": "attempt to divide by zero\\n" }',
+      '{ "type": "test", "name": "tests::slow", "event": "ignored" }',
+      '{ "type": "suite", "event": "failed", "passed": 1, "failed": 1 }',
+    ].join('\n');
+
+    expect(RustTestParser.parseLibtestJson(output)).toEqual([
+      { name: 'tests::add', outcome: 'passed', durationMs: 4 },
+      {
+        name: 'tests::div',
+        outcome: 'failed',
+        durationMs: 100,
+        message: 'attempt to divide by zero',
+      },
+      { name: 'tests::slow', outcome: 'ignored' },
+    ]);
+  });
+
+  it('should attach captured stdout to failed libtest tests', () => {
+    const tests = RustTestParser.parseLibtestOutput(LIBTEST_OUTPUT);
+
+    expect(tests.map(test => [test.name, test.outcome])).toEqual([
+      ['tests::add', 'passed'],
+      ['tests::div', 'failed'],
+      ['tests::slow', 'ignored'],
+    ]);
+    expect(tests[1].message).toBe(
+      "thread 'tests::div' panicked at src/lib.rs:12:9:\nattempt to divide by zero"
+    );
+  });
+
+  it('should summarize counts and sort slow tests', () => {
+    const result = RustTestParser.summarize(
+      'nextest',
+      RustTestParser.parseJUnit(JUNIT_REPORT),
+      100,
+      1000
+    );
+
+    expect(result).toEqual(
+      expect.objectContaining({
+        success: false,
+        passed: 2,
+        failed: 1,
+        ignored: 1,
+        slowThresholdMs: 1000,
+      })
+    );
+    expect(result.slowTests.map(test => test.name)).toEqual([
+      'tests::flaky',
+      'tests::div',
+    ]);
+  });
+
+  it('should fail a run that exited with an error', () => {
+    const tests = RustTestParser.parseJUnit(JUNIT_REPORT).filter(
+      test => test.outcome !== 'failed'
+    );
+
+    expect(RustTestParser.summarize('nextest', tests, 0).success).toBe(true);
+    expect(RustTestParser.summarize('nextest', tests, 101).success).toBe(
+      false
+    );
+  });
+});
+
+describe('RustTestRunner', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-tests-'));
+  });
+
+  afterEach(async () => {
+    await fs.remove(tempDir);
+  });
+
+  it('should read the JUnit report left by cargo-nextest', async () => {
+    const report = path.join(tempDir, 'target', 'nextest', 'ci', 'junit.xml');
+    await fs.ensureDir(path.dirname(report));
+    await fs.writeFile(report, JUNIT_REPORT);
+
+    const result = await new RustTestRunner(tempDir).readLatestJUnit();
+
+    expect(result).toEqual(
+      expect.objectContaining({ runner: 'nextest', failed: 1 })
+    );
+    expect(result!.failedTests[0].name).toBe('tests::div');
+  });
+
+  it('should return null without a nextest report or Cargo.toml', async () => {
+    const runner = new RustTestRunner(tempDir);
+
+    expect(await runner.readLatestJUnit()).toBeNull();
+    expect(await runner.run()).toBeNull();
+  });
+});
@@ -0,0 +1,128 @@
+/**
+ * Unit Tests for RustToolchainInspector
+ * Testing MSRV detection, toolchain files and rustup toolchain matching
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  findMsrvToolchain,
+  isPinnedChannel,
+  parseToolchainChannel,
+  parseToolchainList,
+  RustToolchainInspector,
+} from '../../src/rust/RustToolchainInspector';
+import { ToolExecutor } from '../../src/utils/toolExecutor';
+
+const RUSTUP_OUTPUT = `stable-x86_64-unknown-linux-gnu (default)
+nightly-2024-05-01-x86_64-unknown-linux-gnu
+1.70.0-x86_64-unknown-linux-gnu
+1.70.2-x86_64-unknown-linux-gnu (active)
+`;
+
+describe('toolchain helpers', () => {
+  it('should parse rustup toolchain names and match the MSRV', () => {
+    const toolchains = parseToolchainList(RUSTUP_OUTPUT);
+
+    expect(toolchains).toEqual([
+      'stable-x86_64-unknown-linux-gnu',
+      'nightly-2024-05-01-x86_64-unknown-linux-gnu',
+      '1.70.0-x86_64-unknown-linux-gnu',
+      '1.70.2-x86_64-unknown-linux-gnu',
+    ]);
+    expect(findMsrvToolchain('1.70', toolchains)).toBe(
+      '1.70.2-x86_64-unknown-linux-gnu'
+    );
+    expect(findMsrvToolchain('1.70.3', toolchains)).toBeUndefined();
+    expect(findMsrvToolchain('1.74', toolchains)).toBeUndefined();
+  });
+
+  it('should read channels from toml and legacy toolchain files', () => {
+    expect(
+      parseToolchainChannel('[toolchain]\nchannel = "1.78.0"\n')
+    ).toBe('1.78.0');
+    expect(parseToolchainChannel('nightly-2024-05-01\n')).toBe(
+      'nightly-2024-05-01'
+    );
+    expect(isPinnedChannel('1.78.0')).toBe(true);
+    expect(isPinnedChannel('nightly-2024-05-01')).toBe(true);
+    expect(isPinnedChannel('stable')).toBe(false);
+  });
+});
+
+describe('RustToolchainInspector', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-msrv-'));
+    await fs.outputFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[workspace]\nmembers = ["lib", "cli"]\n\n[workspace.package]\nrust-version = "1.70"\n'
+    );
+    await fs.outputFile(
+      path.join(tempDir, 'lib', 'Cargo.toml'),
+      '[package]\nname = "lib"\nrust-version.workspace = true\n'
+    );
+    await fs.outputFile(path.join(tempDir, 'lib', 'src', 'lib.rs'), '');
+    await fs.outputFile(
+      path.join(tempDir, 'cli', 'Cargo.toml'),
+      '[package]\nname = "cli"\n'
+    );
+    await fs.outputFile(path.join(tempDir, 'cli', 'src', 'main.rs'), '');
+    await fs.outputFile(path.join(tempDir, 'rust-toolchain'), 'stable\n');
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should resolve inherited MSRVs and the installed MSRV toolchain', async () => {
+    jest.spyOn(ToolExecutor, 'runRustupToolchainList').mockResolvedValue({
+      stdout: RUSTUP_OUTPUT,
+      stderr: '',
+      exitCode: 0,
+    });
+
+    const status = await new RustToolchainInspector().inspect(tempDir, {
+      listToolchains: true,
+    });
+
+    expect(status).toMatchObject({
+      crates: [
+        { name: 'cli', library: false, binary: true },
+        { name: 'lib', rustVersion: '1.70', library: true, binary: false },
+      ],
+      msrv: '1.70',
+      toolchainFile: { channel: 'stable', pinned: false },
+      msrvToolchain: '1.70.2-x86_64-unknown-linux-gnu',
+    });
+    expect(status!.crates[0].rustVersion).toBeUndefined();
+  });
+
+  it('should tolerate a missing rustup', async () => {
+    jest
+      .spyOn(ToolExecutor, 'runRustupToolchainList')
+      .mockRejectedValue(
+        Object.assign(new Error('spawn rustup ENOENT'), { code: 'ENOENT' })
+      );
+
+    const status = await new RustToolchainInspector().inspect(tempDir, {
+      listToolchains: true,
+    });
+
+    expect(status!.installedToolchains).toBeNull();
+    expect(status!.msrvToolchain).toBeUndefined();
+  });
+
+  it('should not run rustup unless toolchains are requested', async () => {
+    const list = jest.spyOn(ToolExecutor, 'runRustupToolchainList');
+
+    const status = await new RustToolchainInspector().inspect(tempDir);
+
+    expect(list).not.toHaveBeenCalled();
+    expect(status!.msrv).toBe('1.70');
+    expect(status!.installedToolchains).toBeUndefined();
+  });
+});
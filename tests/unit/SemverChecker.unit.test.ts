@@ -0,0 +1,412 @@
+/**
+ * Unit Tests for SemverChecker
+ * Testing version bump classification, cargo-semver-checks output parsing
+ * and the rustdoc JSON public API diff against a base worktree
+ */
+
+import { execFileSync } from 'child_process';
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  classifyVersionBump,
+  diffPublicApi,
+  extractPublicApi,
+  isBumpSufficient,
+  parseSemverChecksOutput,
+  SemverChecker,
+} from '../../src/rust/SemverChecker';
+import { ToolExecutor } from '../../src/utils/toolExecutor';
+
+const SEMVER_CHECKS_OUTPUT = `     Parsing demo v0.2.1 (current)
+      Parsed [   1.204s] (current)
+     Parsing demo v0.2.0 (baseline)
+    Checking demo v0.2.0 -> v0.2.1 (minor change)
+     Checked [   0.012s] 96 checks: 94 pass, 2 fail, 0 warn, 0 skip
+
+--- failure function_missing: pub fn removed or renamed ---
+
+Description:
+A publicly-visible function cannot be imported by its prior path.
+        ref: https://doc.rust-lang.org/cargo/reference/semver.html#item-remove
+
+Failed in:
+  function demo::helper, previously in file /tmp/base/src/lib.rs:5
+
+--- failure trait_method_added: pub trait method added ---
+
+Description:
+A non-sealed public trait added a new method without a default implementation.
+
+Failed in:
+  trait method demo::Loader::reload in file /tmp/current/src/lib.rs:9
+
+     Summary semver requires new major version: 2 major and 0 minor checks failed
+`;
+
+const u32Type = { primitive: 'u32' };
+const boolType = { primitive: 'bool' };
+
+const fn = (
+  name: string,
+  inputs: Array<[string, object]>,
+  hasBody: boolean = true
+) => ({
+  name,
+  visibility: 'public',
+  inner: {
+    function: {
+      sig: { inputs, output: null, is_c_variadic: false },
+      generics: { params: [], where_predicates: [] },
+      header: { is_const: false, is_unsafe: false, is_async: false },
+      has_body: hasBody,
+    },
+  },
+});
+
+const variant = (name: string) => ({
+  name,
+  visibility: 'default',
+  inner: { variant: { kind: 'plain' } },
+});
+
+const selfRef = { borrowed_ref: { type: { generic: 'Self' } } };
+
+const traitMethod = (name: string, hasBody: boolean) => ({
+  ...fn(name, [['self', selfRef]], hasBody),
+  visibility: 'default',
+});
+
+/**
+ * rustdoc JSON of a small crate; `current` renames ids to show they are
+ * not compared
+ */
+function crateDoc(current: boolean) {
+  const id = (value: number) => (current ? value + 100 : value);
+  const index: Record<string, object> = {
+    [id(0)]: {
+      name: 'demo',
+      visibility: 'public',
+      inner: {
+        module: {
+          is_crate: true,
+          items: current
+            ? [id(1), id(3), id(5), id(10), id(20)]
+            : [id(1), id(2), id(3), id(5), id(10), id(20)],
+        },
+      },
+    },
+    [id(1)]: fn(
+      'load',
+      current
+        ? [
+            ['value', u32Type],
+            ['strict', boolType],
+          ]
+        : [['value', u32Type]]
+    ),
+    [id(2)]: fn('helper', []),
+    [id(3)]: {
+      name: 'Loader',
+      visibility: 'public',
+      inner: {
+        trait: {
+          is_auto: false,
+          is_unsafe: false,
+          items: current ? [id(4), id(7), id(8)] : [id(4)],
+          generics: { params: [], where_predicates: [] },
+          bounds: [],
+          implementations: [],
+        },
+      },
+    },
+    [id(4)]: traitMethod('load_all', false),
+    [id(7)]: traitMethod('reload', false),
+    [id(8)]: traitMethod('describe', true),
+    [id(5)]: {
+      name: 'Mode',
+      visibility: 'public',
+      attrs: ['#[non_exhaustive]'],
+      inner: {
+        enum: {
+          generics: { params: [], where_predicates: [] },
+          variants: current ? [id(6), id(9)] : [id(6)],
+          impls: [],
+        },
+      },
+    },
+    [id(6)]: variant('Fast'),
+    [id(9)]: variant('Slow'),
+    // `pub use config::Config;` from a private module
+    [id(10)]: {
+      name: null,
+      visibility: 'public',
+      inner: {
+        use: {
+          source: 'config::Config',
+          name: 'Config',
+          id: id(11),
+          is_glob: false,
+        },
+      },
+    },
+    [id(11)]: {
+      name: 'Config',
+      visibility: 'public',
+      inner: {
+        struct: {
+          kind: { plain: { fields: [id(12)], has_stripped_fields: true } },
+          generics: { params: [], where_predicates: [] },
+          impls: [id(13), id(15)],
+        },
+      },
+    },
+    [id(12)]: {
+      name: 'name',
+      visibility: 'public',
+      inner: { struct_field: u32Type },
+    },
+    [id(13)]: {
+      name: null,
+      visibility: 'default',
+      inner: {
+        impl: {
+          trait: null,
+          items: [id(14)],
+          for: { resolved_path: { path: 'Config', id: id(11) } },
+        },
+      },
+    },
+    [id(14)]: fn('new', []),
+    // impl Default for Config
+    [id(15)]: {
+      name: null,
+      visibility: 'default',
+      inner: { impl: { trait: { path: 'Default' }, items: [id(16)] } },
+    },
+    [id(16)]: { ...fn('default', []), visibility: 'default' },
+    [id(20)]: {
+      name: 'VERSION',
+      visibility: 'public',
+      inner: { constant: { type: u32Type, const: { expr: '1' } } },
+    },
+  };
+  return { root: id(0), format_version: 45, index };
+}
+
+describe('version bumps', () => {
+  it('should treat the left-most non-zero component as major', () => {
+    expect(classifyVersionBump('1.2.3', '2.0.0')).toBe('major');
+    expect(classifyVersionBump('1.2.3', '1.3.0')).toBe('minor');
+    expect(classifyVersionBump('1.2.3', '1.2.4')).toBe('patch');
+    expect(classifyVersionBump('0.2.0', '0.3.0')).toBe('major');
+    expect(classifyVersionBump('0.2.0', '0.2.1')).toBe('minor');
+    expect(classifyVersionBump('0.0.1', '0.0.2')).toBe('major');
+    expect(classifyVersionBump('1.2.3', '1.2.3')).toBe('none');
+    expect(classifyVersionBump('1.2.3', '1.0.0')).toBe('none');
+  });
+
+  it('should require at least the bump the changes need', () => {
+    expect(isBumpSufficient('none', 'patch')).toBe(true);
+    expect(isBumpSufficient('minor', 'major')).toBe(false);
+    expect(isBumpSufficient('major', 'minor')).toBe(true);
+  });
+});
+
+describe('parseSemverChecksOutput', () => {
+  it('should collect failed lints and the required bump', () => {
+    const { requiredBump, changes } =
+      parseSemverChecksOutput(SEMVER_CHECKS_OUTPUT);
+
+    expect(requiredBump).toBe('major');
+    expect(changes).toEqual([
+      {
+        kind: 'removed',
+        path: 'demo::helper',
+        itemKind: 'function',
+        level: 'major',
+        lint: 'function_missing',
+        detail: 'pub fn removed or renamed',
+      },
+      {
+        kind: 'trait-item-added',
+        path: 'demo::Loader::reload',
+        itemKind: 'trait method',
+        level: 'major',
+        lint: 'trait_method_added',
+        detail: 'pub trait method added',
+      },
+    ]);
+  });
+});
+
+describe('rustdoc JSON public API', () => {
+  it('should flatten public items, re-exports and inherent methods', () => {
+    const api = extractPublicApi(crateDoc(false));
+
+    expect([...api.keys()]).toEqual([
+      'demo::load',
+      'demo::helper',
+      'demo::Loader',
+      'demo::Loader::load_all',
+      'demo::Mode',
+      'demo::Mode::Fast',
+      'demo::Config',
+      'demo::Config::name',
+      'demo::Config::new',
+      'demo::VERSION',
+    ]);
+    expect(api.get('demo::Loader::load_all')?.required).toBe(true);
+    expect(api.get('demo::Mode::Fast')?.required).toBe(false);
+  });
+
+  it('should report removed items, changed signatures and trait items', () => {
+    const changes = diffPublicApi(
+      extractPublicApi(crateDoc(false)),
+      extractPublicApi(crateDoc(true))
+    );
+
+    expect(
+      changes.map(change => [change.kind, change.path, change.level])
+    ).toEqual([
+      ['signature-changed', 'demo::load', 'major'],
+      ['removed', 'demo::helper', 'major'],
+      ['trait-item-added', 'demo::Loader::reload', 'major'],
+      ['added', 'demo::Loader::describe', 'minor'],
+      ['added', 'demo::Mode::Slow', 'minor'],
+    ]);
+  });
+});
+
+describe('SemverChecker', () => {
+  let tempDir: string;
+
+  const git = (...args: string[]) =>
+    execFileSync('git', args, { cwd: tempDir, stdio: 'pipe' });
+  const writeCrate = async (version: string) => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      `[package]\nname = "demo"\nversion = "${version}"\nedition = "2021"\n`
+    );
+  };
+
+  beforeEach(async () => {
+    tempDir = await fs.realpath(
+      await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-semver-'))
+    );
+    git('init', '-q', '-b', 'main');
+    git('config', 'user.email', 'dev@example.com');
+    git('config', 'user.name', 'dev');
+    await writeCrate('0.2.0');
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'lib.rs'),
+      'pub fn load() {}\n'
+    );
+    git('add', '-A');
+    git('commit', '-q', '-m', 'base');
+    git('checkout', '-q', '-b', 'feature');
+    await writeCrate('0.2.1');
+    await fs.writeFile(
+      path.join(tempDir, 'src', 'lib.rs'),
+      'pub fn load(strict: bool) {}\n'
+    );
+    git('commit', '-q', '-am', 'change');
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should diff rustdoc JSON against the base worktree', async () => {
+    jest.spyOn(ToolExecutor, 'runCargoSemverChecks').mockResolvedValue({
+      stdout: '',
+      stderr: 'error: no such command: `semver-checks`',
+      exitCode: 101,
+    });
+    const rustdoc = jest
+      .spyOn(ToolExecutor, 'runCargoRustdocJson')
+      .mockImplementation(async (manifestPath, _pkg, targetDir) => {
+        await fs.outputJson(
+          path.join(targetDir, 'doc', 'demo.json'),
+          crateDoc(!manifestPath.includes('woaru-worktree-'))
+        );
+        return { stdout: '', stderr: '', exitCode: 0 };
+      });
+
+    const [result] = await new SemverChecker().check(
+      tempDir,
+      [path.join(tempDir, 'src', 'lib.rs')],
+      'main'
+    );
+
+    expect(rustdoc).toHaveBeenCalledTimes(2);
+    expect(result).toEqual(
+      expect.objectContaining({
+        packageName: 'demo',
+        runner: 'rustdoc-json',
+        baseVersion: '0.2.0',
+        currentVersion: '0.2.1',
+        requiredBump: 'major',
+        actualBump: 'minor',
+        violation: true,
+      })
+    );
+    expect(result.changes).toHaveLength(5);
+    // The temporary worktree is removed again
+    const worktrees = git('worktree', 'list').toString().trim().split('\n');
+    expect(worktrees).toHaveLength(1);
+  });
+
+  it('should use cargo-semver-checks when installed', async () => {
+    const semverChecks = jest
+      .spyOn(ToolExecutor, 'runCargoSemverChecks')
+      .mockResolvedValue({
+        stdout: '',
+        stderr: SEMVER_CHECKS_OUTPUT,
+        exitCode: 1,
+      });
+
+    const [result] = await new SemverChecker().check(
+      tempDir,
+      [path.join(tempDir, 'Cargo.toml')],
+      'main'
+    );
+
+    expect(semverChecks.mock.calls[0][2]).toContain('woaru-worktree-');
+    expect(result.runner).toBe('cargo-semver-checks');
+    expect(result.violation).toBe(true);
+    expect(result.changes.map(change => change.kind)).toEqual([
+      'removed',
+      'trait-item-added',
+    ]);
+  });
+
+  it('should not count additions without a minor bump as violation', async () => {
+    await writeCrate('0.2.0');
+    git('commit', '-q', '-am', 'keep version');
+    jest.spyOn(ToolExecutor, 'runCargoSemverChecks').mockResolvedValue({
+      stdout: '',
+      stderr: SEMVER_CHECKS_OUTPUT.replace(
+        'requires new major version: 2 major and 0 minor',
+        'requires new minor version: 0 major and 2 minor'
+      ),
+      exitCode: 1,
+    });
+
+    const [result] = await new SemverChecker().check(
+      tempDir,
+      [path.join(tempDir, 'Cargo.toml')],
+      'main'
+    );
+
+    expect(result).toEqual(
+      expect.objectContaining({
+        requiredBump: 'minor',
+        actualBump: 'none',
+        violation: false,
+        bumpTooSmall: true,
+      })
+    );
+  });
+});
@@ -0,0 +1,113 @@
+/**
+ * Unit Tests for TemplateRegistry
+ * Testing the builtin Rust templates
+ */
+
+import { TemplateRegistry } from '../../src/init/TemplateRegistry';
+import { RUST_TEMPLATE_FILES } from '../../src/init/rustTemplates';
+import { isPinnedChannel } from '../../src/rust/RustToolchainInspector';
+
+describe('TemplateRegistry', () => {
+  const registry = new TemplateRegistry('/nonexistent/templates');
+  const rustTemplates = ['rust-axum', 'rust-cli', 'rust-library'];
+
+  it('should register valid Rust templates', () => {
+    for (const id of rustTemplates) {
+      const template = registry.get(id);
+      expect(template).toBeDefined();
+      expect(template?.language).toBe('Rust');
+      expect(template?.packageManager).toBe('cargo');
+      expect(registry.validate(template!).valid).toBe(true);
+    }
+
+    expect(registry.getByCategory('cli').map(t => t.id)).toEqual(['rust-cli']);
+    expect(registry.getByCategory('library').map(t => t.id)).toEqual([
+      'rust-library',
+    ]);
+  });
+
+  it('should pre-wire Rust tooling and ship content for every source', () => {
+    for (const id of rustTemplates) {
+      const { structure } = registry.get(id)!;
+      const destinations = [
+        ...structure.files.map(f => f.destination),
+        ...structure.templates.map(t => t.destination),
+      ];
+
+      expect(destinations).toEqual(
+        expect.arrayContaining([
+          'Cargo.toml',
+          'rustfmt.toml',
+          'clippy.toml',
+          'deny.toml',
+          'rust-toolchain.toml',
+          '.github/workflows/ci.yml',
+        ])
+      );
+      expect(destinations.some(d => d.startsWith('tests/'))).toBe(true);
+
+      for (const source of [
+        ...structure.files.map(f => f.source),
+        ...structure.templates.map(t => t.source),
+      ]) {
+        expect(RUST_TEMPLATE_FILES[source]).toBeDefined();
+      }
+    }
+  });
+
+  it('should pin the toolchain of binaries to their rust-version', () => {
+    const channel = (id: string) =>
+      registry
+        .get(id)!
+        .structure.templates.find(t => t.destination === 'rust-toolchain.toml')
+        ?.variables.channel as string;
+
+    for (const id of ['rust-axum', 'rust-cli']) {
+      expect(isPinnedChannel(channel(id))).toBe(true);
+      expect(RUST_TEMPLATE_FILES[`${id}/Cargo.toml.hbs`]).toContain(
+        `rust-version = "${channel(id)}"`
+      );
+    }
+    expect(channel('rust-library')).toBe('stable');
+    expect(RUST_TEMPLATE_FILES['rust/rust-toolchain.toml.hbs']).toContain(
+      'channel = "{{channel}}"'
+    );
+  });
+
+  it('should gate optional files behind template features', () => {
+    const axum = registry.get('rust-axum')!;
+    const featureIds = axum.features.map(f => f.id);
+    const conditionalFeatures = axum.structure.templates
+      .map(t => t.conditional?.feature)
+      .filter(Boolean);
+
+    expect(featureIds).toEqual(['database', 'tracing', 'docker']);
+    expect(new Set(conditionalFeatures)).toEqual(
+      new Set(['database', 'docker'])
+    );
+    expect(RUST_TEMPLATE_FILES['rust-axum/Cargo.toml.hbs']).toContain(
+      '{{#if features.database}}'
+    );
+  });
+
+  it('should scaffold a cargo-fuzz crate for libraries on request', () => {
+    const library = registry.get('rust-library')!;
+    const fuzzFiles = [
+      ...library.structure.files,
+      ...library.structure.templates,
+    ].filter(f => f.conditional?.feature === 'fuzz');
+
+    expect(library.features.find(f => f.id === 'fuzz')?.default).toBe(false);
+    expect(fuzzFiles.map(f => f.destination).sort()).toEqual([
+      'fuzz/.gitignore',
+      'fuzz/Cargo.toml',
+      'fuzz/fuzz_targets/parse_sum.rs',
+    ]);
+    expect(RUST_TEMPLATE_FILES['rust-library/fuzz/Cargo.toml.hbs']).toContain(
+      'cargo-fuzz = true'
+    );
+    expect(RUST_TEMPLATE_FILES['rust-library/Cargo.toml.hbs']).toContain(
+      'exclude = ["fuzz/"]'
+    );
+  });
+});
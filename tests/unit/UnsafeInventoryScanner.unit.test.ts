@@ -0,0 +1,177 @@
+/**
+ * Unit Tests for UnsafeInventoryScanner
+ * Testing unsafe counting, forbid detection and workspace/registry scans
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  countUnsafe,
+  forbidsUnsafeCode,
+  UnsafeInventoryScanner,
+} from '../../src/rust/UnsafeInventoryScanner';
+
+const UNSAFE_SOURCE = `
+// unsafe { not code }
+/* unsafe impl Send for Comment {} */
+const DOC: &str = "unsafe fn in a string";
+
+extern "C" {
+    fn abs(input: i32) -> i32;
+}
+
+pub unsafe fn raw(ptr: *const u8) -> u8 {
+    *ptr
+}
+
+unsafe impl Send for Wrapper {}
+
+pub fn call() -> i32 {
+    unsafe { abs(-1) }
+}
+`;
+
+describe('countUnsafe', () => {
+  it('should count code but ignore comments and strings', () => {
+    expect(countUnsafe(UNSAFE_SOURCE)).toEqual({
+      unsafeBlocks: 1,
+      unsafeFns: 1,
+      unsafeImpls: 1,
+      externBlocks: 1,
+    });
+  });
+
+  it('should detect #![forbid(unsafe_code)] at the crate root', () => {
+    expect(forbidsUnsafeCode('#![forbid(unsafe_code)]\npub fn a() {}')).toBe(
+      true
+    );
+    expect(
+      forbidsUnsafeCode('#![forbid(missing_docs, unsafe_code)]\nfn main() {}')
+    ).toBe(true);
+    expect(forbidsUnsafeCode('// #![forbid(unsafe_code)]\nfn main() {}')).toBe(
+      false
+    );
+    expect(forbidsUnsafeCode('#![deny(unsafe_code)]')).toBe(false);
+  });
+});
+
+describe('UnsafeInventoryScanner', () => {
+  let tempDir: string;
+  let workspace: string;
+  let registry: string;
+
+  const write = async (file: string, content: string) => {
+    await fs.ensureDir(path.dirname(file));
+    await fs.writeFile(file, content);
+  };
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-unsafe-'));
+    workspace = path.join(tempDir, 'workspace');
+    registry = path.join(tempDir, 'registry', 'src');
+
+    await write(
+      path.join(workspace, 'Cargo.toml'),
+      '[workspace]\nmembers = ["crates/*"]\n\n[workspace.lints.rust]\nunsafe_code = "forbid"\n'
+    );
+    await write(
+      path.join(workspace, 'crates', 'core', 'Cargo.toml'),
+      '[package]\nname = "core"\nversion = "0.1.0"\n'
+    );
+    await write(
+      path.join(workspace, 'crates', 'core', 'src', 'lib.rs'),
+      UNSAFE_SOURCE
+    );
+    await write(
+      path.join(workspace, 'crates', 'safe', 'Cargo.toml'),
+      '[package]\nname = "safe"\nversion = "0.1.0"\n\n[lints]\nworkspace = true\n'
+    );
+    await write(
+      path.join(workspace, 'crates', 'safe', 'src', 'lib.rs'),
+      'pub fn add(a: u8, b: u8) -> u8 { a + b }\n'
+    );
+    await write(
+      path.join(workspace, 'Cargo.lock'),
+      [
+        'version = 3',
+        '',
+        '[[package]]',
+        'name = "core"',
+        'version = "0.1.0"',
+        '',
+        '[[package]]',
+        'name = "libc"',
+        'version = "0.2.150"',
+        'source = "registry+https://github.com/rust-lang/crates.io-index"',
+        '',
+        '[[package]]',
+        'name = "missing"',
+        'version = "1.0.0"',
+        'source = "registry+https://github.com/rust-lang/crates.io-index"',
+        '',
+      ].join('\n')
+    );
+
+    const libc = path.join(registry, 'index.crates.io-6f17d22bba15001f');
+    await write(
+      path.join(libc, 'libc-0.2.150', 'Cargo.toml'),
+      '[package]\nname = "libc"\nversion = "0.2.150"\n'
+    );
+    await write(
+      path.join(libc, 'libc-0.2.150', 'src', 'lib.rs'),
+      'extern "C" { pub fn free(p: *mut u8); }\nunsafe impl Sync for X {}\n'
+    );
+  });
+
+  afterEach(async () => {
+    await fs.remove(tempDir);
+  });
+
+  it('should inventory workspace crates and registry sources', async () => {
+    const scanner = new UnsafeInventoryScanner(undefined, registry);
+    const inventory = await scanner.scan(workspace, {
+      includeDependencies: true,
+    });
+
+    expect(inventory!.crates).toEqual([
+      expect.objectContaining({
+        name: 'core',
+        source: 'workspace',
+        forbidsUnsafe: false,
+        unsafeBlocks: 1,
+        files: [expect.objectContaining({ file: path.join('src', 'lib.rs') })],
+      }),
+      expect.objectContaining({
+        name: 'safe',
+        source: 'workspace',
+        forbidsUnsafe: true,
+        filesScanned: 1,
+        files: [],
+      }),
+      expect.objectContaining({
+        name: 'libc',
+        version: '0.2.150',
+        source: 'registry',
+        externBlocks: 1,
+        unsafeImpls: 1,
+      }),
+    ]);
+    expect(inventory!.unresolvedDependencies).toEqual(['missing 1.0.0']);
+  });
+
+  it('should scan only workspace crates by default', async () => {
+    const scanner = new UnsafeInventoryScanner(undefined, registry);
+    const inventory = await scanner.scan(workspace);
+
+    expect(inventory!.crates.map(crate => crate.name)).toEqual([
+      'core',
+      'safe',
+    ]);
+    expect(inventory!.unresolvedDependencies).toEqual([]);
+  });
+
+  it('should return null outside of a Cargo project', async () => {
+    expect(await new UnsafeInventoryScanner().scan(tempDir)).toBeNull();
+  });
+});
@@ -0,0 +1,168 @@
+/**
+ * Unit Tests for UnusedDependencyDetector
+ * Testing the cargo-machete and cargo-udeps reports and the import scan
+ * fallback
+ */
+
+import * as fs from 'fs-extra';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  parseMacheteOutput,
+  parseUdepsOutput,
+  referencesCrate,
+  UnusedDependencyDetector,
+} from '../../src/rust/UnusedDependencyDetector';
+import { maskRustSource } from '../../src/rust/RustSourceScanner';
+import { ToolExecutor } from '../../src/utils/toolExecutor';
+
+const NOT_INSTALLED = {
+  stdout: '',
+  stderr: 'error: no such command: `machete`',
+  exitCode: 101,
+};
+
+describe('UnusedDependencyDetector', () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'woaru-udeps-'));
+  });
+
+  afterEach(async () => {
+    jest.restoreAllMocks();
+    await fs.remove(tempDir);
+  });
+
+  it('should parse cargo-machete and cargo-udeps reports', () => {
+    const machete = [
+      'Analyzing dependencies of crates in this directory...',
+      'cargo-machete found the following unused dependencies in /ws:',
+      'app -- ./app/Cargo.toml:',
+      '\tserde',
+      '\tlog',
+      '',
+      'Done!',
+    ].join('\n');
+    const udeps = JSON.stringify({
+      success: false,
+      unused_deps: {
+        'app 0.1.0 (path+file:///ws/app)': {
+          manifest_path: '/ws/app/Cargo.toml',
+          normal: ['serde'],
+          development: ['proptest'],
+          build: [],
+        },
+      },
+    });
+
+    expect(parseMacheteOutput(machete, '/ws')).toEqual([
+      {
+        packageName: 'app',
+        manifestPath: path.resolve('/ws/app/Cargo.toml'),
+        dependency: 'serde',
+      },
+      {
+        packageName: 'app',
+        manifestPath: path.resolve('/ws/app/Cargo.toml'),
+        dependency: 'log',
+      },
+    ]);
+    expect(
+      parseUdepsOutput(udeps)!.map(d => [d.packageName, d.dependency, d.kind])
+    ).toEqual([
+      ['app', 'serde', 'normal'],
+      ['app', 'proptest', 'dev'],
+    ]);
+  });
+
+  it('should recognise crate paths but not comments or strings', () => {
+    expect(referencesCrate('use serde_json::Value;', 'serde-json')).toBe(true);
+    expect(referencesCrate('fn f() { ::anyhow::bail!() }', 'anyhow')).toBe(
+      true
+    );
+    expect(referencesCrate('extern crate rand;', 'rand')).toBe(true);
+    expect(
+      referencesCrate(maskRustSource('let log = "log::info"; // log::'), 'log')
+    ).toBe(false);
+  });
+
+  it('should fall back to scanning imports without machete or udeps', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      [
+        '[package]',
+        'name = "app"',
+        'version = "0.1.0"',
+        '',
+        '[package.metadata.cargo-machete]',
+        'ignored = ["openssl-sys"]',
+        '',
+        '[dependencies]',
+        'serde = "1"',
+        'log = "0.4"',
+        'openssl-sys = "0.9"',
+        'json = { package = "serde_json", version = "1" }',
+        '',
+        '[dev-dependencies]',
+        'proptest = "1"',
+      ].join('\n')
+    );
+    await fs.outputFile(
+      path.join(tempDir, 'src', 'lib.rs'),
+      '// log::info!\nuse serde::Serialize;\npub fn f() { json::json!({}); }\n'
+    );
+    jest
+      .spyOn(ToolExecutor, 'runCargoMachete')
+      .mockResolvedValue(NOT_INSTALLED);
+    jest.spyOn(ToolExecutor, 'runCargoUdeps').mockResolvedValue({
+      ...NOT_INSTALLED,
+      stderr: "error: toolchain 'nightly' is not installed",
+    });
+
+    const report = await new UnusedDependencyDetector().detect(tempDir, {
+      udeps: true,
+    });
+
+    expect(report!.tool).toBe('import-scan');
+    expect(report!.dependencies.map(d => [d.dependency, d.kind])).toEqual([
+      ['log', 'normal'],
+      ['proptest', 'dev'],
+    ]);
+  });
+
+  it('should prefer cargo-machete when it is installed', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "app"\nversion = "0.1.0"\n'
+    );
+    jest.spyOn(ToolExecutor, 'runCargoMachete').mockResolvedValue({
+      stdout: 'app -- ./Cargo.toml:\n\tlog\n',
+      stderr: '',
+      exitCode: 1,
+    });
+    const udeps = jest.spyOn(ToolExecutor, 'runCargoUdeps');
+
+    const report = await new UnusedDependencyDetector().detect(tempDir);
+
+    expect(report!.tool).toBe('cargo-machete');
+    expect(report!.dependencies.map(d => d.dependency)).toEqual(['log']);
+    expect(udeps).not.toHaveBeenCalled();
+  });
+
+  it('should only build with cargo-udeps on request', async () => {
+    await fs.writeFile(
+      path.join(tempDir, 'Cargo.toml'),
+      '[package]\nname = "app"\nversion = "0.1.0"\n'
+    );
+    jest
+      .spyOn(ToolExecutor, 'runCargoMachete')
+      .mockResolvedValue(NOT_INSTALLED);
+    const udeps = jest.spyOn(ToolExecutor, 'runCargoUdeps');
+
+    const report = await new UnusedDependencyDetector().detect(tempDir);
+
+    expect(report!.tool).toBe('import-scan');
+    expect(udeps).not.toHaveBeenCalled();
+  });
+});
@@ -217,7 +217,7 @@ describe('WOARUEngine Unit Tests', () => {
     it('should call CodeAnalyzer.analyzeCodebase', async () => {
       await woaruEngine.analyzeProject(mockProjectPath);
 
-      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'typescript');
+      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'typescript', { unsafeInventory: undefined });
     });
 
     it('should call PluginManager methods', async () => {
@@ -276,7 +276,7 @@ describe('WOARUEngine Unit Tests', () => {
 
       const result = await woaruEngine.analyzeProject(mockProjectPath);
 
-      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'typescript');
+      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'typescript', { unsafeInventory: undefined });
     });
 
     it('should detect JavaScript projects', async () => {
@@ -287,7 +287,7 @@ describe('WOARUEngine Unit Tests', () => {
 
       const result = await woaruEngine.analyzeProject(mockProjectPath);
 
-      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'javascript');
+      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'javascript', { unsafeInventory: undefined });
     });
 
     it('should detect Python projects', async () => {
@@ -299,7 +299,7 @@ describe('WOARUEngine Unit Tests', () => {
 
       const result = await woaruEngine.analyzeProject(mockProjectPath);
 
-      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'python');
+      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'python', { unsafeInventory: undefined });
     });
 
     it('should detect Java projects', async () => {
@@ -311,7 +311,7 @@ describe('WOARUEngine Unit Tests', () => {
 
       const result = await woaruEngine.analyzeProject(mockProjectPath);
 
-      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'java');
+      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'java', { unsafeInventory: undefined });
     });
 
     it('should handle unknown languages', async () => {
@@ -322,7 +322,7 @@ describe('WOARUEngine Unit Tests', () => {
 
       const result = await woaruEngine.analyzeProject(mockProjectPath);
 
-      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'unknown');
+      expect(mockCodeAnalyzer.analyzeCodebase).toHaveBeenCalledWith(mockProjectPath, 'unknown', { unsafeInventory: undefined });
     });
   });
 
@@ -0,0 +1,161 @@
+/**
+ * Unit Tests for the minimal TOML parser
+ * Covering the constructs found in Cargo manifests and lockfiles
+ */
+
+import {
+  isTomlTable,
+  parseToml,
+  TomlParseError,
+} from '../../src/utils/tomlParser';
+
+describe('parseToml', () => {
+  it('should parse a typical Cargo manifest', () => {
+    const manifest = parseToml(`
+# Package metadata
+[package]
+name = "demo"            # trailing comment
+version = "0.1.0"
+edition = '2021'
+rust-version = "1.70"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+tokio = { workspace = true }
+anyhow = "1"
+
+[dev-dependencies.proptest]
+version = "1.4"
+
+[features]
+default = ["std"]
+std = []
+full = [
+  "std",
+  "serde/std", # comment inside array
+]
+
+[profile.release]
+lto = true
+codegen-units = 1
+opt-level = 3
+`);
+
+    expect(manifest).toEqual({
+      package: {
+        name: 'demo',
+        version: '0.1.0',
+        edition: '2021',
+        'rust-version': '1.70',
+      },
+      dependencies: {
+        serde: { version: '1.0', features: ['derive'] },
+        tokio: { workspace: true },
+        anyhow: '1',
+      },
+      'dev-dependencies': { proptest: { version: '1.4' } },
+      features: { default: ['std'], std: [], full: ['std', 'serde/std'] },
+      profile: { release: { lto: true, 'codegen-units': 1, 'opt-level': 3 } },
+    });
+  });
+
+  it('should parse arrays of tables as in Cargo.lock', () => {
+    const lock = parseToml(`
+version = 3
+
+[[package]]
+name = "a"
+version = "1.0.0"
+dependencies = [
+ "b 0.1.0",
+]
+
+[[package]]
+name = "b"
+version = "0.1.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+`);
+
+    expect(lock.version).toBe(3);
+    expect(lock.package).toEqual([
+      { name: 'a', version: '1.0.0', dependencies: ['b 0.1.0'] },
+      {
+        name: 'b',
+        version: '0.1.0',
+        source: 'registry+https://github.com/rust-lang/crates.io-index',
+      },
+    ]);
+  });
+
+  it('should support dotted and quoted keys', () => {
+    expect(
+      parseToml(`version.workspace = true\n"quoted key" = 'x'\n`)
+    ).toEqual({ version: { workspace: true }, 'quoted key': 'x' });
+  });
+
+  it('should handle escapes and multi-line strings', () => {
+    const doc = parseToml(
+      [
+        'a = "tab\\there \\u00e9"',
+        'b = """',
+        'line one',
+        'line two"""',
+        "c = '''",
+        "raw \\n'''",
+        'd = """folded \\',
+        '    text"""',
+      ].join('\n')
+    );
+
+    expect(doc).toEqual({
+      a: 'tab\there é',
+      b: 'line one\nline two',
+      c: 'raw \\n',
+      d: 'folded text',
+    });
+  });
+
+  it('should parse numbers, booleans and dates', () => {
+    expect(
+      parseToml(
+        'int = 1_000\nneg = -5\nfloat = 3.14\nhex = 0xff\nflag = false\nwhen = 1979-05-27T07:32:00Z\n'
+      )
+    ).toEqual({
+      int: 1000,
+      neg: -5,
+      float: 3.14,
+      hex: 255,
+      flag: false,
+      when: '1979-05-27T07:32:00Z',
+    });
+  });
+
+  it('should not pollute Object.prototype', () => {
+    const doc = parseToml(
+      [
+        '__proto__.polluted = true',
+        'constructor = "x"',
+        '[__proto__]',
+        'polluted = true',
+        '[features]',
+        'toString = []',
+        'inline = { __proto__ = { polluted = true } }',
+      ].join('\n')
+    );
+
+    expect(({} as Record<string, unknown>).polluted).toBeUndefined();
+    expect(Object.keys(doc)).toEqual(['__proto__', 'constructor', 'features']);
+    expect(doc['__proto__']).toEqual({ polluted: true });
+    expect(isTomlTable(doc.features)).toBe(true);
+    expect(Object.keys(doc.features)).toEqual(['toString', 'inline']);
+  });
+
+  it('should report the line of syntax errors', () => {
+    expect(() => parseToml('[package]\nname = \n')).toThrow(TomlParseError);
+    try {
+      parseToml('a = 1\nb = "unterminated\n');
+    } catch (error) {
+      expect((error as TomlParseError).line).toBe(2);
+    }
+  });
+});